@@ -0,0 +1,246 @@
+//! Context-aware text chunking for long inputs
+//!
+//! Chunk sizes are derived from the active model's context window so that
+//! large-context models receive fewer, bigger chunks.
+
+use super::traits::{LlmConfig, LlmProvider};
+
+/// Tokens reserved for the system prompt and instructions around each chunk
+pub const PROMPT_OVERHEAD_TOKENS: usize = 1_024;
+
+/// Smallest chunk size the chunker will produce, in tokens
+const MIN_CHUNK_TOKENS: usize = 256;
+
+/// Rough token count estimate
+///
+/// ASCII text averages about four characters per token; other scripts
+/// (e.g. Japanese) are counted as one token per character to stay conservative.
+pub fn estimate_tokens(text: &str) -> usize {
+    let (ascii, other) = text.chars().fold((0usize, 0usize), |(a, o), c| {
+        if c.is_ascii() { (a + 1, o) } else { (a, o + 1) }
+    });
+    ascii.div_ceil(4) + other
+}
+
+/// Splits text into chunks that fit a token budget
+#[derive(Debug, Clone)]
+pub struct TextChunker {
+    chunk_tokens: usize,
+}
+
+impl TextChunker {
+    /// Create a chunker with an explicit per-chunk token budget
+    pub fn new(chunk_tokens: usize) -> Self {
+        Self {
+            chunk_tokens: chunk_tokens.max(MIN_CHUNK_TOKENS),
+        }
+    }
+
+    /// Create a chunker sized for a context window
+    ///
+    /// The budget is the context window minus the tokens reserved for the
+    /// completion and [`PROMPT_OVERHEAD_TOKENS`].
+    pub fn for_context(context_tokens: usize, completion_tokens: usize) -> Self {
+        Self::new(context_tokens.saturating_sub(completion_tokens + PROMPT_OVERHEAD_TOKENS))
+    }
+
+    /// Create a chunker sized for the provider's model under `config`
+    pub fn for_provider<P: LlmProvider + ?Sized>(provider: &P, config: &LlmConfig) -> Self {
+        let completion_tokens = config.max_tokens.unwrap_or(0) as usize;
+        Self::for_context(provider.effective_context_tokens(config), completion_tokens)
+    }
+
+    /// Per-chunk token budget
+    pub fn chunk_tokens(&self) -> usize {
+        self.chunk_tokens
+    }
+
+    /// Split text into chunks, preferring paragraph boundaries
+    ///
+    /// Paragraphs larger than the budget are split between words, and runs
+    /// without whitespace that alone exceed it (e.g. Japanese text or a long
+    /// URL) by character count.
+    pub fn chunk(&self, text: &str) -> Vec<String> {
+        let mut chunks = Vec::new();
+        let mut current = String::new();
+        let mut current_tokens = 0;
+
+        for paragraph in text.split("\n\n").filter(|p| !p.trim().is_empty()) {
+            let tokens = estimate_tokens(paragraph);
+
+            if current_tokens + tokens > self.chunk_tokens && !current.is_empty() {
+                chunks.push(std::mem::take(&mut current));
+                current_tokens = 0;
+            }
+
+            if tokens > self.chunk_tokens {
+                chunks.extend(self.split_oversized(paragraph));
+                continue;
+            }
+
+            if !current.is_empty() {
+                current.push_str("\n\n");
+            }
+            current.push_str(paragraph);
+            current_tokens += tokens;
+        }
+
+        if !current.is_empty() {
+            chunks.push(current);
+        }
+        chunks
+    }
+
+    /// Split a single oversized paragraph into budget-sized pieces
+    fn split_oversized(&self, paragraph: &str) -> Vec<String> {
+        let mut pieces = Vec::new();
+        let mut piece = String::new();
+        let mut piece_tokens = 0;
+
+        for word in paragraph.split_inclusive(char::is_whitespace) {
+            let units = if estimate_tokens(word) > self.chunk_tokens {
+                Self::char_runs(word, self.chunk_tokens)
+            } else {
+                vec![word]
+            };
+            for unit in units {
+                let tokens = estimate_tokens(unit);
+                if piece_tokens + tokens > self.chunk_tokens && !piece.is_empty() {
+                    pieces.push(std::mem::take(&mut piece));
+                    piece_tokens = 0;
+                }
+                piece.push_str(unit);
+                piece_tokens += tokens;
+            }
+        }
+
+        if !piece.is_empty() {
+            pieces.push(piece);
+        }
+        pieces
+    }
+
+    /// Split text into runs of at most `max_chars` characters
+    ///
+    /// A character is at most one estimated token, so each run fits a budget
+    /// of `max_chars` tokens.
+    fn char_runs(text: &str, max_chars: usize) -> Vec<&str> {
+        let mut runs = Vec::new();
+        let mut start = 0;
+        for (count, (index, _)) in text.char_indices().enumerate() {
+            if count > 0 && count % max_chars == 0 {
+                runs.push(&text[start..index]);
+                start = index;
+            }
+        }
+        runs.push(&text[start..]);
+        runs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::traits::Message;
+    use crate::shared::errors::AppResult;
+    use async_trait::async_trait;
+
+    struct ModelProvider(&'static str);
+
+    #[async_trait]
+    impl LlmProvider for ModelProvider {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn default_model(&self) -> &str {
+            self.0
+        }
+
+        async fn complete(
+            &self,
+            _messages: Vec<Message>,
+            _config: &LlmConfig,
+        ) -> AppResult<String> {
+            Ok(String::new())
+        }
+    }
+
+    fn long_text() -> String {
+        (0..2_000)
+            .map(|i| {
+                format!(
+                    "Paragraph {} discusses the proposed attention mechanism in detail.",
+                    i
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    #[test]
+    fn test_large_context_model_produces_fewer_chunks() {
+        let text = long_text();
+        let config = LlmConfig::default();
+
+        let small = TextChunker::for_provider(&ModelProvider("gpt-4"), &config);
+        let large = TextChunker::for_provider(&ModelProvider("claude-sonnet-4-20250514"), &config);
+
+        let small_chunks = small.chunk(&text);
+        let large_chunks = large.chunk(&text);
+
+        assert!(small_chunks.len() > 1);
+        assert!(large_chunks.len() < small_chunks.len());
+        assert!(
+            small_chunks
+                .iter()
+                .all(|c| estimate_tokens(c) <= small.chunk_tokens())
+        );
+    }
+
+    #[test]
+    fn test_context_window_override() {
+        let provider = ModelProvider("claude-sonnet-4-20250514");
+        let config = LlmConfig::default().with_context_window(8_192);
+
+        let chunker = TextChunker::for_provider(&provider, &config);
+        assert_eq!(
+            chunker.chunk_tokens(),
+            8_192 - 4_096 - PROMPT_OVERHEAD_TOKENS
+        );
+    }
+
+    #[test]
+    fn test_oversized_paragraph_is_split() {
+        let chunker = TextChunker::new(MIN_CHUNK_TOKENS);
+        let paragraph = "word ".repeat(2_000);
+
+        let chunks = chunker.chunk(&paragraph);
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), paragraph);
+    }
+
+    #[test]
+    fn test_paragraph_without_whitespace_is_split() {
+        let chunker = TextChunker::new(MIN_CHUNK_TOKENS);
+        let paragraph = "本研究では注意機構のみに基づく新しいモデルを提案する。".repeat(100);
+
+        let chunks = chunker.chunk(&paragraph);
+        assert!(chunks.len() > 1);
+        assert!(
+            chunks
+                .iter()
+                .all(|c| estimate_tokens(c) <= chunker.chunk_tokens())
+        );
+        assert_eq!(chunks.concat(), paragraph);
+
+        // A long URL inside English text is split too
+        let url = format!("https://example.com/{}", "a".repeat(5_000));
+        let chunks = chunker.chunk(&format!("See {} for details.", url));
+        assert!(
+            chunks
+                .iter()
+                .all(|c| estimate_tokens(c) <= chunker.chunk_tokens())
+        );
+    }
+}
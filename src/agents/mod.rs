@@ -1,10 +1,12 @@
 //! Agents module for LLM-powered paper analysis
 //!
 //! This module provides:
-//! - LLM provider traits and implementations (OpenAI, Anthropic, Ollama)
+//! - LLM provider traits and implementations (OpenAI, Azure OpenAI, OpenAI-compatible, Anthropic, Bedrock, Cohere, Ollama)
 //! - Paper analysis agents
 //! - Prompt templates for structured analysis
+//! - Context-aware text chunking
 
+mod chunking;
 mod paper_analyzer;
 mod prompts;
 mod traits;
@@ -12,9 +14,19 @@ mod traits;
 pub mod providers;
 
 // Re-export main types
-pub use paper_analyzer::{PaperAnalyzer, PaperAnalyzerBuilder};
-pub use prompts::PromptTemplates;
-pub use traits::{AnalysisAgent, LlmConfig, LlmProvider, Message, MessageRole};
+pub use chunking::{TextChunker, estimate_tokens};
+pub use paper_analyzer::{
+    DEFAULT_MAX_TEXT_CHARS, DEFAULT_SECTION_CONCURRENCY, DynPaperAnalyzer, PaperAnalyzer,
+    PaperAnalyzerBuilder, SectionSummaries,
+};
+pub use prompts::{AnalysisLanguage, PromptConfig, PromptTemplates};
+pub use traits::{
+    AnalysisAgent, DEFAULT_CONTEXT_TOKENS, EmbeddingProvider, LlmConfig, LlmProvider, Message,
+    MessageRole, ResponseFormat, context_window_for_model,
+};
 
 // Re-export providers for convenience
-pub use providers::{AnthropicProvider, OllamaProvider, OpenAiProvider};
+pub use providers::{
+    AnthropicProvider, AzureOpenAiProvider, BedrockProvider, CohereProvider, MockProvider,
+    OllamaProvider, OpenAiCompatibleProvider, OpenAiProvider, RetryingProvider, build_provider,
+};
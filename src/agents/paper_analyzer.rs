@@ -1,12 +1,18 @@
 //! Paper analysis agent implementation
 
-use super::prompts::PromptTemplates;
-use super::traits::{AnalysisAgent, LlmConfig, LlmProvider, Message};
-use crate::export::{KeywordsData, ResearchContext, TechnicalTerm};
-use crate::models::{AcademicPaper, DatasetInfo, PaperAnalysis};
-use crate::shared::errors::AppResult;
+use super::chunking::TextChunker;
+use super::prompts::{AnalysisLanguage, PromptConfig, PromptTemplates};
+use super::traits::{AnalysisAgent, LlmConfig, LlmProvider, Message, ResponseFormat};
+use crate::client::{PaperClient, PaperId};
+use crate::export::{KeywordsData, ResearchContext, SectionSummary, TechnicalTerm};
+use crate::models::{
+    AcademicPaper, ComparisonVerdict, DatasetInfo, ENGLISH_LANGUAGE_CODE, PaperAnalysis,
+    PaperComparison, PaperSection, PaperText, SectionImportance,
+};
+use crate::shared::errors::{AppError, AppResult};
 use async_trait::async_trait;
 use chrono::Local;
+use futures::{Stream, StreamExt};
 use serde::Deserialize;
 
 /// Response structure for dataset information from LLM
@@ -84,10 +90,44 @@ struct ResearchContextResponse {
     related_directions: Vec<String>,
 }
 
+/// Response structure for a two-paper comparison
+#[derive(Debug, Deserialize)]
+struct ComparisonResponse {
+    #[serde(default)]
+    shared_tasks: Vec<String>,
+    #[serde(default)]
+    methodological_differences: Vec<String>,
+    #[serde(default)]
+    advances: ComparisonVerdict,
+    #[serde(default)]
+    rationale: String,
+}
+
+/// Default character budget for extracted text in full-text analysis prompts
+pub const DEFAULT_MAX_TEXT_CHARS: usize = 60_000;
+
+/// Default number of section summaries requested at once
+pub const DEFAULT_SECTION_CONCURRENCY: usize = 4;
+
+/// Result of [`PaperAnalyzer::summarize_sections`]
+#[derive(Debug, Default)]
+pub struct SectionSummaries {
+    /// Summaries that succeeded, in document order
+    pub summaries: Vec<SectionSummary>,
+
+    /// Title and error of each section that could not be summarized
+    pub failures: Vec<(String, AppError)>,
+}
+
 /// Paper analysis agent that uses LLM for analysis
 pub struct PaperAnalyzer<P: LlmProvider> {
     provider: P,
     config: LlmConfig,
+    prompts: PromptConfig,
+    max_text_chars: usize,
+    section_concurrency: usize,
+    structured_output: bool,
+    translate_abstracts: bool,
 }
 
 impl<P: LlmProvider> PaperAnalyzer<P> {
@@ -96,9 +136,60 @@ impl<P: LlmProvider> PaperAnalyzer<P> {
         Self {
             provider,
             config: LlmConfig::default(),
+            prompts: PromptConfig::default(),
+            max_text_chars: DEFAULT_MAX_TEXT_CHARS,
+            section_concurrency: DEFAULT_SECTION_CONCURRENCY,
+            structured_output: false,
+            translate_abstracts: false,
         }
     }
 
+    /// Translate non-English abstracts to English in `analyze_and_update`
+    ///
+    /// Off by default; see [`ensure_english_abstract`](Self::ensure_english_abstract).
+    pub fn with_abstract_translation(mut self, enabled: bool) -> Self {
+        self.translate_abstracts = enabled;
+        self
+    }
+
+    /// Set how many section summaries are requested at once (at least 1)
+    pub fn with_section_concurrency(mut self, limit: usize) -> Self {
+        self.section_concurrency = limit.max(1);
+        self
+    }
+
+    /// Ask the provider to enforce the analysis JSON schema
+    ///
+    /// Only providers supporting structured outputs (OpenAI) enforce it;
+    /// others keep relying on the JSON format described in the prompt.
+    pub fn with_structured_output(mut self, enabled: bool) -> Self {
+        self.structured_output = enabled;
+        self
+    }
+
+    /// Override the system prompt and/or analysis prompt templates
+    ///
+    /// Replaces the whole prompt configuration, including its language.
+    pub fn with_prompts(mut self, prompts: PromptConfig) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Select the language of the default prompts (Japanese by default)
+    ///
+    /// Prompt overrides are used as-is; only the templates they fall back to
+    /// change. Analysis metadata such as `provider`/`model` is unaffected.
+    pub fn with_language(mut self, language: AnalysisLanguage) -> Self {
+        self.prompts.language = language;
+        self
+    }
+
+    /// Set the character budget for extracted text used by full-text analysis
+    pub fn with_max_text_chars(mut self, max_chars: usize) -> Self {
+        self.max_text_chars = max_chars;
+        self
+    }
+
     /// Configure the analyzer with custom LLM settings
     pub fn with_config(mut self, config: LlmConfig) -> Self {
         self.config = config;
@@ -126,24 +217,372 @@ impl<P: LlmProvider> PaperAnalyzer<P> {
         config
     }
 
+    /// Cut `text` to the first chunk that fits the model's context window
+    ///
+    /// The chunk budget comes from [`TextChunker::for_provider`], so a small
+    /// context model gets a shorter excerpt than the character budget alone
+    /// would allow.
+    fn fit_to_context(&self, text: &str) -> String {
+        TextChunker::for_provider(&self.provider, &self.effective_config())
+            .chunk(text)
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// Effective config for calls that expect a JSON object back
+    fn json_config(&self) -> LlmConfig {
+        self.effective_config()
+            .with_response_format(ResponseFormat::Json)
+    }
+
     /// Analyze a paper and update it with the analysis
+    ///
+    /// With [`with_abstract_translation`](Self::with_abstract_translation),
+    /// a non-English abstract is translated first.
     pub async fn analyze_and_update(&self, paper: &mut AcademicPaper) -> AppResult<()> {
+        if self.translate_abstracts {
+            self.ensure_english_abstract(paper).await?;
+        }
         let analysis = self.analyze(paper).await?;
         paper.set_analysis(analysis);
         Ok(())
     }
 
-    /// Extract keywords, topics, and technical terms from a paper
-    pub async fn extract_keywords(&self, paper: &AcademicPaper) -> AppResult<KeywordsData> {
+    /// Analyze a paper from its extracted full text when available, else from its abstract
+    ///
+    /// The analysis is stored on the paper. With
+    /// [`with_abstract_translation`](Self::with_abstract_translation), a
+    /// non-English abstract is translated first on either path. See
+    /// [`analyze_full_text`](Self::analyze_full_text) and
+    /// [`analyze_and_update`](Self::analyze_and_update).
+    pub async fn analyze_paper(&self, paper: &mut AcademicPaper) -> AppResult<()> {
+        if self.translate_abstracts {
+            self.ensure_english_abstract(paper).await?;
+        }
+        let analysis = if paper.has_extracted_text() {
+            self.analyze_full_text(paper).await?
+        } else {
+            self.analyze(paper).await?
+        };
+        paper.set_analysis(analysis);
+        Ok(())
+    }
+
+    /// Fetch and analyze the papers in `ids`, at most `concurrency` at a time
+    ///
+    /// Each paper is analyzed with [`analyze_paper`](Self::analyze_paper) as
+    /// soon as [`PaperClient::fetch_by_paper_id`] returns it, so no more than
+    /// `concurrency` papers and their extracted text are held at once. Results
+    /// are yielded as they complete, tagged with the index of their ID in
+    /// `ids`; a failed fetch or analysis affects only its own paper.
+    pub fn analyze_batch<'a>(
+        &'a self,
+        client: &'a PaperClient,
+        ids: &'a [PaperId],
+        concurrency: usize,
+    ) -> impl Stream<Item = (usize, AppResult<AcademicPaper>)> + 'a {
+        futures::stream::iter(ids.iter().enumerate())
+            .map(move |(index, id)| async move {
+                let result: AppResult<AcademicPaper> = async {
+                    let mut paper = client.fetch_by_paper_id(id).await?;
+                    self.analyze_paper(&mut paper).await?;
+                    Ok(paper)
+                }
+                .await;
+                (index, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+
+    /// Translate the abstract to English if it is reliably detected as another language
+    ///
+    /// The original text is kept in `abstract_text_original`. Returns whether
+    /// the abstract was translated; undetectable or English abstracts are left
+    /// untouched, as are papers translated before.
+    pub async fn ensure_english_abstract(&self, paper: &mut AcademicPaper) -> AppResult<bool> {
+        if !paper.abstract_text_original.is_empty() {
+            return Ok(false);
+        }
+        match paper.detect_abstract_language() {
+            Some(language) if language != ENGLISH_LANGUAGE_CODE => {
+                tracing::info!(
+                    "Translating {} abstract of '{}' to English",
+                    language,
+                    paper.title
+                );
+                let messages = vec![
+                    Message::system(PromptTemplates::english_translation_system()),
+                    Message::user(PromptTemplates::translation_prompt(
+                        &paper.abstract_text,
+                        "English",
+                    )),
+                ];
+                let translated = self
+                    .provider
+                    .complete(messages, &self.effective_config())
+                    .await?;
+                paper.abstract_text_original =
+                    std::mem::replace(&mut paper.abstract_text, translated.trim().to_string());
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Analyze a paper using its extracted full text
+    ///
+    /// When `paper.has_extracted_text()`, the prompt is built from the critical
+    /// and high-importance sections (Method, Experiments, Results, ...) within
+    /// the configured character budget, cut further to fit the model's context
+    /// window. Otherwise falls back to
+    /// [`analyze`](AnalysisAgent::analyze), which uses the abstract only.
+    pub async fn analyze_full_text(&self, paper: &AcademicPaper) -> AppResult<PaperAnalysis> {
+        let Some(text) = paper.extracted_text.as_ref().filter(|t| t.is_valid()) else {
+            return self.analyze(paper).await;
+        };
+
+        let body = self.fit_to_context(&Self::build_full_text_body(text, self.max_text_chars));
+        if body.is_empty() {
+            return self.analyze(paper).await;
+        }
+
         let messages = vec![
-            Message::system(PromptTemplates::system_prompt()),
-            Message::user(PromptTemplates::keyword_extraction_prompt(
+            Message::system(self.prompts.system_prompt()),
+            Message::user(self.prompts.full_analysis_prompt_with_text(
                 &paper.title,
                 &paper.abstract_text,
+                &body,
             )),
         ];
+        self.run_analysis(messages).await
+    }
+
+    /// Summarize each critical/high-importance section in 1-2 sentences
+    ///
+    /// Requires extracted text. At most `section_concurrency` requests are in
+    /// flight at once, and the summaries keep the document order of their
+    /// sections. Section bodies are cut to the character budget and to the
+    /// model's context window. A failed
+    /// section does not discard the others; it is listed in `failures`.
+    pub async fn summarize_sections(&self, paper: &AcademicPaper) -> AppResult<SectionSummaries> {
+        let Some(text) = paper.extracted_text.as_ref().filter(|t| t.is_valid()) else {
+            return Err(AppError::AnalysisError(
+                "No extracted text to summarize".to_string(),
+            ));
+        };
 
         let config = self.effective_config();
+        let requests = text
+            .sections
+            .iter()
+            .filter(|s| {
+                matches!(
+                    s.importance,
+                    SectionImportance::Critical | SectionImportance::High
+                ) && !s.content.trim().is_empty()
+            })
+            .map(|section| {
+                let content = section.content.trim();
+                let content = self
+                    .fit_to_context(&content[..content.floor_char_boundary(self.max_text_chars)]);
+                let messages = vec![
+                    Message::system(self.prompts.system_prompt()),
+                    Message::user(self.prompts.section_summary_prompt(
+                        &paper.title,
+                        &section.title,
+                        &content,
+                    )),
+                ];
+                let config = &config;
+                async move {
+                    let result = self.provider.complete(messages, config).await;
+                    (section.title.clone(), result)
+                }
+            });
+
+        let mut results = SectionSummaries::default();
+        let mut responses =
+            std::pin::pin!(futures::stream::iter(requests).buffered(self.section_concurrency));
+        while let Some((section_title, result)) = responses.next().await {
+            match result {
+                Ok(summary) => results.summaries.push(SectionSummary {
+                    section_title,
+                    summary: summary.trim().to_string(),
+                }),
+                Err(e) => results.failures.push((section_title, e)),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Build the prompt body from critical/high-importance sections
+    ///
+    /// Sections are chosen with [`PaperText::select_sections_within_budget_by`],
+    /// counting their headings, and keep their document order. If no section
+    /// fits whole, the first important section is cut at `max_chars` instead.
+    fn build_full_text_body(text: &PaperText, max_chars: usize) -> String {
+        const SEPARATOR: &str = "\n\n";
+        let is_important = |s: &PaperSection| {
+            matches!(
+                s.importance,
+                SectionImportance::Critical | SectionImportance::High
+            )
+        };
+        let render = |s: &PaperSection| format!("## {}\n{}", s.title, s.content);
+
+        // Charge every section its heading and a separator, so the joined
+        // body stays within the budget; other sections never fit
+        let selected = text.select_sections_within_budget_by(max_chars, |s| {
+            if is_important(s) {
+                "## \n".len() + s.title.len() + s.content.len() + SEPARATOR.len()
+            } else {
+                usize::MAX
+            }
+        });
+
+        if selected.is_empty() {
+            let Some(section) = text.sections.iter().find(|s| is_important(s)) else {
+                return String::new();
+            };
+            let mut body = render(section);
+            body.truncate(body.floor_char_boundary(max_chars));
+            return body;
+        }
+
+        selected
+            .into_iter()
+            .map(render)
+            .collect::<Vec<_>>()
+            .join(SEPARATOR)
+    }
+
+    /// Send an analysis prompt and convert the JSON response
+    async fn run_analysis(&self, messages: Vec<Message>) -> AppResult<PaperAnalysis> {
+        let mut config = self.json_config();
+        if self.structured_output {
+            config = config.with_json_schema(PromptTemplates::analysis_json_schema());
+        }
+        let (response, stats): (AnalysisResponse, _) = self
+            .provider
+            .complete_json_with_stats(messages, &config)
+            .await?;
+
+        let mut analysis = PaperAnalysis {
+            summary: response.summary,
+            background_and_purpose: response.background_and_purpose,
+            methodology: response.methodology,
+            datasets: response
+                .datasets
+                .into_iter()
+                .map(DatasetInfo::from)
+                .filter(|d| d.is_valid())
+                .collect(),
+            results: response.results,
+            advantages_limitations_and_future_work: response.advantages_limitations_and_future_work,
+            key_contributions: response.key_contributions,
+            tasks: response.tasks,
+            analyzed_at: Local::now(),
+            provider: self.provider.name().to_string(),
+            model: config.model,
+            warnings: Vec::new(),
+            request_stats: Some(stats),
+        };
+        analysis.strip_placeholders();
+        Ok(analysis)
+    }
+
+    /// Analyze a paper, degrading gracefully when the JSON response is unusable
+    ///
+    /// Tries [`analyze`](AnalysisAgent::analyze) first. If the combined JSON
+    /// response cannot be parsed or does not match the expected structure
+    /// ([`AppError::LlmResponseParse`], common with smaller local models), the
+    /// summary, methodology, key contributions and tasks are requested with
+    /// separate, simpler prompts and assembled into a best-effort
+    /// [`PaperAnalysis`]. Fields that could not be generated stay empty and are
+    /// described in `warnings`. Any other error (authentication, network,
+    /// rate limits, refusals) is returned as-is.
+    pub async fn analyze_lenient(&self, paper: &AcademicPaper) -> AppResult<PaperAnalysis> {
+        match self.analyze(paper).await {
+            Err(AppError::LlmResponseParse(reason)) => {
+                tracing::warn!(
+                    "Combined analysis failed for '{}', falling back to separate prompts: {}",
+                    paper.title,
+                    reason
+                );
+                self.analyze_piecewise(paper, reason).await
+            }
+            result => result,
+        }
+    }
+
+    /// Assemble an analysis from separate summary/methodology/list prompts
+    async fn analyze_piecewise(
+        &self,
+        paper: &AcademicPaper,
+        reason: String,
+    ) -> AppResult<PaperAnalysis> {
+        let (summary, methodology, key_contributions, tasks) = futures::join!(
+            self.generate_summary(paper),
+            self.generate_methodology(paper),
+            self.extract_key_contributions(paper),
+            self.extract_research_tasks(paper),
+        );
+
+        if summary.is_err() && methodology.is_err() && key_contributions.is_err() && tasks.is_err()
+        {
+            return Err(AppError::AnalysisError(format!(
+                "Combined analysis failed ({}) and no field could be generated separately",
+                reason
+            )));
+        }
+
+        let mut warnings = vec![format!(
+            "Combined analysis response was unusable; fields were generated separately ({})",
+            reason
+        )];
+        let summary = lenient_field("summary", summary, &mut warnings);
+        let methodology = lenient_field("methodology", methodology, &mut warnings);
+        let key_contributions =
+            lenient_field("key_contributions", key_contributions, &mut warnings);
+        let tasks = lenient_field("tasks", tasks, &mut warnings);
+
+        let mut analysis = PaperAnalysis {
+            summary: summary.trim().to_string(),
+            methodology: methodology.trim().to_string(),
+            key_contributions,
+            tasks,
+            analyzed_at: Local::now(),
+            provider: self.provider.name().to_string(),
+            model: self.effective_config().model,
+            warnings,
+            ..Default::default()
+        };
+        analysis.strip_placeholders();
+        Ok(analysis)
+    }
+
+    /// Send a prompt expecting a JSON array of strings
+    async fn complete_string_list(&self, prompt: String) -> AppResult<Vec<String>> {
+        let messages = vec![
+            Message::system(self.prompts.system_prompt()),
+            Message::user(prompt),
+        ];
+        let config = self.effective_config();
+        self.provider.complete_json(messages, &config).await
+    }
+
+    /// Extract keywords, topics, and technical terms from a paper
+    pub async fn extract_keywords(&self, paper: &AcademicPaper) -> AppResult<KeywordsData> {
+        let messages = vec![
+            Message::system(self.prompts.system_prompt()),
+            Message::user(
+                self.prompts
+                    .keyword_extraction_prompt(&paper.title, &paper.abstract_text),
+            ),
+        ];
+
+        let config = self.json_config();
         let response: KeywordsResponse = self.provider.complete_json(messages, &config).await?;
 
         Ok(KeywordsData {
@@ -169,15 +608,15 @@ impl<P: LlmProvider> PaperAnalyzer<P> {
         keywords: &[String],
     ) -> AppResult<ResearchContext> {
         let messages = vec![
-            Message::system(PromptTemplates::system_prompt()),
-            Message::user(PromptTemplates::research_context_prompt(
+            Message::system(self.prompts.system_prompt()),
+            Message::user(self.prompts.research_context_prompt(
                 &paper.title,
                 &paper.abstract_text,
                 keywords,
             )),
         ];
 
-        let config = self.effective_config();
+        let config = self.json_config();
         let response: ResearchContextResponse =
             self.provider.complete_json(messages, &config).await?;
 
@@ -189,49 +628,123 @@ impl<P: LlmProvider> PaperAnalyzer<P> {
             related_directions: response.related_directions,
         })
     }
-}
 
-#[async_trait]
-impl<P: LlmProvider> AnalysisAgent for PaperAnalyzer<P> {
-    async fn analyze(&self, paper: &AcademicPaper) -> AppResult<PaperAnalysis> {
+    /// Compare two papers head to head from their titles and abstracts
+    ///
+    /// In the result, paper `a` is "A" and paper `b` is "B".
+    pub async fn compare_papers(
+        &self,
+        a: &AcademicPaper,
+        b: &AcademicPaper,
+    ) -> AppResult<PaperComparison> {
         let messages = vec![
-            Message::system(PromptTemplates::system_prompt()),
-            Message::user(PromptTemplates::full_analysis_prompt(
-                &paper.title,
-                &paper.abstract_text,
+            Message::system(self.prompts.system_prompt()),
+            Message::user(self.prompts.comparison_prompt(
+                &a.title,
+                &a.abstract_text,
+                &b.title,
+                &b.abstract_text,
             )),
         ];
 
-        let config = self.effective_config();
-        let response: AnalysisResponse = self.provider.complete_json(messages, &config).await?;
+        let config = self.json_config();
+        let response: ComparisonResponse = self.provider.complete_json(messages, &config).await?;
 
-        Ok(PaperAnalysis {
-            summary: response.summary,
-            background_and_purpose: response.background_and_purpose,
-            methodology: response.methodology,
-            datasets: response
-                .datasets
-                .into_iter()
-                .map(DatasetInfo::from)
-                .filter(|d| d.is_valid())
-                .collect(),
-            results: response.results,
-            advantages_limitations_and_future_work: response.advantages_limitations_and_future_work,
-            key_contributions: response.key_contributions,
-            tasks: response.tasks,
-            analyzed_at: Local::now(),
+        Ok(PaperComparison {
+            paper_a: a.title.clone(),
+            paper_b: b.title.clone(),
+            shared_tasks: response.shared_tasks,
+            methodological_differences: response.methodological_differences,
+            advances: response.advances,
+            rationale: response.rationale,
+            compared_at: Local::now(),
             provider: self.provider.name().to_string(),
             model: config.model,
         })
     }
 
+    /// Write one thematic synthesis across several papers
+    ///
+    /// Papers are numbered `[1]`, `[2]`, ... in input order, and their
+    /// abstracts are shortened to share the configured character budget so the
+    /// prompt stays within context however many papers are given. `focus`
+    /// narrows the synthesis to a topic. Returns prose suitable for a
+    /// related-work paragraph.
+    pub async fn synthesize(
+        &self,
+        papers: &[AcademicPaper],
+        focus: Option<&str>,
+    ) -> AppResult<String> {
+        if papers.is_empty() {
+            return Err(AppError::AnalysisError(
+                "No papers to synthesize".to_string(),
+            ));
+        }
+
+        let body = Self::build_synthesis_body(papers, self.max_text_chars);
+        let messages = vec![
+            Message::system(self.prompts.system_prompt()),
+            Message::user(self.prompts.synthesis_prompt(&body, focus)),
+        ];
+
+        let config = self.effective_config();
+        let synthesis = self.provider.complete(messages, &config).await?;
+        Ok(synthesis.trim().to_string())
+    }
+
+    /// Number the papers and fit their abstracts into `max_chars`
+    ///
+    /// Each paper gets an equal share of the budget; an abstract longer than
+    /// what is left of its share after the title is cut and marked with "…".
+    fn build_synthesis_body(papers: &[AcademicPaper], max_chars: usize) -> String {
+        let share = max_chars / papers.len().max(1);
+        papers
+            .iter()
+            .enumerate()
+            .map(|(i, paper)| {
+                let header = format!("[{}] {}", i + 1, paper.title.trim());
+                let room = share.saturating_sub(header.len() + 1);
+                let mut abstract_text = paper.abstract_text.trim().to_string();
+                if abstract_text.len() > room {
+                    let cut = room.saturating_sub('…'.len_utf8());
+                    abstract_text.truncate(abstract_text.floor_char_boundary(cut));
+                    abstract_text.push('…');
+                }
+                format!("{}\n{}", header, abstract_text)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Unwrap a separately generated field, recording a warning on failure
+fn lenient_field<T: Default>(field: &str, result: AppResult<T>, warnings: &mut Vec<String>) -> T {
+    result.unwrap_or_else(|e| {
+        warnings.push(format!("{} could not be generated: {}", field, e));
+        T::default()
+    })
+}
+
+#[async_trait]
+impl<P: LlmProvider> AnalysisAgent for PaperAnalyzer<P> {
+    async fn analyze(&self, paper: &AcademicPaper) -> AppResult<PaperAnalysis> {
+        let messages = vec![
+            Message::system(self.prompts.system_prompt()),
+            Message::user(
+                self.prompts
+                    .full_analysis_prompt(&paper.title, &paper.abstract_text),
+            ),
+        ];
+        self.run_analysis(messages).await
+    }
+
     async fn generate_summary(&self, paper: &AcademicPaper) -> AppResult<String> {
         let messages = vec![
-            Message::system(PromptTemplates::system_prompt()),
-            Message::user(PromptTemplates::summary_prompt(
-                &paper.title,
-                &paper.abstract_text,
-            )),
+            Message::system(self.prompts.system_prompt()),
+            Message::user(
+                self.prompts
+                    .summary_prompt(&paper.title, &paper.abstract_text),
+            ),
         ];
 
         let config = self.effective_config();
@@ -240,17 +753,33 @@ impl<P: LlmProvider> AnalysisAgent for PaperAnalyzer<P> {
 
     async fn generate_methodology(&self, paper: &AcademicPaper) -> AppResult<String> {
         let messages = vec![
-            Message::system(PromptTemplates::system_prompt()),
-            Message::user(PromptTemplates::methodology_prompt(
-                &paper.title,
-                &paper.abstract_text,
-            )),
+            Message::system(self.prompts.system_prompt()),
+            Message::user(
+                self.prompts
+                    .methodology_prompt(&paper.title, &paper.abstract_text),
+            ),
         ];
 
         let config = self.effective_config();
         self.provider.complete(messages, &config).await
     }
 
+    async fn extract_key_contributions(&self, paper: &AcademicPaper) -> AppResult<Vec<String>> {
+        self.complete_string_list(
+            self.prompts
+                .key_contributions_prompt(&paper.title, &paper.abstract_text),
+        )
+        .await
+    }
+
+    async fn extract_research_tasks(&self, paper: &AcademicPaper) -> AppResult<Vec<String>> {
+        self.complete_string_list(
+            self.prompts
+                .research_tasks_prompt(&paper.title, &paper.abstract_text),
+        )
+        .await
+    }
+
     async fn translate_to_japanese(&self, text: &str) -> AppResult<String> {
         let messages = vec![
             Message::system(PromptTemplates::japanese_translation_system()),
@@ -262,10 +791,27 @@ impl<P: LlmProvider> AnalysisAgent for PaperAnalyzer<P> {
     }
 }
 
+/// Paper analyzer over a provider chosen at runtime
+///
+/// Use [`PaperAnalyzer::from_boxed`] with a provider from
+/// [`build_provider`](crate::agents::providers::build_provider); the generic
+/// form keeps static dispatch for a concrete provider type.
+pub type DynPaperAnalyzer = PaperAnalyzer<Box<dyn LlmProvider>>;
+
+impl PaperAnalyzer<Box<dyn LlmProvider>> {
+    /// Create a paper analyzer from a boxed provider
+    pub fn from_boxed(provider: Box<dyn LlmProvider>) -> Self {
+        Self::new(provider)
+    }
+}
+
 /// Builder for PaperAnalyzer with fluent API
 pub struct PaperAnalyzerBuilder<P: LlmProvider> {
     provider: P,
     config: LlmConfig,
+    prompts: PromptConfig,
+    structured_output: bool,
+    translate_abstracts: bool,
 }
 
 impl<P: LlmProvider> PaperAnalyzerBuilder<P> {
@@ -274,9 +820,36 @@ impl<P: LlmProvider> PaperAnalyzerBuilder<P> {
         Self {
             provider,
             config: LlmConfig::default(),
+            prompts: PromptConfig::default(),
+            structured_output: false,
+            translate_abstracts: false,
         }
     }
 
+    /// Translate non-English abstracts to English before analysis
+    pub fn abstract_translation(mut self, enabled: bool) -> Self {
+        self.translate_abstracts = enabled;
+        self
+    }
+
+    /// Enforce the analysis JSON schema where the provider supports it
+    pub fn structured_output(mut self, enabled: bool) -> Self {
+        self.structured_output = enabled;
+        self
+    }
+
+    /// Set prompt overrides
+    pub fn prompts(mut self, prompts: PromptConfig) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Set the language of the default prompts
+    pub fn language(mut self, language: AnalysisLanguage) -> Self {
+        self.prompts.language = language;
+        self
+    }
+
     /// Set temperature
     pub fn temperature(mut self, temp: f32) -> Self {
         self.config.temperature = Some(temp);
@@ -300,6 +873,11 @@ impl<P: LlmProvider> PaperAnalyzerBuilder<P> {
         PaperAnalyzer {
             provider: self.provider,
             config: self.config,
+            prompts: self.prompts,
+            max_text_chars: DEFAULT_MAX_TEXT_CHARS,
+            section_concurrency: DEFAULT_SECTION_CONCURRENCY,
+            structured_output: self.structured_output,
+            translate_abstracts: self.translate_abstracts,
         }
     }
 }
@@ -307,70 +885,547 @@ impl<P: LlmProvider> PaperAnalyzerBuilder<P> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    // Mock provider for testing
-    struct MockProvider;
+    use crate::agents::providers::{MockProvider, RetryingProvider};
 
-    #[async_trait]
-    impl LlmProvider for MockProvider {
-        fn name(&self) -> &str {
-            "mock"
-        }
+    // Messages and config of the most recent request
+    fn last_request(provider: &MockProvider) -> (Vec<Message>, LlmConfig) {
+        provider.requests().pop().expect("no request recorded")
+    }
 
-        fn default_model(&self) -> &str {
-            "mock-model"
-        }
+    // User prompt of the most recent request
+    fn last_prompt(provider: &MockProvider) -> String {
+        let (messages, _) = last_request(provider);
+        messages
+            .last()
+            .map(|m| m.content.clone())
+            .unwrap_or_default()
+    }
 
-        async fn complete(
-            &self,
-            _messages: Vec<Message>,
-            _config: &LlmConfig,
-        ) -> AppResult<String> {
-            Ok(r#"{
-                "summary": "Test summary",
-                "background_and_purpose": "Test background",
-                "methodology": "Test methodology",
-                "datasets": [
-                    {
-                        "name": "Test Dataset",
-                        "url": "https://example.com/dataset",
-                        "paper_title": "Test Paper",
-                        "paper_url": "https://example.com/paper",
-                        "paper_authors": "Test Author",
-                        "description": "A test dataset",
-                        "domain": "NLP",
-                        "size": "10K samples"
-                    }
-                ],
-                "results": "Test results",
-                "advantages_limitations_and_future_work": "Test advantages",
-                "key_contributions": ["contribution 1"],
-                "tasks": ["task 1"]
-            }"#
-            .to_string())
-        }
+    // Paper with a title and a short abstract, shared by the tests below
+    fn test_paper() -> AcademicPaper {
+        let mut paper = AcademicPaper::new();
+        paper.title = "Test Paper".to_string();
+        paper.abstract_text = "Test abstract".to_string();
+        paper
     }
 
     #[tokio::test]
     async fn test_paper_analyzer_creation() {
-        let provider = MockProvider;
+        let provider = MockProvider::new();
         let analyzer = PaperAnalyzer::new(provider);
         assert_eq!(analyzer.config.temperature, None);
     }
 
     #[tokio::test]
     async fn test_analyze_paper() {
-        let provider = MockProvider;
+        let provider = MockProvider::new();
         let analyzer = PaperAnalyzer::new(provider);
 
-        let mut paper = AcademicPaper::new();
-        paper.title = "Test Paper".to_string();
-        paper.abstract_text = "Test abstract".to_string();
+        let paper = test_paper();
 
         let result = analyzer.analyze(&paper).await;
         assert!(result.is_ok());
 
         let analysis = result.unwrap();
-        assert_eq!(analysis.summary, "Test summary");
+        assert_eq!(analysis.summary, "[mock] Test abstract");
+        assert_eq!(analysis.provider, "mock");
+    }
+
+    #[tokio::test]
+    async fn test_analyze_with_boxed_provider() {
+        let analyzer = PaperAnalyzer::from_boxed(Box::new(MockProvider::new()));
+
+        let paper = test_paper();
+
+        let analysis = analyzer.analyze(&paper).await.unwrap();
+        assert_eq!(analysis.summary, "[mock] Test abstract");
+        assert_eq!(analysis.provider, "mock");
+        assert_eq!(analysis.model, "mock-model");
+    }
+
+    #[tokio::test]
+    async fn test_analysis_records_request_stats() {
+        let paper = test_paper();
+
+        let analysis = PaperAnalyzer::new(MockProvider::new())
+            .analyze(&paper)
+            .await
+            .unwrap();
+        let stats = analysis.request_stats.unwrap();
+        assert_eq!(stats.attempts, 1);
+        assert!(!stats.retried);
+
+        let mock =
+            MockProvider::new().with_failures(2, || AppError::LlmRateLimited { retry_after: None });
+        let analyzer = PaperAnalyzer::new(
+            RetryingProvider::new(mock.clone()).with_base_delay(std::time::Duration::ZERO),
+        );
+        let analysis = analyzer.analyze(&paper).await.unwrap();
+        assert_eq!(analysis.summary, "[mock] Test abstract");
+        let stats = analysis.request_stats.unwrap();
+        assert_eq!(stats.attempts, 3);
+        assert!(stats.retried);
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_full_text_uses_important_sections() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+
+        let mut paper = test_paper();
+        paper.extracted_text = Some(PaperText {
+            plain_text: "full text".to_string(),
+            sections: vec![
+                PaperSection::for_test(0, "Method", "We stack six encoder layers."),
+                PaperSection::for_test(1, "Related Work", "Prior work on RNNs."),
+                PaperSection::for_test(2, "Results", "BLEU improves by 2.0."),
+                PaperSection::for_test(3, "References", "[1] Some citation."),
+            ],
+            ..Default::default()
+        });
+
+        let analysis = analyzer.analyze_full_text(&paper).await.unwrap();
+        assert_eq!(analysis.summary, "[mock] Test abstract");
+
+        let prompt = last_prompt(&analyzer.provider);
+        assert!(prompt.contains("We stack six encoder layers."));
+        assert!(prompt.contains("BLEU improves by 2.0."));
+        assert!(!prompt.contains("Prior work on RNNs."));
+        assert!(!prompt.contains("[1] Some citation."));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_full_text_fits_context_window() {
+        let paragraphs: Vec<String> = (0..400)
+            .map(|i| format!("Paragraph {} describes the encoder stack in detail.", i))
+            .collect();
+        let mut paper = test_paper();
+        paper.extracted_text = Some(PaperText {
+            plain_text: "full text".to_string(),
+            sections: vec![PaperSection::for_test(0, "Method", paragraphs.join("\n\n"))],
+            ..Default::default()
+        });
+
+        // The whole method section fits a large context window
+        let large = PaperAnalyzer::new(MockProvider::new())
+            .with_config(LlmConfig::default().with_context_window(200_000));
+        large.analyze_full_text(&paper).await.unwrap();
+        assert!(last_prompt(&large.provider).contains("Paragraph 399 "));
+
+        // A small window keeps only the leading paragraphs
+        let small = PaperAnalyzer::new(MockProvider::new())
+            .with_config(LlmConfig::default().with_context_window(6_000));
+        small.analyze_full_text(&paper).await.unwrap();
+        let prompt = last_prompt(&small.provider);
+        assert!(prompt.contains("Paragraph 0 "));
+        assert!(!prompt.contains("Paragraph 399 "));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_paper_prefers_full_text() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+
+        let mut paper = test_paper();
+        analyzer.analyze_paper(&mut paper).await.unwrap();
+        assert!(paper.analysis.is_some());
+        assert!(!last_prompt(&analyzer.provider).contains("We stack six encoder layers."));
+
+        paper.analysis = None;
+        paper.extracted_text = Some(PaperText {
+            plain_text: "full text".to_string(),
+            sections: vec![PaperSection::for_test(
+                0,
+                "Method",
+                "We stack six encoder layers.",
+            )],
+            ..Default::default()
+        });
+        analyzer.analyze_paper(&mut paper).await.unwrap();
+        assert!(paper.analysis.is_some());
+        assert!(last_prompt(&analyzer.provider).contains("We stack six encoder layers."));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_full_text_falls_back_to_abstract() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+
+        let paper = test_paper();
+
+        analyzer.analyze_full_text(&paper).await.unwrap();
+
+        let prompt = last_prompt(&analyzer.provider);
+        assert!(prompt.contains("Test abstract"));
+        assert!(!prompt.contains("本文（主要セクションの抜粋）"));
+    }
+
+    #[test]
+    fn test_build_synthesis_body_shares_budget() {
+        let papers: Vec<AcademicPaper> = ["A", "B", "C"]
+            .iter()
+            .map(|title| {
+                let mut paper = AcademicPaper::new();
+                paper.title = title.to_string();
+                paper.abstract_text = "word ".repeat(200);
+                paper
+            })
+            .collect();
+
+        let body = PaperAnalyzer::<MockProvider>::build_synthesis_body(&papers, 300);
+        let entries: Vec<&str> = body.split("\n\n").collect();
+        assert_eq!(entries.len(), 3);
+        assert!(entries[0].starts_with("[1] A\n"));
+        assert!(entries[2].starts_with("[3] C\n"));
+        assert!(entries.iter().all(|e| e.len() <= 100 && e.ends_with('…')));
+
+        // Short abstracts are kept whole
+        let body = PaperAnalyzer::<MockProvider>::build_synthesis_body(&papers[..1], 10_000);
+        assert!(!body.ends_with('…'));
+    }
+
+    #[test]
+    fn test_build_full_text_body_respects_budget() {
+        let text = PaperText {
+            plain_text: "x".to_string(),
+            sections: vec![PaperSection::for_test(0, "Method", "a".repeat(500))],
+            ..Default::default()
+        };
+
+        let body = PaperAnalyzer::<MockProvider>::build_full_text_body(&text, 100);
+        assert_eq!(body.len(), 100);
+
+        // Headings and separators count towards the budget: the two bodies
+        // alone fit in 200 characters, but not with their headings
+        let text = PaperText {
+            plain_text: "x".to_string(),
+            sections: vec![
+                PaperSection::for_test(0, "Method", "a".repeat(100)),
+                PaperSection::for_test(1, "Results", "b".repeat(100)),
+            ],
+            ..Default::default()
+        };
+        let body = PaperAnalyzer::<MockProvider>::build_full_text_body(&text, 200);
+        assert!(body.len() <= 200);
+        assert_eq!(body, format!("## Method\n{}", "a".repeat(100)));
+
+        let body = PaperAnalyzer::<MockProvider>::build_full_text_body(&text, 300);
+        assert!(body.len() <= 300);
+        assert!(body.contains("## Results"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_system_prompt_reaches_provider() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new()).with_prompts(
+            PromptConfig::new().with_system_prompt("You are a robotics expert. Answer in English."),
+        );
+
+        let paper = test_paper();
+
+        analyzer.analyze(&paper).await.unwrap();
+
+        let system = last_request(&analyzer.provider).0[0].content.clone();
+        assert_eq!(system, "You are a robotics expert. Answer in English.");
+    }
+
+    #[tokio::test]
+    async fn test_structured_calls_request_json_mode() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+
+        let paper = test_paper();
+
+        analyzer.analyze(&paper).await.unwrap();
+        assert_eq!(
+            last_request(&analyzer.provider).1.response_format,
+            ResponseFormat::Json
+        );
+
+        // Free-text calls keep the default format
+        analyzer.generate_summary(&paper).await.unwrap();
+        assert_eq!(
+            last_request(&analyzer.provider).1.response_format,
+            ResponseFormat::Text
+        );
+    }
+
+    #[tokio::test]
+    async fn test_analyze_refusal_maps_to_clear_error() {
+        let analyzer = PaperAnalyzer::new(
+            MockProvider::new().with_reply("", "I'm sorry, but I cannot help with that request."),
+        );
+
+        let paper = test_paper();
+
+        let err = analyzer.analyze(&paper).await.unwrap_err();
+        assert!(matches!(err, crate::shared::errors::AppError::LlmError(_)));
+        assert!(
+            err.to_string()
+                .contains("model refused or returned non-content")
+        );
+    }
+
+    // Mock provider that breaks the combined JSON but answers simpler prompts
+    fn weak_json_provider(fail_tasks: bool) -> MockProvider {
+        let tasks = if fail_tasks {
+            "Machine translation, I think."
+        } else {
+            r#"["Machine Translation"]"#
+        };
+        MockProvider::new()
+            .with_reply(
+                "包括的に分析",
+                r#"{"summary": "Truncated summary", "methodology": "#,
+            )
+            .with_reply(
+                "主要な貢献を特定",
+                r#"Here you go: ["New architecture", "State-of-the-art BLEU"]"#,
+            )
+            .with_reply("研究分野とタスク", tasks)
+            .with_reply("方法論を抽出", "  Self-attention only encoder-decoder.  ")
+            .with_reply("", "A plain-text summary.")
+    }
+
+    #[tokio::test]
+    async fn test_analyze_lenient_assembles_fields_on_json_failure() {
+        let analyzer = PaperAnalyzer::new(weak_json_provider(false));
+        let paper = test_paper();
+
+        assert!(analyzer.analyze(&paper).await.is_err());
+
+        let analysis = analyzer.analyze_lenient(&paper).await.unwrap();
+        assert_eq!(analysis.summary, "A plain-text summary.");
+        assert_eq!(analysis.methodology, "Self-attention only encoder-decoder.");
+        assert_eq!(
+            analysis.key_contributions,
+            vec!["New architecture", "State-of-the-art BLEU"]
+        );
+        assert_eq!(analysis.tasks, vec!["Machine Translation"]);
+        assert!(analysis.results.is_empty());
         assert_eq!(analysis.provider, "mock");
+        assert_eq!(analysis.warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_lenient_returns_request_errors() {
+        let provider = MockProvider::new()
+            .with_failures(1, || AppError::LlmError("connection reset".to_string()));
+        let analyzer = PaperAnalyzer::new(provider.clone());
+
+        let err = analyzer.analyze_lenient(&test_paper()).await.unwrap_err();
+        assert!(matches!(err, AppError::LlmError(ref msg) if msg == "connection reset"));
+        assert_eq!(provider.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_lenient_records_failed_fields() {
+        let analyzer = PaperAnalyzer::new(weak_json_provider(true));
+
+        let analysis = analyzer.analyze_lenient(&test_paper()).await.unwrap();
+        assert!(analysis.tasks.is_empty());
+        assert_eq!(analysis.warnings.len(), 2);
+        assert!(analysis.warnings[1].starts_with("tasks could not be generated"));
+    }
+
+    // Analysis response padded with "not mentioned" placeholders
+    const PLACEHOLDER_ANALYSIS: &str = r#"{
+        "summary": "Test summary",
+        "background_and_purpose": "Test background",
+        "methodology": "Test methodology",
+        "datasets": [{"name": "記載なし"}],
+        "results": "アブストラクトに記載なし。",
+        "advantages_limitations_and_future_work": "Test advantages",
+        "key_contributions": ["New architecture", "記載なし", "Not mentioned."],
+        "tasks": ["N/A", "Machine Translation"]
+    }"#;
+
+    #[tokio::test]
+    async fn test_analyze_filters_placeholders_and_records_warnings() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new().with_reply("", PLACEHOLDER_ANALYSIS));
+
+        let analysis = analyzer.analyze(&test_paper()).await.unwrap();
+        assert_eq!(analysis.key_contributions, vec!["New architecture"]);
+        assert_eq!(analysis.tasks, vec!["Machine Translation"]);
+        assert!(analysis.datasets.is_empty());
+        assert!(analysis.results.is_empty());
+        assert_eq!(analysis.summary, "Test summary");
+
+        assert_eq!(analysis.warnings.len(), 3);
+        assert!(analysis.warnings[0].starts_with("results is not stated"));
+        assert_eq!(
+            analysis.warnings[1],
+            "Removed 2 placeholder entries from key_contributions"
+        );
+        assert_eq!(
+            analysis.warnings[2],
+            "Removed 1 placeholder entries from tasks"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ensure_english_abstract_translates_and_keeps_original() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+
+        let french = "Les modèles dominants de transduction de séquences reposent sur des \
+            réseaux de neurones récurrents ou convolutifs complexes. Nous proposons une nouvelle \
+            architecture de réseau simple, le Transformer, fondée uniquement sur des mécanismes \
+            d'attention.";
+        let mut paper = test_paper();
+        paper.abstract_text = french.to_string();
+
+        assert!(analyzer.ensure_english_abstract(&mut paper).await.unwrap());
+        assert_eq!(paper.abstract_text_original, french);
+        assert_ne!(paper.abstract_text, french);
+        assert!(last_prompt(&analyzer.provider).contains("English"));
+
+        // Already translated: nothing to do
+        assert!(!analyzer.ensure_english_abstract(&mut paper).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_paper_translates_abstract_before_full_text() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new()).with_abstract_translation(true);
+
+        let french = "Les modèles dominants de transduction de séquences reposent sur des \
+            réseaux de neurones récurrents ou convolutifs complexes. Nous proposons une nouvelle \
+            architecture de réseau simple, le Transformer, fondée uniquement sur des mécanismes \
+            d'attention.";
+        let mut paper = test_paper();
+        paper.abstract_text = french.to_string();
+        paper.extracted_text = Some(PaperText {
+            plain_text: "full text".to_string(),
+            sections: vec![PaperSection::for_test(
+                0,
+                "Method",
+                "We stack six encoder layers.",
+            )],
+            ..Default::default()
+        });
+
+        analyzer.analyze_paper(&mut paper).await.unwrap();
+
+        assert_eq!(paper.abstract_text_original, french);
+        assert_ne!(paper.abstract_text, french);
+        assert!(paper.analysis.is_some());
+        // One translation request, then the full-text analysis
+        assert_eq!(analyzer.provider.requests().len(), 2);
+        let prompt = last_prompt(&analyzer.provider);
+        assert!(prompt.contains("We stack six encoder layers."));
+        assert!(!prompt.contains("Les modèles dominants"));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_english_abstract_skips_english() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+        let mut paper = test_paper();
+        paper.abstract_text = "The dominant sequence transduction models are based on complex \
+            recurrent or convolutional neural networks. We propose a new simple network \
+            architecture, the Transformer, based solely on attention mechanisms."
+            .to_string();
+
+        assert!(!analyzer.ensure_english_abstract(&mut paper).await.unwrap());
+        assert!(paper.abstract_text_original.is_empty());
+        assert!(analyzer.provider.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_lenient_passes_through_valid_json() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+
+        let analysis = analyzer.analyze_lenient(&test_paper()).await.unwrap();
+        assert_eq!(analysis.summary, "[mock] Test abstract");
+        assert!(analysis.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compare_papers() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+        let other = AcademicPaper {
+            title: "BERT: Pre-training of Deep Bidirectional Transformers".to_string(),
+            ..test_paper()
+        };
+
+        let comparison = analyzer
+            .compare_papers(&test_paper(), &other)
+            .await
+            .unwrap();
+
+        assert_eq!(comparison.paper_a, "Test Paper");
+        assert_eq!(comparison.paper_b, other.title);
+        assert_eq!(
+            comparison.advances,
+            crate::models::ComparisonVerdict::Neither
+        );
+        assert_eq!(comparison.methodological_differences.len(), 1);
+        assert!(!comparison.rationale.is_empty());
+        assert_eq!(comparison.provider, "mock");
+        assert_eq!(comparison.model, "mock-model");
+    }
+
+    #[tokio::test]
+    async fn test_synthesize() {
+        let analyzer = PaperAnalyzer::new(MockProvider::new());
+        let other = AcademicPaper {
+            title: "BERT: Pre-training of Deep Bidirectional Transformers".to_string(),
+            ..test_paper()
+        };
+
+        let synthesis = analyzer
+            .synthesize(&[test_paper(), other], Some("attention"))
+            .await
+            .unwrap();
+        assert!(synthesis.starts_with("[mock]"));
+
+        assert!(analyzer.synthesize(&[], None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_summarize_sections() {
+        let mut paper = test_paper();
+        paper.extracted_text = Some(PaperText {
+            plain_text: "full text".to_string(),
+            sections: vec![
+                PaperSection::for_test(0, "Introduction", "Content of Introduction."),
+                PaperSection::for_test(1, "Related Work", "Content of Related Work."),
+                PaperSection::for_test(2, "Method", "Content of Method."),
+                PaperSection::for_test(3, "References", "Content of References."),
+            ],
+            ..Default::default()
+        });
+
+        let analyzer = PaperAnalyzer::new(MockProvider::new()).with_section_concurrency(2);
+        let summaries = analyzer.summarize_sections(&paper).await.unwrap().summaries;
+
+        // Only critical/high-importance sections, in document order
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].section_title, "Introduction");
+        assert_eq!(
+            summaries[0].summary,
+            "[mock] Summary of section \"Introduction\" of \"Test Paper\"."
+        );
+        assert_eq!(summaries[1].section_title, "Method");
+
+        assert!(analyzer.summarize_sections(&test_paper()).await.is_err());
+
+        // A failed section is reported without discarding the others
+        let analyzer =
+            PaperAnalyzer::new(MockProvider::new().with_failures(1, || AppError::LlmServer(503)))
+                .with_section_concurrency(1);
+        let results = analyzer.summarize_sections(&paper).await.unwrap();
+        assert_eq!(results.summaries.len(), 1);
+        assert_eq!(results.summaries[0].section_title, "Method");
+        assert_eq!(results.failures.len(), 1);
+        assert_eq!(results.failures[0].0, "Introduction");
+    }
+
+    #[tokio::test]
+    async fn test_key_contributions_and_research_tasks() {
+        for language in [AnalysisLanguage::Japanese, AnalysisLanguage::English] {
+            let analyzer = PaperAnalyzer::new(MockProvider::new()).with_language(language);
+            let paper = test_paper();
+
+            let contributions = analyzer.extract_key_contributions(&paper).await.unwrap();
+            assert_eq!(contributions, vec!["test", "paper"]);
+
+            let tasks = analyzer.extract_research_tasks(&paper).await.unwrap();
+            assert_eq!(tasks, vec!["test", "paper"]);
+        }
     }
 }
@@ -1,9 +1,453 @@
 //! 論文分析用プロンプトテンプレート
 
+use crate::models::PaperAnalysis;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::sync::LazyLock;
+
+/// 上書きテンプレートで置換されるプレースホルダー
+static PLACEHOLDER_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\{(json_format|keywords|full_text|abstract|title)\}")
+        .expect("valid placeholder regex")
+});
+
+/// 完全論文分析の出力形式（JSON構造とデータセットの説明）
+const ANALYSIS_JSON_FORMAT: &str = r#"以下の構造のJSONオブジェクトとして分析結果を提供してください:
+{
+    "summary": "論文の2〜3段落のサマリー",
+    "background_and_purpose": "研究の背景、動機、目的",
+    "methodology": "技術的アプローチ、使用された手法と技術",
+    "datasets": [
+        {
+            "name": "データセット名（例: ImageNet, COCO, SQuAD）",
+            "url": "データセットにアクセスできるURL（不明な場合は空文字）",
+            "paper_title": "データセットを提案した元論文のタイトル（不明な場合は空文字）",
+            "paper_url": "元論文のURL（不明な場合は空文字）",
+            "paper_authors": "元論文の著者（不明な場合は空文字）",
+            "description": "データセットの簡単な説明（不明な場合は空文字）",
+            "domain": "分野（例: Computer Vision, NLP, Speech）",
+            "size": "サイズ情報（例: 1.2M images, 100K samples、不明な場合は空文字）"
+        }
+    ],
+    "results": "主要な発見と実験結果",
+    "advantages_limitations_and_future_work": "長所、短所、今後の方向性",
+    "key_contributions": ["貢献1", "貢献2", ...],
+    "tasks": ["研究分野1", "研究分野2", ...]
+}
+
+datasetsは論文で使用されているすべてのデータセットのリストです。データセットが使用されていない場合や記載がない場合は空の配列[]を返してください。"#;
+
+/// 完全論文分析の出力形式（英語プロンプト用）
+const ANALYSIS_JSON_FORMAT_EN: &str = r#"Provide the analysis as a JSON object with the following structure:
+{
+    "summary": "A 2-3 paragraph summary of the paper",
+    "background_and_purpose": "Background, motivation and goals of the research",
+    "methodology": "Technical approach, methods and techniques used",
+    "datasets": [
+        {
+            "name": "Dataset name (e.g. ImageNet, COCO, SQuAD)",
+            "url": "URL where the dataset can be accessed (empty string if unknown)",
+            "paper_title": "Title of the paper that introduced the dataset (empty string if unknown)",
+            "paper_url": "URL of that paper (empty string if unknown)",
+            "paper_authors": "Authors of that paper (empty string if unknown)",
+            "description": "Short description of the dataset (empty string if unknown)",
+            "domain": "Domain (e.g. Computer Vision, NLP, Speech)",
+            "size": "Size information (e.g. 1.2M images, 100K samples; empty string if unknown)"
+        }
+    ],
+    "results": "Key findings and experimental results",
+    "advantages_limitations_and_future_work": "Strengths, limitations and future directions",
+    "key_contributions": ["Contribution 1", "Contribution 2", ...],
+    "tasks": ["Research area 1", "Research area 2", ...]
+}
+
+datasets lists every dataset used in the paper. Return an empty array [] if no dataset is used or mentioned."#;
+
 /// LLMベースの論文分析用プロンプトテンプレート
+///
+/// 各メソッドは日本語のプロンプトを返し、`_en` 付きのメソッドは同じJSON構造を
+/// 英語で出力させるプロンプトを返す。
 pub struct PromptTemplates;
 
+/// 分析結果の出力言語
+///
+/// デフォルトは従来通り日本語。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalysisLanguage {
+    /// 日本語のプロンプトで日本語の分析結果を得る
+    #[default]
+    Japanese,
+    /// 英語のプロンプトで英語の分析結果を得る
+    English,
+}
+
+impl AnalysisLanguage {
+    /// 完全論文分析用のJSON出力形式の説明
+    fn json_format(self) -> &'static str {
+        match self {
+            Self::Japanese => ANALYSIS_JSON_FORMAT,
+            Self::English => ANALYSIS_JSON_FORMAT_EN,
+        }
+    }
+}
+
+impl std::fmt::Display for AnalysisLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Japanese => write!(f, "japanese"),
+            Self::English => write!(f, "english"),
+        }
+    }
+}
+
+/// プロンプトの上書き設定
+///
+/// 未設定の項目は [`PromptTemplates`] のデフォルトを使用する。
+/// 上書きテンプレートでは次のプレースホルダーが置換される:
+/// `{title}`, `{abstract}`, `{full_text}`, `{keywords}`, `{json_format}`
+/// （`{json_format}` は完全分析用のJSON出力形式の説明）。
+/// 分析系のテンプレートは、デフォルトと同じJSON構造を出力させる必要がある。
+/// 上書きのない項目は `language` に応じた言語のデフォルトを使用する。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptConfig {
+    /// デフォルトテンプレートの言語
+    #[serde(default)]
+    pub language: AnalysisLanguage,
+    /// システムプロンプト（ペルソナ・出力言語など）
+    pub system_prompt: Option<String>,
+    /// 完全論文分析用テンプレート
+    pub full_analysis: Option<String>,
+    /// 本文を用いた完全論文分析用テンプレート
+    pub full_analysis_with_text: Option<String>,
+    /// サマリー生成用テンプレート
+    pub summary: Option<String>,
+    /// 方法論抽出用テンプレート
+    pub methodology: Option<String>,
+    /// キーワード抽出用テンプレート
+    pub keyword_extraction: Option<String>,
+    /// 研究コンテキスト生成用テンプレート
+    pub research_context: Option<String>,
+}
+
+impl PromptConfig {
+    /// デフォルト（上書きなし）の設定を作成
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// デフォルトテンプレートの言語を設定
+    pub fn with_language(mut self, language: AnalysisLanguage) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// システムプロンプトを上書き
+    pub fn with_system_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.system_prompt = Some(prompt.into());
+        self
+    }
+
+    /// 完全論文分析用テンプレートを上書き
+    pub fn with_full_analysis(mut self, template: impl Into<String>) -> Self {
+        self.full_analysis = Some(template.into());
+        self
+    }
+
+    /// 本文を用いた完全論文分析用テンプレートを上書き
+    pub fn with_full_analysis_with_text(mut self, template: impl Into<String>) -> Self {
+        self.full_analysis_with_text = Some(template.into());
+        self
+    }
+
+    /// サマリー生成用テンプレートを上書き
+    pub fn with_summary(mut self, template: impl Into<String>) -> Self {
+        self.summary = Some(template.into());
+        self
+    }
+
+    /// 方法論抽出用テンプレートを上書き
+    pub fn with_methodology(mut self, template: impl Into<String>) -> Self {
+        self.methodology = Some(template.into());
+        self
+    }
+
+    /// キーワード抽出用テンプレートを上書き
+    pub fn with_keyword_extraction(mut self, template: impl Into<String>) -> Self {
+        self.keyword_extraction = Some(template.into());
+        self
+    }
+
+    /// 研究コンテキスト生成用テンプレートを上書き
+    pub fn with_research_context(mut self, template: impl Into<String>) -> Self {
+        self.research_context = Some(template.into());
+        self
+    }
+
+    /// システムプロンプト
+    pub fn system_prompt(&self) -> &str {
+        self.system_prompt
+            .as_deref()
+            .unwrap_or(match self.language {
+                AnalysisLanguage::Japanese => PromptTemplates::system_prompt(),
+                AnalysisLanguage::English => PromptTemplates::system_prompt_en(),
+            })
+    }
+
+    /// 完全論文分析用プロンプト
+    pub fn full_analysis_prompt(&self, title: &str, abstract_text: &str) -> String {
+        match &self.full_analysis {
+            Some(template) => self.render(template, title, abstract_text, "", &[]),
+            None => match self.language {
+                AnalysisLanguage::Japanese => {
+                    PromptTemplates::full_analysis_prompt(title, abstract_text)
+                }
+                AnalysisLanguage::English => {
+                    PromptTemplates::full_analysis_prompt_en(title, abstract_text)
+                }
+            },
+        }
+    }
+
+    /// 本文を用いた完全論文分析用プロンプト
+    pub fn full_analysis_prompt_with_text(
+        &self,
+        title: &str,
+        abstract_text: &str,
+        full_text: &str,
+    ) -> String {
+        match &self.full_analysis_with_text {
+            Some(template) => self.render(template, title, abstract_text, full_text, &[]),
+            None => match self.language {
+                AnalysisLanguage::Japanese => {
+                    PromptTemplates::full_analysis_prompt_with_text(title, abstract_text, full_text)
+                }
+                AnalysisLanguage::English => PromptTemplates::full_analysis_prompt_with_text_en(
+                    title,
+                    abstract_text,
+                    full_text,
+                ),
+            },
+        }
+    }
+
+    /// サマリー生成用プロンプト
+    pub fn summary_prompt(&self, title: &str, abstract_text: &str) -> String {
+        match &self.summary {
+            Some(template) => self.render(template, title, abstract_text, "", &[]),
+            None => match self.language {
+                AnalysisLanguage::Japanese => PromptTemplates::summary_prompt(title, abstract_text),
+                AnalysisLanguage::English => {
+                    PromptTemplates::summary_prompt_en(title, abstract_text)
+                }
+            },
+        }
+    }
+
+    /// 方法論抽出用プロンプト
+    pub fn methodology_prompt(&self, title: &str, abstract_text: &str) -> String {
+        match &self.methodology {
+            Some(template) => self.render(template, title, abstract_text, "", &[]),
+            None => match self.language {
+                AnalysisLanguage::Japanese => {
+                    PromptTemplates::methodology_prompt(title, abstract_text)
+                }
+                AnalysisLanguage::English => {
+                    PromptTemplates::methodology_prompt_en(title, abstract_text)
+                }
+            },
+        }
+    }
+
+    /// キーワード・トピック抽出用プロンプト
+    pub fn keyword_extraction_prompt(&self, title: &str, abstract_text: &str) -> String {
+        match &self.keyword_extraction {
+            Some(template) => self.render(template, title, abstract_text, "", &[]),
+            None => match self.language {
+                AnalysisLanguage::Japanese => {
+                    PromptTemplates::keyword_extraction_prompt(title, abstract_text)
+                }
+                AnalysisLanguage::English => {
+                    PromptTemplates::keyword_extraction_prompt_en(title, abstract_text)
+                }
+            },
+        }
+    }
+
+    /// 研究コンテキスト生成用プロンプト
+    pub fn research_context_prompt(
+        &self,
+        title: &str,
+        abstract_text: &str,
+        keywords: &[String],
+    ) -> String {
+        match &self.research_context {
+            Some(template) => self.render(template, title, abstract_text, "", keywords),
+            None => match self.language {
+                AnalysisLanguage::Japanese => {
+                    PromptTemplates::research_context_prompt(title, abstract_text, keywords)
+                }
+                AnalysisLanguage::English => {
+                    PromptTemplates::research_context_prompt_en(title, abstract_text, keywords)
+                }
+            },
+        }
+    }
+
+    /// 主要貢献抽出用プロンプト（上書き不可）
+    pub fn key_contributions_prompt(&self, title: &str, abstract_text: &str) -> String {
+        match self.language {
+            AnalysisLanguage::Japanese => {
+                PromptTemplates::key_contributions_prompt(title, abstract_text)
+            }
+            AnalysisLanguage::English => {
+                PromptTemplates::key_contributions_prompt_en(title, abstract_text)
+            }
+        }
+    }
+
+    /// 研究タスク・分野識別用プロンプト（上書き不可）
+    pub fn research_tasks_prompt(&self, title: &str, abstract_text: &str) -> String {
+        match self.language {
+            AnalysisLanguage::Japanese => {
+                PromptTemplates::research_tasks_prompt(title, abstract_text)
+            }
+            AnalysisLanguage::English => {
+                PromptTemplates::research_tasks_prompt_en(title, abstract_text)
+            }
+        }
+    }
+
+    /// 2論文比較用プロンプト（上書き不可）
+    pub fn comparison_prompt(
+        &self,
+        title_a: &str,
+        abstract_a: &str,
+        title_b: &str,
+        abstract_b: &str,
+    ) -> String {
+        match self.language {
+            AnalysisLanguage::Japanese => {
+                PromptTemplates::comparison_prompt(title_a, abstract_a, title_b, abstract_b)
+            }
+            AnalysisLanguage::English => {
+                PromptTemplates::comparison_prompt_en(title_a, abstract_a, title_b, abstract_b)
+            }
+        }
+    }
+
+    /// セクション要約用プロンプト（上書き不可）
+    pub fn section_summary_prompt(
+        &self,
+        title: &str,
+        section_title: &str,
+        content: &str,
+    ) -> String {
+        match self.language {
+            AnalysisLanguage::Japanese => {
+                PromptTemplates::section_summary_prompt(title, section_title, content)
+            }
+            AnalysisLanguage::English => {
+                PromptTemplates::section_summary_prompt_en(title, section_title, content)
+            }
+        }
+    }
+
+    /// 複数論文の統合まとめ用プロンプト（上書き不可）
+    pub fn synthesis_prompt(&self, papers: &str, focus: Option<&str>) -> String {
+        match self.language {
+            AnalysisLanguage::Japanese => PromptTemplates::synthesis_prompt(papers, focus),
+            AnalysisLanguage::English => PromptTemplates::synthesis_prompt_en(papers, focus),
+        }
+    }
+
+    /// 上書きテンプレートのプレースホルダーを一度の走査で置換
+    ///
+    /// 置換後の文字列（タイトルや本文）に含まれるプレースホルダーは再展開しない。
+    fn render(
+        &self,
+        template: &str,
+        title: &str,
+        abstract_text: &str,
+        full_text: &str,
+        keywords: &[String],
+    ) -> String {
+        let keywords = keywords.join(", ");
+        PLACEHOLDER_PATTERN
+            .replace_all(template, |caps: &Captures| match &caps[1] {
+                "json_format" => self.language.json_format(),
+                "keywords" => keywords.as_str(),
+                "full_text" => full_text,
+                "abstract" => abstract_text,
+                _ => title,
+            })
+            .into_owned()
+    }
+}
+
 impl PromptTemplates {
+    /// 完全論文分析の出力形式を表すJSON Schema
+    ///
+    /// [`ANALYSIS_JSON_FORMAT`] と同じ構造を表し、structured outputs に対応した
+    /// プロバイダーで出力形式を強制するために使用する。`title` は
+    /// [`PaperAnalysis`] の型名で、プロバイダーはここからスキーマ名を作る。
+    pub fn analysis_json_schema() -> Value {
+        let string = || json!({ "type": "string" });
+        let string_array = || json!({ "type": "array", "items": { "type": "string" } });
+        let dataset_fields = [
+            "name",
+            "url",
+            "paper_title",
+            "paper_url",
+            "paper_authors",
+            "description",
+            "domain",
+            "size",
+        ];
+        let dataset_properties: serde_json::Map<String, Value> = dataset_fields
+            .iter()
+            .map(|field| (field.to_string(), string()))
+            .collect();
+
+        let type_name = std::any::type_name::<PaperAnalysis>();
+        let title = type_name.rsplit("::").next().unwrap_or(type_name);
+
+        json!({
+            "title": title,
+            "type": "object",
+            "properties": {
+                "summary": string(),
+                "background_and_purpose": string(),
+                "methodology": string(),
+                "datasets": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": dataset_properties,
+                        "required": dataset_fields,
+                        "additionalProperties": false
+                    }
+                },
+                "results": string(),
+                "advantages_limitations_and_future_work": string(),
+                "key_contributions": string_array(),
+                "tasks": string_array()
+            },
+            "required": [
+                "summary",
+                "background_and_purpose",
+                "methodology",
+                "datasets",
+                "results",
+                "advantages_limitations_and_future_work",
+                "key_contributions",
+                "tasks"
+            ],
+            "additionalProperties": false
+        })
+    }
+
     /// 論文分析用システムプロンプト
     pub fn system_prompt() -> &'static str {
         r#"あなたは複数の科学分野に深い知識を持つ学術論文分析の専門家です。研究論文を分析し、構造化された情報を抽出することがあなたの役割です。
@@ -21,6 +465,11 @@ impl PromptTemplates {
         r#"あなたは英語の学術論文を日本語に翻訳する専門の翻訳者です。専門用語の正確性と学術的なトーンを維持してください。"#
     }
 
+    /// 英語翻訳用システムプロンプト（英語以外のアブストラクト向け）
+    pub fn english_translation_system() -> &'static str {
+        r#"あなたは学術論文を英語に翻訳する専門の翻訳者です。専門用語の正確性と学術的なトーンを維持してください。"#
+    }
+
     /// 論文サマリー生成用プロンプト
     pub fn summary_prompt(title: &str, abstract_text: &str) -> String {
         format!(
@@ -67,34 +516,37 @@ impl PromptTemplates {
 
 アブストラクト: {abstract_text}
 
-以下の構造のJSONオブジェクトとして分析結果を提供してください:
-{{
-    "summary": "論文の2〜3段落のサマリー",
-    "background_and_purpose": "研究の背景、動機、目的",
-    "methodology": "技術的アプローチ、使用された手法と技術",
-    "datasets": [
-        {{
-            "name": "データセット名（例: ImageNet, COCO, SQuAD）",
-            "url": "データセットにアクセスできるURL（不明な場合は空文字）",
-            "paper_title": "データセットを提案した元論文のタイトル（不明な場合は空文字）",
-            "paper_url": "元論文のURL（不明な場合は空文字）",
-            "paper_authors": "元論文の著者（不明な場合は空文字）",
-            "description": "データセットの簡単な説明（不明な場合は空文字）",
-            "domain": "分野（例: Computer Vision, NLP, Speech）",
-            "size": "サイズ情報（例: 1.2M images, 100K samples、不明な場合は空文字）"
-        }}
-    ],
-    "results": "主要な発見と実験結果",
-    "advantages_limitations_and_future_work": "長所、短所、今後の方向性",
-    "key_contributions": ["貢献1", "貢献2", ...],
-    "tasks": ["研究分野1", "研究分野2", ...]
-}}
+{ANALYSIS_JSON_FORMAT}
 
-datasetsは論文で使用されているすべてのデータセットのリストです。データセットが使用されていない場合や記載がない場合は空の配列[]を返してください。
 すべてのフィールドを埋めてください。アブストラクトに情報がない場合は、合理的な推測を行うか「記載なし」と示してください。"#
         )
     }
 
+    /// 本文を用いた完全論文分析用プロンプト（JSON出力）
+    ///
+    /// `full_text` には重要度の高いセクションを抜粋した本文を渡す
+    pub fn full_analysis_prompt_with_text(
+        title: &str,
+        abstract_text: &str,
+        full_text: &str,
+    ) -> String {
+        format!(
+            r#"この学術論文を本文に基づいて包括的に分析し、構造化された分析結果を提供してください。
+
+タイトル: {title}
+
+アブストラクト: {abstract_text}
+
+本文（主要セクションの抜粋）:
+{full_text}
+
+{ANALYSIS_JSON_FORMAT}
+
+手法・実験・結果については、アブストラクトではなく本文の具体的な記述（モデル構成、実験設定、数値結果など）に基づいて記述してください。
+すべてのフィールドを埋めてください。本文に情報がない場合は「記載なし」と示してください。"#
+        )
+    }
+
     /// テキスト翻訳用プロンプト
     pub fn translation_prompt(text: &str, target_lang: &str) -> String {
         format!(
@@ -148,6 +600,77 @@ datasetsは論文で使用されているすべてのデータセットのリス
         )
     }
 
+    /// 2論文比較用プロンプト
+    pub fn comparison_prompt(
+        title_a: &str,
+        abstract_a: &str,
+        title_b: &str,
+        abstract_b: &str,
+    ) -> String {
+        format!(
+            r#"以下の2つの学術論文を比較してください。
+
+論文A タイトル: {title_a}
+
+論文A アブストラクト: {abstract_a}
+
+論文B タイトル: {title_b}
+
+論文B アブストラクト: {abstract_b}
+
+以下の構造のJSONオブジェクトとして出力してください:
+{{
+    "shared_tasks": ["共通タスク1", "共通タスク2", ...],
+    "methodological_differences": ["手法の違い1", "手法の違い2", ...],
+    "advances": "a、b、neitherのいずれか",
+    "rationale": "判断の根拠（2〜3文）"
+}}
+
+ガイドライン:
+- shared_tasks: 両論文が共通して取り組んでいる研究タスク（なければ空配列）
+- methodological_differences: 手法・アプローチ・実験設定の主な違い（3〜5個）
+- advances: 論文Aが論文Bを発展・改善している場合は "a"、その逆は "b"、補完的・無関係で判断できない場合は "neither"
+- rationale: advancesの判断根拠。アブストラクトに記載されている内容のみに基づくこと"#
+        )
+    }
+
+    /// セクション要約用プロンプト
+    pub fn section_summary_prompt(title: &str, section_title: &str, content: &str) -> String {
+        format!(
+            r#"以下は学術論文の1つのセクションです。このセクションの内容を1〜2文で要約してください。
+
+タイトル: {title}
+
+セクション: {section_title}
+
+本文:
+{content}
+
+要約のみを出力し、前置きや説明は不要です。"#
+        )
+    }
+
+    /// 複数論文の統合まとめ（関連研究）用プロンプト
+    ///
+    /// `papers` は `[1] タイトル` の行とアブストラクトを論文ごとに並べたもの。
+    pub fn synthesis_prompt(papers: &str, focus: Option<&str>) -> String {
+        let focus = focus
+            .map(|topic| format!("\n特に「{topic}」の観点に焦点を当ててください。\n"))
+            .unwrap_or_default();
+        format!(
+            r#"以下の学術論文群を横断的に整理し、関連研究の節に使える統合的なまとめを作成してください。
+{focus}
+{papers}
+
+ガイドライン:
+- 論文ごとの要約を並べるのではなく、共通するテーマ・手法・研究の流れごとにまとめる
+- 論文に言及する際は [1] のように番号で引用する
+- 論文間の関係（発展、対立、補完）を明示する
+- 与えられたタイトルとアブストラクトに記載されている内容のみに基づくこと
+- 見出しや箇条書きは使わず、2〜4段落の文章で出力する"#
+        )
+    }
+
     /// キーワード・トピック抽出用プロンプト
     pub fn keyword_extraction_prompt(title: &str, abstract_text: &str) -> String {
         format!(
@@ -211,6 +734,262 @@ datasetsは論文で使用されているすべてのデータセットのリス
 - related_directions: この研究から発展しうる関連研究方向（3〜5個）"#
         )
     }
+
+    /// 論文分析用システムプロンプト（英語出力）
+    pub fn system_prompt_en() -> &'static str {
+        r#"You are an expert in academic paper analysis with deep knowledge across multiple scientific fields. Your role is to analyze research papers and extract structured information.
+
+Guidelines:
+- Be concise and accurate
+- Focus on the main contributions and what is novel
+- Use technical terminology appropriately
+- Stay objective in your analysis
+- If the abstract does not provide some information, state "Not stated in the abstract"
+- Write all output in English"#
+    }
+
+    /// 論文サマリー生成用プロンプト（英語出力）
+    pub fn summary_prompt_en(title: &str, abstract_text: &str) -> String {
+        format!(
+            r#"Analyze this academic paper and write a concise summary (2-3 paragraphs).
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+Focus on:
+1. The main problem or research question being addressed
+2. The proposed solution, method or approach
+3. The key findings and contributions
+
+Provide a clear, well-structured summary that captures the essence of the paper."#
+        )
+    }
+
+    /// 方法論抽出用プロンプト（英語出力）
+    pub fn methodology_prompt_en(title: &str, abstract_text: &str) -> String {
+        format!(
+            r#"Extract and describe the methodology of this paper.
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+Describe:
+1. The research approach (experimental, theoretical, empirical, etc.)
+2. The main methods and techniques used
+3. The evaluation methodology and metrics
+4. Any novel methodological contributions
+
+If the abstract gives few methodological details, describe what can be inferred."#
+        )
+    }
+
+    /// 完全論文分析用プロンプト（JSON出力、英語）
+    pub fn full_analysis_prompt_en(title: &str, abstract_text: &str) -> String {
+        format!(
+            r#"Analyze this academic paper comprehensively and provide a structured analysis.
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+{ANALYSIS_JSON_FORMAT_EN}
+
+Fill in every field. If the abstract does not provide some information, make a reasonable inference or state "Not stated"."#
+        )
+    }
+
+    /// 本文を用いた完全論文分析用プロンプト（JSON出力、英語）
+    pub fn full_analysis_prompt_with_text_en(
+        title: &str,
+        abstract_text: &str,
+        full_text: &str,
+    ) -> String {
+        format!(
+            r#"Analyze this academic paper comprehensively based on its full text and provide a structured analysis.
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+Full text (excerpts of the main sections):
+{full_text}
+
+{ANALYSIS_JSON_FORMAT_EN}
+
+Base the methodology, experiments and results on the concrete descriptions in the full text (model architecture, experimental setup, numerical results, etc.) rather than on the abstract.
+Fill in every field. If the full text does not provide some information, state "Not stated"."#
+        )
+    }
+
+    /// 主要貢献抽出用プロンプト（英語出力）
+    pub fn key_contributions_prompt_en(title: &str, abstract_text: &str) -> String {
+        format!(
+            r#"Identify the key contributions of this paper.
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+List 3-5 key contributions. Each contribution should be:
+- Specific and concrete
+- Clearly stated
+- Focused on what is new or improved over prior work
+
+Output them as a JSON array of strings:
+["Contribution 1", "Contribution 2", ...]"#
+        )
+    }
+
+    /// 研究タスク・分野識別用プロンプト（英語出力）
+    pub fn research_tasks_prompt_en(title: &str, abstract_text: &str) -> String {
+        format!(
+            r#"Identify the research areas and tasks this paper addresses.
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+List the relevant research areas and specific tasks (e.g. "Natural Language Processing", "Machine Translation", "Image Classification").
+
+Output them as a JSON array of strings:
+["Area/Task 1", "Area/Task 2", ...]"#
+        )
+    }
+
+    /// 2論文比較用プロンプト（英語出力）
+    pub fn comparison_prompt_en(
+        title_a: &str,
+        abstract_a: &str,
+        title_b: &str,
+        abstract_b: &str,
+    ) -> String {
+        format!(
+            r#"Compare the following two academic papers.
+
+Paper A Title: {title_a}
+
+Paper A Abstract: {abstract_a}
+
+Paper B Title: {title_b}
+
+Paper B Abstract: {abstract_b}
+
+Output a JSON object with the following structure:
+{{
+    "shared_tasks": ["Shared task 1", "Shared task 2", ...],
+    "methodological_differences": ["Difference 1", "Difference 2", ...],
+    "advances": "one of a, b or neither",
+    "rationale": "Reasoning behind the verdict (2-3 sentences)"
+}}
+
+Guidelines:
+- shared_tasks: research tasks both papers address (empty array if none)
+- methodological_differences: the main differences in methods, approach and experimental setup (3-5 items)
+- advances: "a" if paper A builds on or improves paper B, "b" for the reverse, "neither" if they are complementary, unrelated or it cannot be determined
+- rationale: the reasoning for advances, based only on what the abstracts state"#
+        )
+    }
+
+    /// セクション要約用プロンプト（英語出力）
+    pub fn section_summary_prompt_en(title: &str, section_title: &str, content: &str) -> String {
+        format!(
+            r#"The following is one section of an academic paper. Summarize it in 1-2 sentences.
+
+Title: {title}
+
+Section: {section_title}
+
+Content:
+{content}
+
+Output only the summary, without preamble or explanation."#
+        )
+    }
+
+    /// 複数論文の統合まとめ（関連研究）用プロンプト（英語出力）
+    pub fn synthesis_prompt_en(papers: &str, focus: Option<&str>) -> String {
+        let focus = focus
+            .map(|topic| format!("\nFocus in particular on {topic}.\n"))
+            .unwrap_or_default();
+        format!(
+            r#"Synthesize the following academic papers into one cohesive overview suitable for a related-work section.
+{focus}
+{papers}
+
+Guidelines:
+- Organize by shared themes, methods and lines of research rather than summarizing each paper in turn
+- Cite papers by number, e.g. [1]
+- Make the relationships between papers explicit (builds on, contrasts with, complements)
+- Base the synthesis only on what the given titles and abstracts state
+- Write 2-4 paragraphs of prose without headings or bullet points"#
+        )
+    }
+
+    /// キーワード・トピック抽出用プロンプト（英語出力）
+    pub fn keyword_extraction_prompt_en(title: &str, abstract_text: &str) -> String {
+        format!(
+            r#"Extract keywords, topics and technical terms from the following academic paper.
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+Output a JSON object with the following structure:
+{{
+    "keywords": ["Main keyword 1", "Main keyword 2", ...],
+    "topics": ["Research topic 1", "Research topic 2", ...],
+    "technical_terms": [
+        {{"term": "Technical term 1", "definition": "Short definition"}},
+        {{"term": "Technical term 2", "definition": "Short definition"}}
+    ],
+    "methods": ["Method 1", "Method 2", ...],
+    "datasets": ["Dataset 1", "Dataset 2", ...]
+}}
+
+Guidelines:
+- keywords: the main search keywords for the paper (5-10)
+- topics: research areas and topics (3-5)
+- technical_terms: important technical terms with definitions (about 5)
+- methods: methods and techniques used (all that apply)
+- datasets: datasets mentioned (all that apply; empty array if none)"#
+        )
+    }
+
+    /// 研究コンテキスト生成用プロンプト（英語出力）
+    pub fn research_context_prompt_en(
+        title: &str,
+        abstract_text: &str,
+        keywords: &[String],
+    ) -> String {
+        let keywords_str = keywords.join(", ");
+        format!(
+            r#"Analyze how the following academic paper is positioned within its research field.
+
+Title: {title}
+
+Abstract: {abstract_text}
+
+Keywords: {keywords_str}
+
+Output a JSON object with the following structure:
+{{
+    "primary_field": "Primary research field",
+    "sub_fields": ["Sub-field 1", "Sub-field 2", ...],
+    "research_type": "Research type (one of: empirical, theoretical, survey, methodology, application)",
+    "positioning": "How this work is positioned in its field (2-3 sentences)",
+    "related_directions": ["Related direction 1", "Related direction 2", ...]
+}}
+
+Guidelines:
+- primary_field: the most relevant primary research field
+- sub_fields: more specific sub-fields (2-4)
+- research_type: one of empirical, theoretical, survey, methodology (proposes a method) or application
+- positioning: how this work contributes to its field and which problem it tries to solve
+- related_directions: research directions that could follow from this work (3-5)"#
+        )
+    }
 }
 
 #[cfg(test)]
@@ -223,6 +1002,40 @@ mod tests {
         assert!(prompt.contains("学術論文分析の専門家"));
     }
 
+    #[test]
+    fn test_system_prompt_per_language() {
+        let japanese = PromptConfig::new();
+        assert!(japanese.system_prompt().contains("学術論文分析の専門家"));
+
+        let english = PromptConfig::new().with_language(AnalysisLanguage::English);
+        assert_eq!(english.system_prompt(), PromptTemplates::system_prompt_en());
+        assert!(
+            english
+                .system_prompt()
+                .contains("Write all output in English")
+        );
+        assert!(!english.system_prompt().contains("専門家"));
+    }
+
+    #[test]
+    fn test_english_prompts_keep_json_keys() {
+        let config = PromptConfig::new().with_language(AnalysisLanguage::English);
+        let prompt = config.full_analysis_prompt("Attention", "We propose...");
+        assert!(prompt.contains("Title: Attention"));
+        assert!(prompt.contains("\"key_contributions\""));
+        assert!(prompt.contains("\"advantages_limitations_and_future_work\""));
+
+        let rendered = PromptConfig::new()
+            .with_language(AnalysisLanguage::English)
+            .with_full_analysis("{json_format}")
+            .full_analysis_prompt("Attention", "We propose...");
+        assert!(rendered.starts_with("Provide the analysis as a JSON object"));
+
+        let prompt = config.comparison_prompt("A", "a", "B", "b");
+        assert!(prompt.contains("\"methodological_differences\""));
+        assert!(!prompt.contains("{{"));
+    }
+
     #[test]
     fn test_summary_prompt() {
         let prompt = PromptTemplates::summary_prompt("Test Title", "Test abstract");
@@ -237,4 +1050,104 @@ mod tests {
         assert!(prompt.contains("summary"));
         assert!(prompt.contains("methodology"));
     }
+
+    #[test]
+    fn test_analysis_json_schema_required_keys() {
+        let schema = PromptTemplates::analysis_json_schema();
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+
+        for key in ["summary", "methodology", "key_contributions", "tasks"] {
+            assert!(required.contains(&key), "missing required key {key}");
+            assert!(schema["properties"].get(key).is_some());
+        }
+        assert_eq!(schema["title"], "PaperAnalysis");
+        assert_eq!(schema["properties"]["tasks"]["type"], "array");
+        assert_eq!(
+            schema["properties"]["datasets"]["items"]["required"]
+                .as_array()
+                .unwrap()
+                .len(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_prompt_config_defaults_match_templates() {
+        let config = PromptConfig::new();
+        assert_eq!(config.system_prompt(), PromptTemplates::system_prompt());
+        assert_eq!(
+            config.full_analysis_prompt("Title", "Abstract"),
+            PromptTemplates::full_analysis_prompt("Title", "Abstract")
+        );
+    }
+
+    #[test]
+    fn test_prompt_config_renders_placeholders() {
+        let config = PromptConfig::new()
+            .with_summary("Summarize \"{title}\" in English: {abstract}")
+            .with_full_analysis("Analyze {title}.\n{json_format}");
+
+        assert_eq!(
+            config.summary_prompt("Attention", "We propose..."),
+            "Summarize \"Attention\" in English: We propose..."
+        );
+        let prompt = config.full_analysis_prompt("Attention", "We propose...");
+        assert!(prompt.starts_with("Analyze Attention."));
+        assert!(prompt.contains("\"key_contributions\""));
+    }
+
+    #[test]
+    fn test_prompt_config_does_not_expand_substituted_text() {
+        let config = PromptConfig::new().with_summary("Title: {title}\nAbstract: {abstract}");
+
+        assert_eq!(
+            config.summary_prompt("Why {abstract} matters", "We study {title} tokens."),
+            "Title: Why {abstract} matters\nAbstract: We study {title} tokens."
+        );
+    }
+
+    #[test]
+    fn test_comparison_prompt_includes_both_papers() {
+        let prompt = PromptTemplates::comparison_prompt(
+            "Attention Is All You Need",
+            "We propose the Transformer.",
+            "BERT",
+            "We introduce BERT.",
+        );
+        assert!(prompt.contains("論文A タイトル: Attention Is All You Need"));
+        assert!(prompt.contains("論文B アブストラクト: We introduce BERT."));
+        assert!(prompt.contains("\"methodological_differences\""));
+        assert!(!prompt.contains("{{"));
+    }
+
+    #[test]
+    fn test_synthesis_prompt_focus() {
+        let papers = "[1] Attention Is All You Need\nWe propose the Transformer.";
+        let prompt = PromptTemplates::synthesis_prompt(papers, None);
+        assert!(prompt.contains(papers));
+        assert!(!prompt.contains("焦点を当てて"));
+
+        let prompt = PromptConfig::new()
+            .with_language(AnalysisLanguage::English)
+            .synthesis_prompt(papers, Some("efficiency"));
+        assert!(prompt.contains(papers));
+        assert!(prompt.contains("Focus in particular on efficiency."));
+    }
+
+    #[test]
+    fn test_full_analysis_prompt_with_text() {
+        let prompt = PromptTemplates::full_analysis_prompt_with_text(
+            "Title",
+            "Abstract",
+            "## Method\nWe stack six encoder layers.",
+        );
+        assert!(prompt.contains("We stack six encoder layers."));
+        assert!(prompt.contains("\"datasets\""));
+        assert!(!prompt.contains("{{"));
+    }
 }
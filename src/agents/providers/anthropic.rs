@@ -1,6 +1,8 @@
 //! Anthropic Claude API provider using anthropic-tools crate
 
-use crate::agents::traits::{LlmConfig, LlmProvider, Message, MessageRole};
+use crate::agents::traits::{
+    LlmConfig, LlmProvider, Message, MessageRole, REFUSAL_ERROR_MESSAGE, REFUSAL_STOP_REASON,
+};
 use crate::shared::errors::{AppError, AppResult};
 use anthropic_tools::Messages;
 use async_trait::async_trait;
@@ -15,7 +17,7 @@ pub struct AnthropicProvider {
     default_model: String,
 }
 
-const DEFAULT_ANTHROPIC_MODEL: &str = "claude-sonnet-4-20250514";
+pub(super) const DEFAULT_ANTHROPIC_MODEL: &str = "claude-sonnet-4-20250514";
 
 impl AnthropicProvider {
     /// Create a new Anthropic provider
@@ -100,14 +102,16 @@ impl LlmProvider for AnthropicProvider {
         let response = client
             .post()
             .await
-            .map_err(|e| AppError::LlmError(format!("Anthropic API error: {}", e)))?;
+            .map_err(|e| AppError::from_llm_message(format!("Anthropic API error: {}", e)))?;
 
-        // Extract text from response
+        // The structured stop reason is authoritative; otherwise an empty body
+        // means the model refused or stopped without producing content
+        if response.stop_reason.as_deref() == Some(REFUSAL_STOP_REASON) {
+            return Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()));
+        }
         let text = response.get_text();
         if text.is_empty() {
-            Err(AppError::LlmError(
-                "No text response from Anthropic".to_string(),
-            ))
+            Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()))
         } else {
             Ok(text)
         }
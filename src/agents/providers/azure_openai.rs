@@ -0,0 +1,279 @@
+//! Azure OpenAI Chat Completions provider
+
+use super::chat_completions::{ChatMessage, ChatResponse};
+use super::openai::{is_reasoning_model, supports_structured_output};
+use crate::agents::traits::{LlmConfig, LlmProvider, Message};
+use crate::shared::config::ClientConfig;
+use crate::shared::errors::{AppError, AppResult, parse_retry_after};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Serialize;
+
+pub(super) const DEFAULT_AZURE_API_VERSION: &str = "2024-10-21";
+
+/// Azure OpenAI provider
+///
+/// Azure routes requests by deployment rather than by model name, so the
+/// deployment takes the place of the model: `LlmConfig::model`, when set,
+/// selects a different deployment on the same resource.
+///
+/// Configuration is loaded from the AZURE_OPENAI_ENDPOINT, AZURE_OPENAI_API_KEY,
+/// AZURE_OPENAI_DEPLOYMENT and AZURE_OPENAI_API_VERSION environment variables.
+pub struct AzureOpenAiProvider {
+    client: Client,
+    endpoint: String,
+    api_key: String,
+    deployment: String,
+    api_version: String,
+}
+
+impl AzureOpenAiProvider {
+    /// Create a new Azure OpenAI provider
+    ///
+    /// `endpoint` is the resource URL, e.g. `https://my-resource.openai.azure.com`.
+    pub fn new(
+        endpoint: impl Into<String>,
+        api_key: impl Into<String>,
+        deployment: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            api_key: api_key.into(),
+            deployment: deployment.into(),
+            api_version: DEFAULT_AZURE_API_VERSION.to_string(),
+        }
+    }
+
+    /// Set the `api-version` query parameter
+    pub fn with_api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Apply proxy/timeout/user-agent settings to the HTTP client
+    pub fn with_http_config(mut self, config: &ClientConfig) -> AppResult<Self> {
+        self.client = config.build_client()?;
+        Ok(self)
+    }
+
+    /// Create from environment variables
+    ///
+    /// Reads AZURE_OPENAI_ENDPOINT, AZURE_OPENAI_API_KEY and AZURE_OPENAI_DEPLOYMENT
+    /// (required) and AZURE_OPENAI_API_VERSION (optional, defaults to 2024-10-21)
+    pub fn from_env() -> AppResult<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Build the provider from a variable lookup (the environment in `from_env`)
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> AppResult<Self> {
+        let required = |name: &str| {
+            var(name).ok_or_else(|| {
+                AppError::ConfigError(format!("{} environment variable not set", name))
+            })
+        };
+
+        let endpoint = required("AZURE_OPENAI_ENDPOINT")?;
+        let api_key = required("AZURE_OPENAI_API_KEY")?;
+        let deployment = required("AZURE_OPENAI_DEPLOYMENT")?;
+        let api_version = var("AZURE_OPENAI_API_VERSION")
+            .unwrap_or_else(|| DEFAULT_AZURE_API_VERSION.to_string());
+
+        Ok(Self::new(endpoint, api_key, deployment).with_api_version(api_version))
+    }
+
+    /// Chat Completions URL for a deployment
+    fn chat_url(&self, deployment: &str) -> String {
+        format!(
+            "{}/openai/deployments/{}/chat/completions?api-version={}",
+            self.endpoint, deployment, self.api_version
+        )
+    }
+}
+
+#[derive(Serialize)]
+struct ChatRequest {
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_completion_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<serde_json::Value>,
+}
+
+#[async_trait]
+impl LlmProvider for AzureOpenAiProvider {
+    fn name(&self) -> &str {
+        "azure-openai"
+    }
+
+    fn default_model(&self) -> &str {
+        &self.deployment
+    }
+
+    async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String> {
+        let deployment = if config.model.is_empty() {
+            self.deployment.clone()
+        } else {
+            config.model.clone()
+        };
+
+        // Deployments are usually named after their model, so the OpenAI
+        // model-family rules are applied to the deployment name
+        let temperature = match config.temperature {
+            Some(temp) if is_reasoning_model(&deployment) => {
+                tracing::debug!(
+                    "Ignoring temperature {} for reasoning deployment {}",
+                    temp,
+                    deployment
+                );
+                None
+            }
+            temperature => temperature,
+        };
+        let response_format = config
+            .json_schema
+            .as_ref()
+            .filter(|_| supports_structured_output(&deployment))
+            .map(|schema| {
+                serde_json::json!({
+                    "type": "json_schema",
+                    "json_schema": {
+                        "name": crate::agents::prompts::ANALYSIS_SCHEMA_NAME,
+                        "strict": true,
+                        "schema": schema,
+                    }
+                })
+            })
+            .or_else(|| {
+                config
+                    .wants_json()
+                    .then(|| serde_json::json!({ "type": "json_object" }))
+            });
+
+        let request = ChatRequest {
+            messages: messages.into_iter().map(ChatMessage::from).collect(),
+            temperature,
+            max_completion_tokens: config.max_tokens,
+            top_p: config.top_p,
+            stop: config.stop_sequences.clone(),
+            response_format,
+        };
+
+        let response = self
+            .client
+            .post(self.chat_url(&deployment))
+            .header("api-key", &self.api_key)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| AppError::LlmError(format!("Azure OpenAI request failed: {}", e)))?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::LlmError(format!("Failed to read response: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(AppError::from_llm_status(
+                status.as_u16(),
+                retry_after,
+                format!("Azure OpenAI API error ({}): {}", status, body),
+            ));
+        }
+
+        let chat_response: ChatResponse = serde_json::from_str(&body)
+            .map_err(|e| AppError::LlmError(format!("Failed to parse response: {}", e)))?;
+
+        chat_response.into_text()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_provider_name() {
+        let provider =
+            AzureOpenAiProvider::new("https://example.openai.azure.com", "key", "gpt-4o");
+        assert_eq!(provider.name(), "azure-openai");
+        assert_eq!(provider.default_model(), "gpt-4o");
+    }
+
+    #[test]
+    fn test_chat_url_from_env() {
+        let provider = AzureOpenAiProvider::from_vars(lookup(&[
+            (
+                "AZURE_OPENAI_ENDPOINT",
+                "https://my-resource.openai.azure.com/",
+            ),
+            ("AZURE_OPENAI_API_KEY", "secret"),
+            ("AZURE_OPENAI_DEPLOYMENT", "gpt-4o-prod"),
+            ("AZURE_OPENAI_API_VERSION", "2024-08-01-preview"),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            provider.chat_url(provider.default_model()),
+            "https://my-resource.openai.azure.com/openai/deployments/gpt-4o-prod/chat/completions?api-version=2024-08-01-preview"
+        );
+    }
+
+    #[test]
+    fn test_default_api_version() {
+        let provider = AzureOpenAiProvider::from_vars(lookup(&[
+            (
+                "AZURE_OPENAI_ENDPOINT",
+                "https://my-resource.openai.azure.com",
+            ),
+            ("AZURE_OPENAI_API_KEY", "secret"),
+            ("AZURE_OPENAI_DEPLOYMENT", "gpt-4o-prod"),
+        ]))
+        .unwrap();
+
+        assert!(
+            provider
+                .chat_url("other-deployment")
+                .ends_with("/deployments/other-deployment/chat/completions?api-version=2024-10-21")
+        );
+    }
+
+    #[test]
+    fn test_missing_env_is_config_error() {
+        let result = AzureOpenAiProvider::from_vars(lookup(&[
+            (
+                "AZURE_OPENAI_ENDPOINT",
+                "https://my-resource.openai.azure.com",
+            ),
+            ("AZURE_OPENAI_API_KEY", "secret"),
+        ]));
+
+        match result {
+            Err(AppError::ConfigError(message)) => {
+                assert!(message.contains("AZURE_OPENAI_DEPLOYMENT"))
+            }
+            _ => panic!("expected ConfigError"),
+        }
+    }
+}
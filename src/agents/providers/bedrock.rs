@@ -0,0 +1,248 @@
+//! AWS Bedrock provider for Anthropic Claude models
+//!
+//! Calls the Bedrock Runtime `InvokeModel` API with the Anthropic Messages
+//! request body. Region and credentials come from the standard AWS chain
+//! (environment variables, shared config/credentials files, SSO, IMDS, ...).
+
+use crate::agents::traits::{
+    DEFAULT_CONTEXT_TOKENS, LlmConfig, LlmProvider, Message, MessageRole, REFUSAL_ERROR_MESSAGE,
+    REFUSAL_STOP_REASON,
+};
+use crate::shared::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use aws_config::{BehaviorVersion, Region};
+use aws_sdk_bedrockruntime::Client;
+use aws_sdk_bedrockruntime::error::DisplayErrorContext;
+use aws_sdk_bedrockruntime::primitives::Blob;
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+
+pub(super) const DEFAULT_BEDROCK_MODEL: &str = "anthropic.claude-3-5-sonnet-20240620-v1:0";
+
+/// API version string required by Anthropic models on Bedrock
+const BEDROCK_ANTHROPIC_VERSION: &str = "bedrock-2023-05-31";
+
+/// Context window of Claude models on Bedrock
+const CLAUDE_CONTEXT_TOKENS: usize = 200_000;
+
+/// AWS Bedrock provider
+///
+/// Region is read from AWS_REGION and the model from BEDROCK_MODEL_ID.
+/// The AWS SDK client is created lazily on the first request.
+pub struct BedrockProvider {
+    /// AWS region hosting the model
+    region: String,
+    /// Default Bedrock model ID
+    default_model: String,
+    client: OnceCell<Client>,
+}
+
+impl BedrockProvider {
+    /// Create a new Bedrock provider for a region and model ID
+    pub fn new(region: impl Into<String>, model_id: impl Into<String>) -> Self {
+        Self {
+            region: region.into(),
+            default_model: model_id.into(),
+            client: OnceCell::new(),
+        }
+    }
+
+    /// Create from environment variables
+    ///
+    /// Reads AWS_REGION (required) and BEDROCK_MODEL_ID (optional, defaults to
+    /// anthropic.claude-3-5-sonnet-20240620-v1:0). Credentials are resolved by
+    /// the AWS SDK at request time.
+    pub fn from_env() -> AppResult<Self> {
+        let region = std::env::var("AWS_REGION").map_err(|_| {
+            AppError::ConfigError("AWS_REGION environment variable not set".to_string())
+        })?;
+        let model =
+            std::env::var("BEDROCK_MODEL_ID").unwrap_or_else(|_| DEFAULT_BEDROCK_MODEL.to_string());
+
+        Ok(Self::new(region, model))
+    }
+
+    /// Get the AWS region
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Get or create the Bedrock Runtime client
+    async fn client(&self) -> &Client {
+        self.client
+            .get_or_init(|| async {
+                let sdk_config = aws_config::defaults(BehaviorVersion::latest())
+                    .region(Region::new(self.region.clone()))
+                    .load()
+                    .await;
+                Client::new(&sdk_config)
+            })
+            .await
+    }
+
+    /// Build the Anthropic-on-Bedrock request body
+    fn build_request(messages: Vec<Message>, config: &LlmConfig) -> BedrockRequest {
+        let mut system = Vec::new();
+        let mut turns = Vec::new();
+        for msg in messages {
+            match msg.role {
+                MessageRole::System => system.push(msg.content),
+                MessageRole::User | MessageRole::Assistant => turns.push(BedrockMessage {
+                    role: msg.role.as_str().to_string(),
+                    content: msg.content,
+                }),
+            }
+        }
+
+        BedrockRequest {
+            anthropic_version: BEDROCK_ANTHROPIC_VERSION.to_string(),
+            max_tokens: config.max_tokens.unwrap_or(4096),
+            system: (!system.is_empty()).then(|| system.join("\n\n")),
+            messages: turns,
+            temperature: config.temperature,
+            top_p: config.top_p,
+            stop_sequences: config.stop_sequences.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BedrockRequest {
+    anthropic_version: String,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    messages: Vec<BedrockMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop_sequences: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct BedrockMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BedrockResponse {
+    #[serde(default)]
+    content: Vec<BedrockContent>,
+    #[serde(default)]
+    stop_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BedrockContent {
+    #[serde(rename = "type")]
+    content_type: String,
+    #[serde(default)]
+    text: String,
+}
+
+#[async_trait]
+impl LlmProvider for BedrockProvider {
+    fn name(&self) -> &str {
+        "bedrock"
+    }
+
+    fn default_model(&self) -> &str {
+        &self.default_model
+    }
+
+    fn max_context_tokens(&self) -> usize {
+        if self.default_model.contains("claude") {
+            CLAUDE_CONTEXT_TOKENS
+        } else {
+            DEFAULT_CONTEXT_TOKENS
+        }
+    }
+
+    async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String> {
+        let model = if config.model.is_empty() {
+            self.default_model().to_string()
+        } else {
+            config.model.clone()
+        };
+
+        let request = Self::build_request(messages, config);
+        let body = serde_json::to_vec(&request)?;
+
+        let output = self
+            .client()
+            .await
+            .invoke_model()
+            .model_id(&model)
+            .content_type("application/json")
+            .accept("application/json")
+            .body(Blob::new(body))
+            .send()
+            .await
+            .map_err(|e| {
+                let status = e.raw_response().map(|r| r.status().as_u16());
+                let message = format!("Bedrock API error: {}", DisplayErrorContext(&e));
+                match status {
+                    Some(status) => AppError::from_llm_status(status, None, message),
+                    None => AppError::LlmError(message),
+                }
+            })?;
+
+        let response: BedrockResponse = serde_json::from_slice(output.body().as_ref())
+            .map_err(|e| AppError::LlmError(format!("Failed to parse Bedrock response: {}", e)))?;
+
+        // The structured stop reason is authoritative; otherwise no text
+        // means the model refused or stopped without producing content
+        if response.stop_reason.as_deref() == Some(REFUSAL_STOP_REASON) {
+            return Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()));
+        }
+        let text: String = response
+            .content
+            .into_iter()
+            .filter(|c| c.content_type == "text")
+            .map(|c| c.text)
+            .collect();
+        if text.is_empty() {
+            Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()))
+        } else {
+            Ok(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_name() {
+        let provider = BedrockProvider::new("us-east-1", DEFAULT_BEDROCK_MODEL);
+        assert_eq!(provider.name(), "bedrock");
+        assert_eq!(provider.region(), "us-east-1");
+        assert_eq!(provider.default_model(), DEFAULT_BEDROCK_MODEL);
+        assert_eq!(provider.max_context_tokens(), CLAUDE_CONTEXT_TOKENS);
+    }
+
+    #[test]
+    fn test_build_request_maps_roles() {
+        let messages = vec![
+            Message::system("You are an expert."),
+            Message::user("Summarize this paper."),
+            Message::assistant("Sure."),
+        ];
+        let config = LlmConfig::default().with_temperature(0.2);
+
+        let request = BedrockProvider::build_request(messages, &config);
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["anthropic_version"], BEDROCK_ANTHROPIC_VERSION);
+        assert_eq!(json["max_tokens"], 4096);
+        assert_eq!(json["system"], "You are an expert.");
+        assert_eq!(json["messages"][0]["role"], "user");
+        assert_eq!(json["messages"][1]["role"], "assistant");
+        assert_eq!(json["messages"].as_array().unwrap().len(), 2);
+        assert!(json.get("stop_sequences").is_none());
+    }
+}
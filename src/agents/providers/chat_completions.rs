@@ -0,0 +1,88 @@
+//! Message and response shapes shared by Chat Completions style APIs
+//!
+//! Used by providers that talk to OpenAI's `/chat/completions` wire format
+//! directly over HTTP (Azure OpenAI and OpenAI-compatible servers).
+
+use crate::agents::traits::{Message, MessageRole, REFUSAL_ERROR_MESSAGE};
+use crate::shared::errors::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+
+/// A single chat message in a Chat Completions request
+#[derive(Debug, Serialize)]
+pub(super) struct ChatMessage {
+    pub(super) role: &'static str,
+    pub(super) content: String,
+}
+
+impl From<Message> for ChatMessage {
+    fn from(msg: Message) -> Self {
+        let role = match msg.role {
+            MessageRole::System => "system",
+            MessageRole::User => "user",
+            MessageRole::Assistant => "assistant",
+        };
+        Self {
+            role,
+            content: msg.content,
+        }
+    }
+}
+
+/// Chat Completions response body
+#[derive(Deserialize)]
+pub(super) struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Deserialize)]
+struct ChatChoice {
+    message: ChatResponseMessage,
+}
+
+#[derive(Deserialize)]
+struct ChatResponseMessage {
+    content: Option<String>,
+    #[serde(default)]
+    refusal: Option<String>,
+}
+
+impl ChatResponse {
+    /// Text of the first choice
+    ///
+    /// An explicit `refusal` is reported as a refusal even when content is
+    /// present; missing content means the model refused or returned no text.
+    pub(super) fn into_text(self) -> AppResult<String> {
+        let message = self.choices.into_iter().next().map(|c| c.message);
+        if let Some(refusal) = message.as_ref().and_then(|m| m.refusal.as_deref()) {
+            tracing::debug!("Chat Completions refusal: {}", refusal);
+            return Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()));
+        }
+        message
+            .and_then(|m| m.content)
+            .ok_or_else(|| AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(body: serde_json::Value) -> AppResult<String> {
+        serde_json::from_value::<ChatResponse>(body)
+            .unwrap()
+            .into_text()
+    }
+
+    #[test]
+    fn test_into_text_honors_refusal_field() {
+        let text = parse(serde_json::json!({
+            "choices": [{ "message": { "content": "{\"a\": 1}" } }]
+        }));
+        assert_eq!(text.unwrap(), "{\"a\": 1}");
+
+        let refused = parse(serde_json::json!({
+            "choices": [{ "message": { "content": null, "refusal": "I can't help with that." } }]
+        }));
+        assert!(matches!(refused, Err(AppError::LlmError(msg)) if msg == REFUSAL_ERROR_MESSAGE));
+    }
+}
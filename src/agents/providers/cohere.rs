@@ -0,0 +1,233 @@
+//! Cohere Chat API provider
+
+use crate::agents::traits::{LlmConfig, LlmProvider, Message, MessageRole};
+use crate::shared::config::ClientConfig;
+use crate::shared::errors::{AppError, AppResult, parse_retry_after};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+pub(super) const DEFAULT_COHERE_MODEL: &str = "command-r-plus";
+const COHERE_CHAT_URL: &str = "https://api.cohere.ai/v1/chat";
+
+/// Cohere Chat API provider
+///
+/// API key is loaded from the COHERE_API_KEY environment variable.
+/// Model can be configured via the COHERE_MODEL environment variable.
+pub struct CohereProvider {
+    client: Client,
+    api_key: String,
+    default_model: String,
+}
+
+impl CohereProvider {
+    /// Create a new Cohere provider with the given API key
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            api_key: api_key.into(),
+            default_model: DEFAULT_COHERE_MODEL.to_string(),
+        }
+    }
+
+    /// Set the default model
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.default_model = model.into();
+        self
+    }
+
+    /// Apply proxy/timeout/user-agent settings to the HTTP client
+    pub fn with_http_config(mut self, config: &ClientConfig) -> AppResult<Self> {
+        self.client = config.build_client()?;
+        Ok(self)
+    }
+
+    /// Create from environment variables
+    ///
+    /// Reads COHERE_API_KEY (required) and COHERE_MODEL (optional, defaults to command-r-plus)
+    pub fn from_env() -> AppResult<Self> {
+        let api_key = std::env::var("COHERE_API_KEY").map_err(|_| {
+            AppError::ConfigError("COHERE_API_KEY environment variable not set".to_string())
+        })?;
+        let model =
+            std::env::var("COHERE_MODEL").unwrap_or_else(|_| DEFAULT_COHERE_MODEL.to_string());
+
+        Ok(Self::new(api_key).with_model(model))
+    }
+
+    /// Split messages into Cohere's preamble, chat history and current message
+    ///
+    /// System messages are joined into the preamble, the last user message
+    /// becomes `message`, and every other turn goes into `chat_history`.
+    fn convert_messages(messages: Vec<Message>) -> (Option<String>, Vec<CohereChatTurn>, String) {
+        let mut preamble: Vec<String> = Vec::new();
+        let mut turns: Vec<Message> = Vec::new();
+        for msg in messages {
+            match msg.role {
+                MessageRole::System => preamble.push(msg.content),
+                _ => turns.push(msg),
+            }
+        }
+
+        let message = match turns.iter().rposition(|m| m.role == MessageRole::User) {
+            Some(idx) => turns.remove(idx).content,
+            None => String::new(),
+        };
+
+        let chat_history = turns
+            .into_iter()
+            .map(|m| CohereChatTurn {
+                role: match m.role {
+                    MessageRole::Assistant => "CHATBOT",
+                    _ => "USER",
+                },
+                message: m.content,
+            })
+            .collect();
+
+        let preamble = (!preamble.is_empty()).then(|| preamble.join("\n\n"));
+        (preamble, chat_history, message)
+    }
+}
+
+#[derive(Serialize)]
+struct ChatRequest {
+    model: String,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preamble: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    chat_history: Vec<CohereChatTurn>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    p: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop_sequences: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CohereChatTurn {
+    role: &'static str,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    text: String,
+}
+
+#[async_trait]
+impl LlmProvider for CohereProvider {
+    fn name(&self) -> &str {
+        "cohere"
+    }
+
+    fn default_model(&self) -> &str {
+        &self.default_model
+    }
+
+    async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String> {
+        let model = if config.model.is_empty() {
+            self.default_model.clone()
+        } else {
+            config.model.clone()
+        };
+
+        let (preamble, chat_history, message) = Self::convert_messages(messages);
+        let request = ChatRequest {
+            model,
+            message,
+            preamble,
+            chat_history,
+            temperature: config.temperature,
+            max_tokens: config.max_tokens,
+            p: config.top_p,
+            stop_sequences: config.stop_sequences.clone(),
+        };
+
+        let response = self
+            .client
+            .post(COHERE_CHAT_URL)
+            .bearer_auth(&self.api_key)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| AppError::LlmError(format!("Cohere API request failed: {}", e)))?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::LlmError(format!("Failed to read response: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(AppError::from_llm_status(
+                status.as_u16(),
+                retry_after,
+                format!("Cohere API error ({}): {}", status, body),
+            ));
+        }
+
+        let chat_response: ChatResponse = serde_json::from_str(&body)
+            .map_err(|e| AppError::LlmError(format!("Failed to parse response: {}", e)))?;
+
+        Ok(chat_response.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_name() {
+        let provider = CohereProvider::new("test-key");
+        assert_eq!(provider.name(), "cohere");
+    }
+
+    #[test]
+    fn test_default_model() {
+        let provider = CohereProvider::new("test-key");
+        assert_eq!(provider.default_model(), "command-r-plus");
+
+        let provider = CohereProvider::new("test-key").with_model("command-r");
+        assert_eq!(provider.default_model(), "command-r");
+    }
+
+    #[test]
+    fn test_convert_messages() {
+        let messages = vec![
+            Message::system("You are a research assistant."),
+            Message::user("Summarize paper A."),
+            Message::assistant("Paper A proposes X."),
+            Message::user("Now compare it with paper B."),
+        ];
+
+        let (preamble, history, message) = CohereProvider::convert_messages(messages);
+
+        assert_eq!(preamble.as_deref(), Some("You are a research assistant."));
+        assert_eq!(message, "Now compare it with paper B.");
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].role, "USER");
+        assert_eq!(history[0].message, "Summarize paper A.");
+        assert_eq!(history[1].role, "CHATBOT");
+        assert_eq!(history[1].message, "Paper A proposes X.");
+    }
+
+    #[test]
+    fn test_convert_messages_without_system() {
+        let (preamble, history, message) =
+            CohereProvider::convert_messages(vec![Message::user("Hello")]);
+        assert!(preamble.is_none());
+        assert!(history.is_empty());
+        assert_eq!(message, "Hello");
+    }
+}
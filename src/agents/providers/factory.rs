@@ -0,0 +1,197 @@
+//! Provider selection from a [`Config`]
+
+use super::anthropic::DEFAULT_ANTHROPIC_MODEL;
+use super::azure_openai::DEFAULT_AZURE_API_VERSION;
+use super::bedrock::DEFAULT_BEDROCK_MODEL;
+use super::cohere::DEFAULT_COHERE_MODEL;
+use super::openai::DEFAULT_OPENAI_MODEL;
+use super::{
+    AnthropicProvider, AzureOpenAiProvider, BedrockProvider, CohereProvider, MockProvider,
+    OllamaProvider, OpenAiCompatibleProvider, OpenAiProvider, RetryingProvider,
+};
+use crate::agents::traits::LlmProvider;
+use crate::shared::config::{Config, LlmProviderType};
+use crate::shared::errors::{AppError, AppResult};
+
+/// Build the provider for `provider_type` from configuration values
+///
+/// Credentials and models come from the provider-specific `Config` fields,
+/// with the same defaults as each provider's `from_env`. Fails with
+/// [`AppError::ConfigError`] when a required value is missing. The provider
+/// is wrapped in a [`RetryingProvider`], so rate-limit and server errors are
+/// retried with backoff.
+///
+/// The OpenAI and Anthropic SDKs read their API keys from `OPENAI_API_KEY` /
+/// `ANTHROPIC_API_KEY` themselves; for those providers the configured key
+/// only gates construction.
+pub fn build_provider(
+    provider_type: LlmProviderType,
+    config: &Config,
+) -> AppResult<Box<dyn LlmProvider>> {
+    let provider: Box<dyn LlmProvider> = match provider_type {
+        LlmProviderType::OpenAi => {
+            required(&config.openai_api_key, "OPENAI_API_KEY")?;
+            Box::new(OpenAiProvider::with_model(model_or(
+                &config.openai_model,
+                DEFAULT_OPENAI_MODEL,
+            )))
+        }
+        LlmProviderType::AzureOpenAi => Box::new(
+            AzureOpenAiProvider::new(
+                required(&config.azure_openai_endpoint, "AZURE_OPENAI_ENDPOINT")?,
+                required(&config.azure_openai_api_key, "AZURE_OPENAI_API_KEY")?,
+                required(&config.azure_openai_deployment, "AZURE_OPENAI_DEPLOYMENT")?,
+            )
+            .with_api_version(model_or(
+                &config.azure_openai_api_version,
+                DEFAULT_AZURE_API_VERSION,
+            )),
+        ),
+        LlmProviderType::OpenAiCompatible => Box::new(OpenAiCompatibleProvider::from_parts(
+            required(&config.openai_compat_base_url, "OPENAI_COMPAT_BASE_URL")?.to_string(),
+            config
+                .openai_compat_api_key
+                .clone()
+                .filter(|k| !k.trim().is_empty()),
+            required(&config.openai_compat_model, "OPENAI_COMPAT_MODEL")?.to_string(),
+        )),
+        LlmProviderType::Anthropic => {
+            required(&config.anthropic_api_key, "ANTHROPIC_API_KEY")?;
+            Box::new(AnthropicProvider::with_model(model_or(
+                &config.anthropic_model,
+                DEFAULT_ANTHROPIC_MODEL,
+            )))
+        }
+        LlmProviderType::Bedrock => Box::new(BedrockProvider::new(
+            required(&config.aws_region, "AWS_REGION")?,
+            model_or(&config.bedrock_model_id, DEFAULT_BEDROCK_MODEL),
+        )),
+        LlmProviderType::Cohere => Box::new(
+            CohereProvider::new(required(&config.cohere_api_key, "COHERE_API_KEY")?)
+                .with_model(model_or(&config.cohere_model, DEFAULT_COHERE_MODEL)),
+        ),
+        LlmProviderType::Ollama => {
+            let mut provider = match config.ollama_model {
+                Some(ref model) => OllamaProvider::new(model),
+                None => OllamaProvider::default(),
+            };
+            if let Some(keep_alive) = config.ollama_keep_alive {
+                provider = provider.with_keep_alive(keep_alive);
+            }
+            if let Some(num_ctx) = config.ollama_num_ctx {
+                provider = provider.with_num_ctx(num_ctx);
+            }
+            Box::new(provider.with_base_url(config.ollama_url()))
+        }
+        LlmProviderType::Mock => Box::new(MockProvider::new()),
+    };
+    Ok(Box::new(RetryingProvider::new(provider)))
+}
+
+/// A required, non-blank configuration value
+fn required<'a>(value: &'a Option<String>, name: &str) -> AppResult<&'a str> {
+    value
+        .as_deref()
+        .filter(|v| !v.trim().is_empty())
+        .ok_or_else(|| AppError::ConfigError(format!("{} is not configured", name)))
+}
+
+/// An optional configuration value with a fallback
+fn model_or<'a>(value: &'a Option<String>, default: &'a str) -> &'a str {
+    value.as_deref().unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::traits::{LlmConfig, Message};
+
+    fn full_config() -> Config {
+        Config {
+            openai_api_key: Some("sk-test".to_string()),
+            openai_model: Some("gpt-4o".to_string()),
+            azure_openai_endpoint: Some("https://example.openai.azure.com".to_string()),
+            azure_openai_api_key: Some("azure-key".to_string()),
+            azure_openai_deployment: Some("gpt-4o-deploy".to_string()),
+            openai_compat_base_url: Some("http://localhost:8000/v1".to_string()),
+            openai_compat_model: Some("qwen2.5".to_string()),
+            anthropic_api_key: Some("ant-key".to_string()),
+            aws_region: Some("us-east-1".to_string()),
+            cohere_api_key: Some("co-key".to_string()),
+            ollama_model: Some("llama3.1".to_string()),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_build_provider_selects_each_type() {
+        let config = full_config();
+        let cases = [
+            (LlmProviderType::OpenAi, "openai", "gpt-4o"),
+            (
+                LlmProviderType::AzureOpenAi,
+                "azure-openai",
+                "gpt-4o-deploy",
+            ),
+            (
+                LlmProviderType::OpenAiCompatible,
+                "openai-compatible",
+                "qwen2.5",
+            ),
+            (
+                LlmProviderType::Anthropic,
+                "anthropic",
+                DEFAULT_ANTHROPIC_MODEL,
+            ),
+            (LlmProviderType::Bedrock, "bedrock", DEFAULT_BEDROCK_MODEL),
+            (LlmProviderType::Cohere, "cohere", DEFAULT_COHERE_MODEL),
+            (LlmProviderType::Ollama, "ollama", "llama3.1"),
+            (LlmProviderType::Mock, "mock", "mock-model"),
+        ];
+
+        for (provider_type, name, model) in cases {
+            let provider = build_provider(provider_type, &config).unwrap();
+            assert_eq!(provider.name(), name);
+            assert_eq!(provider.default_model(), model, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_build_provider_missing_credentials() {
+        let config = Config::default();
+        for provider_type in [
+            LlmProviderType::OpenAi,
+            LlmProviderType::AzureOpenAi,
+            LlmProviderType::OpenAiCompatible,
+            LlmProviderType::Anthropic,
+            LlmProviderType::Bedrock,
+            LlmProviderType::Cohere,
+        ] {
+            let result = build_provider(provider_type, &config);
+            assert!(
+                matches!(result, Err(AppError::ConfigError(_))),
+                "{}",
+                provider_type
+            );
+        }
+
+        // Local and offline providers need no credentials
+        assert!(build_provider(LlmProviderType::Ollama, &config).is_ok());
+        assert!(build_provider(LlmProviderType::Mock, &config).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_boxed_provider_complete_value() {
+        let provider = build_provider(LlmProviderType::Mock, &Config::default()).unwrap();
+        let value = provider
+            .complete_value(
+                vec![Message::user(
+                    "JSON配列で返してください。\nタイトル: Attention Is All You Need",
+                )],
+                &LlmConfig::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(value, serde_json::json!(["attention", "need"]));
+    }
+}
@@ -1,9 +1,24 @@
 //! LLM provider implementations
 
 mod anthropic;
+mod azure_openai;
+mod bedrock;
+mod chat_completions;
+mod cohere;
+mod factory;
+mod mock;
 mod ollama;
 mod openai;
+mod openai_compatible;
+mod retrying;
 
 pub use anthropic::AnthropicProvider;
+pub use azure_openai::AzureOpenAiProvider;
+pub use bedrock::BedrockProvider;
+pub use cohere::CohereProvider;
+pub use factory::build_provider;
+pub use mock::MockProvider;
 pub use ollama::OllamaProvider;
 pub use openai::OpenAiProvider;
+pub use openai_compatible::OpenAiCompatibleProvider;
+pub use retrying::{DEFAULT_MAX_ATTEMPTS, DEFAULT_RETRY_DELAY, RetryingProvider};
@@ -1,16 +1,23 @@
 //! Ollama local LLM provider
 
-use crate::agents::traits::{LlmConfig, LlmProvider, Message};
-use crate::shared::errors::{AppError, AppResult};
+use crate::agents::traits::{EmbeddingProvider, LlmConfig, LlmProvider, Message};
+use crate::shared::config::{ClientConfig, Config, OllamaKeepAlive};
+use crate::shared::errors::{AppError, AppResult, parse_retry_after};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
+/// Default model for `/api/embed`
+const DEFAULT_EMBEDDING_MODEL: &str = "nomic-embed-text";
+
 /// Ollama local LLM provider
 pub struct OllamaProvider {
     client: Client,
     base_url: String,
     default_model: String,
+    embedding_model: String,
+    keep_alive: Option<OllamaKeepAlive>,
+    num_ctx: Option<u32>,
 }
 
 impl OllamaProvider {
@@ -20,19 +27,31 @@ impl OllamaProvider {
             client: Client::new(),
             base_url: "http://localhost:11434".to_string(),
             default_model: model.into(),
+            embedding_model: DEFAULT_EMBEDDING_MODEL.to_string(),
+            keep_alive: None,
+            num_ctx: None,
         }
     }
 
     /// Create from environment variables
+    ///
+    /// Besides the URL and models, reads `OLLAMA_KEEP_ALIVE` (e.g. `10m`, or
+    /// `-1` to keep the model loaded) and `OLLAMA_NUM_CTX`; invalid values are
+    /// ignored with a warning.
     pub fn from_env() -> AppResult<Self> {
         let base_url = std::env::var("OLLAMA_BASE_URL")
             .unwrap_or_else(|_| "http://localhost:11434".to_string());
         let model = std::env::var("OLLAMA_MODEL").unwrap_or_else(|_| "llama3.2".to_string());
+        let embedding_model = std::env::var("OLLAMA_EMBEDDING_MODEL")
+            .unwrap_or_else(|_| DEFAULT_EMBEDDING_MODEL.to_string());
 
         Ok(Self {
             client: Client::new(),
             base_url,
             default_model: model,
+            embedding_model,
+            keep_alive: Config::ollama_keep_alive_from_env(),
+            num_ctx: Config::ollama_num_ctx_from_env(),
         })
     }
 
@@ -41,6 +60,71 @@ impl OllamaProvider {
         self.base_url = url.into();
         self
     }
+
+    /// Set the model used for embeddings (default: nomic-embed-text)
+    pub fn with_embedding_model(mut self, model: impl Into<String>) -> Self {
+        self.embedding_model = model.into();
+        self
+    }
+
+    /// Keep the model loaded after each request, for a duration or forever
+    ///
+    /// Avoids reloading the model between calls in batch runs; without it the
+    /// server default applies (5 minutes).
+    pub fn with_keep_alive(mut self, keep_alive: impl Into<OllamaKeepAlive>) -> Self {
+        self.keep_alive = Some(keep_alive.into());
+        self
+    }
+
+    /// Set the context window in tokens (Ollama's `num_ctx`)
+    ///
+    /// Ollama's default is small enough to silently truncate full-text prompts.
+    pub fn with_num_ctx(mut self, num_ctx: u32) -> Self {
+        self.num_ctx = Some(num_ctx);
+        self
+    }
+
+    /// Apply proxy/timeout/user-agent settings to the HTTP client
+    pub fn with_http_config(mut self, config: &ClientConfig) -> AppResult<Self> {
+        self.client = config.build_client()?;
+        Ok(self)
+    }
+
+    /// Build the `/api/chat` request body for a completion
+    fn build_request(&self, messages: Vec<Message>, config: &LlmConfig) -> ChatRequest {
+        let model = if config.model.is_empty() {
+            self.default_model.clone()
+        } else {
+            config.model.clone()
+        };
+
+        let ollama_messages: Vec<OllamaMessage> = messages
+            .into_iter()
+            .map(|m| OllamaMessage {
+                role: m.role.as_str().to_string(),
+                content: m.content,
+            })
+            .collect();
+
+        let options = OllamaOptions {
+            temperature: config.temperature,
+            num_predict: config.max_tokens,
+            top_p: config.top_p,
+            stop: config.stop_sequences.clone(),
+            num_ctx: self.num_ctx,
+        };
+
+        ChatRequest {
+            model,
+            messages: ollama_messages,
+            stream: false,
+            // Constrains decoding to valid JSON, which small local models
+            // otherwise often wrap in prose or truncate
+            format: config.wants_json().then(|| "json".to_string()),
+            options: Some(options),
+            keep_alive: self.keep_alive.map(OllamaKeepAlive::to_request_value),
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -49,7 +133,11 @@ struct ChatRequest {
     messages: Vec<OllamaMessage>,
     stream: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<OllamaOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_alive: Option<serde_json::Value>,
 }
 
 #[derive(Serialize)]
@@ -68,6 +156,19 @@ struct OllamaOptions {
     top_p: Option<f32>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     stop: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_ctx: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct EmbedRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+#[derive(Deserialize)]
+struct EmbedResponse {
+    embeddings: Vec<Vec<f32>>,
 }
 
 #[derive(Deserialize)]
@@ -91,33 +192,7 @@ impl LlmProvider for OllamaProvider {
     }
 
     async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String> {
-        let model = if config.model.is_empty() {
-            self.default_model.clone()
-        } else {
-            config.model.clone()
-        };
-
-        let ollama_messages: Vec<OllamaMessage> = messages
-            .into_iter()
-            .map(|m| OllamaMessage {
-                role: m.role.as_str().to_string(),
-                content: m.content,
-            })
-            .collect();
-
-        let options = OllamaOptions {
-            temperature: config.temperature,
-            num_predict: config.max_tokens,
-            top_p: config.top_p,
-            stop: config.stop_sequences.clone(),
-        };
-
-        let request = ChatRequest {
-            model,
-            messages: ollama_messages,
-            stream: false,
-            options: Some(options),
-        };
+        let request = self.build_request(messages, config);
 
         let response = self
             .client
@@ -134,16 +209,22 @@ impl LlmProvider for OllamaProvider {
             })?;
 
         let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
         let body = response
             .text()
             .await
             .map_err(|e| AppError::LlmError(format!("Failed to read response: {}", e)))?;
 
         if !status.is_success() {
-            return Err(AppError::LlmError(format!(
-                "Ollama API error ({}): {}",
-                status, body
-            )));
+            return Err(AppError::from_llm_status(
+                status.as_u16(),
+                retry_after,
+                format!("Ollama API error ({}): {}", status, body),
+            ));
         }
 
         let chat_response: ChatResponse = serde_json::from_str(&body)
@@ -153,6 +234,60 @@ impl LlmProvider for OllamaProvider {
     }
 }
 
+#[async_trait]
+impl EmbeddingProvider for OllamaProvider {
+    fn embedding_model(&self) -> &str {
+        &self.embedding_model
+    }
+
+    async fn embed(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let request = EmbedRequest {
+            model: &self.embedding_model,
+            input: texts,
+        };
+
+        let response = self
+            .client
+            .post(format!("{}/api/embed", self.base_url))
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::LlmError(format!(
+                    "Failed to connect to Ollama at {}: {}",
+                    self.base_url, e
+                ))
+            })?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::LlmError(format!("Failed to read response: {}", e)))?;
+        if !status.is_success() {
+            return Err(AppError::from_llm_status(
+                status.as_u16(),
+                None,
+                format!("Ollama embed API error ({}): {}", status, body),
+            ));
+        }
+
+        let embed_response: EmbedResponse = serde_json::from_str(&body)
+            .map_err(|e| AppError::LlmError(format!("Failed to parse response: {}", e)))?;
+        if embed_response.embeddings.len() != texts.len() {
+            return Err(AppError::LlmError(format!(
+                "Ollama returned {} embeddings for {} inputs",
+                embed_response.embeddings.len(),
+                texts.len()
+            )));
+        }
+        Ok(embed_response.embeddings)
+    }
+}
+
 impl Default for OllamaProvider {
     /// Create with default model (llama3.2)
     fn default() -> Self {
@@ -163,6 +298,7 @@ impl Default for OllamaProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_provider_name() {
@@ -181,4 +317,66 @@ mod tests {
         let provider = OllamaProvider::new("llama3.2").with_base_url("http://remote:11434");
         assert_eq!(provider.base_url, "http://remote:11434");
     }
+
+    #[test]
+    fn test_request_body_json_mode() {
+        use crate::agents::traits::ResponseFormat;
+
+        let provider = OllamaProvider::new("llama3.2");
+        let config = LlmConfig::new()
+            .with_temperature(0.1)
+            .with_response_format(ResponseFormat::Json);
+        let request = provider.build_request(vec![Message::user("Return JSON.")], &config);
+        let body = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(body["format"], "json");
+        assert_eq!(body["model"], "llama3.2");
+        assert_eq!(body["stream"], false);
+        assert_eq!(body["options"]["num_predict"], 4096);
+
+        let text = provider.build_request(vec![Message::user("Hi")], &LlmConfig::new());
+        let body = serde_json::to_value(&text).unwrap();
+        assert!(body.get("format").is_none());
+    }
+
+    #[test]
+    fn test_request_body_keep_alive_and_num_ctx() {
+        let provider = OllamaProvider::new("llama3.2")
+            .with_keep_alive(Duration::from_secs(600))
+            .with_num_ctx(32_768);
+        let request = provider.build_request(vec![Message::user("Hi")], &LlmConfig::new());
+        let body = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(body["keep_alive"], "600s");
+        assert_eq!(body["options"]["num_ctx"], 32_768);
+
+        let request = OllamaProvider::new("llama3.2")
+            .with_keep_alive(OllamaKeepAlive::Forever)
+            .build_request(vec![Message::user("Hi")], &LlmConfig::new());
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["keep_alive"], -1);
+
+        let request = OllamaProvider::new("llama3.2")
+            .build_request(vec![Message::user("Hi")], &LlmConfig::new());
+        let body = serde_json::to_value(&request).unwrap();
+        assert!(body.get("keep_alive").is_none());
+        assert!(body["options"].get("num_ctx").is_none());
+    }
+
+    #[test]
+    fn test_with_http_config_proxy() {
+        let config = ClientConfig::new().with_proxy("http://proxy.example.com:8080");
+        assert!(
+            OllamaProvider::new("llama3.2")
+                .with_http_config(&config)
+                .is_ok()
+        );
+
+        let invalid = ClientConfig::new().with_proxy("not a url");
+        assert!(
+            OllamaProvider::new("llama3.2")
+                .with_http_config(&invalid)
+                .is_err()
+        );
+    }
 }
@@ -1,14 +1,70 @@
 //! OpenAI API provider using openai-tools crate
 
-use crate::agents::traits::{LlmConfig, LlmProvider, Message, MessageRole};
-use crate::shared::errors::{AppError, AppResult};
+use super::chat_completions::{ChatMessage, ChatResponse};
+use crate::agents::traits::{LlmConfig, LlmProvider, Message, MessageRole, REFUSAL_ERROR_MESSAGE};
+use crate::shared::errors::{AppError, AppResult, parse_retry_after};
 use async_trait::async_trait;
 use openai_tools::chat::request::ChatCompletion;
 use openai_tools::common::message::Message as OpenAiMessage;
 use openai_tools::common::models::ChatModel;
 use openai_tools::common::role::Role as OpenAiRole;
+use openai_tools::common::structured_output::Schema;
+use reqwest::Client;
+use serde::Serialize;
 
-const DEFAULT_OPENAI_MODEL: &str = "gpt-5-mini";
+pub(super) const DEFAULT_OPENAI_MODEL: &str = "gpt-5-mini";
+
+/// Chat Completions endpoint, called directly for JSON mode
+const OPENAI_CHAT_COMPLETIONS_URL: &str = "https://api.openai.com/v1/chat/completions";
+
+/// Model name prefixes that support `response_format: json_schema`
+const STRUCTURED_OUTPUT_MODELS: &[&str] = &["gpt-5", "gpt-4.1", "gpt-4o", "o1", "o3", "o4"];
+
+/// Models matching a structured-output prefix that nevertheless lack support
+const STRUCTURED_OUTPUT_EXCLUDED: &[&str] = &["o1-mini", "o1-preview"];
+
+/// Reasoning model prefixes, which only accept the default temperature
+const REASONING_MODELS: &[&str] = &["o1", "o3", "o4", "gpt-5"];
+
+/// Turn a schema title such as `PaperAnalysis` into a `response_format` name
+/// (`paper_analysis`); characters OpenAI rejects become underscores
+fn schema_name(title: &str) -> String {
+    let mut name = String::with_capacity(title.len() + 4);
+    for (i, c) in title.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() || c == '-' {
+            name.push(c);
+        } else {
+            name.push('_');
+        }
+    }
+    name
+}
+
+/// Check whether a model is a reasoning model (o1/o3/o4/gpt-5 family)
+///
+/// Reasoning models reject custom sampling temperatures, so any configured
+/// temperature is ignored for them. `gpt-5-chat` models are not reasoning models.
+pub fn is_reasoning_model(model: &str) -> bool {
+    REASONING_MODELS
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+        && !model.starts_with("gpt-5-chat")
+}
+
+/// Check whether a model supports structured outputs (JSON Schema enforcement)
+pub fn supports_structured_output(model: &str) -> bool {
+    STRUCTURED_OUTPUT_MODELS
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+        && !STRUCTURED_OUTPUT_EXCLUDED
+            .iter()
+            .any(|prefix| model.starts_with(prefix))
+}
 
 /// OpenAI API provider
 ///
@@ -18,6 +74,8 @@ const DEFAULT_OPENAI_MODEL: &str = "gpt-5-mini";
 pub struct OpenAiProvider {
     /// Default model to use (from OPENAI_MODEL env var or fallback)
     default_model: String,
+    /// HTTP client for JSON-mode requests
+    client: Client,
 }
 
 impl OpenAiProvider {
@@ -27,15 +85,14 @@ impl OpenAiProvider {
     /// the actual key is read from the OPENAI_API_KEY environment variable
     /// by the underlying openai-tools crate.
     pub fn new(_api_key: impl Into<String>) -> Self {
-        Self {
-            default_model: DEFAULT_OPENAI_MODEL.to_string(),
-        }
+        Self::with_model(DEFAULT_OPENAI_MODEL)
     }
 
     /// Create a new OpenAI provider with a custom model
     pub fn with_model(model: impl Into<String>) -> Self {
         Self {
             default_model: model.into(),
+            client: Client::new(),
         }
     }
 
@@ -51,9 +108,7 @@ impl OpenAiProvider {
         let model =
             std::env::var("OPENAI_MODEL").unwrap_or_else(|_| DEFAULT_OPENAI_MODEL.to_string());
 
-        Ok(Self {
-            default_model: model,
-        })
+        Ok(Self::with_model(model))
     }
 
     /// Convert internal Message to openai-tools Message
@@ -66,6 +121,105 @@ impl OpenAiProvider {
         OpenAiMessage::from_string(role, msg.content)
     }
 
+    /// Wrap a JSON Schema into an OpenAI `response_format` schema
+    ///
+    /// The schema name is derived from its `title`, which is moved out of
+    /// the schema body.
+    fn response_format(schema: &serde_json::Value) -> AppResult<Schema> {
+        let mut schema = schema.clone();
+        let name = match schema
+            .as_object_mut()
+            .and_then(|object| object.remove("title"))
+        {
+            Some(serde_json::Value::String(title)) => schema_name(&title),
+            _ => "response".to_string(),
+        };
+
+        serde_json::from_value(serde_json::json!({
+            "type": "json_schema",
+            "json_schema": {
+                "name": name,
+                "strict": true,
+                "schema": schema,
+            }
+        }))
+        .map_err(|e| AppError::LlmError(format!("Invalid JSON schema: {}", e)))
+    }
+
+    /// Build a JSON-mode (`response_format: json_object`) request body
+    fn json_mode_request(
+        model: &str,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> JsonModeRequest {
+        JsonModeRequest {
+            model: model.to_string(),
+            messages: messages.into_iter().map(ChatMessage::from).collect(),
+            temperature: config.temperature.filter(|_| !is_reasoning_model(model)),
+            max_completion_tokens: config.max_tokens,
+            response_format: serde_json::json!({ "type": "json_object" }),
+        }
+    }
+
+    /// Complete in JSON mode
+    ///
+    /// openai-tools only exposes `response_format` through JSON Schema, so
+    /// JSON-mode requests are posted to the Chat Completions API directly.
+    async fn complete_json_mode(
+        &self,
+        model: &str,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> AppResult<String> {
+        let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| {
+            AppError::ConfigError("OPENAI_API_KEY environment variable not set".to_string())
+        })?;
+        let request = Self::json_mode_request(model, messages, config);
+
+        let response = self
+            .client
+            .post(OPENAI_CHAT_COMPLETIONS_URL)
+            .bearer_auth(api_key)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| AppError::LlmError(format!("OpenAI request failed: {}", e)))?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::LlmError(format!("Failed to read response: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(AppError::from_llm_status(
+                status.as_u16(),
+                retry_after,
+                format!("OpenAI API error ({}): {}", status, body),
+            ));
+        }
+
+        let chat_response: ChatResponse = serde_json::from_str(&body)
+            .map_err(|e| AppError::LlmError(format!("Failed to parse response: {}", e)))?;
+        chat_response.into_text()
+    }
+
+}
+
+#[derive(Serialize)]
+struct JsonModeRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_completion_tokens: Option<u32>,
+    response_format: serde_json::Value,
 }
 
 #[async_trait]
@@ -85,6 +239,11 @@ impl LlmProvider for OpenAiProvider {
             config.model.clone()
         };
 
+        if config.json_schema.is_none() && config.wants_json() && supports_structured_output(&model)
+        {
+            return self.complete_json_mode(&model, messages, config).await;
+        }
+
         // Convert messages to openai-tools format
         let openai_messages: Vec<OpenAiMessage> =
             messages.into_iter().map(Self::convert_message).collect();
@@ -95,27 +254,46 @@ impl LlmProvider for OpenAiProvider {
             .messages(openai_messages);
 
         if let Some(temp) = config.temperature {
-            chat.temperature(temp);
+            if is_reasoning_model(&model) {
+                tracing::debug!(
+                    "Ignoring temperature {} for reasoning model {}",
+                    temp,
+                    model
+                );
+            } else {
+                chat.temperature(temp);
+            }
         }
 
         if let Some(max_tokens) = config.max_tokens {
             chat.max_completion_tokens(max_tokens as u64);
         }
 
+        // Enforce the schema when the model supports structured outputs;
+        // otherwise the prompt alone describes the expected JSON
+        if let Some(schema) = &config.json_schema
+            && supports_structured_output(&model)
+        {
+            chat.json_schema(Self::response_format(schema)?);
+        }
+
         // Execute the request
         let response = chat
             .chat()
             .await
-            .map_err(|e| AppError::LlmError(format!("OpenAI API error: {}", e)))?;
-
-        // Extract text from response
-        response
-            .choices
-            .into_iter()
-            .next()
-            .and_then(|c| c.message.content)
+            .map_err(|e| AppError::from_llm_message(format!("OpenAI API error: {}", e)))?;
+
+        // An explicit `refusal` is authoritative; otherwise missing content
+        // means the model refused or returned no text
+        let message = response.choices.into_iter().next().map(|c| c.message);
+        if let Some(refusal) = message.as_ref().and_then(|m| m.refusal.as_deref()) {
+            tracing::debug!("OpenAI refusal: {}", refusal);
+            return Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()));
+        }
+        message
+            .and_then(|m| m.content)
             .and_then(|c| c.text)
-            .ok_or_else(|| AppError::LlmError("No response from OpenAI".to_string()))
+            .ok_or_else(|| AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()))
     }
 }
 
@@ -138,4 +316,59 @@ mod tests {
         assert_eq!(provider.default_model(), "gpt-4-turbo");
     }
 
+    #[test]
+    fn test_is_reasoning_model() {
+        assert!(is_reasoning_model("o1"));
+        assert!(is_reasoning_model("o3-mini"));
+        assert!(is_reasoning_model("gpt-5-mini"));
+        assert!(!is_reasoning_model("gpt-5-chat-latest"));
+        assert!(!is_reasoning_model("gpt-4o"));
+        assert!(!is_reasoning_model("gpt-4.1-mini"));
+    }
+
+    #[test]
+    fn test_supports_structured_output() {
+        assert!(supports_structured_output("gpt-5-mini"));
+        assert!(supports_structured_output("gpt-4o-2024-08-06"));
+        assert!(supports_structured_output("o3-mini"));
+        assert!(!supports_structured_output("gpt-4-turbo"));
+        assert!(!supports_structured_output("gpt-3.5-turbo"));
+        assert!(!supports_structured_output("o1-mini"));
+    }
+
+    #[test]
+    fn test_schema_name_from_title() {
+        assert_eq!(schema_name("PaperAnalysis"), "paper_analysis");
+        assert_eq!(schema_name("paper summary"), "paper_summary");
+
+        let schema = crate::agents::prompts::PromptTemplates::analysis_json_schema();
+        let title = schema["title"].as_str().unwrap();
+        assert_eq!(schema_name(title), "paper_analysis");
+        assert!(OpenAiProvider::response_format(&schema).is_ok());
+    }
+
+    #[test]
+    fn test_json_mode_request() {
+        use crate::agents::traits::ResponseFormat;
+
+        let config = LlmConfig::new()
+            .with_temperature(0.2)
+            .with_response_format(ResponseFormat::Json);
+        let request =
+            OpenAiProvider::json_mode_request("gpt-4o", vec![Message::user("JSON")], &config);
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            body["response_format"],
+            serde_json::json!({ "type": "json_object" })
+        );
+        assert_eq!(body["max_completion_tokens"], 4096);
+        assert!((body["temperature"].as_f64().unwrap() - 0.2).abs() < 1e-6);
+
+        // Reasoning models only accept the default temperature
+        let request =
+            OpenAiProvider::json_mode_request("gpt-5-mini", vec![Message::user("JSON")], &config);
+        let body = serde_json::to_value(&request).unwrap();
+        assert!(body.get("temperature").is_none());
+    }
+
 }
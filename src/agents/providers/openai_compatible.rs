@@ -0,0 +1,320 @@
+//! Generic provider for OpenAI-compatible Chat Completions endpoints
+//!
+//! Works with any server exposing `/v1/chat/completions` in the OpenAI wire
+//! format: DeepSeek, Groq, Together, a local vLLM, and so on.
+
+use super::chat_completions::{ChatMessage, ChatResponse};
+use crate::agents::traits::{LlmConfig, LlmProvider, Message};
+use crate::shared::config::ClientConfig;
+use crate::shared::errors::{AppError, AppResult, parse_retry_after};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Serialize;
+
+/// Environment variable holding the endpoint base URL
+const OPENAI_COMPAT_BASE_URL_ENV: &str = "OPENAI_COMPAT_BASE_URL";
+
+/// Environment variable holding the API key (optional for keyless local servers)
+const OPENAI_COMPAT_API_KEY_ENV: &str = "OPENAI_COMPAT_API_KEY";
+
+/// Environment variable holding the default model
+const OPENAI_COMPAT_MODEL_ENV: &str = "OPENAI_COMPAT_MODEL";
+
+/// OpenAI-compatible provider
+///
+/// `base_url` is the API root including the version segment, e.g.
+/// `https://api.deepseek.com/v1` or `http://localhost:8000/v1`; requests go
+/// to `{base_url}/chat/completions`. Structured outputs are requested with
+/// `response_format: json_object`, which compatible servers support far more
+/// widely than `json_schema`.
+pub struct OpenAiCompatibleProvider {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+    default_model: String,
+}
+
+impl OpenAiCompatibleProvider {
+    /// Create a new provider
+    ///
+    /// The API key is read from the `api_key_env` environment variable; when
+    /// it is unset, requests are sent without an `Authorization` header.
+    pub fn new(
+        base_url: impl Into<String>,
+        api_key_env: &str,
+        default_model: impl Into<String>,
+    ) -> Self {
+        let api_key = std::env::var(api_key_env)
+            .ok()
+            .filter(|k| !k.trim().is_empty());
+        Self::from_parts(base_url.into(), api_key, default_model.into())
+    }
+
+    pub(super) fn from_parts(
+        base_url: String,
+        api_key: Option<String>,
+        default_model: String,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+            default_model,
+        }
+    }
+
+    /// Set custom base URL
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Apply proxy/timeout/user-agent settings to the HTTP client
+    pub fn with_http_config(mut self, config: &ClientConfig) -> AppResult<Self> {
+        self.client = config.build_client()?;
+        Ok(self)
+    }
+
+    /// Create from environment variables
+    ///
+    /// Reads OPENAI_COMPAT_BASE_URL and OPENAI_COMPAT_MODEL (required) and
+    /// OPENAI_COMPAT_API_KEY (optional)
+    pub fn from_env() -> AppResult<Self> {
+        Self::from_env_with_base_url(None)
+    }
+
+    /// Create from environment variables, with `base_url` (e.g. from a CLI
+    /// flag) taking precedence over OPENAI_COMPAT_BASE_URL
+    pub fn from_env_with_base_url(base_url: Option<&str>) -> AppResult<Self> {
+        Self::from_vars(|name| std::env::var(name).ok(), base_url)
+    }
+
+    /// Build the provider from a variable lookup (the environment in `from_env`)
+    fn from_vars(var: impl Fn(&str) -> Option<String>, base_url: Option<&str>) -> AppResult<Self> {
+        let required = |name: &str| {
+            var(name).filter(|v| !v.trim().is_empty()).ok_or_else(|| {
+                AppError::ConfigError(format!("{} environment variable not set", name))
+            })
+        };
+
+        let base_url = match base_url {
+            Some(url) => url.to_string(),
+            None => required(OPENAI_COMPAT_BASE_URL_ENV)?,
+        };
+        let model = required(OPENAI_COMPAT_MODEL_ENV)?;
+        let api_key = var(OPENAI_COMPAT_API_KEY_ENV).filter(|k| !k.trim().is_empty());
+
+        Ok(Self::from_parts(base_url, api_key, model))
+    }
+
+    /// Chat Completions URL
+    fn chat_url(&self) -> String {
+        format!("{}/chat/completions", self.base_url)
+    }
+
+    /// Build the request body for a completion
+    fn build_request(&self, messages: Vec<Message>, config: &LlmConfig) -> ChatRequest {
+        let model = if config.model.is_empty() {
+            self.default_model.clone()
+        } else {
+            config.model.clone()
+        };
+
+        ChatRequest {
+            model,
+            messages: messages.into_iter().map(ChatMessage::from).collect(),
+            temperature: config.temperature,
+            max_tokens: config.max_tokens,
+            top_p: config.top_p,
+            stop: config.stop_sequences.clone(),
+            response_format: (config.json_schema.is_some() || config.wants_json())
+                .then(|| serde_json::json!({ "type": "json_object" })),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ChatRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<serde_json::Value>,
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiCompatibleProvider {
+    fn name(&self) -> &str {
+        "openai-compatible"
+    }
+
+    fn default_model(&self) -> &str {
+        &self.default_model
+    }
+
+    async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String> {
+        let request = self.build_request(messages, config);
+
+        let mut builder = self.client.post(self.chat_url()).json(&request);
+        if let Some(ref api_key) = self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| AppError::LlmError(format!("OpenAI-compatible request failed: {}", e)))?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::LlmError(format!("Failed to read response: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(AppError::from_llm_status(
+                status.as_u16(),
+                retry_after,
+                format!("OpenAI-compatible API error ({}): {}", status, body),
+            ));
+        }
+
+        let chat_response: ChatResponse = serde_json::from_str(&body)
+            .map_err(|e| AppError::LlmError(format!("Failed to parse response: {}", e)))?;
+
+        chat_response.into_text()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_provider_name() {
+        let provider = OpenAiCompatibleProvider::from_parts(
+            "https://api.deepseek.com/v1".to_string(),
+            None,
+            "deepseek-chat".to_string(),
+        );
+        assert_eq!(provider.name(), "openai-compatible");
+        assert_eq!(provider.default_model(), "deepseek-chat");
+    }
+
+    #[test]
+    fn test_url_and_key_from_env() {
+        let provider = OpenAiCompatibleProvider::from_vars(
+            lookup(&[
+                ("OPENAI_COMPAT_BASE_URL", "https://api.groq.com/openai/v1/"),
+                ("OPENAI_COMPAT_API_KEY", "secret"),
+                ("OPENAI_COMPAT_MODEL", "llama-3.3-70b-versatile"),
+            ]),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            provider.chat_url(),
+            "https://api.groq.com/openai/v1/chat/completions"
+        );
+        assert_eq!(provider.api_key.as_deref(), Some("secret"));
+        assert_eq!(provider.default_model(), "llama-3.3-70b-versatile");
+    }
+
+    #[test]
+    fn test_base_url_override_and_keyless() {
+        let provider = OpenAiCompatibleProvider::from_vars(
+            lookup(&[
+                ("OPENAI_COMPAT_BASE_URL", "https://api.deepseek.com/v1"),
+                ("OPENAI_COMPAT_MODEL", "qwen2.5-7b"),
+            ]),
+            Some("http://localhost:8000/v1"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            provider.chat_url(),
+            "http://localhost:8000/v1/chat/completions"
+        );
+        assert!(provider.api_key.is_none());
+    }
+
+    #[test]
+    fn test_missing_env_is_config_error() {
+        let result = OpenAiCompatibleProvider::from_vars(
+            lookup(&[("OPENAI_COMPAT_MODEL", "deepseek-chat")]),
+            None,
+        );
+        match result {
+            Err(AppError::ConfigError(message)) => {
+                assert!(message.contains("OPENAI_COMPAT_BASE_URL"))
+            }
+            _ => panic!("expected ConfigError"),
+        }
+
+        let result =
+            OpenAiCompatibleProvider::from_vars(lookup(&[]), Some("http://localhost:8000/v1"));
+        match result {
+            Err(AppError::ConfigError(message)) => assert!(message.contains("OPENAI_COMPAT_MODEL")),
+            _ => panic!("expected ConfigError"),
+        }
+    }
+
+    #[test]
+    fn test_request_conversion() {
+        let provider = OpenAiCompatibleProvider::from_parts(
+            "https://api.deepseek.com/v1".to_string(),
+            None,
+            "deepseek-chat".to_string(),
+        );
+        let config = LlmConfig {
+            temperature: Some(0.2),
+            max_tokens: Some(512),
+            json_schema: Some(serde_json::json!({ "type": "object" })),
+            ..Default::default()
+        };
+
+        let request = provider.build_request(
+            vec![Message::system("Be brief."), Message::user("Summarize.")],
+            &config,
+        );
+        let body = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(body["model"], "deepseek-chat");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][0]["content"], "Be brief.");
+        assert_eq!(body["messages"][1]["role"], "user");
+        assert_eq!(body["max_tokens"], 512);
+        assert_eq!(body["response_format"]["type"], "json_object");
+        assert!(body.get("top_p").is_none());
+        assert!(body.get("stop").is_none());
+
+        let config = LlmConfig {
+            model: "deepseek-reasoner".to_string(),
+            ..Default::default()
+        };
+        let request = provider.build_request(vec![Message::user("Hi")], &config);
+        assert_eq!(request.model, "deepseek-reasoner");
+        assert!(request.response_format.is_none());
+    }
+}
@@ -0,0 +1,203 @@
+//! Retry wrapper for LLM providers
+
+use crate::agents::traits::{LlmConfig, LlmProvider, Message};
+use crate::models::RequestStats;
+use crate::shared::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use std::time::{Duration, Instant};
+
+/// Default number of attempts per request, including the first
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Default delay before the first retry; doubled for each further retry
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Provider wrapper that retries rate-limited and server errors
+///
+/// Errors for which [`AppError::is_retryable`] holds are retried up to
+/// `max_attempts` attempts in total, waiting for the provider's
+/// `Retry-After` delay when one was given and backing off exponentially from
+/// `base_delay` otherwise. [`LlmProvider::complete_with_stats`] reports the
+/// attempts actually made.
+#[derive(Debug, Clone)]
+pub struct RetryingProvider<P> {
+    inner: P,
+    max_attempts: u32,
+    base_delay: Duration,
+}
+
+impl<P: LlmProvider> RetryingProvider<P> {
+    /// Wrap `inner` with the default retry policy
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_RETRY_DELAY,
+        }
+    }
+
+    /// Set the number of attempts per request (at least 1)
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// The wrapped provider
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Delay before retrying after the `attempt`-th attempt failed with `error`
+    fn retry_delay(&self, attempt: u32, error: &AppError) -> Duration {
+        match error {
+            AppError::LlmRateLimited {
+                retry_after: Some(delay),
+            } => *delay,
+            _ => self.base_delay * 2u32.saturating_pow(attempt - 1),
+        }
+    }
+}
+
+#[async_trait]
+impl<P: LlmProvider> LlmProvider for RetryingProvider<P> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn default_model(&self) -> &str {
+        self.inner.default_model()
+    }
+
+    fn max_context_tokens(&self) -> usize {
+        self.inner.max_context_tokens()
+    }
+
+    fn effective_context_tokens(&self, config: &LlmConfig) -> usize {
+        self.inner.effective_context_tokens(config)
+    }
+
+    async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String> {
+        let (response, _) = self.complete_with_stats(messages, config).await?;
+        Ok(response)
+    }
+
+    async fn complete_with_stats(
+        &self,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> AppResult<(String, RequestStats)> {
+        let started = Instant::now();
+        let mut attempt = 1;
+        loop {
+            match self.inner.complete(messages.clone(), config).await {
+                Ok(response) => {
+                    return Ok((response, RequestStats::new(attempt, started.elapsed())));
+                }
+                Err(e) if e.is_retryable() && attempt < self.max_attempts => {
+                    let delay = self.retry_delay(attempt, &e);
+                    tracing::warn!(
+                        "{} request failed (attempt {}/{}), retrying in {:?}: {}",
+                        self.inner.name(),
+                        attempt,
+                        self.max_attempts,
+                        delay,
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    // A health check should report failures rather than retry past them
+    async fn health_check(&self) -> AppResult<()> {
+        self.inner.health_check().await
+    }
+
+    fn is_refusal(&self, response: &str) -> bool {
+        self.inner.is_refusal(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::providers::MockProvider;
+
+    fn rate_limited() -> AppError {
+        AppError::LlmRateLimited { retry_after: None }
+    }
+
+    fn retrying(mock: &MockProvider) -> RetryingProvider<MockProvider> {
+        RetryingProvider::new(mock.clone()).with_base_delay(Duration::ZERO)
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_errors_and_reports_attempts() {
+        let mock = MockProvider::new().with_failures(2, rate_limited);
+
+        let (response, stats) = retrying(&mock)
+            .complete_with_stats(
+                vec![Message::user("Translate this.")],
+                &LlmConfig::default(),
+            )
+            .await
+            .unwrap();
+        assert!(response.starts_with("[mock]"));
+        assert_eq!(stats.attempts, 3);
+        assert!(stats.retried);
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let mock = MockProvider::new().with_failures(5, || AppError::LlmServer(503));
+
+        let err = retrying(&mock)
+            .with_max_attempts(2)
+            .complete(
+                vec![Message::user("Translate this.")],
+                &LlmConfig::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::LlmServer(503)));
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_permanent_errors() {
+        let mock = MockProvider::new().with_failures(1, || AppError::LlmAuth("bad key".into()));
+
+        let err = retrying(&mock)
+            .complete(
+                vec![Message::user("Translate this.")],
+                &LlmConfig::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::LlmAuth(_)));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_retry_delay_prefers_retry_after() {
+        let provider = RetryingProvider::new(MockProvider::new());
+        let error = AppError::LlmRateLimited {
+            retry_after: Some(Duration::from_secs(7)),
+        };
+        assert_eq!(provider.retry_delay(1, &error), Duration::from_secs(7));
+        assert_eq!(
+            provider.retry_delay(3, &rate_limited()),
+            DEFAULT_RETRY_DELAY * 4
+        );
+    }
+}
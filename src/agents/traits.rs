@@ -1,9 +1,10 @@
 //! Core traits for LLM providers and analysis agents
 
-use crate::models::{AcademicPaper, PaperAnalysis};
-use crate::shared::errors::AppResult;
+use crate::models::{AcademicPaper, PaperAnalysis, RequestStats};
+use crate::shared::errors::{AppError, AppResult};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::time::Instant;
 
 /// Role of a message in a conversation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -62,6 +63,17 @@ impl Message {
     }
 }
 
+/// Output format requested from the model
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFormat {
+    /// Free-form text
+    #[default]
+    Text,
+    /// A single JSON object (Ollama `format: "json"`, OpenAI JSON mode)
+    Json,
+}
+
 /// Configuration for LLM requests
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmConfig {
@@ -79,6 +91,17 @@ pub struct LlmConfig {
 
     /// Stop sequences
     pub stop_sequences: Vec<String>,
+
+    /// Context window override in tokens (defaults to the provider/model limit)
+    pub context_window: Option<usize>,
+
+    /// JSON Schema the response must follow (enforced by providers that
+    /// support structured outputs, ignored by the others)
+    pub json_schema: Option<serde_json::Value>,
+
+    /// Output format to request from providers with a JSON mode
+    #[serde(default)]
+    pub response_format: ResponseFormat,
 }
 
 impl Default for LlmConfig {
@@ -89,6 +112,9 @@ impl Default for LlmConfig {
             model: String::new(), // Provider-specific default
             top_p: None,
             stop_sequences: Vec::new(),
+            context_window: None,
+            json_schema: None,
+            response_format: ResponseFormat::Text,
         }
     }
 }
@@ -128,6 +154,68 @@ impl LlmConfig {
         self.stop_sequences.push(seq.into());
         self
     }
+
+    /// Override the context window size (in tokens)
+    pub fn with_context_window(mut self, tokens: usize) -> Self {
+        self.context_window = Some(tokens);
+        self
+    }
+
+    /// Request structured output following the given JSON Schema
+    pub fn with_json_schema(mut self, schema: serde_json::Value) -> Self {
+        self.json_schema = Some(schema);
+        self
+    }
+
+    /// Set the output format
+    pub fn with_response_format(mut self, format: ResponseFormat) -> Self {
+        self.response_format = format;
+        self
+    }
+
+    /// Whether JSON mode was requested
+    pub fn wants_json(&self) -> bool {
+        self.response_format == ResponseFormat::Json
+    }
+}
+
+/// Conservative context window used when the model is unknown
+pub const DEFAULT_CONTEXT_TOKENS: usize = 8_192;
+
+/// Known context windows by model name prefix (first match wins)
+const MODEL_CONTEXT_WINDOWS: &[(&str, usize)] = &[
+    ("gpt-5", 400_000),
+    ("gpt-4.1", 1_047_576),
+    ("gpt-4o", 128_000),
+    ("gpt-4-turbo", 128_000),
+    ("gpt-4", 8_192),
+    ("gpt-3.5-turbo", 16_385),
+    ("o1", 200_000),
+    ("o3", 200_000),
+    ("o4", 200_000),
+    ("claude", 200_000),
+    ("command-r", 128_000),
+    ("llama3.1", 128_000),
+    ("llama3.2", 128_000),
+    ("llama3.3", 128_000),
+    ("llama3", 8_192),
+    ("qwen2.5", 32_768),
+    ("qwen3", 40_960),
+    ("mistral", 32_768),
+    ("gemma2", 8_192),
+    ("gemma3", 128_000),
+];
+
+/// Look up the context window (in tokens) for a model name
+///
+/// Falls back to [`DEFAULT_CONTEXT_TOKENS`] for unknown models.
+pub fn context_window_for_model(model: &str) -> usize {
+    let model = model.to_lowercase();
+    MODEL_CONTEXT_WINDOWS
+        .iter()
+        .find(|(prefix, _)| model.starts_with(prefix))
+        .map(|&(_, tokens)| tokens)
+        .unwrap_or(DEFAULT_CONTEXT_TOKENS)
 }
 
 /// Trait for LLM providers (OpenAI, Anthropic, Ollama, etc.)
@@ -139,47 +227,309 @@ pub trait LlmProvider: Send + Sync {
     /// Get the default model for this provider
     fn default_model(&self) -> &str;
 
+    /// Maximum context window (prompt + completion) of the default model, in tokens
+    fn max_context_tokens(&self) -> usize {
+        context_window_for_model(self.default_model())
+    }
+
+    /// Context window that applies to a request made with `config`
+    ///
+    /// An explicit `config.context_window` wins, then the window of
+    /// `config.model`, then [`LlmProvider::max_context_tokens`].
+    fn effective_context_tokens(&self, config: &LlmConfig) -> usize {
+        match config.context_window {
+            Some(tokens) => tokens,
+            None if !config.model.is_empty() => context_window_for_model(&config.model),
+            None => self.max_context_tokens(),
+        }
+    }
+
     /// Send a completion request and get a text response
     async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String>;
 
+    /// Send a completion request and report the attempts it took
+    ///
+    /// [`RetryingProvider`](crate::agents::RetryingProvider) overrides this to
+    /// report every attempt; the default makes a single
+    /// [`LlmProvider::complete`] call.
+    async fn complete_with_stats(
+        &self,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> AppResult<(String, RequestStats)> {
+        let started = Instant::now();
+        let response = self.complete(messages, config).await?;
+        Ok((response, RequestStats::single(started.elapsed())))
+    }
+
     /// Send a completion request expecting JSON response
     async fn complete_json<T: DeserializeOwned + Send>(
         &self,
         messages: Vec<Message>,
         config: &LlmConfig,
-    ) -> AppResult<T> {
+    ) -> AppResult<T>
+    where
+        Self: Sized,
+    {
         let response = self.complete(messages, config).await?;
+        if self.is_refusal(&response) {
+            return Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()));
+        }
         self.parse_json_response(&response)
     }
 
-    /// Parse JSON from response text (handles markdown code blocks)
-    fn parse_json_response<T: DeserializeOwned>(&self, response: &str) -> AppResult<T> {
-        // Try to extract JSON from markdown code blocks
-        let json_str = if response.contains("```json") {
-            response
-                .split("```json")
-                .nth(1)
-                .and_then(|s| s.split("```").next())
-                .map(|s| s.trim())
-                .unwrap_or(response)
-        } else if response.contains("```") {
-            response
-                .split("```")
-                .nth(1)
-                .map(|s| s.trim())
-                .unwrap_or(response)
-        } else {
-            response.trim()
-        };
-
-        serde_json::from_str(json_str).map_err(|e| {
-            crate::shared::errors::AppError::LlmError(format!(
-                "Failed to parse JSON response: {}. Response: {}",
-                e,
-                &response[..response.len().min(500)]
-            ))
-        })
+    /// Like [`LlmProvider::complete_json`], also returning the request stats
+    async fn complete_json_with_stats<T: DeserializeOwned + Send>(
+        &self,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> AppResult<(T, RequestStats)>
+    where
+        Self: Sized,
+    {
+        let (response, stats) = self.complete_with_stats(messages, config).await?;
+        if self.is_refusal(&response) {
+            return Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()));
+        }
+        Ok((self.parse_json_response(&response)?, stats))
+    }
+
+    /// Send a completion request expecting JSON, returned as an untyped value
+    ///
+    /// Object-safe counterpart of [`LlmProvider::complete_json`], usable
+    /// through `Box<dyn LlmProvider>`.
+    async fn complete_value(
+        &self,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> AppResult<serde_json::Value> {
+        let response = self.complete(messages, config).await?;
+        if self.is_refusal(&response) {
+            return Err(AppError::LlmError(REFUSAL_ERROR_MESSAGE.to_string()));
+        }
+        parse_json_text(&response)
+    }
+
+    /// Lightweight connectivity and credential check
+    ///
+    /// Sends a minimal completion request. A refusal or empty reply still
+    /// proves the endpoint is reachable and the credentials are accepted.
+    async fn health_check(&self) -> AppResult<()> {
+        let config = LlmConfig::default().with_max_tokens(16);
+        match self.complete(vec![Message::user("ping")], &config).await {
+            Ok(_) => Ok(()),
+            Err(AppError::LlmError(msg)) if msg == REFUSAL_ERROR_MESSAGE => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Check whether a response is a refusal or carries no usable content
+    ///
+    /// Providers sometimes answer with HTTP 200 and text such as
+    /// "I cannot help with that" instead of the requested output.
+    fn is_refusal(&self, response: &str) -> bool {
+        is_refusal_text(response)
+    }
+
+    /// Parse JSON from response text (handles code fences and surrounding prose)
+    fn parse_json_response<T: DeserializeOwned>(&self, response: &str) -> AppResult<T>
+    where
+        Self: Sized,
+    {
+        parse_json_text(response)
+    }
+}
+
+/// Trait for providers that turn text into embedding vectors
+#[async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Name of the model producing the embeddings
+    ///
+    /// Vectors from different models are not comparable, so a
+    /// [`PaperIndex`](crate::index::PaperIndex) records it.
+    fn embedding_model(&self) -> &str;
+
+    /// Embed a batch of texts, returning one vector per input in order
+    async fn embed(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>>;
+}
+
+/// Boxed providers, as returned by [`crate::agents::providers::build_provider`]
+#[async_trait]
+impl LlmProvider for Box<dyn LlmProvider> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn default_model(&self) -> &str {
+        (**self).default_model()
+    }
+
+    fn max_context_tokens(&self) -> usize {
+        (**self).max_context_tokens()
+    }
+
+    fn effective_context_tokens(&self, config: &LlmConfig) -> usize {
+        (**self).effective_context_tokens(config)
+    }
+
+    async fn complete(&self, messages: Vec<Message>, config: &LlmConfig) -> AppResult<String> {
+        (**self).complete(messages, config).await
+    }
+
+    async fn complete_with_stats(
+        &self,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> AppResult<(String, RequestStats)> {
+        (**self).complete_with_stats(messages, config).await
+    }
+
+    async fn complete_value(
+        &self,
+        messages: Vec<Message>,
+        config: &LlmConfig,
+    ) -> AppResult<serde_json::Value> {
+        (**self).complete_value(messages, config).await
+    }
+
+    async fn health_check(&self) -> AppResult<()> {
+        (**self).health_check().await
+    }
+
+    fn is_refusal(&self, response: &str) -> bool {
+        (**self).is_refusal(response)
+    }
+}
+
+/// Parse a JSON value out of raw LLM output
+///
+/// Prefers the contents of a ```` ```json ```` / ```` ``` ```` fence when they
+/// hold valid JSON, then the first balanced top-level JSON object or array in
+/// the whole text (so prose before or after the JSON, including stray fences,
+/// is ignored), and finally falls back to the whole string.
+pub(crate) fn parse_json_text<T: DeserializeOwned>(response: &str) -> AppResult<T> {
+    let json_str = fenced_block(response)
+        .and_then(json_in)
+        .or_else(|| json_in(response))
+        .unwrap_or(response.trim());
+
+    serde_json::from_str(json_str).map_err(|e| {
+        let preview: String = response.chars().take(500).collect();
+        AppError::LlmResponseParse(format!("{}. Response: {}", e, preview))
+    })
+}
+
+/// Contents of the first markdown code fence, if any
+fn fenced_block(response: &str) -> Option<&str> {
+    let after = if let Some((_, rest)) = response.split_once("```json") {
+        rest
+    } else {
+        response.split_once("```")?.1
+    };
+    let inner = after.split("```").next().unwrap_or(after);
+    Some(inner.trim())
+}
+
+/// Valid JSON in `text`: its first JSON object or array, or the whole text
+fn json_in(text: &str) -> Option<&str> {
+    extract_json_block(text).or_else(|| {
+        let trimmed = text.trim();
+        serde_json::from_str::<serde::de::IgnoredAny>(trimmed)
+            .is_ok()
+            .then_some(trimmed)
+    })
+}
+
+/// Locate the first balanced top-level JSON object or array in `text`
+///
+/// Brackets inside string literals are ignored. Candidates that are balanced
+/// but not valid JSON (e.g. "[see below]" in prose) are skipped.
+fn extract_json_block(text: &str) -> Option<&str> {
+    let mut search_from = 0;
+    while let Some(offset) = text[search_from..].find(['{', '[']) {
+        let start = search_from + offset;
+        if let Some(end) = matching_bracket(&text[start..]) {
+            let candidate = &text[start..start + end];
+            if serde_json::from_str::<serde::de::IgnoredAny>(candidate).is_ok() {
+                return Some(candidate);
+            }
+        }
+        search_from = start + 1;
+    }
+    None
+}
+
+/// Byte length of the bracketed value at the start of `text`, if balanced
+fn matching_bracket(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
     }
+    None
+}
+
+/// Error message used when a model refuses or returns no usable content
+pub(crate) const REFUSAL_ERROR_MESSAGE: &str = "model refused or returned non-content";
+
+/// `stop_reason` Anthropic models (direct API and Bedrock) report for a refusal
+pub(crate) const REFUSAL_STOP_REASON: &str = "refusal";
+
+/// Phrases that typically open a refusal or safety response
+const REFUSAL_PHRASES: &[&str] = &[
+    "i cannot",
+    "i can't",
+    "i can not",
+    "i'm sorry",
+    "i am sorry",
+    "i'm unable",
+    "i am unable",
+    "i won't",
+    "i will not",
+    "as an ai",
+    "申し訳ありません",
+    "申し訳ございません",
+    "お手伝いできません",
+    "対応できません",
+];
+
+/// Heuristic refusal detection on raw response text
+///
+/// Empty responses count as non-content. Responses that contain a JSON
+/// object or array are never treated as refusals, so a refusal phrase quoted
+/// inside a real analysis does not trigger a false positive.
+fn is_refusal_text(response: &str) -> bool {
+    let trimmed = response.trim();
+    if trimmed.is_empty() {
+        return true;
+    }
+    if trimmed.contains('{') || trimmed.contains('[') {
+        return false;
+    }
+
+    let lower = trimmed.to_lowercase();
+    REFUSAL_PHRASES.iter().any(|phrase| lower.contains(phrase))
 }
 
 /// Trait for paper analysis agents
@@ -194,6 +544,12 @@ pub trait AnalysisAgent: Send + Sync {
     /// Generate methodology description
     async fn generate_methodology(&self, paper: &AcademicPaper) -> AppResult<String>;
 
+    /// Extract the main contributions as a list
+    async fn extract_key_contributions(&self, paper: &AcademicPaper) -> AppResult<Vec<String>>;
+
+    /// Extract the research fields and tasks the paper addresses
+    async fn extract_research_tasks(&self, paper: &AcademicPaper) -> AppResult<Vec<String>>;
+
     /// Translate text to Japanese
     async fn translate_to_japanese(&self, text: &str) -> AppResult<String>;
 }
@@ -220,4 +576,92 @@ mod tests {
         assert_eq!(config.max_tokens, Some(2048));
         assert_eq!(config.model, "gpt-4");
     }
+
+    #[test]
+    fn test_context_window_for_model() {
+        assert_eq!(
+            context_window_for_model("claude-sonnet-4-20250514"),
+            200_000
+        );
+        assert_eq!(context_window_for_model("gpt-4o-mini"), 128_000);
+        assert_eq!(context_window_for_model("gpt-4"), 8_192);
+        assert_eq!(context_window_for_model("llama3.2"), 128_000);
+        assert_eq!(
+            context_window_for_model("some-unknown-model"),
+            DEFAULT_CONTEXT_TOKENS
+        );
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Sample {
+        summary: String,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_parse_json_text_prose_prefix() {
+        let response = "Here is the analysis:\n{\"summary\": \"ok\", \"tags\": [\"a\"]}";
+        let parsed: Sample = parse_json_text(response).unwrap();
+        assert_eq!(parsed.summary, "ok");
+        assert_eq!(parsed.tags, vec!["a"]);
+    }
+
+    #[test]
+    fn test_parse_json_text_fenced() {
+        let response = "Sure!\n```json\n{\"summary\": \"fenced\", \"tags\": []}\n```\nDone.";
+        let parsed: Sample = parse_json_text(response).unwrap();
+        assert_eq!(parsed.summary, "fenced");
+
+        let response = "```\n[\"x\", \"y\"]\n```";
+        let parsed: Vec<String> = parse_json_text(response).unwrap();
+        assert_eq!(parsed, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn test_parse_json_text_ignores_fences_outside_json() {
+        let response = "Here is the analysis: {\"summary\": \"ok\", \"tags\": []}\n\nTo use it:\n```\nparse(response)\n```";
+        let parsed: Sample = parse_json_text(response).unwrap();
+        assert_eq!(parsed.summary, "ok");
+
+        let response = "```json\n{\"summary\": \"run ```cargo test``` first\", \"tags\": []}\n```";
+        let parsed: Sample = parse_json_text(response).unwrap();
+        assert_eq!(parsed.summary, "run ```cargo test``` first");
+    }
+
+    #[test]
+    fn test_parse_json_text_trailing_commentary() {
+        let response = "{\"summary\": \"uses {braces} and \\\"quotes\\\"\", \"tags\": [\"b\"]}\n\nLet me know if you need more detail [1].";
+        let parsed: Sample = parse_json_text(response).unwrap();
+        assert_eq!(parsed.summary, "uses {braces} and \"quotes\"");
+    }
+
+    #[test]
+    fn test_parse_json_text_skips_prose_brackets() {
+        let response = "The result [see below] follows: {\"summary\": \"s\", \"tags\": []}";
+        let parsed: Sample = parse_json_text(response).unwrap();
+        assert_eq!(parsed.summary, "s");
+    }
+
+    #[test]
+    fn test_parse_json_text_invalid() {
+        let result: AppResult<Sample> = parse_json_text("no json here");
+        assert!(matches!(result, Err(AppError::LlmResponseParse(_))));
+    }
+
+    #[test]
+    fn test_is_refusal_text() {
+        assert!(is_refusal_text(""));
+        assert!(is_refusal_text("   \n"));
+        assert!(is_refusal_text("I cannot help with that request."));
+        assert!(is_refusal_text("I'm sorry, but I can't assist with this."));
+        assert!(is_refusal_text("申し訳ありませんが、お手伝いできません。"));
+
+        assert!(!is_refusal_text(
+            r#"{"summary": "I cannot believe how good this is"}"#
+        ));
+        assert!(!is_refusal_text("```json\n[\"a\", \"b\"]\n```"));
+        assert!(!is_refusal_text(
+            "The paper proposes a new attention mechanism."
+        ));
+    }
 }
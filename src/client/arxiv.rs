@@ -1,33 +1,420 @@
 //! arXiv API client wrapper
 
+use crate::models::{AcademicPaper, ArxivVersion};
 use crate::shared::errors::{AppError, AppResult};
 use arxiv_tools::{ArXiv, Paper as ArxivPaper, QueryParams, SortBy, SortOrder};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use regex::Regex;
+use serde::Deserialize;
+use std::sync::LazyLock;
+use strsim::normalized_levenshtein;
 
 use super::search::SearchParams;
 
+/// arXiv Atom API endpoint (used directly for result counts and category listings)
+const ARXIV_API_URL: &str = "https://export.arxiv.org/api/query";
+
+/// arXiv abstract page base URL (used for the submission history)
+const ARXIV_ABS_URL: &str = "https://arxiv.org/abs";
+
+/// One `[vN] <date> UTC (<size>)` entry of an abs page's submission history
+static VERSION_ENTRY_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[v(\d+)\](?:</a>)?\s*</strong>\s*([^<(]+?)\s*\(([^)]*)\)")
+        .expect("valid version entry regex")
+});
+
+/// An arXiv Atom feed, as returned by the query API
+///
+/// Namespaced elements (`opensearch:totalResults`, `arxiv:doi`, …) are
+/// accepted with or without their prefix.
+#[derive(Debug, Deserialize)]
+struct AtomFeed {
+    #[serde(rename = "totalResults", alias = "opensearch:totalResults")]
+    total_results: Option<usize>,
+    #[serde(rename = "entry", default)]
+    entries: Vec<AtomEntry>,
+}
+
+/// One `<entry>` of an arXiv Atom feed
+#[derive(Debug, Deserialize)]
+struct AtomEntry {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    published: String,
+    #[serde(default)]
+    updated: String,
+    #[serde(rename = "author", default)]
+    authors: Vec<AtomAuthor>,
+    #[serde(alias = "arxiv:doi", default)]
+    doi: String,
+    #[serde(alias = "arxiv:comment")]
+    comment: Option<String>,
+    #[serde(alias = "arxiv:journal_ref", default)]
+    journal_ref: String,
+    #[serde(rename = "link", default)]
+    links: Vec<AtomLink>,
+    #[serde(alias = "arxiv:primary_category")]
+    primary_category: Option<AtomCategory>,
+    #[serde(rename = "category", default)]
+    categories: Vec<AtomCategory>,
+}
+
+/// `<author>` of a feed entry
+#[derive(Debug, Deserialize)]
+struct AtomAuthor {
+    #[serde(default)]
+    name: String,
+}
+
+/// `<link>` of a feed entry
+#[derive(Debug, Deserialize)]
+struct AtomLink {
+    #[serde(rename = "@href")]
+    href: String,
+    #[serde(rename = "@title")]
+    title: Option<String>,
+}
+
+/// `<category>` / `<arxiv:primary_category>` of a feed entry
+#[derive(Debug, Deserialize)]
+struct AtomCategory {
+    #[serde(rename = "@term")]
+    term: String,
+}
+
+impl AtomEntry {
+    /// Convert to the `arxiv_tools` paper type, or `None` without an ID
+    fn into_paper(self) -> Option<ArxivPaper> {
+        if self.id.trim().is_empty() {
+            return None;
+        }
+        let mut categories: Vec<String> = Vec::new();
+        for category in self.categories {
+            if !categories.contains(&category.term) {
+                categories.push(category.term);
+            }
+        }
+        let pdf_url = self
+            .links
+            .into_iter()
+            .find(|link| link.title.as_deref() == Some("pdf"))
+            .map(|link| link.href)
+            .unwrap_or_default();
+
+        Some(ArxivPaper {
+            id: self.id.trim().to_string(),
+            title: self.title.split_whitespace().collect::<Vec<_>>().join(" "),
+            authors: self
+                .authors
+                .into_iter()
+                .map(|author| author.name.trim().to_string())
+                .collect(),
+            abstract_text: self.summary.trim().to_string(),
+            published: self.published.trim().to_string(),
+            updated: self.updated.trim().to_string(),
+            doi: self.doi.trim().to_string(),
+            comment: self
+                .comment
+                .map(|comment| comment.trim().to_string())
+                .into_iter()
+                .collect(),
+            journal_ref: self.journal_ref.trim().to_string(),
+            pdf_url,
+            primary_category: self
+                .primary_category
+                .map(|category| category.term)
+                .unwrap_or_default(),
+            categories,
+        })
+    }
+}
+
+/// Known arXiv categories as `(code, name, aliases)`
+///
+/// Names follow the arXiv category taxonomy; aliases are common ways of
+/// referring to the field. Covers all of `cs.*` and the categories most often
+/// cross-listed with it. Where two categories share a name, the first wins
+/// name lookups.
+pub const ARXIV_CATEGORIES: &[(&str, &str, &[&str])] = &[
+    ("cs.AI", "Artificial Intelligence", &["ai"]),
+    ("cs.AR", "Hardware Architecture", &["computer architecture"]),
+    ("cs.CC", "Computational Complexity", &["complexity theory"]),
+    (
+        "cs.CE",
+        "Computational Engineering, Finance, and Science",
+        &[],
+    ),
+    ("cs.CG", "Computational Geometry", &[]),
+    (
+        "cs.CL",
+        "Computation and Language",
+        &[
+            "natural language processing",
+            "nlp",
+            "computational linguistics",
+        ],
+    ),
+    (
+        "cs.CR",
+        "Cryptography and Security",
+        &["security", "cryptography"],
+    ),
+    (
+        "cs.CV",
+        "Computer Vision and Pattern Recognition",
+        &["computer vision", "pattern recognition"],
+    ),
+    ("cs.CY", "Computers and Society", &[]),
+    ("cs.DB", "Databases", &[]),
+    (
+        "cs.DC",
+        "Distributed, Parallel, and Cluster Computing",
+        &["distributed computing", "parallel computing"],
+    ),
+    ("cs.DL", "Digital Libraries", &[]),
+    ("cs.DM", "Discrete Mathematics", &[]),
+    ("cs.DS", "Data Structures and Algorithms", &["algorithms"]),
+    ("cs.ET", "Emerging Technologies", &[]),
+    ("cs.FL", "Formal Languages and Automata Theory", &[]),
+    ("cs.GL", "General Literature", &[]),
+    ("cs.GR", "Graphics", &["computer graphics"]),
+    (
+        "cs.GT",
+        "Computer Science and Game Theory",
+        &["game theory"],
+    ),
+    ("cs.HC", "Human-Computer Interaction", &["hci"]),
+    (
+        "cs.IR",
+        "Information Retrieval",
+        &["search", "recommender systems"],
+    ),
+    ("cs.IT", "Information Theory", &[]),
+    ("cs.LG", "Machine Learning", &["ml", "deep learning"]),
+    ("cs.LO", "Logic in Computer Science", &[]),
+    ("cs.MA", "Multiagent Systems", &["multi-agent systems"]),
+    ("cs.MM", "Multimedia", &[]),
+    ("cs.MS", "Mathematical Software", &[]),
+    ("cs.NA", "Numerical Analysis", &[]),
+    (
+        "cs.NE",
+        "Neural and Evolutionary Computing",
+        &["evolutionary computation", "neural networks"],
+    ),
+    (
+        "cs.NI",
+        "Networking and Internet Architecture",
+        &["networking"],
+    ),
+    ("cs.OH", "Other Computer Science", &[]),
+    ("cs.OS", "Operating Systems", &[]),
+    ("cs.PF", "Performance", &[]),
+    ("cs.PL", "Programming Languages", &[]),
+    ("cs.RO", "Robotics", &[]),
+    ("cs.SC", "Symbolic Computation", &[]),
+    ("cs.SD", "Sound", &["audio"]),
+    ("cs.SE", "Software Engineering", &[]),
+    (
+        "cs.SI",
+        "Social and Information Networks",
+        &["social networks"],
+    ),
+    ("cs.SY", "Systems and Control", &["control theory"]),
+    (
+        "eess.AS",
+        "Audio and Speech Processing",
+        &["speech processing", "speech recognition"],
+    ),
+    (
+        "eess.IV",
+        "Image and Video Processing",
+        &["image processing"],
+    ),
+    ("eess.SP", "Signal Processing", &[]),
+    ("math.OC", "Optimization and Control", &["optimization"]),
+    ("math.ST", "Statistics Theory", &[]),
+    ("physics.comp-ph", "Computational Physics", &[]),
+    ("q-bio.NC", "Neurons and Cognition", &["neuroscience"]),
+    ("q-bio.QM", "Quantitative Methods", &[]),
+    ("q-fin.CP", "Computational Finance", &[]),
+    ("stat.AP", "Applications", &["applied statistics"]),
+    ("stat.CO", "Computation", &["computational statistics"]),
+    ("stat.ME", "Methodology", &["statistical methodology"]),
+    (
+        "stat.ML",
+        "Machine Learning",
+        &["statistical machine learning"],
+    ),
+];
+
+/// Minimum name similarity (normalized Levenshtein) for a fuzzy category match
+const CATEGORY_NAME_MIN_SIMILARITY: f64 = 0.8;
+
+/// Resolve a category code or human-readable name to its arXiv code
+///
+/// Codes match case-insensitively ("CS.cl" → "cs.CL"). Otherwise the input is
+/// compared with each category's name and aliases, ignoring case and
+/// punctuation, allowing small typos ("natural languge processing" →
+/// "cs.CL"). Returns `None` when nothing is close enough.
+pub fn resolve_arxiv_category(input: &str) -> Option<&'static str> {
+    let input = input.trim();
+    if let Some((code, _, _)) = ARXIV_CATEGORIES
+        .iter()
+        .find(|(code, _, _)| code.eq_ignore_ascii_case(input))
+    {
+        return Some(code);
+    }
+
+    let query = normalize_category_name(input);
+    if query.is_empty() {
+        return None;
+    }
+    let mut best: Option<(&'static str, f64)> = None;
+    for (code, name, aliases) in ARXIV_CATEGORIES {
+        let similarity = std::iter::once(name)
+            .chain(aliases.iter())
+            .map(|candidate| normalized_levenshtein(&query, &normalize_category_name(candidate)))
+            .fold(0.0, f64::max);
+        // Strictly greater, so the first of equally close categories wins
+        if similarity >= CATEGORY_NAME_MIN_SIMILARITY
+            && best.is_none_or(|(_, best_similarity)| similarity > best_similarity)
+        {
+            best = Some((code, similarity));
+        }
+    }
+    best.map(|(code, _)| code)
+}
+
+/// Lowercase words of a category name, without punctuation
+fn normalize_category_name(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Client for arXiv API operations
-#[derive(Debug, Clone, Default)]
-pub struct ArxivClient;
+#[derive(Debug, Clone)]
+pub struct ArxivClient {
+    http_client: reqwest::Client,
+    api_url: String,
+}
+
+impl Default for ArxivClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl ArxivClient {
     /// Create a new arXiv client
     pub fn new() -> Self {
-        Self
+        Self {
+            http_client: reqwest::Client::new(),
+            api_url: ARXIV_API_URL.to_string(),
+        }
+    }
+
+    /// Use a preconfigured HTTP client (proxy, timeout, user agent) for Atom
+    /// feed requests (category searches and counts)
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
     }
 
-    /// Search papers on arXiv
+    /// Search papers on arXiv, newest submissions first
+    ///
+    /// Searches with categories (including category-only listings such as
+    /// "latest in cs.CL") go to the Atom API directly, since `arxiv_tools`
+    /// cannot filter by an arbitrary category code.
     pub async fn search(&self, params: &SearchParams) -> AppResult<Vec<ArxivPaper>> {
+        let (papers, _) = self.search_with_total(params).await?;
+        Ok(papers)
+    }
+
+    /// Like [`search`](Self::search), also returning the total match count
+    /// when the response reports it
+    ///
+    /// Only the Atom feed used for category searches carries the total;
+    /// keyword searches return `None` (see [`total_results`](Self::total_results)).
+    pub async fn search_with_total(
+        &self,
+        params: &SearchParams,
+    ) -> AppResult<(Vec<ArxivPaper>, Option<usize>)> {
+        if !params.categories.is_empty() {
+            let (start, max_results) = Self::result_window(params);
+            let body = self
+                .fetch_feed(&Self::search_query_text(params)?, start, max_results)
+                .await?;
+            return Self::parse_feed(&body);
+        }
+
         let query = self.build_query(params)?;
+        let (start, max_results) = Self::result_window(params);
 
         let papers = ArXiv::from_args(query)
-            .max_results(params.max_results as u64)
+            .start(start)
+            .max_results(max_results)
             .sort_by(SortBy::SubmittedDate)
             .sort_order(SortOrder::Descending)
             .query()
             .await
             .map_err(|e| AppError::ArxivError(e.to_string()))?;
 
-        Ok(papers)
+        Ok((papers, None))
+    }
+
+    /// Total number of arXiv papers matching the search criteria
+    ///
+    /// `arxiv_tools` does not surface `opensearch:totalResults`, so this asks
+    /// the Atom API for zero entries and reads the count from the feed header.
+    pub async fn total_results(&self, params: &SearchParams) -> AppResult<usize> {
+        let body = self
+            .fetch_feed(&Self::search_query_text(params)?, 0, 0)
+            .await?;
+        let (_, total) = Self::parse_feed(&body)?;
+        total.ok_or_else(|| AppError::ArxivError("Response has no totalResults".to_string()))
+    }
+
+    /// Fetch a page of the Atom feed for `search_query`, newest submissions first
+    async fn fetch_feed(
+        &self,
+        search_query: &str,
+        start: u64,
+        max_results: u64,
+    ) -> AppResult<String> {
+        let response = self
+            .http_client
+            .get(&self.api_url)
+            .query(&[
+                ("search_query", search_query),
+                ("start", start.to_string().as_str()),
+                ("max_results", max_results.to_string().as_str()),
+                ("sortBy", "submittedDate"),
+                ("sortOrder", "descending"),
+            ])
+            .send()
+            .await
+            .map_err(|e| AppError::ArxivError(format!("Feed request failed: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AppError::ArxivError(format!(
+                "Feed request failed: HTTP {}",
+                status
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| AppError::ArxivError(format!("Failed to read response: {}", e)))
     }
 
     /// Fetch a single paper by arXiv ID
@@ -43,6 +430,76 @@ impl ArxivClient {
             .ok_or_else(|| AppError::PaperNotFound(format!("arXiv paper not found: {}", arxiv_id)))
     }
 
+    /// Fetch the revision history of an arXiv paper, oldest first
+    ///
+    /// `arxiv_tools` only reports the first and latest dates, so this parses
+    /// the "Submission history" list on the paper's abs page. A version suffix
+    /// on `arxiv_id` is ignored.
+    pub async fn fetch_versions(&self, arxiv_id: &str) -> AppResult<Vec<ArxivVersion>> {
+        let (id, _) = AcademicPaper::parse_arxiv_id(arxiv_id);
+        let url = format!("{}/{}", ARXIV_ABS_URL, id);
+        let response = self
+            .http_client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| AppError::ArxivError(format!("Abs page request failed: {}", e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(AppError::PaperNotFound(format!(
+                "arXiv paper not found: {}",
+                id
+            )));
+        }
+        if !status.is_success() {
+            return Err(AppError::ArxivError(format!(
+                "Abs page request failed: HTTP {}",
+                status
+            )));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::ArxivError(format!("Failed to read response: {}", e)))?;
+        let versions = Self::parse_versions(&body);
+        if versions.is_empty() {
+            return Err(AppError::ArxivError(format!(
+                "No submission history found on {}",
+                url
+            )));
+        }
+        Ok(versions)
+    }
+
+    /// Parse the submission history of an abs page
+    ///
+    /// Entries look like `[v2] Mon, 19 Jun 2017 16:49:45 UTC (1,125 KB)`;
+    /// entries with an unparseable date are skipped.
+    fn parse_versions(html: &str) -> Vec<ArxivVersion> {
+        let history = html
+            .find("submission-history")
+            .map_or(html, |start| &html[start..]);
+
+        VERSION_ENTRY_PATTERN
+            .captures_iter(history)
+            .filter_map(|caps| {
+                let version = caps[1].parse().ok()?;
+                let date = caps[2].trim().trim_end_matches("UTC").trim();
+                let submitted_at = NaiveDateTime::parse_from_str(date, "%a, %d %b %Y %H:%M:%S")
+                    .ok()?
+                    .and_utc()
+                    .with_timezone(&Local);
+                Some(ArxivVersion {
+                    version,
+                    submitted_at,
+                    size: caps[3].trim().to_string(),
+                })
+            })
+            .collect()
+    }
+
     /// Build QueryParams from SearchParams
     fn build_query(&self, params: &SearchParams) -> AppResult<QueryParams> {
         let mut conditions: Vec<QueryParams> = Vec::new();
@@ -62,30 +519,109 @@ impl ArxivClient {
             conditions.push(QueryParams::abstract_text(abstract_text));
         }
 
-        // Note: category filter requires Category enum, skipping for now
-        // as it requires mapping from string to Category
+        // Categories cannot be expressed as QueryParams; `search` sends
+        // searches with categories through `search_query_text` instead
 
-        // Build the final query
         if conditions.is_empty() {
             // If no specific conditions, use the general query
             if let Some(ref query) = params.query {
-                Ok(QueryParams::all(query))
+                conditions.push(QueryParams::all(query));
             } else {
-                Err(AppError::ArxivError(
+                return Err(AppError::ArxivError(
                     "No search criteria provided".to_string(),
-                ))
+                ));
             }
-        } else if conditions.len() == 1 {
+        }
+
+        // Restrict to recent submissions
+        if let Some(since) = params.submitted_after {
+            let (from, to) = Self::submitted_date_range(&since, &Local::now());
+            conditions.push(QueryParams::submitted_date(&from, &to));
+        }
+
+        // Build the final query
+        if conditions.len() == 1 {
             Ok(conditions.pop().unwrap())
         } else {
             Ok(QueryParams::and(conditions))
         }
     }
+
+    /// `(start, max_results)` of the requested page
+    fn result_window(params: &SearchParams) -> (u64, u64) {
+        (params.offset as u64, params.max_results as u64)
+    }
+
+    /// Raw `search_query` string equivalent to [`build_query`](Self::build_query)
+    fn search_query_text(params: &SearchParams) -> AppResult<String> {
+        let mut conditions: Vec<String> = Vec::new();
+        if let Some(ref title) = params.title {
+            conditions.push(format!("ti:\"{}\"", title));
+        }
+        if let Some(ref author) = params.author {
+            conditions.push(format!("au:\"{}\"", author));
+        }
+        if let Some(ref abstract_text) = params.abstract_contains {
+            conditions.push(format!("abs:\"{}\"", abstract_text));
+        }
+        if conditions.is_empty()
+            && let Some(ref query) = params.query
+        {
+            conditions.push(format!("all:\"{}\"", query));
+        }
+        match params.categories.as_slice() {
+            [] => {}
+            [category] => conditions.push(format!("cat:{}", category)),
+            categories => conditions.push(format!(
+                "({})",
+                categories
+                    .iter()
+                    .map(|category| format!("cat:{}", category))
+                    .collect::<Vec<_>>()
+                    .join(" OR ")
+            )),
+        }
+        if conditions.is_empty() {
+            return Err(AppError::ArxivError(
+                "No search criteria provided".to_string(),
+            ));
+        }
+        if let Some(since) = params.submitted_after {
+            let (from, to) = Self::submitted_date_range(&since, &Local::now());
+            conditions.push(format!("submittedDate:[{} TO {}]", from, to));
+        }
+        Ok(conditions.join(" AND "))
+    }
+
+    /// Parse an arXiv Atom feed into its papers and `opensearch:totalResults`
+    ///
+    /// Entries without an ID are skipped.
+    fn parse_feed(body: &str) -> AppResult<(Vec<ArxivPaper>, Option<usize>)> {
+        let feed: AtomFeed = quick_xml::de::from_str(body)
+            .map_err(|e| AppError::ArxivError(format!("Failed to parse feed: {}", e)))?;
+        let papers = feed
+            .entries
+            .into_iter()
+            .filter_map(AtomEntry::into_paper)
+            .collect();
+        Ok((papers, feed.total_results))
+    }
+
+    /// Bounds of the arXiv `submittedDate:[FROM TO TO]` clause
+    ///
+    /// arXiv expects `YYYYMMDDHHMM` timestamps in GMT.
+    fn submitted_date_range(since: &DateTime<Local>, until: &DateTime<Local>) -> (String, String) {
+        let format =
+            |time: &DateTime<Local>| time.with_timezone(&Utc).format("%Y%m%d%H%M").to_string();
+        (format(since), format(until))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::test_server::{request_line, serve_responses};
+    use chrono::TimeZone;
 
     #[test]
     fn test_search_params_build() {
@@ -105,6 +641,249 @@ mod tests {
         assert!(query.is_ok());
     }
 
+    #[test]
+    fn test_submitted_date_range() {
+        let since = Utc
+            .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+        let until = Utc
+            .with_ymd_and_hms(2024, 3, 15, 9, 30, 0)
+            .unwrap()
+            .with_timezone(&Local);
+
+        let (from, to) = ArxivClient::submitted_date_range(&since, &until);
+        assert_eq!(from, "202401010000");
+        assert_eq!(to, "202403150930");
+    }
+
+    #[test]
+    fn test_resolve_arxiv_category_codes() {
+        assert_eq!(resolve_arxiv_category("cs.CL"), Some("cs.CL"));
+        assert_eq!(resolve_arxiv_category(" CS.cv "), Some("cs.CV"));
+        assert_eq!(resolve_arxiv_category("q-bio.nc"), Some("q-bio.NC"));
+    }
+
+    #[test]
+    fn test_resolve_arxiv_category_names() {
+        assert_eq!(
+            resolve_arxiv_category("natural language processing"),
+            Some("cs.CL")
+        );
+        assert_eq!(
+            resolve_arxiv_category("Natural Languge Processing"),
+            Some("cs.CL")
+        );
+        assert_eq!(
+            resolve_arxiv_category("computation and language"),
+            Some("cs.CL")
+        );
+        assert_eq!(
+            resolve_arxiv_category("human computer interaction"),
+            Some("cs.HC")
+        );
+        // Shared names resolve to the first listed category
+        assert_eq!(resolve_arxiv_category("machine learning"), Some("cs.LG"));
+
+        assert_eq!(resolve_arxiv_category("underwater basket weaving"), None);
+        assert_eq!(resolve_arxiv_category(""), None);
+    }
+
+    #[test]
+    fn test_submitted_after_with_category_builds_query() {
+        let client = ArxivClient::new();
+        let params = SearchParams::new()
+            .with_query("diffusion")
+            .with_category("cs.CV")
+            .with_submitted_after(Local::now());
+
+        assert!(client.build_query(&params).is_ok());
+    }
+
+    #[test]
+    fn test_search_query_text_combines_criteria() {
+        let params = SearchParams::new()
+            .with_title("attention".to_string())
+            .with_author("Vaswani".to_string());
+        assert_eq!(
+            ArxivClient::search_query_text(&params).unwrap(),
+            "ti:\"attention\" AND au:\"Vaswani\""
+        );
+
+        let params = SearchParams::new().with_query("transformer".to_string());
+        assert_eq!(
+            ArxivClient::search_query_text(&params).unwrap(),
+            "all:\"transformer\""
+        );
+        assert!(ArxivClient::search_query_text(&SearchParams::new()).is_err());
+    }
+
+    #[test]
+    fn test_category_only_params_build_query() {
+        let params = SearchParams::new().with_category("natural language processing");
+        assert!(params.validate().is_ok());
+        assert_eq!(
+            ArxivClient::search_query_text(&params).unwrap(),
+            "cat:cs.CL"
+        );
+
+        let params = SearchParams::new()
+            .with_query("transformer".to_string())
+            .with_category("cs.CL")
+            .with_category("cs.LG");
+        assert_eq!(
+            ArxivClient::search_query_text(&params).unwrap(),
+            "all:\"transformer\" AND (cat:cs.CL OR cat:cs.LG)"
+        );
+    }
+
+    #[test]
+    fn test_parse_feed_entries() {
+        let body = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <opensearch:totalResults>2</opensearch:totalResults>
+  <entry>
+    <id>http://arxiv.org/abs/1706.03762v7</id>
+    <updated>2023-08-02T00:41:18Z</updated>
+    <published>2017-06-12T17:57:34Z</published>
+    <title>Attention Is All
+  You Need</title>
+    <summary>  The dominant sequence transduction models &amp; more, by Jos&#233; et al.
+</summary>
+    <author>
+      <name>Ashish Vaswani</name>
+    </author>
+    <author>
+      <name>Noam Shazeer</name>
+      <arxiv:affiliation>Google</arxiv:affiliation>
+    </author>
+    <arxiv:comment>15 pages, 5 figures</arxiv:comment>
+    <link href="http://arxiv.org/abs/1706.03762v7" rel="alternate" type="text/html"/>
+    <link title="pdf" href="http://arxiv.org/pdf/1706.03762v7" rel="related" type="application/pdf"/>
+    <arxiv:primary_category xmlns:arxiv="http://arxiv.org/schemas/atom" term="cs.CL" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="cs.CL" scheme="http://arxiv.org/schemas/atom"/>
+    <category term="cs.LG" scheme="http://arxiv.org/schemas/atom"/>
+  </entry>
+  <entry>
+    <title>Entry without an ID</title>
+  </entry>
+</feed>"#;
+
+        let (papers, total) = ArxivClient::parse_feed(body).unwrap();
+        assert_eq!(total, Some(2));
+        assert_eq!(papers.len(), 1);
+        let paper = &papers[0];
+        assert_eq!(paper.id, "http://arxiv.org/abs/1706.03762v7");
+        assert_eq!(paper.title, "Attention Is All You Need");
+        assert_eq!(
+            paper.abstract_text,
+            "The dominant sequence transduction models & more, by José et al."
+        );
+        assert_eq!(paper.authors, vec!["Ashish Vaswani", "Noam Shazeer"]);
+        assert_eq!(paper.published, "2017-06-12T17:57:34Z");
+        assert_eq!(paper.comment, vec!["15 pages, 5 figures"]);
+        assert_eq!(paper.pdf_url, "http://arxiv.org/pdf/1706.03762v7");
+        assert_eq!(paper.primary_category, "cs.CL");
+        assert_eq!(paper.categories, vec!["cs.CL", "cs.LG"]);
+    }
+
+    #[tokio::test]
+    async fn test_category_search_sends_start_and_max_results() {
+        let (url, server) = serve_responses(vec![(200, r#"<feed xmlns="http://www.w3.org/2005/Atom"><opensearch:totalResults xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">120</opensearch:totalResults></feed>"#)]).await;
+        let mut client = ArxivClient::new();
+        client.api_url = format!("{}/api/query", url);
+        let params = SearchParams::new()
+            .with_category("cs.CL")
+            .with_max_results(25)
+            .with_offset(50);
+
+        let (papers, total) = client.search_with_total(&params).await.unwrap();
+        assert!(papers.is_empty());
+        assert_eq!(total, Some(120));
+
+        let requests = server.await.unwrap();
+        let line = request_line(&requests[0]);
+        assert!(line.starts_with("GET /api/query?"));
+        assert!(line.contains("start=50"));
+        assert!(line.contains("max_results=25"));
+    }
+
+    #[tokio::test]
+    async fn test_category_search_sends_submitted_date_clause() {
+        let (url, server) = serve_responses(vec![(
+            200,
+            r#"<feed xmlns="http://www.w3.org/2005/Atom"></feed>"#,
+        )])
+        .await;
+        let mut client = ArxivClient::new();
+        client.api_url = format!("{}/api/query", url);
+        let since = Utc
+            .with_ymd_and_hms(2024, 1, 1, 0, 0, 0)
+            .unwrap()
+            .with_timezone(&Local);
+        let params = SearchParams::new()
+            .with_query("diffusion")
+            .with_category("cs.CV")
+            .with_submitted_after(since);
+
+        client.search(&params).await.unwrap();
+
+        let requests = server.await.unwrap();
+        let path = request_line(&requests[0])
+            .split_whitespace()
+            .nth(1)
+            .unwrap();
+        let url = reqwest::Url::parse(&format!("http://localhost{}", path)).unwrap();
+        let search_query = url
+            .query_pairs()
+            .find(|(key, _)| key == "search_query")
+            .map(|(_, value)| value.into_owned())
+            .unwrap();
+        assert!(search_query.starts_with("all:\"diffusion\" AND cat:cs.CV AND "));
+        assert!(search_query.contains("submittedDate:[202401010000 TO "));
+    }
+
+    #[test]
+    fn test_parse_versions_from_submission_history() {
+        let html = r#"<div class="submission-history">
+    <h2>Submission history</h2> From: Ashish Vaswani [<a href="/show-email/f53b7360/1706.03762">view email</a>]
+    <br/><strong><a href="/abs/1706.03762v1" rel="nofollow">[v1]</a></strong>
+        Mon, 12 Jun 2017 17:57:34 UTC (1,102 KB)<br/>
+    <strong><a href="/abs/1706.03762v2" rel="nofollow">[v2]</a></strong>
+        Mon, 19 Jun 2017 16:49:45 UTC (1,125 KB)<br/>
+    <strong>[v7]</strong>
+        Wed, 2 Aug 2023 00:41:18 UTC (1,124 KB)<br/>
+</div>"#;
+
+        let versions = ArxivClient::parse_versions(html);
+        assert_eq!(versions.len(), 3);
+        assert_eq!(versions[0].version, 1);
+        assert_eq!(versions[0].size, "1,102 KB");
+        assert_eq!(
+            versions[0].submitted_at,
+            Utc.with_ymd_and_hms(2017, 6, 12, 17, 57, 34).unwrap()
+        );
+        assert_eq!(versions[2].version, 7);
+        assert_eq!(
+            versions[2].submitted_at,
+            Utc.with_ymd_and_hms(2023, 8, 2, 0, 41, 18).unwrap()
+        );
+        assert!(ArxivClient::parse_versions("<html></html>").is_empty());
+    }
+
+    #[test]
+    fn test_parse_total_results() {
+        let feed = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <opensearch:totalResults xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">3421</opensearch:totalResults>
+  <opensearch:startIndex xmlns:opensearch="http://a9.com/-/spec/opensearch/1.1/">0</opensearch:startIndex>
+</feed>"#;
+        let (papers, total) = ArxivClient::parse_feed(feed).unwrap();
+        assert!(papers.is_empty());
+        assert_eq!(total, Some(3421));
+        assert_eq!(ArxivClient::parse_feed("<feed></feed>").unwrap().1, None);
+        assert!(ArxivClient::parse_feed("<feed><entry>").is_err());
+    }
+
     #[test]
     fn test_empty_params_error() {
         let client = ArxivClient::new();
@@ -0,0 +1,303 @@
+//! Search result cache for [`PaperClient`](super::PaperClient)
+//!
+//! Results are kept in memory and, when a directory is configured, also
+//! written to disk as JSON so they survive across runs.
+
+use super::DedupStrategy;
+use super::search::{SearchParams, SearchResult};
+use crate::models::PaperSource;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// Default time-to-live of cached search results
+pub const DEFAULT_SEARCH_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Everything a cached result depends on: the normalized search and the
+/// client settings that change its results
+#[derive(Debug, Serialize)]
+pub(crate) struct CacheKey {
+    params: SearchParams,
+    dedup_strategy: DedupStrategy,
+    use_dblp: bool,
+}
+
+impl CacheKey {
+    /// Key for `params` searched with the given client settings
+    ///
+    /// Text criteria are compared trimmed, lowercased and with collapsed
+    /// whitespace, and categories regardless of order.
+    pub(crate) fn new(
+        params: &SearchParams,
+        dedup_strategy: DedupStrategy,
+        use_dblp: bool,
+    ) -> Self {
+        let normalize = |text: &Option<String>| {
+            text.as_ref().map(|text| {
+                text.split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .to_lowercase()
+            })
+        };
+        let mut normalized = params.clone();
+        normalized.query = normalize(&params.query);
+        normalized.title = normalize(&params.title);
+        normalized.author = normalize(&params.author);
+        normalized.abstract_contains = normalize(&params.abstract_contains);
+        normalized.categories.sort();
+        normalized.categories.dedup();
+        Self {
+            params: normalized,
+            dedup_strategy,
+            use_dblp,
+        }
+    }
+
+    /// Canonical JSON form, stored with each entry to rule out hash collisions
+    fn canonical(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// 64-bit FNV-1a hash of the canonical form
+    ///
+    /// Unlike the standard hasher, FNV-1a is fixed, so disk entries stay
+    /// valid across builds.
+    fn hash(canonical: &str) -> u64 {
+        canonical.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+    }
+}
+
+/// A cached result and when it was stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Canonical form of the [`CacheKey`] the result was stored under
+    key: String,
+    cached_at: SystemTime,
+    result: SearchResult,
+    /// Source of each paper; `AcademicPaper::source` is not serialized with the paper
+    paper_sources: Vec<Option<PaperSource>>,
+}
+
+impl CacheEntry {
+    fn new(key: String, result: &SearchResult) -> Self {
+        Self {
+            key,
+            cached_at: SystemTime::now(),
+            result: result.clone(),
+            paper_sources: result.papers.iter().map(|paper| paper.source).collect(),
+        }
+    }
+
+    /// The cached result with each paper's source restored
+    fn result(&self) -> SearchResult {
+        let mut result = self.result.clone();
+        if self.paper_sources.len() == result.papers.len() {
+            for (paper, source) in result.papers.iter_mut().zip(&self.paper_sources) {
+                paper.source = *source;
+            }
+        }
+        result
+    }
+}
+
+/// TTL-bounded cache of search results, keyed by [`CacheKey`]
+#[derive(Debug)]
+pub(crate) struct SearchCache {
+    ttl: Duration,
+    dir: Option<PathBuf>,
+    entries: Mutex<HashMap<u64, CacheEntry>>,
+}
+
+impl SearchCache {
+    /// Create an in-memory cache
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            dir: None,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set how long results stay valid
+    pub(crate) fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Also persist results as JSON files in `dir`
+    pub(crate) fn with_dir(mut self, dir: PathBuf) -> Self {
+        self.dir = Some(dir);
+        self
+    }
+
+    /// Cached result for `key`, if stored within the TTL
+    ///
+    /// Falls back to the disk cache on a memory miss. Unreadable or corrupt
+    /// cache files, and entries stored under a different key with the same
+    /// hash, count as misses.
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<SearchResult> {
+        let canonical = key.canonical();
+        let hash = CacheKey::hash(&canonical);
+        if let Some(entry) = self.entries.lock().unwrap().get(&hash)
+            && self.is_valid(entry, &canonical)
+        {
+            return Some(entry.result());
+        }
+
+        let entry = self
+            .read_file(hash)
+            .filter(|entry| self.is_valid(entry, &canonical))?;
+        let result = entry.result();
+        self.entries.lock().unwrap().insert(hash, entry);
+        Some(result)
+    }
+
+    /// Store `result` for `key`
+    ///
+    /// Disk write failures are logged and otherwise ignored.
+    pub(crate) fn insert(&self, key: &CacheKey, result: &SearchResult) {
+        let canonical = key.canonical();
+        let hash = CacheKey::hash(&canonical);
+        let entry = CacheEntry::new(canonical, result);
+        if let Err(e) = self.write_file(hash, &entry) {
+            tracing::debug!("Failed to write search cache entry: {}", e);
+        }
+        self.entries.lock().unwrap().insert(hash, entry);
+    }
+
+    /// Whether `entry` was stored under `canonical` and is younger than the TTL
+    fn is_valid(&self, entry: &CacheEntry, canonical: &str) -> bool {
+        entry.key == canonical && entry.cached_at.elapsed().is_ok_and(|age| age <= self.ttl)
+    }
+
+    /// Path of the disk entry for `hash`, if a directory is configured
+    fn path(&self, hash: u64) -> Option<PathBuf> {
+        self.dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{:016x}.json", hash)))
+    }
+
+    fn read_file(&self, hash: u64) -> Option<CacheEntry> {
+        let content = std::fs::read_to_string(self.path(hash)?).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn write_file(&self, hash: u64, entry: &CacheEntry) -> std::io::Result<()> {
+        let (Some(dir), Some(path)) = (&self.dir, self.path(hash)) else {
+            return Ok(());
+        };
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(path, serde_json::to_string(entry)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AcademicPaper;
+
+    fn key(params: &SearchParams) -> CacheKey {
+        CacheKey::new(params, DedupStrategy::default(), false)
+    }
+
+    fn result_titled(title: &str) -> SearchResult {
+        let mut paper = AcademicPaper::new();
+        paper.title = title.to_string();
+        let mut result = SearchResult::new();
+        result.papers.push(paper);
+        result
+    }
+
+    #[test]
+    fn test_key_ignores_case_whitespace_and_category_order() {
+        let params = SearchParams::new()
+            .with_query("Attention  is all you need")
+            .with_category("cs.CL")
+            .with_category("cs.LG");
+        let same = SearchParams::new()
+            .with_query(" attention is all YOU need ")
+            .with_category("cs.LG")
+            .with_category("cs.CL");
+        assert_eq!(key(&params).canonical(), key(&same).canonical());
+
+        let other_page = same.clone().with_offset(10);
+        assert_ne!(key(&params).canonical(), key(&other_page).canonical());
+    }
+
+    #[test]
+    fn test_key_includes_client_settings() {
+        let params = SearchParams::new().with_query("transformer");
+        let canonical = key(&params).canonical();
+        assert_ne!(
+            canonical,
+            CacheKey::new(&params, DedupStrategy::ByIdentifier, false).canonical()
+        );
+        assert_ne!(
+            canonical,
+            CacheKey::new(&params, DedupStrategy::default(), true).canonical()
+        );
+    }
+
+    #[test]
+    fn test_hash_is_stable_fnv1a() {
+        assert_eq!(CacheKey::hash(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(CacheKey::hash("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_get_rejects_entries_stored_under_another_key() {
+        let cache = SearchCache::new(Duration::from_secs(60));
+        let params = SearchParams::new().with_query("transformer");
+        let canonical = key(&params).canonical();
+        let colliding = CacheEntry::new("{\"other\":true}".to_string(), &result_titled("Other"));
+        cache
+            .entries
+            .lock()
+            .unwrap()
+            .insert(CacheKey::hash(&canonical), colliding);
+        assert!(cache.get(&key(&params)).is_none());
+    }
+
+    #[test]
+    fn test_get_respects_ttl() {
+        let cache = SearchCache::new(Duration::from_secs(60));
+        let params = SearchParams::new().with_query("transformer");
+        assert!(cache.get(&key(&params)).is_none());
+
+        cache.insert(&key(&params), &result_titled("Fresh"));
+        assert_eq!(cache.get(&key(&params)).unwrap().papers[0].title, "Fresh");
+
+        let canonical = key(&params).canonical();
+        let mut stale = CacheEntry::new(canonical.clone(), &result_titled("Stale"));
+        stale.cached_at = SystemTime::now() - Duration::from_secs(120);
+        cache
+            .entries
+            .lock()
+            .unwrap()
+            .insert(CacheKey::hash(&canonical), stale);
+        assert!(cache.get(&key(&params)).is_none());
+    }
+
+    #[test]
+    fn test_disk_entries_survive_a_new_cache() {
+        let dir = std::env::temp_dir().join(format!("search-cache-test-{}", std::process::id()));
+        let params = SearchParams::new().with_title("Attention Is All You Need".to_string());
+
+        let mut result = result_titled("On disk");
+        result.papers[0].source = Some(PaperSource::Both);
+        SearchCache::new(Duration::from_secs(60))
+            .with_dir(dir.clone())
+            .insert(&key(&params), &result);
+        let reloaded = SearchCache::new(Duration::from_secs(60)).with_dir(dir.clone());
+        let cached = reloaded.get(&key(&params)).unwrap();
+        assert_eq!(cached.papers[0].title, "On disk");
+        assert_eq!(cached.papers[0].source, Some(PaperSource::Both));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
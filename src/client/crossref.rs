@@ -0,0 +1,312 @@
+//! CrossRef API client for DOI-based lookup
+
+use crate::models::{AcademicPaper, Author, PaperSource};
+use crate::shared::errors::{AppError, AppResult};
+use crate::shared::utils::normalize_doi;
+use chrono::{Local, TimeZone};
+use regex::Regex;
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+/// JATS/XML tags embedded in CrossRef abstracts
+static MARKUP_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<[^>]+>").expect("valid markup regex"));
+
+/// Default CrossRef REST API endpoint
+const CROSSREF_API_URL: &str = "https://api.crossref.org";
+
+/// CrossRef REST API client
+///
+/// Resolves DOIs via `https://api.crossref.org/works/{doi}`.
+/// See: <https://api.crossref.org/swagger-ui/index.html>
+pub struct CrossRefClient {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Default for CrossRefClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CrossRefResponse {
+    message: CrossRefWork,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CrossRefWork {
+    #[serde(rename = "DOI", default)]
+    doi: String,
+    #[serde(default)]
+    title: Vec<String>,
+    #[serde(default)]
+    author: Vec<CrossRefAuthor>,
+    #[serde(default)]
+    container_title: Vec<String>,
+    #[serde(rename = "abstract")]
+    abstract_text: Option<String>,
+    #[serde(rename = "URL")]
+    url: Option<String>,
+    published: Option<CrossRefDate>,
+    published_print: Option<CrossRefDate>,
+    published_online: Option<CrossRefDate>,
+    issued: Option<CrossRefDate>,
+    #[serde(default)]
+    is_referenced_by_count: i32,
+    #[serde(default)]
+    reference_count: i32,
+    #[serde(default)]
+    subject: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrossRefAuthor {
+    given: Option<String>,
+    family: Option<String>,
+    /// Organizational authors only carry a name
+    name: Option<String>,
+    #[serde(default)]
+    affiliation: Vec<CrossRefAffiliation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrossRefAffiliation {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct CrossRefDate {
+    /// `[[year, month, day]]`, where month and day are optional
+    #[serde(default)]
+    date_parts: Vec<Vec<Option<i32>>>,
+}
+
+impl CrossRefClient {
+    /// Create a new CrossRef client
+    pub fn new() -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            base_url: CROSSREF_API_URL.to_string(),
+        }
+    }
+
+    /// Send requests to another endpoint (e.g. a mirror or a local stub)
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// Use a preconfigured HTTP client (proxy, timeout, user agent)
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Fetch a paper by DOI
+    pub async fn fetch_by_doi(&self, doi: &str) -> AppResult<AcademicPaper> {
+        let doi = doi.trim();
+        if doi.is_empty() {
+            return Err(AppError::CrossRefError("DOI is empty".to_string()));
+        }
+        let doi = normalize_doi(doi)
+            .ok_or_else(|| AppError::CrossRefError(format!("Invalid DOI: {}", doi)))?;
+
+        let url = format!("{}/works/{}", self.base_url, urlencoding::encode(&doi));
+        let response = self
+            .http_client
+            .get(&url)
+            .header(
+                "User-Agent",
+                concat!("academic-paper-interpreter/", env!("CARGO_PKG_VERSION")),
+            )
+            .send()
+            .await
+            .map_err(|e| AppError::CrossRefError(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        if status.as_u16() == 404 {
+            return Err(AppError::PaperNotFound(format!("DOI not found: {}", doi)));
+        }
+        if !status.is_success() {
+            return Err(AppError::CrossRefError(format!(
+                "HTTP {}: {}",
+                status,
+                status.canonical_reason().unwrap_or("Unknown")
+            )));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::CrossRefError(format!("Failed to read response: {}", e)))?;
+        Self::parse_work(&body)
+    }
+
+    /// Parse a CrossRef `/works/{doi}` response body into a paper
+    fn parse_work(body: &str) -> AppResult<AcademicPaper> {
+        let response: CrossRefResponse = serde_json::from_str(body)
+            .map_err(|e| AppError::CrossRefError(format!("Failed to parse response: {}", e)))?;
+        Ok(Self::paper_from_work(response.message))
+    }
+
+    /// Map a CrossRef work record into an `AcademicPaper`
+    fn paper_from_work(work: CrossRefWork) -> AcademicPaper {
+        let mut paper = AcademicPaper::new();
+        paper.source = Some(PaperSource::CrossRef);
+
+        // CrossRef returns titles as an array (usually of one)
+        paper.title = work
+            .title
+            .into_iter()
+            .next()
+            .map(|t| t.split_whitespace().collect::<Vec<_>>().join(" "))
+            .unwrap_or_default();
+        paper.doi = normalize_doi(&work.doi).unwrap_or_default();
+        paper.url = work
+            .url
+            .unwrap_or_else(|| format!("https://doi.org/{}", paper.doi));
+        paper.journal = work.container_title.into_iter().next().unwrap_or_default();
+        paper.categories = work.subject;
+        paper.abstract_text = work
+            .abstract_text
+            .map(|a| Self::strip_markup(&a))
+            .unwrap_or_default();
+        paper.citations_count = work.is_referenced_by_count;
+        paper.references_count = work.reference_count;
+        paper.metrics_known = true;
+
+        paper.authors = work
+            .author
+            .into_iter()
+            .filter_map(|a| {
+                let name = match (a.given, a.family, a.name) {
+                    (Some(given), Some(family), _) => format!("{} {}", given, family),
+                    (None, Some(family), _) => family,
+                    (_, None, Some(name)) => name,
+                    _ => return None,
+                };
+                let mut author = Author::new(name);
+                author.affiliations = a.affiliation.into_iter().map(|af| af.name).collect();
+                Some(author)
+            })
+            .collect();
+
+        if let Some(date) = [
+            work.published,
+            work.published_print,
+            work.published_online,
+            work.issued,
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(|d| Self::to_datetime(&d))
+        {
+            paper.published_date = date;
+        }
+
+        paper
+    }
+
+    /// Convert CrossRef date-parts to a local datetime (missing month/day default to 1)
+    fn to_datetime(date: &CrossRefDate) -> Option<chrono::DateTime<Local>> {
+        let parts = date.date_parts.first()?;
+        let year = (*parts.first()?)?;
+        let month = parts.get(1).copied().flatten().unwrap_or(1) as u32;
+        let day = parts.get(2).copied().flatten().unwrap_or(1) as u32;
+        Local.with_ymd_and_hms(year, month, day, 0, 0, 0).single()
+    }
+
+    /// Remove JATS markup from an abstract and collapse whitespace
+    fn strip_markup(text: &str) -> String {
+        MARKUP_PATTERN
+            .replace_all(text, " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RESPONSE: &str = r#"{
+        "status": "ok",
+        "message-type": "work",
+        "message": {
+            "DOI": "10.1145/3065386",
+            "title": ["ImageNet classification with deep convolutional\n   neural networks"],
+            "author": [
+                {"given": "Alex", "family": "Krizhevsky", "affiliation": [{"name": "University of Toronto"}]},
+                {"given": "Ilya", "family": "Sutskever", "affiliation": []},
+                {"name": "SuperVision Team"}
+            ],
+            "container-title": ["Communications of the ACM"],
+            "abstract": "<jats:p>We trained a large, deep convolutional neural network.</jats:p>",
+            "URL": "http://dx.doi.org/10.1145/3065386",
+            "published": {"date-parts": [[2017, 5, 24]]},
+            "is-referenced-by-count": 42000,
+            "reference-count": 30,
+            "subject": ["General Computer Science"]
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_crossref_work() {
+        let paper = CrossRefClient::parse_work(SAMPLE_RESPONSE).unwrap();
+
+        assert_eq!(paper.doi, "10.1145/3065386");
+        assert_eq!(
+            paper.title,
+            "ImageNet classification with deep convolutional neural networks"
+        );
+        assert_eq!(paper.journal, "Communications of the ACM");
+        assert_eq!(
+            paper.abstract_text,
+            "We trained a large, deep convolutional neural network."
+        );
+        assert_eq!(paper.url, "http://dx.doi.org/10.1145/3065386");
+        assert_eq!(paper.citations_count, 42000);
+        assert_eq!(paper.references_count, 30);
+        assert_eq!(paper.categories, vec!["General Computer Science"]);
+        assert_eq!(
+            paper.published_date.format("%Y-%m-%d").to_string(),
+            "2017-05-24"
+        );
+
+        assert_eq!(paper.authors.len(), 3);
+        assert_eq!(paper.authors[0].name, "Alex Krizhevsky");
+        assert_eq!(paper.authors[0].affiliations, vec!["University of Toronto"]);
+        assert_eq!(paper.authors[2].name, "SuperVision Team");
+    }
+
+    #[test]
+    fn test_parse_crossref_work_with_missing_fields() {
+        let body = r#"{
+            "message": {
+                "DOI": "10.1234/minimal",
+                "issued": {"date-parts": [[2020]]}
+            }
+        }"#;
+
+        let paper = CrossRefClient::parse_work(body).unwrap();
+        assert_eq!(paper.doi, "10.1234/minimal");
+        assert!(paper.title.is_empty());
+        assert!(paper.authors.is_empty());
+        assert_eq!(paper.url, "https://doi.org/10.1234/minimal");
+        assert_eq!(
+            paper.published_date.format("%Y-%m-%d").to_string(),
+            "2020-01-01"
+        );
+    }
+
+    #[test]
+    fn test_parse_crossref_invalid_json() {
+        let result = CrossRefClient::parse_work("not json");
+        assert!(matches!(result, Err(AppError::CrossRefError(_))));
+    }
+}
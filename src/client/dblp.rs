@@ -0,0 +1,394 @@
+//! DBLP API client for computer-science bibliographic search
+
+use super::search::SearchParams;
+use crate::models::{AcademicPaper, Author, PaperSource};
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{Local, TimeZone};
+use serde::Deserialize;
+
+/// DBLP publication search API client
+///
+/// Queries `https://dblp.org/search/publ/api` with `format=json`.
+/// DBLP has clean venue and author metadata for computer-science papers but
+/// carries no abstracts, so papers from this source have an empty
+/// `abstract_text` until they are merged with another source.
+/// See: <https://dblp.org/faq/How+to+use+the+dblp+search+API.html>
+pub struct DblpClient {
+    http_client: reqwest::Client,
+}
+
+impl Default for DblpClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DblpResponse {
+    result: DblpResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct DblpResult {
+    hits: DblpHits,
+}
+
+#[derive(Debug, Deserialize)]
+struct DblpHits {
+    /// Total number of matches (DBLP sends it as a string)
+    #[serde(rename = "@total")]
+    total: Option<String>,
+    #[serde(default)]
+    hit: Vec<DblpHit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DblpHit {
+    info: DblpInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct DblpInfo {
+    #[serde(default)]
+    title: String,
+    authors: Option<DblpAuthors>,
+    venue: Option<OneOrMany<String>>,
+    year: Option<String>,
+    doi: Option<String>,
+    /// Electronic edition (publisher landing page or PDF)
+    ee: Option<OneOrMany<String>>,
+    /// DBLP record page
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DblpAuthors {
+    author: OneOrMany<DblpAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DblpAuthor {
+    text: String,
+}
+
+/// DBLP collapses single-element arrays into a bare value
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(value) => vec![value],
+            OneOrMany::Many(values) => values,
+        }
+    }
+}
+
+impl DblpClient {
+    /// Create a new DBLP client
+    pub fn new() -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Use a preconfigured HTTP client (proxy, timeout, user agent)
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Search DBLP publications
+    ///
+    /// The query, title and author criteria are combined into a single DBLP
+    /// query; abstract filters and categories are not supported by DBLP and
+    /// are ignored. The year filter is applied to the returned hits.
+    pub async fn search(&self, params: &SearchParams) -> AppResult<Vec<AcademicPaper>> {
+        let (papers, _) = self.search_with_total(params).await?;
+        Ok(papers)
+    }
+
+    /// Like [`search`](Self::search), also returning the total match count
+    /// reported in the same response (ignoring the year filter)
+    pub async fn search_with_total(
+        &self,
+        params: &SearchParams,
+    ) -> AppResult<(Vec<AcademicPaper>, Option<usize>)> {
+        let Some(query) = Self::build_query(params) else {
+            return Ok((Vec::new(), Some(0)));
+        };
+
+        let body = self
+            .fetch_hits(&query, params.max_results, params.offset)
+            .await?;
+        let papers = Self::parse_hits(&body)?;
+        let papers = match &params.year {
+            Some(year) => papers
+                .into_iter()
+                .filter(|p| Self::year_matches(p, year))
+                .collect(),
+            None => papers,
+        };
+        Ok((papers, Self::parse_total(&body).ok()))
+    }
+
+    /// Total number of DBLP publications matching the query criteria
+    ///
+    /// The year filter is applied client-side by [`search`](Self::search), so
+    /// this count ignores it.
+    pub async fn total_results(&self, params: &SearchParams) -> AppResult<usize> {
+        let Some(query) = Self::build_query(params) else {
+            return Ok(0);
+        };
+        let body = self.fetch_hits(&query, 0, 0).await?;
+        Self::parse_total(&body)
+    }
+
+    /// Query the publication search API for up to `hits` results, skipping `first`
+    async fn fetch_hits(&self, query: &str, hits: usize, first: usize) -> AppResult<String> {
+        let response = self
+            .http_client
+            .get("https://dblp.org/search/publ/api")
+            .query(&[
+                ("q", query),
+                ("format", "json"),
+                ("h", &hits.to_string()),
+                ("f", &first.to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| AppError::DblpError(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AppError::DblpError(format!(
+                "HTTP {}: {}",
+                status,
+                status.canonical_reason().unwrap_or("Unknown")
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| AppError::DblpError(format!("Failed to read response: {}", e)))
+    }
+
+    /// Combine the supported search criteria into a DBLP query string
+    fn build_query(params: &SearchParams) -> Option<String> {
+        let terms: Vec<&str> = [&params.query, &params.title, &params.author]
+            .into_iter()
+            .flatten()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect();
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+
+    /// Read the `@total` hit count from a DBLP search response body
+    fn parse_total(body: &str) -> AppResult<usize> {
+        let response: DblpResponse = serde_json::from_str(body)
+            .map_err(|e| AppError::DblpError(format!("Failed to parse response: {}", e)))?;
+        response
+            .result
+            .hits
+            .total
+            .and_then(|total| total.parse().ok())
+            .ok_or_else(|| AppError::DblpError("Response has no hit total".to_string()))
+    }
+
+    /// Parse a DBLP search response body into papers
+    fn parse_hits(body: &str) -> AppResult<Vec<AcademicPaper>> {
+        let response: DblpResponse = serde_json::from_str(body)
+            .map_err(|e| AppError::DblpError(format!("Failed to parse response: {}", e)))?;
+        Ok(response
+            .result
+            .hits
+            .hit
+            .into_iter()
+            .map(|hit| Self::paper_from_info(hit.info))
+            .collect())
+    }
+
+    /// Map a DBLP hit into an `AcademicPaper`
+    fn paper_from_info(info: DblpInfo) -> AcademicPaper {
+        let mut paper = AcademicPaper::new();
+        paper.source = Some(PaperSource::Dblp);
+
+        // DBLP titles end with a period
+        paper.title = info.title.trim().trim_end_matches('.').to_string();
+        paper.doi = info.doi.unwrap_or_default();
+        paper.journal = info
+            .venue
+            .map(|v| v.into_vec().join(", "))
+            .unwrap_or_default();
+        paper.url = info
+            .ee
+            .and_then(|ee| ee.into_vec().into_iter().next())
+            .or(info.url)
+            .unwrap_or_default();
+        paper.authors = info
+            .authors
+            .map(|a| a.author.into_vec())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|a| Author::new(Self::strip_homonym_suffix(&a.text)))
+            .collect();
+
+        if let Some(date) = info
+            .year
+            .and_then(|y| y.parse::<i32>().ok())
+            .and_then(|y| Local.with_ymd_and_hms(y, 1, 1, 0, 0, 0).single())
+        {
+            paper.published_date = date;
+        }
+
+        paper
+    }
+
+    /// Remove DBLP's homonym disambiguation number (e.g. "Wei Wang 0001")
+    fn strip_homonym_suffix(name: &str) -> String {
+        match name.rsplit_once(' ') {
+            Some((base, suffix))
+                if !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                base.to_string()
+            }
+            _ => name.to_string(),
+        }
+    }
+
+    /// Check a paper's year against a filter like "2023", "2020-2023", "2020-" or "-2023"
+    fn year_matches(paper: &AcademicPaper, filter: &str) -> bool {
+        use chrono::Datelike;
+
+        let year = paper.published_date.year();
+        let bound = |s: &str| s.trim().parse::<i32>().ok();
+        match filter.split_once('-') {
+            Some((from, to)) => {
+                bound(from).is_none_or(|from| year >= from) && bound(to).is_none_or(|to| year <= to)
+            }
+            None => bound(filter).is_none_or(|y| year == y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    const SAMPLE_RESPONSE: &str = r#"{
+        "result": {
+            "query": "attention is all you need",
+            "status": {"@code": "200", "text": "OK"},
+            "hits": {
+                "@total": "2", "@computed": "2", "@sent": "2", "@first": "0",
+                "hit": [
+                    {
+                        "@score": "10", "@id": "1",
+                        "info": {
+                            "authors": {"author": [
+                                {"@pid": "21/1", "text": "Ashish Vaswani"},
+                                {"@pid": "21/2", "text": "Noam Shazeer"},
+                                {"@pid": "21/3", "text": "Wei Wang 0001"}
+                            ]},
+                            "title": "Attention is All you Need.",
+                            "venue": "NIPS",
+                            "pages": "5998-6008",
+                            "year": "2017",
+                            "type": "Conference and Workshop Papers",
+                            "key": "conf/nips/VaswaniSPUJGKP17",
+                            "doi": "10.5555/3295222.3295349",
+                            "ee": "https://proceedings.neurips.cc/paper/2017/hash/3f5ee243547dee91fbd053c1c4a845aa-Abstract.html",
+                            "url": "https://dblp.org/rec/conf/nips/VaswaniSPUJGKP17"
+                        }
+                    },
+                    {
+                        "@score": "5", "@id": "2",
+                        "info": {
+                            "authors": {"author": {"@pid": "99/9", "text": "Solo Author"}},
+                            "title": "A Single-Author Note.",
+                            "venue": ["CoRR", "arXiv"],
+                            "year": "2021",
+                            "url": "https://dblp.org/rec/journals/corr/abs-2101-00001"
+                        }
+                    }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_dblp_hits() {
+        let papers = DblpClient::parse_hits(SAMPLE_RESPONSE).unwrap();
+        assert_eq!(papers.len(), 2);
+
+        let paper = &papers[0];
+        assert_eq!(paper.title, "Attention is All you Need");
+        assert_eq!(paper.journal, "NIPS");
+        assert_eq!(paper.doi, "10.5555/3295222.3295349");
+        assert!(paper.url.starts_with("https://proceedings.neurips.cc/"));
+        assert_eq!(paper.published_date.year(), 2017);
+        assert!(paper.abstract_text.is_empty());
+        let names: Vec<&str> = paper.authors.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["Ashish Vaswani", "Noam Shazeer", "Wei Wang"]);
+
+        // Single author object, venue array, no ee/doi
+        let paper = &papers[1];
+        assert_eq!(paper.authors.len(), 1);
+        assert_eq!(paper.authors[0].name, "Solo Author");
+        assert_eq!(paper.journal, "CoRR, arXiv");
+        assert_eq!(
+            paper.url,
+            "https://dblp.org/rec/journals/corr/abs-2101-00001"
+        );
+        assert!(paper.doi.is_empty());
+    }
+
+    #[test]
+    fn test_parse_dblp_no_hits() {
+        let body = r#"{"result": {"hits": {"@total": "0", "@sent": "0"}}}"#;
+        assert!(DblpClient::parse_hits(body).unwrap().is_empty());
+        assert!(matches!(
+            DblpClient::parse_hits("<xml/>"),
+            Err(AppError::DblpError(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_dblp_total() {
+        assert_eq!(DblpClient::parse_total(SAMPLE_RESPONSE).unwrap(), 2);
+        let body = r#"{"result": {"hits": {"@total": "3421", "@sent": "0"}}}"#;
+        assert_eq!(DblpClient::parse_total(body).unwrap(), 3421);
+        assert!(DblpClient::parse_total(r#"{"result": {"hits": {}}}"#).is_err());
+    }
+
+    #[test]
+    fn test_build_query() {
+        let params = SearchParams::new()
+            .with_title("Attention is all you need")
+            .with_author("Vaswani");
+        assert_eq!(
+            DblpClient::build_query(&params).as_deref(),
+            Some("Attention is all you need Vaswani")
+        );
+
+        let params = SearchParams::new().with_abstract("transformers");
+        assert!(DblpClient::build_query(&params).is_none());
+    }
+
+    #[test]
+    fn test_year_matches() {
+        let papers = DblpClient::parse_hits(SAMPLE_RESPONSE).unwrap();
+        assert!(DblpClient::year_matches(&papers[0], "2017"));
+        assert!(DblpClient::year_matches(&papers[0], "2015-2018"));
+        assert!(DblpClient::year_matches(&papers[1], "2020-"));
+        assert!(!DblpClient::year_matches(&papers[1], "-2020"));
+        assert!(!DblpClient::year_matches(&papers[0], "2021"));
+    }
+}
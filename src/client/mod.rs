@@ -1,28 +1,169 @@
 //! Client module for paper search and retrieval
 //!
 //! This module provides a unified interface for searching and fetching papers
-//! from multiple sources (arXiv and Semantic Scholar).
+//! from multiple sources (arXiv, Semantic Scholar and optionally DBLP).
 
 mod arxiv;
+mod cache;
+mod crossref;
+mod dblp;
 mod search;
 mod semantic;
 mod unpaywall;
 
-pub use arxiv::ArxivClient;
-pub use search::{PaperSource, SearchParams, SearchResult};
-pub use semantic::SemanticScholarClient;
+pub use crate::models::PaperSource;
+pub use arxiv::{ARXIV_CATEGORIES, ArxivClient, resolve_arxiv_category};
+pub use cache::DEFAULT_SEARCH_CACHE_TTL;
+pub use crossref::CrossRefClient;
+pub use dblp::DblpClient;
+pub(crate) use search::is_arxiv_id;
+pub use search::{PaperId, PaperIds, SearchParams, SearchResult, SortKey};
+pub use semantic::{
+    DEFAULT_MAX_CONCURRENT_SS_REQUESTS, SemanticScholarClient, rank_author_candidates,
+};
 pub use unpaywall::UnpaywallClient;
 
-use crate::models::AcademicPaper;
+use crate::export::{CitationGraph, PaperSummary};
+use crate::models::{
+    ARXIV_DOI_PREFIX, AcademicPaper, ArxivVersion, AuthorProfile, PaperText, split_author_name,
+};
 use crate::pdf::{ExtractionConfig, PdfExtractor, PdfUrlResolver};
+use crate::shared::config::ClientConfig;
 use crate::shared::errors::{AppError, AppResult};
+use crate::shared::utils::normalize_doi;
+use async_trait::async_trait;
+use cache::{CacheKey, SearchCache};
+use futures::stream::FuturesUnordered;
+use futures::{Stream, StreamExt};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 use strsim::normalized_levenshtein;
 
+/// Results from a single source, as yielded by [`PaperClient::search_stream`]
+pub type SourceSearchResult = (PaperSource, AppResult<Vec<AcademicPaper>>);
+
+/// Results from a single source with the total match count it reported, if any
+type CountedSourceResult = (PaperSource, AppResult<(Vec<AcademicPaper>, Option<usize>)>);
+
+/// Default title similarity (normalized Levenshtein) above which papers are deduplicated
+pub const DEFAULT_DEDUP_THRESHOLD: f64 = 0.9;
+
+/// Minimum number of words a main title needs before subtitle-only differences are ignored
+const MIN_SUBTITLE_MATCH_WORDS: usize = 3;
+
+/// Abstract similarity above which papers with differing titles are merged
+const ABSTRACT_DEDUP_THRESHOLD: f64 = 0.95;
+
+/// Minimum number of normalized words an abstract needs to be matched on
+///
+/// Shorter texts are mostly placeholders such as "No abstract available".
+const MIN_ABSTRACT_DEDUP_WORDS: usize = 20;
+
+/// Maximum publication year gap tolerated for same-titled papers without author data
+const MAX_DUPLICATE_YEAR_GAP: i32 = 2;
+
+/// Default number of concurrent lookups in [`PaperClient::fetch_many`]
+pub const DEFAULT_FETCH_CONCURRENCY: usize = 8;
+
+/// Number of author search hits considered when resolving a name
+const AUTHOR_SEARCH_LIMIT: usize = 10;
+
+/// Maximum number of alternative candidates kept in an [`AuthorProfile`]
+const MAX_AUTHOR_ALTERNATIVES: usize = 4;
+
+/// Well-known arXiv ID used by [`PaperClient::health_check`]
+const ARXIV_PROBE_ID: &str = "1706.03762";
+
+/// Semantic Scholar ID of the same paper, used by [`PaperClient::health_check`]
+const SEMANTIC_SCHOLAR_PROBE_ID: &str = "204e3073870fae3d05bcbc2f6a8e263d9b72e776";
+
+/// Error text of a rate-limit response (HTTP 429)
+static RATE_LIMIT_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b429\b|too many requests|rate limit").expect("valid rate limit regex")
+});
+
+/// Number of abstract words used as the related-papers query when a paper has no title
+const RELATED_QUERY_ABSTRACT_WORDS: usize = 20;
+
+/// Outcome of probing one paper source in [`PaperClient::health_check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceHealth {
+    /// The source answered the probe
+    Reachable,
+    /// The source answered with a rate-limit response (HTTP 429)
+    RateLimited(String),
+    /// The probe failed for another reason
+    Unreachable(String),
+    /// No answer arrived within the timeout
+    TimedOut,
+}
+
+impl SourceHealth {
+    /// Classify a probe outcome; `None` means the probe timed out
+    fn from_probe<T>(outcome: Option<AppResult<T>>) -> Self {
+        match outcome {
+            Some(Ok(_)) => Self::Reachable,
+            Some(Err(e)) if RATE_LIMIT_PATTERN.is_match(&e.to_string()) => {
+                Self::RateLimited(e.to_string())
+            }
+            Some(Err(e)) => Self::Unreachable(e.to_string()),
+            None => Self::TimedOut,
+        }
+    }
+}
+
+/// How [`PaperClient`] decides that two records describe the same paper
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum DedupStrategy {
+    /// Merge records whose titles are identical after normalization
+    ///
+    /// Same-titled records that look like distinct works (no shared author, or
+    /// years far apart) are still kept apart.
+    ExactTitle,
+    /// Merge records whose title similarity reaches the threshold (0.0–1.0), or
+    /// whose abstracts are near-identical
+    FuzzyTitle(f64),
+    /// Merge records that share a DOI, arXiv ID or Semantic Scholar ID
+    ByIdentifier,
+    /// Keep every record
+    None,
+}
+
+impl Default for DedupStrategy {
+    fn default() -> Self {
+        Self::FuzzyTitle(DEFAULT_DEDUP_THRESHOLD)
+    }
+}
+
+/// Text extraction step run by the `fetch_by_*` methods of [`PaperClient`]
+///
+/// Set one with [`PaperClient::with_text_extractor`] to replace the built-in
+/// PDF resolution and download, e.g. with a local PDF store.
+#[async_trait]
+pub trait PaperTextExtractor: Send + Sync {
+    /// Extract the full text of `paper`
+    async fn extract(&self, paper: &AcademicPaper) -> AppResult<PaperText>;
+}
+
 /// Unified client for paper search and retrieval across multiple sources
 pub struct PaperClient {
     arxiv: ArxivClient,
     semantic_scholar: SemanticScholarClient,
+    crossref: CrossRefClient,
+    dblp: DblpClient,
     unpaywall: Option<UnpaywallClient>,
+    dedup_strategy: DedupStrategy,
+    use_dblp: bool,
+    fetch_concurrency: usize,
+    auto_extract: bool,
+    pdf_http_client: reqwest::Client,
+    text_extractor: Option<Arc<dyn PaperTextExtractor>>,
+    search_cache: Option<SearchCache>,
 }
 
 impl Default for PaperClient {
@@ -37,10 +178,144 @@ impl PaperClient {
         Self {
             arxiv: ArxivClient::new(),
             semantic_scholar: SemanticScholarClient::new(),
+            crossref: CrossRefClient::new(),
+            dblp: DblpClient::new(),
             unpaywall: UnpaywallClient::from_env(),
+            dedup_strategy: DedupStrategy::default(),
+            use_dblp: false,
+            fetch_concurrency: DEFAULT_FETCH_CONCURRENCY,
+            auto_extract: true,
+            pdf_http_client: reqwest::Client::new(),
+            text_extractor: None,
+            search_cache: None,
         }
     }
 
+    /// Apply proxy/timeout/user-agent settings to the HTTP-based sources
+    ///
+    /// Covers CrossRef, DBLP, Unpaywall, arXiv category searches and result
+    /// counts, Semantic Scholar search and author endpoints and PDF downloads
+    /// for text extraction. arXiv keyword searches and paper lookups on both
+    /// sources use upstream crates that build their own clients; see
+    /// [`ClientConfig`].
+    pub fn with_http_config(mut self, config: &ClientConfig) -> AppResult<Self> {
+        let http_client = config.build_client()?;
+        self.arxiv = self.arxiv.with_http_client(http_client.clone());
+        self.semantic_scholar = self.semantic_scholar.with_http_client(http_client.clone());
+        self.crossref = self.crossref.with_http_client(http_client.clone());
+        self.dblp = self.dblp.with_http_client(http_client.clone());
+        self.unpaywall = self
+            .unpaywall
+            .map(|unpaywall| unpaywall.with_http_client(http_client.clone()));
+        self.pdf_http_client = http_client;
+        Ok(self)
+    }
+
+    /// Set the maximum number of concurrent lookups in [`fetch_many`](Self::fetch_many)
+    pub fn with_fetch_concurrency(mut self, concurrency: usize) -> Self {
+        self.fetch_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Enable or disable automatic PDF text extraction in the `fetch_by_*` methods
+    ///
+    /// Enabled by default. Disable it for metadata-only bulk fetches; call
+    /// [`extract_text`](Self::extract_text) to extract on demand.
+    pub fn with_auto_extract(mut self, enabled: bool) -> Self {
+        self.auto_extract = enabled;
+        self
+    }
+
+    /// Extract text with `extractor` instead of resolving and downloading the PDF
+    ///
+    /// Used by the `fetch_by_*` methods and [`extract_text`](Self::extract_text);
+    /// [`extract_text_with_config`](Self::extract_text_with_config) always
+    /// downloads the PDF.
+    pub fn with_text_extractor(mut self, extractor: impl PaperTextExtractor + 'static) -> Self {
+        self.text_extractor = Some(Arc::new(extractor));
+        self
+    }
+
+    /// Cache [`search`](Self::search) results in memory for `ttl`
+    ///
+    /// Repeating a search with the same criteria (ignoring case and extra
+    /// whitespace) and the same dedup/DBLP settings within the TTL returns the
+    /// cached result without contacting any source. ID lookups that extract
+    /// text are never cached.
+    pub fn with_search_cache(mut self, ttl: Duration) -> Self {
+        self.search_cache = Some(match self.search_cache.take() {
+            Some(cache) => cache.with_ttl(ttl),
+            None => SearchCache::new(ttl),
+        });
+        self
+    }
+
+    /// Also persist cached search results as JSON files in `dir`
+    ///
+    /// Enables the cache with [`DEFAULT_SEARCH_CACHE_TTL`] unless
+    /// [`with_search_cache`](Self::with_search_cache) set a TTL.
+    pub fn with_search_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        let cache = self
+            .search_cache
+            .take()
+            .unwrap_or_else(|| SearchCache::new(DEFAULT_SEARCH_CACHE_TTL));
+        self.search_cache = Some(cache.with_dir(dir.into()));
+        self
+    }
+
+    /// Include DBLP in keyword searches
+    ///
+    /// DBLP has clean venue/author metadata for computer-science papers but no
+    /// abstracts; DBLP-only results have an empty `abstract_text`.
+    pub fn with_dblp(mut self, enabled: bool) -> Self {
+        self.use_dblp = enabled;
+        self
+    }
+
+    /// Set the title similarity threshold used for deduplication
+    ///
+    /// The value is a normalized Levenshtein similarity (0.0 = different, 1.0 = same).
+    /// Shorthand for [`DedupStrategy::FuzzyTitle`].
+    pub fn with_dedup_threshold(self, threshold: f64) -> Self {
+        self.with_dedup_strategy(DedupStrategy::FuzzyTitle(threshold))
+    }
+
+    /// Set how duplicate records are detected when merging results
+    ///
+    /// Defaults to [`DedupStrategy::FuzzyTitle`] with [`DEFAULT_DEDUP_THRESHOLD`].
+    pub fn with_dedup_strategy(mut self, strategy: DedupStrategy) -> Self {
+        self.dedup_strategy = match strategy {
+            DedupStrategy::FuzzyTitle(threshold) => {
+                DedupStrategy::FuzzyTitle(threshold.clamp(0.0, 1.0))
+            }
+            other => other,
+        };
+        self
+    }
+
+    /// Authenticate Semantic Scholar requests with an API key
+    ///
+    /// Defaults to `SEMANTIC_SCHOLAR_API_KEY`; unauthenticated access is heavily
+    /// throttled, especially for bulk citation/reference fetches.
+    pub fn with_ss_api_key(mut self, key: impl Into<String>) -> Self {
+        self.semantic_scholar = self.semantic_scholar.with_api_key(key);
+        self
+    }
+
+    /// Limit how many Semantic Scholar requests may be in flight at once
+    ///
+    /// Applies to every Semantic Scholar call made by this client (search,
+    /// details, citations, references), so concurrent citation and reference
+    /// fetches share one budget. Defaults to
+    /// [`DEFAULT_MAX_CONCURRENT_SS_REQUESTS`]; raise it when using an API key
+    /// with a higher rate limit.
+    pub fn with_max_concurrent_ss_requests(mut self, max_concurrent: usize) -> Self {
+        self.semantic_scholar = self
+            .semantic_scholar
+            .with_max_concurrent_requests(max_concurrent);
+        self
+    }
+
     /// Create a client with custom Semantic Scholar retry configuration
     pub fn with_ss_retry_config(mut self, retry_count: u64, wait_time: u64) -> Self {
         self.semantic_scholar = self
@@ -51,68 +326,323 @@ impl PaperClient {
 
     /// Search papers across all sources
     ///
-    /// Searches both arXiv and Semantic Scholar in parallel and merges results.
+    /// Searches arXiv and Semantic Scholar (and DBLP, if enabled) in parallel
+    /// and merges results.
     pub async fn search(&self, params: SearchParams) -> AppResult<SearchResult> {
+        params.validate()?;
+
+        // Extracted text is large and tied to the PDF; don't cache it
+        let cache = self
+            .search_cache
+            .as_ref()
+            .filter(|_| !(params.is_id_lookup() && self.auto_extract));
+        let Some(cache) = cache else {
+            return self.search_uncached(params).await;
+        };
+        let key = CacheKey::new(&params, self.dedup_strategy, self.use_dblp);
+        if let Some(result) = cache.get(&key) {
+            return Ok(result);
+        }
+        let result = self.search_uncached(params).await?;
+        cache.insert(&key, &result);
+        Ok(result)
+    }
+
+    /// [`search`](Self::search) without the result cache
+    async fn search_uncached(&self, params: SearchParams) -> AppResult<SearchResult> {
         // If it's an ID lookup, use the specific fetch methods
         if params.is_id_lookup() {
             return self.fetch_by_id(&params).await;
         }
 
-        // Search both sources in parallel
-        let arxiv_future = self.arxiv.search(&params);
-        let ss_future = self.semantic_scholar.search(&params);
+        // Search all sources in parallel (results keep the source order)
+        let counted_results = futures::future::join_all(
+            self.search_sources(&params)
+                .into_iter()
+                .map(|source| self.search_source_counted(source, params.clone())),
+        )
+        .await;
+        let (source_results, total_count) = Self::split_total_counts(counted_results);
+        let mut result = Self::collect_source_results(source_results)?;
+        result.total_count = total_count;
+        let result = self.refine_result(result, &params);
 
-        let (arxiv_result, ss_result) = tokio::join!(arxiv_future, ss_future);
+        if result.papers.is_empty() {
+            return Err(AppError::PaperNotFound(
+                "No papers found matching the search criteria".to_string(),
+            ));
+        }
 
-        let mut result = SearchResult::new();
+        Ok(result)
+    }
 
-        // Process arXiv results
-        if let Ok(arxiv_papers) = arxiv_result {
-            for paper in arxiv_papers {
-                let academic_paper = AcademicPaper::from_arxiv(paper);
-                result.papers.push(academic_paper);
-            }
-            result.sources.push(PaperSource::ArXiv);
+    /// Search papers across all sources, yielding each source's results as they arrive
+    ///
+    /// Unlike [`search`](Self::search), results are not merged or deduplicated;
+    /// consumers can collect all papers and pass them to
+    /// [`deduplicate`](Self::deduplicate) as a final pass. ID lookups are not
+    /// handled here; use `search` or the `fetch_by_*` methods for those.
+    pub fn search_stream(
+        &self,
+        params: SearchParams,
+    ) -> impl Stream<Item = SourceSearchResult> + '_ {
+        Self::source_stream(
+            self.search_sources(&params)
+                .into_iter()
+                .map(move |source| self.search_source(source, params.clone())),
+        )
+    }
+
+    /// Sources queried by keyword searches, in merge order
+    ///
+    /// Category-only listings are arXiv-only.
+    fn search_sources(&self, params: &SearchParams) -> Vec<PaperSource> {
+        if params.is_category_listing() {
+            return vec![PaperSource::ArXiv];
         }
+        let mut sources = vec![PaperSource::ArXiv, PaperSource::SemanticScholar];
+        if self.use_dblp {
+            sources.push(PaperSource::Dblp);
+        }
+        sources
+    }
 
-        // Process Semantic Scholar results
-        if let Ok(ss_papers) = ss_result {
-            for paper in ss_papers {
-                let academic_paper = AcademicPaper::from_semantic_scholar(paper);
-                result.papers.push(academic_paper);
+    /// Run a keyword search against a single source
+    async fn search_source(&self, source: PaperSource, params: SearchParams) -> SourceSearchResult {
+        let (source, result) = self
+            .search_source_counted(source, params.with_count_total(false))
+            .await;
+        (source, result.map(|(papers, _)| papers))
+    }
+
+    /// Run a keyword search against a single source, with its total match count
+    ///
+    /// The total is read from the search response where the source reports
+    /// one; other sources are only asked separately when
+    /// [`SearchParams::count_total`] is set.
+    async fn search_source_counted(
+        &self,
+        source: PaperSource,
+        params: SearchParams,
+    ) -> CountedSourceResult {
+        let result = match source {
+            PaperSource::ArXiv => {
+                let searched = if params.categories.is_empty() {
+                    let (papers, total) = futures::join!(
+                        self.arxiv.search(&params),
+                        self.separate_total(source, &params)
+                    );
+                    papers.map(|papers| (papers, total))
+                } else {
+                    self.arxiv.search_with_total(&params).await
+                };
+                searched.map(|(papers, total)| {
+                    let papers = papers.into_iter().map(AcademicPaper::from_arxiv).collect();
+                    (papers, total)
+                })
             }
-            result.sources.push(PaperSource::SemanticScholar);
+            PaperSource::SemanticScholar => self
+                .semantic_scholar
+                .search_with_total(&params)
+                .await
+                .map(|(papers, total)| {
+                    let papers = papers
+                        .into_iter()
+                        .map(AcademicPaper::from_semantic_scholar)
+                        .collect();
+                    (papers, total)
+                }),
+            PaperSource::Dblp => self.dblp.search_with_total(&params).await,
+            // CrossRef only supports DOI lookups
+            PaperSource::CrossRef | PaperSource::Both => Ok((Vec::new(), None)),
+        };
+        (source, result)
+    }
+
+    /// Deduplicate and filter merged source results according to `params`
+    fn refine_result(&self, mut result: SearchResult, params: &SearchParams) -> SearchResult {
+        // Deduplicate papers (by title similarity) unless raw per-source results were requested
+        if params.deduplicate {
+            result.papers = self.deduplicate_papers(std::mem::take(&mut result.papers));
         }
 
-        // Deduplicate papers (by title similarity)
-        result.papers = self.deduplicate_papers(result.papers);
+        // Apply the citation filter uniformly (arXiv has no citation data at query time)
+        result.retain(|paper| Self::meets_min_citations(paper, params));
+        if params.open_access_only {
+            result.retain(Self::has_accessible_pdf);
+        }
+        if let Some(status) = params.publication_status {
+            result.retain(|paper| paper.publication_status() == status);
+        }
+        if let Some(since) = params.submitted_after {
+            result.retain(|paper| paper.published_date >= since);
+            result.sort_newest_first();
+        }
+        result.sort(params.sort);
+        result
+    }
 
-        if result.papers.is_empty() {
-            return Err(AppError::PaperNotFound(
-                "No papers found matching the search criteria".to_string(),
-            ));
+    /// Separate count request for a source whose search responses carry no total
+    ///
+    /// Only sent when [`SearchParams::count_total`] is set. A failed count is
+    /// logged and treated as unknown.
+    async fn separate_total(&self, source: PaperSource, params: &SearchParams) -> Option<usize> {
+        if !params.count_total {
+            return None;
         }
+        let total = match source {
+            PaperSource::ArXiv => self.arxiv.total_results(params).await,
+            _ => return None,
+        };
+        total
+            .map_err(|e| tracing::debug!("{:?} count failed: {}", source, e))
+            .ok()
+    }
+
+    /// Split per-source results from the largest total any source reported
+    ///
+    /// Sources overlap heavily, so the maximum is a better estimate of the
+    /// distinct matches than the sum. `None` means no source reported a total.
+    fn split_total_counts(
+        counted_results: Vec<CountedSourceResult>,
+    ) -> (Vec<SourceSearchResult>, Option<usize>) {
+        let mut total_count = None;
+        let source_results = counted_results
+            .into_iter()
+            .map(|(source, result)| {
+                let papers = result.map(|(papers, total)| {
+                    total_count = total_count.max(total);
+                    papers
+                });
+                (source, papers)
+            })
+            .collect();
+        (source_results, total_count)
+    }
 
+    /// Merge per-source results, skipping sources that failed
+    ///
+    /// Fails with `AllSourcesFailed` only when every source returned an error,
+    /// so outages and bad credentials are not reported as "no matches".
+    fn collect_source_results(source_results: Vec<SourceSearchResult>) -> AppResult<SearchResult> {
+        let mut result = SearchResult::new();
+        let mut errors = Vec::new();
+        for (source, papers) in source_results {
+            match papers {
+                Ok(mut papers) => {
+                    for paper in &mut papers {
+                        paper.source.get_or_insert(source);
+                    }
+                    result.papers.extend(papers);
+                    result.sources.push(source);
+                }
+                Err(e) => {
+                    tracing::warn!("{:?} search failed: {}", source, e);
+                    errors.push(format!("{:?}: {}", source, e));
+                }
+            }
+        }
+
+        if result.sources.is_empty() && !errors.is_empty() {
+            return Err(AppError::AllSourcesFailed(errors));
+        }
         Ok(result)
     }
 
+    /// Poll per-source futures concurrently, yielding each as it completes
+    fn source_stream<F>(futures: impl IntoIterator<Item = F>) -> FuturesUnordered<F>
+    where
+        F: Future<Output = SourceSearchResult>,
+    {
+        futures.into_iter().collect()
+    }
+
+    /// Whether a paper passes `params.min_citations`
+    ///
+    /// Citation counts come from Semantic Scholar, so a paper without an SS ID
+    /// has an unknown count; it is dropped unless `keep_unknown_citations` is set.
+    fn meets_min_citations(paper: &AcademicPaper, params: &SearchParams) -> bool {
+        let Some(min_citations) = params.min_citations else {
+            return true;
+        };
+        if paper.ss_id.is_empty() {
+            params.keep_unknown_citations
+        } else {
+            paper.citations_count >= min_citations as i32
+        }
+    }
+
+    /// Whether a paper has an accessible PDF
+    ///
+    /// A paper qualifies if it is flagged open access, has an open-access PDF
+    /// URL, or has an arXiv ID (arXiv PDFs are always freely available).
+    fn has_accessible_pdf(paper: &AcademicPaper) -> bool {
+        paper.is_open_access
+            || paper
+                .open_access_pdf_url
+                .as_ref()
+                .is_some_and(|u| !u.is_empty())
+            || !paper.arxiv_id.is_empty()
+    }
+
+    /// Deduplicate papers gathered from multiple sources
+    ///
+    /// Applies the same title- and abstract-similarity merge used by
+    /// [`search`](Self::search).
+    pub fn deduplicate(&self, papers: Vec<AcademicPaper>) -> Vec<AcademicPaper> {
+        self.deduplicate_papers(papers)
+    }
+
+    /// Check that arXiv and Semantic Scholar are reachable with this client's settings
+    ///
+    /// Each source gets one trivial lookup, bounded by `timeout`. The Semantic
+    /// Scholar lookup is not retried, so a rate limit is reported as such
+    /// instead of using up the timeout. LLM providers are checked by
+    /// [`crate::doctor::run_checks`].
+    pub async fn health_check(&self, timeout: Duration) -> Vec<(PaperSource, SourceHealth)> {
+        let semantic_scholar = self.semantic_scholar.clone().with_retry_config(1, 1);
+        let (arxiv, semantic_scholar) = tokio::join!(
+            tokio::time::timeout(timeout, self.arxiv.fetch_by_id(ARXIV_PROBE_ID)),
+            tokio::time::timeout(
+                timeout,
+                semantic_scholar.fetch_details(SEMANTIC_SCHOLAR_PROBE_ID)
+            ),
+        );
+        vec![
+            (PaperSource::ArXiv, SourceHealth::from_probe(arxiv.ok())),
+            (
+                PaperSource::SemanticScholar,
+                SourceHealth::from_probe(semantic_scholar.ok()),
+            ),
+        ]
+    }
+
     /// Fetch a paper by arXiv ID
     ///
+    /// A versioned ID (e.g., "1706.03762v5") fetches that version.
     /// This method also attempts to extract PDF text automatically.
     /// If PDF extraction fails, the paper is still returned with `extracted_text` as `None`.
     pub async fn fetch_by_arxiv_id(&self, arxiv_id: &str) -> AppResult<AcademicPaper> {
         let arxiv_paper = self.arxiv.fetch_by_id(arxiv_id).await?;
         let mut paper = AcademicPaper::from_arxiv(arxiv_paper);
+        // Keep an explicitly requested version even if the API reports another
+        if let (_, Some(version)) = AcademicPaper::parse_arxiv_id(arxiv_id) {
+            paper.arxiv_version = Some(version);
+        }
 
-        // Try to enrich with Semantic Scholar data
-        if let Ok(ss_paper) = self.semantic_scholar.search_exact_title(&paper.title).await {
-            paper.enrich_from_semantic_scholar(ss_paper);
+        // Try to enrich with Semantic Scholar data; without it, arXiv provides no
+        // citation/reference counts, so they stay unknown (`metrics_known == false`)
+        match self.semantic_scholar.search_exact_title(&paper.title).await {
+            Ok(ss_paper) => paper.enrich_from_semantic_scholar(ss_paper),
+            Err(e) => tracing::debug!(
+                "No Semantic Scholar match for '{}', metrics unknown: {}",
+                paper.title,
+                e
+            ),
         }
 
         // Try to extract PDF text (non-fatal on failure)
-        self.try_extract_text(&mut paper).await;
-
-        Ok(paper)
+        Ok(self.auto_extract_text(paper).await)
     }
 
     /// Fetch a paper by Semantic Scholar ID
@@ -132,9 +662,215 @@ impl PaperClient {
         }
 
         // Try to extract PDF text (non-fatal on failure)
-        self.try_extract_text(&mut paper).await;
+        Ok(self.auto_extract_text(paper).await)
+    }
+
+    /// Fetch a paper by DOI
+    ///
+    /// Metadata comes from CrossRef; the paper is then enriched from Semantic
+    /// Scholar (by title) and arXiv (if SS reports an arXiv ID), and PDF text
+    /// extraction is attempted as with the other `fetch_by_*` methods.
+    pub async fn fetch_by_doi(&self, doi: &str) -> AppResult<AcademicPaper> {
+        let mut paper = self.crossref.fetch_by_doi(doi).await?;
+
+        if !paper.title.is_empty()
+            && let Ok(ss_paper) = self.semantic_scholar.search_exact_title(&paper.title).await
+        {
+            paper.enrich_from_semantic_scholar(ss_paper);
+        }
+
+        if !paper.arxiv_id.is_empty()
+            && let Ok(arxiv_paper) = self.arxiv.fetch_by_id(&paper.arxiv_id).await
+        {
+            paper.enrich_from_arxiv(arxiv_paper);
+        }
+
+        // Try to extract PDF text (non-fatal on failure)
+        Ok(self.auto_extract_text(paper).await)
+    }
 
-        Ok(paper)
+    /// Fetch a paper by any supported identifier
+    pub async fn fetch_by_paper_id(&self, id: &PaperId) -> AppResult<AcademicPaper> {
+        match id {
+            PaperId::Arxiv(arxiv_id) => self.fetch_by_arxiv_id(arxiv_id).await,
+            PaperId::Ss(ss_id) => self.fetch_by_ss_id(ss_id).await,
+            PaperId::Doi(doi) => self.fetch_by_doi(doi).await,
+        }
+    }
+
+    /// Fetch many papers concurrently
+    ///
+    /// Runs at most `fetch_concurrency` lookups at a time (default
+    /// [`DEFAULT_FETCH_CONCURRENCY`]). The output has one entry per input ID,
+    /// in input order; a failed lookup yields an `Err` entry instead of
+    /// aborting the batch.
+    pub async fn fetch_many(&self, ids: &[PaperId]) -> Vec<AppResult<AcademicPaper>> {
+        Self::run_bounded(ids, self.fetch_concurrency, |id| self.fetch_by_paper_id(id)).await
+    }
+
+    /// Fetch the revision history of an arXiv paper, oldest first
+    pub async fn fetch_arxiv_versions(&self, arxiv_id: &str) -> AppResult<Vec<ArxivVersion>> {
+        self.arxiv.fetch_versions(arxiv_id).await
+    }
+
+    /// Resolve any paper identifier to its arXiv ID, Semantic Scholar ID and DOI
+    ///
+    /// The identifier type is detected with [`PaperId::detect`], and Semantic
+    /// Scholar's paper endpoint (which returns all external IDs at once) is
+    /// queried with it. IDs the endpoint does not report stay empty, except for
+    /// the input ID itself.
+    pub async fn resolve_ids(&self, any_id: &str) -> AppResult<PaperIds> {
+        let paper_id = PaperId::detect(any_id).ok_or_else(|| {
+            AppError::PaperNotFound(format!("Unrecognized paper identifier: {}", any_id))
+        })?;
+        let paper_id = match paper_id {
+            PaperId::Doi(doi) => PaperId::Doi(normalize_doi(&doi).unwrap_or(doi)),
+            id => id,
+        };
+        let ss_lookup = match &paper_id {
+            PaperId::Arxiv(id) => format!("arXiv:{}", AcademicPaper::parse_arxiv_id(id).0),
+            PaperId::Ss(id) => id.clone(),
+            PaperId::Doi(doi) => format!("DOI:{}", doi),
+        };
+
+        let ss_paper = self.semantic_scholar.fetch_details(&ss_lookup).await?;
+        let paper = AcademicPaper::from_semantic_scholar(ss_paper);
+        let mut ids = PaperIds {
+            arxiv_id: paper.arxiv_id,
+            ss_id: paper.ss_id,
+            doi: paper.doi,
+        };
+        match paper_id {
+            PaperId::Arxiv(id) if ids.arxiv_id.is_empty() => {
+                ids.arxiv_id = AcademicPaper::parse_arxiv_id(&id).0
+            }
+            PaperId::Ss(id) if ids.ss_id.is_empty() => ids.ss_id = id,
+            PaperId::Doi(doi) if ids.doi.is_empty() => ids.doi = doi,
+            _ => {}
+        }
+        Ok(ids)
+    }
+
+    /// Run `f` over `items` with at most `limit` futures in flight, preserving order
+    async fn run_bounded<'a, T, F, Fut>(items: &'a [T], limit: usize, f: F) -> Vec<Fut::Output>
+    where
+        F: FnMut(&'a T) -> Fut,
+        Fut: Future,
+    {
+        futures::stream::iter(items.iter().map(f))
+            .buffered(limit.max(1))
+            .collect()
+            .await
+    }
+
+    /// Look up an author profile by name or Semantic Scholar author ID
+    ///
+    /// A purely numeric argument is treated as an author ID. Otherwise the
+    /// name is searched and candidates are ranked with
+    /// [`rank_author_candidates`]; the best match is returned as the profile
+    /// author and the next few are kept in `other_candidates` so callers can
+    /// disambiguate authors sharing a name. The chosen author's `paper_limit`
+    /// most recent papers are returned, newest first.
+    pub async fn author_profile(
+        &self,
+        name_or_id: &str,
+        paper_limit: usize,
+    ) -> AppResult<AuthorProfile> {
+        let name_or_id = name_or_id.trim();
+        let (author, other_candidates) = if Self::is_author_id(name_or_id) {
+            (
+                self.semantic_scholar.fetch_author(name_or_id).await?,
+                Vec::new(),
+            )
+        } else {
+            let candidates = self
+                .semantic_scholar
+                .search_authors(name_or_id, AUTHOR_SEARCH_LIMIT)
+                .await?;
+            let mut ranked = rank_author_candidates(name_or_id, candidates).into_iter();
+            let Some(best) = ranked.next() else {
+                return Err(AppError::PaperNotFound(format!(
+                    "No author found matching '{}'",
+                    name_or_id
+                )));
+            };
+            (best, ranked.take(MAX_AUTHOR_ALTERNATIVES).collect())
+        };
+
+        // The author papers endpoint has no sort order, so take the newest
+        // after fetching every paper
+        let total = usize::try_from(author.paper_count)
+            .unwrap_or(0)
+            .max(paper_limit);
+        let mut papers = self
+            .semantic_scholar
+            .fetch_author_papers(&author.ss_id, total)
+            .await?;
+        papers.sort_by(|a, b| b.published_date.cmp(&a.published_date));
+        papers.truncate(paper_limit);
+
+        Ok(AuthorProfile {
+            author,
+            papers,
+            other_candidates,
+        })
+    }
+
+    /// Find papers conceptually related to `paper`
+    ///
+    /// Uses Semantic Scholar recommendations seeded by the paper's
+    /// Semantic Scholar ID. Papers without one fall back to a keyword search
+    /// on the title (or the start of the abstract); the seed paper itself is
+    /// excluded from the fallback results.
+    pub async fn find_related(
+        &self,
+        paper: &AcademicPaper,
+        limit: usize,
+    ) -> AppResult<Vec<AcademicPaper>> {
+        if !paper.ss_id.is_empty() {
+            return self
+                .semantic_scholar
+                .fetch_recommendations(&paper.ss_id, limit)
+                .await;
+        }
+
+        let Some(query) = Self::related_query(paper) else {
+            return Err(AppError::PaperNotFound(
+                "Paper has no Semantic Scholar ID, title or abstract to find related work"
+                    .to_string(),
+            ));
+        };
+        let params = SearchParams::new()
+            .with_query(query)
+            .with_max_results(limit + 1);
+        let mut related: Vec<AcademicPaper> = self
+            .search(params)
+            .await?
+            .papers
+            .into_iter()
+            .filter(|candidate| !self.titles_match(&candidate.title, &paper.title))
+            .collect();
+        related.truncate(limit);
+        Ok(related)
+    }
+
+    /// Keyword query for the related-papers fallback search
+    fn related_query(paper: &AcademicPaper) -> Option<String> {
+        let title = paper.title.trim();
+        if !title.is_empty() {
+            return Some(title.to_string());
+        }
+        let words: Vec<&str> = paper
+            .abstract_text
+            .split_whitespace()
+            .take(RELATED_QUERY_ABSTRACT_WORDS)
+            .collect();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
+
+    /// Semantic Scholar author IDs are plain integers
+    fn is_author_id(value: &str) -> bool {
+        !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
     }
 
     /// Create a PDF URL resolver using this client's sub-clients
@@ -142,62 +878,66 @@ impl PaperClient {
         PdfUrlResolver::new(&self.semantic_scholar, self.unpaywall.as_ref())
     }
 
-    /// Try to extract PDF text for a paper (non-fatal on failure)
+    /// Create a PDF extractor that downloads through this client's HTTP settings
+    fn pdf_extractor(&self, config: ExtractionConfig) -> PdfExtractor {
+        PdfExtractor::with_config(config).with_http_client(self.pdf_http_client.clone())
+    }
+
+    /// Automatic extraction step of the `fetch_by_*` methods
     ///
-    /// If extraction fails, a warning is logged and `extracted_text` remains `None`.
-    async fn try_extract_text(&self, paper: &mut AcademicPaper) {
-        let resolver = self.pdf_resolver();
-        match resolver.resolve(paper).await {
-            Ok(url) => {
-                let extractor = PdfExtractor::new();
-                match extractor.extract_from_url(&url).await {
-                    Ok(text) => {
-                        paper.set_extracted_text(text);
-                    }
-                    Err(e) => {
-                        tracing::warn!("PDF extraction failed for '{}': {}", paper.title, e);
-                    }
-                }
-            }
-            Err(e) => {
-                tracing::warn!("PDF URL resolution failed for '{}': {}", paper.title, e);
-            }
+    /// Does nothing if automatic extraction is disabled. If extraction fails,
+    /// a warning is logged and `extracted_text` remains `None`.
+    async fn auto_extract_text(&self, mut paper: AcademicPaper) -> AcademicPaper {
+        if self.auto_extract
+            && let Err(e) = self.extract_text(&mut paper).await
+        {
+            tracing::warn!("PDF extraction failed for '{}': {}", paper.title, e);
         }
+        paper
     }
 
     /// Extract PDF text for a paper, returning an error on failure
     ///
     /// Use this method when you need to ensure text extraction succeeds.
     pub async fn extract_text(&self, paper: &mut AcademicPaper) -> AppResult<()> {
-        let resolver = self.pdf_resolver();
-        let url = resolver.resolve(paper).await?;
-        let extractor = PdfExtractor::new();
-        let text = extractor.extract_from_url(&url).await?;
-        paper.set_extracted_text(text);
-        Ok(())
+        self.extract_text_with_config(paper, ExtractionConfig::default())
+            .await
     }
 
     /// Extract PDF text with custom configuration
     ///
     /// Use this method when you need fine-grained control over extraction options
-    /// such as enabling/disabling math markup or reference extraction.
+    /// such as enabling/disabling math markup or reference extraction. An
+    /// extractor set with [`Self::with_text_extractor`] takes precedence and
+    /// ignores `config`.
     pub async fn extract_text_with_config(
         &self,
         paper: &mut AcademicPaper,
         config: ExtractionConfig,
     ) -> AppResult<()> {
-        let resolver = self.pdf_resolver();
-        let url = resolver.resolve(paper).await?;
-        let extractor = PdfExtractor::with_config(config);
-        let text = extractor.extract_from_url(&url).await?;
+        let text = match &self.text_extractor {
+            Some(extractor) => extractor.extract(paper).await?,
+            None => {
+                let url = self.pdf_resolver().resolve(paper).await?;
+                self.pdf_extractor(config).extract_from_url(&url).await?
+            }
+        };
         paper.set_extracted_text(text);
         Ok(())
     }
 
     /// Fetch papers that cite the given paper
-    pub async fn fetch_citations(&self, paper: &AcademicPaper) -> AppResult<Vec<AcademicPaper>> {
+    ///
+    /// With `limit: None` a single page of the API's default size is
+    /// returned; with `Some(limit)` result pages are followed until `limit`
+    /// papers are collected or the citations run out.
+    pub async fn fetch_citations(
+        &self,
+        paper: &AcademicPaper,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<AcademicPaper>> {
         let ss_id = paper.ss_id()?;
-        let citations = self.semantic_scholar.fetch_citations(&ss_id).await?;
+        let citations = self.semantic_scholar.fetch_citations(&ss_id, limit).await?;
 
         Ok(citations
             .into_iter()
@@ -206,9 +946,18 @@ impl PaperClient {
     }
 
     /// Fetch papers referenced by the given paper
-    pub async fn fetch_references(&self, paper: &AcademicPaper) -> AppResult<Vec<AcademicPaper>> {
+    ///
+    /// `limit` works as in [`Self::fetch_citations`].
+    pub async fn fetch_references(
+        &self,
+        paper: &AcademicPaper,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<AcademicPaper>> {
         let ss_id = paper.ss_id()?;
-        let references = self.semantic_scholar.fetch_references(&ss_id).await?;
+        let references = self
+            .semantic_scholar
+            .fetch_references(&ss_id, limit)
+            .await?;
 
         Ok(references
             .into_iter()
@@ -216,6 +965,150 @@ impl PaperClient {
             .collect())
     }
 
+    /// Crawl the citation network of a paper breadth-first
+    ///
+    /// Level 1 holds the papers citing `paper`, level 2 the papers citing
+    /// those, and so on up to `depth`. At most `max_per_node` citing papers are
+    /// followed per node, and papers reached along several paths appear once.
+    /// Every lookup goes through the shared Semantic Scholar client, so its
+    /// concurrency limit and retry configuration apply; a failed lookup below
+    /// the root is logged and left in [`CitationGraph::pending`] so the rest
+    /// of the graph is kept and a later resume can retry it.
+    pub async fn citation_network(
+        &self,
+        paper: &AcademicPaper,
+        depth: usize,
+        max_per_node: usize,
+    ) -> AppResult<CitationGraph> {
+        self.resume_citation_network(
+            CitationGraph::from_root(paper)?,
+            depth,
+            max_per_node,
+            |_| {},
+        )
+        .await
+    }
+
+    /// Continue a citation crawl from a partially crawled graph
+    ///
+    /// Expands the graph's `pending` nodes as [`Self::citation_network`]
+    /// does. `on_level` receives the graph after each level, so callers can
+    /// save a checkpoint to resume from if the crawl is interrupted.
+    pub async fn resume_citation_network(
+        &self,
+        graph: CitationGraph,
+        depth: usize,
+        max_per_node: usize,
+        on_level: impl FnMut(&CitationGraph),
+    ) -> AppResult<CitationGraph> {
+        Self::crawl_citations(
+            graph,
+            depth,
+            max_per_node,
+            self.fetch_concurrency,
+            |node: AcademicPaper| async move {
+                self.fetch_citations(&node, Some(max_per_node)).await
+            },
+            on_level,
+        )
+        .await
+    }
+
+    /// Breadth-first citation crawl over an arbitrary citation lookup
+    async fn crawl_citations<F, Fut>(
+        mut graph: CitationGraph,
+        depth: usize,
+        max_per_node: usize,
+        concurrency: usize,
+        mut fetch: F,
+        mut on_level: impl FnMut(&CitationGraph),
+    ) -> AppResult<CitationGraph>
+    where
+        F: FnMut(AcademicPaper) -> Fut,
+        Fut: Future<Output = AppResult<Vec<AcademicPaper>>>,
+    {
+        let mut seen_nodes: HashSet<String> = graph
+            .nodes
+            .iter()
+            .filter_map(CitationGraph::node_id)
+            .collect();
+        let mut seen_edges: HashSet<(String, String)> = graph.edges.iter().cloned().collect();
+        // Papers found by this crawl; resumed nodes are rebuilt from their summaries
+        let mut found: HashMap<String, AcademicPaper> = HashMap::new();
+
+        for level in 0..depth {
+            let (frontier, later): (Vec<_>, Vec<_>) = std::mem::take(&mut graph.pending)
+                .into_iter()
+                .partition(|(_, node_level)| *node_level == level);
+            graph.pending = later;
+            if frontier.is_empty() {
+                continue;
+            }
+
+            let nodes: Vec<AcademicPaper> = frontier
+                .iter()
+                .map(|(id, _)| {
+                    found
+                        .remove(id)
+                        .unwrap_or_else(|| Self::summary_paper(&graph, id))
+                })
+                .collect();
+            let results: Vec<AppResult<Vec<AcademicPaper>>> =
+                futures::stream::iter(nodes.iter().map(|node| fetch(node.clone())))
+                    .buffered(concurrency.max(1))
+                    .collect()
+                    .await;
+
+            for (((cited_id, _), cited), result) in frontier.into_iter().zip(&nodes).zip(results) {
+                let citing_papers = match result {
+                    Ok(papers) => papers,
+                    Err(e) if level == 0 => return Err(e),
+                    Err(e) => {
+                        tracing::warn!("Citation lookup failed for '{}': {}", cited.title, e);
+                        graph.pending.push((cited_id, level));
+                        continue;
+                    }
+                };
+                for citing in citing_papers.into_iter().take(max_per_node) {
+                    let summary = PaperSummary::from_academic_paper(&citing);
+                    let Some(citing_id) = CitationGraph::node_id(&summary) else {
+                        continue;
+                    };
+                    let edge = (citing_id.clone(), cited_id.clone());
+                    if seen_edges.insert(edge.clone()) {
+                        graph.edges.push(edge);
+                    }
+                    if seen_nodes.insert(citing_id.clone()) {
+                        graph.nodes.push(summary);
+                        if level + 1 < depth {
+                            graph.pending.push((citing_id.clone(), level + 1));
+                            found.insert(citing_id, citing);
+                        }
+                    }
+                }
+            }
+            on_level(&graph);
+        }
+
+        Ok(graph)
+    }
+
+    /// Paper to look up citations for a node known only by its summary
+    fn summary_paper(graph: &CitationGraph, id: &str) -> AcademicPaper {
+        let mut paper = AcademicPaper::new();
+        if let Some(summary) = graph
+            .nodes
+            .iter()
+            .find(|n| CitationGraph::node_id(n).as_deref() == Some(id))
+        {
+            paper.ss_id = summary.ss_id.clone();
+            paper.arxiv_id = summary.arxiv_id.clone();
+            paper.doi = summary.doi.clone();
+            paper.title = summary.title.clone();
+        }
+        paper
+    }
+
     /// Handle ID-based lookups
     async fn fetch_by_id(&self, params: &SearchParams) -> AppResult<SearchResult> {
         let mut result = SearchResult::new();
@@ -232,51 +1125,239 @@ impl PaperClient {
             result.sources.push(PaperSource::SemanticScholar);
         }
 
+        if let Some(ref doi) = params.doi {
+            let paper = self.fetch_by_doi(doi).await?;
+            result.papers.push(paper);
+            result.sources.push(PaperSource::CrossRef);
+        }
+
         Ok(result)
     }
 
-    /// Deduplicate papers by title similarity, merging data from duplicates
+    /// Deduplicate papers per the configured [`DedupStrategy`], merging data from duplicates
     ///
     /// When a duplicate is found, its data is merged into the existing paper
     /// rather than being discarded. This preserves SS metrics on arXiv-sourced
-    /// papers and vice versa.
+    /// papers and vice versa. The record with more metadata is kept as the base
+    /// of the merge; a paper merged from different sources ends up with
+    /// `PaperSource::Both`.
     fn deduplicate_papers(&self, papers: Vec<AcademicPaper>) -> Vec<AcademicPaper> {
         let mut unique_papers: Vec<AcademicPaper> = Vec::new();
 
         for paper in papers {
-            let normalized_title = self.normalize_title(&paper.title);
-            let dup_index = unique_papers.iter().position(|p| {
-                let existing_normalized = self.normalize_title(&p.title);
-                self.titles_match(&normalized_title, &existing_normalized)
-            });
-
-            if let Some(idx) = dup_index {
-                unique_papers[idx].merge_with(paper);
+            if let Some(idx) = self.find_duplicate(&paper, &unique_papers) {
+                let unique = &mut unique_papers[idx];
+                if paper.metadata_score() > unique.metadata_score() {
+                    let existing = std::mem::replace(unique, paper);
+                    unique.merge_with(existing);
+                } else {
+                    unique.merge_with(paper);
+                }
             } else {
                 unique_papers.push(paper);
             }
         }
 
-        unique_papers
+        unique_papers
+    }
+
+    /// Index of the unique paper that `paper` duplicates, if any
+    fn find_duplicate(
+        &self,
+        paper: &AcademicPaper,
+        unique_papers: &[AcademicPaper],
+    ) -> Option<usize> {
+        let mut candidates = unique_papers.iter();
+        match self.dedup_strategy {
+            DedupStrategy::ExactTitle => candidates.position(|p| {
+                let title = self.normalize_title(&paper.title);
+                !title.is_empty()
+                    && title == self.normalize_title(&p.title)
+                    && Self::plausibly_same_work(paper, p)
+            }),
+            // Title match first; abstracts catch title drift between sources
+            DedupStrategy::FuzzyTitle(_) => candidates
+                .clone()
+                .position(|p| self.is_same_work(paper, p))
+                .or_else(|| candidates.position(|p| self.abstracts_match(paper, p))),
+            DedupStrategy::ByIdentifier => {
+                candidates.position(|p| Self::identifiers_match(paper, p))
+            }
+            DedupStrategy::None => None,
+        }
+    }
+
+    /// Title similarity threshold for [`titles_match`](Self::titles_match)
+    ///
+    /// Strategies without a threshold fall back to [`DEFAULT_DEDUP_THRESHOLD`].
+    fn title_threshold(&self) -> f64 {
+        match self.dedup_strategy {
+            DedupStrategy::FuzzyTitle(threshold) => threshold,
+            _ => DEFAULT_DEDUP_THRESHOLD,
+        }
+    }
+
+    /// Normalize title for comparison
+    fn normalize_title(&self, title: &str) -> String {
+        title
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Check if two titles refer to the same paper
+    ///
+    /// Titles are normalized first, then compared by normalized Levenshtein
+    /// similarity against the configured dedup threshold. A title that only
+    /// differs by a subtitle (e.g. "Title: Subtitle" vs "Title") also matches
+    /// as long as the main title is long enough to be distinctive.
+    fn titles_match(&self, title1: &str, title2: &str) -> bool {
+        let normalized1 = self.normalize_title(title1);
+        let normalized2 = self.normalize_title(title2);
+        if normalized1.is_empty() || normalized2.is_empty() {
+            return false;
+        }
+        if normalized1 == normalized2
+            || normalized_levenshtein(&normalized1, &normalized2) >= self.title_threshold()
+        {
+            return true;
+        }
+
+        let main1 = self.normalize_title(Self::main_title(title1));
+        let main2 = self.normalize_title(Self::main_title(title2));
+        main1 == main2 && main1.split_whitespace().count() >= MIN_SUBTITLE_MATCH_WORDS
+    }
+
+    /// Check if two papers are the same work
+    ///
+    /// Titles must match, and the papers must not look like distinct works that
+    /// happen to share a generic title (e.g. "Introduction"): see
+    /// [`plausibly_same_work`](Self::plausibly_same_work).
+    fn is_same_work(&self, paper1: &AcademicPaper, paper2: &AcademicPaper) -> bool {
+        self.titles_match(&paper1.title, &paper2.title) && Self::plausibly_same_work(paper1, paper2)
+    }
+
+    /// Check that two same-titled papers do not look like distinct works
+    ///
+    /// Papers with conflicting arXiv IDs or publisher DOIs are kept apart (e.g.
+    /// "…: Part I" and "…: Part II"), as are papers whose author lists share no
+    /// surname. When author data is missing on either side, papers published
+    /// more than a couple of years apart are kept apart too.
+    fn plausibly_same_work(paper1: &AcademicPaper, paper2: &AcademicPaper) -> bool {
+        if Self::identifiers_conflict(paper1, paper2) {
+            return false;
+        }
+        if !paper1.authors.is_empty() && !paper2.authors.is_empty() {
+            return Self::authors_overlap(paper1, paper2);
+        }
+
+        match (paper1.known_year(), paper2.known_year()) {
+            (Some(year1), Some(year2)) => (year1 - year2).abs() <= MAX_DUPLICATE_YEAR_GAP,
+            _ => true,
+        }
+    }
+
+    /// Check if two papers have near-identical abstracts
+    ///
+    /// Catches the same paper listed under slightly different titles (e.g. an
+    /// arXiv preprint and its journal version). Abstracts shorter than
+    /// [`MIN_ABSTRACT_DEDUP_WORDS`] never match, and neither do papers that
+    /// look like distinct works.
+    fn abstracts_match(&self, paper1: &AcademicPaper, paper2: &AcademicPaper) -> bool {
+        if !Self::plausibly_same_work(paper1, paper2) {
+            return false;
+        }
+        let abstract1 = self.normalize_title(&paper1.abstract_text);
+        let abstract2 = self.normalize_title(&paper2.abstract_text);
+        let long_enough = |text: &str| text.split_whitespace().count() >= MIN_ABSTRACT_DEDUP_WORDS;
+        if !long_enough(&abstract1) || !long_enough(&abstract2) {
+            return false;
+        }
+        if abstract1 == abstract2 {
+            return true;
+        }
+
+        // The edit distance is at least the length difference; skip the
+        // quadratic comparison when that alone rules out a match
+        let (len1, len2) = (abstract1.chars().count(), abstract2.chars().count());
+        let max_len = len1.max(len2) as f64;
+        if (len1.abs_diff(len2) as f64) / max_len > 1.0 - ABSTRACT_DEDUP_THRESHOLD {
+            return false;
+        }
+        normalized_levenshtein(&abstract1, &abstract2) >= ABSTRACT_DEDUP_THRESHOLD
     }
 
-    /// Normalize title for comparison
-    fn normalize_title(&self, title: &str) -> String {
-        title
-            .to_lowercase()
-            .chars()
-            .filter(|c| c.is_alphanumeric() || c.is_whitespace())
-            .collect::<String>()
-            .split_whitespace()
-            .collect::<Vec<&str>>()
-            .join(" ")
+    /// Check if two papers share a DOI, arXiv ID or Semantic Scholar ID
+    ///
+    /// DOIs are compared after [`normalize_doi`]; empty identifiers never match.
+    pub(crate) fn identifiers_match(paper1: &AcademicPaper, paper2: &AcademicPaper) -> bool {
+        let same = |id1: &str, id2: &str| !id1.is_empty() && id1.eq_ignore_ascii_case(id2);
+        let doi1 = normalize_doi(&paper1.doi);
+        (doi1.is_some() && doi1 == normalize_doi(&paper2.doi))
+            || same(&paper1.arxiv_id, &paper2.arxiv_id)
+            || same(&paper1.ss_id, &paper2.ss_id)
     }
 
-    /// Check if two normalized titles match
-    fn titles_match(&self, title1: &str, title2: &str) -> bool {
-        // Simple exact match after normalization
-        // Could be enhanced with fuzzy matching
-        title1 == title2
+    /// Check if two papers carry different arXiv IDs or publisher DOIs
+    ///
+    /// arXiv versions are ignored, and so are arXiv's own `10.48550/` DOIs,
+    /// which a preprint has alongside its journal version's DOI.
+    fn identifiers_conflict(paper1: &AcademicPaper, paper2: &AcademicPaper) -> bool {
+        let arxiv_id = |paper: &AcademicPaper| {
+            (!paper.arxiv_id.is_empty()).then(|| {
+                AcademicPaper::parse_arxiv_id(&paper.arxiv_id)
+                    .0
+                    .to_lowercase()
+            })
+        };
+        let publisher_doi = |paper: &AcademicPaper| {
+            normalize_doi(&paper.doi).filter(|doi| !doi.starts_with(ARXIV_DOI_PREFIX))
+        };
+        let differ = |id1: Option<String>, id2: Option<String>| matches!((id1, id2), (Some(id1), Some(id2)) if id1 != id2);
+        differ(arxiv_id(paper1), arxiv_id(paper2))
+            || differ(publisher_doi(paper1), publisher_doi(paper2))
+    }
+
+    /// Check if two papers share at least one author surname
+    fn authors_overlap(paper1: &AcademicPaper, paper2: &AcademicPaper) -> bool {
+        let surnames1: Vec<String> = paper1
+            .authors
+            .iter()
+            .filter_map(|a| Self::surname(&a.name))
+            .collect();
+        paper2
+            .authors
+            .iter()
+            .filter_map(|a| Self::surname(&a.name))
+            .any(|s| surnames1.contains(&s))
+    }
+
+    /// Extract a normalized surname from an author name
+    ///
+    /// Handles both "Given Family" and "Family, Given"; of a multi-word family
+    /// name only the last word is kept, so "van der Berg" matches "Berg".
+    fn surname(name: &str) -> Option<String> {
+        let (_, family) = split_author_name(name);
+        family.split_whitespace().last().map(|s| {
+            s.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+                .to_lowercase()
+        })
+    }
+
+    /// Strip a subtitle introduced by ':' or ' - ' from a raw title
+    fn main_title(title: &str) -> &str {
+        let end = [title.find(':'), title.find(" - ")]
+            .into_iter()
+            .flatten()
+            .min()
+            .unwrap_or(title.len());
+        &title[..end]
     }
 
     /// Find the best matching paper by title using Levenshtein distance
@@ -288,13 +1369,18 @@ impl PaperClient {
         papers: &[AcademicPaper],
         query: &str,
     ) -> Option<(usize, f64)> {
-        if papers.is_empty() {
-            return None;
-        }
+        self.rank_by_title(papers, query).into_iter().next()
+    }
 
+    /// Rank papers by title distance to `query`
+    ///
+    /// Returns `(index, distance)` pairs sorted by ascending normalized
+    /// Levenshtein distance (0.0 = exact match, 1.0 = completely different);
+    /// ties keep their original order.
+    pub fn rank_by_title(&self, papers: &[AcademicPaper], query: &str) -> Vec<(usize, f64)> {
         let normalized_query = self.normalize_title(query);
 
-        papers
+        let mut ranked: Vec<(usize, f64)> = papers
             .iter()
             .enumerate()
             .map(|(idx, paper)| {
@@ -305,19 +1391,41 @@ impl PaperClient {
                 let distance = 1.0 - similarity;
                 (idx, distance)
             })
-            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .collect();
+        ranked.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked
     }
 
-    /// Search papers by title and return the best match
+    /// Keep the `k` papers closest to `title` whose distance is within `threshold`
+    fn top_title_matches(
+        &self,
+        papers: Vec<AcademicPaper>,
+        title: &str,
+        threshold: f64,
+        k: usize,
+    ) -> Vec<(AcademicPaper, f64)> {
+        let ranked = self.rank_by_title(&papers, title);
+        let mut slots: Vec<Option<AcademicPaper>> = papers.into_iter().map(Some).collect();
+        ranked
+            .into_iter()
+            .take_while(|&(_, distance)| distance <= threshold)
+            .take(k)
+            .filter_map(|(idx, distance)| slots[idx].take().map(|paper| (paper, distance)))
+            .collect()
+    }
+
+    /// Search papers by title and return up to `k` ranked candidates
     ///
-    /// Searches both arXiv and Semantic Scholar (general search + exact title search),
-    /// then finds the best matching paper based on Levenshtein distance.
-    /// Returns an error if no paper matches within the threshold.
-    pub async fn search_by_title_fuzzy(
+    /// Searches both arXiv and Semantic Scholar (general search + exact title search)
+    /// and returns the papers whose title distance is within `threshold`, closest
+    /// first, paired with their distance. Returns an error only if the searches
+    /// found no papers at all; an empty list means nothing was close enough.
+    pub async fn search_title_candidates(
         &self,
         title: &str,
         threshold: f64,
-    ) -> AppResult<AcademicPaper> {
+        k: usize,
+    ) -> AppResult<Vec<(AcademicPaper, f64)>> {
         // Search using the title (general search + exact title search in parallel)
         let params = SearchParams::new()
             .with_title(title.to_string())
@@ -336,11 +1444,10 @@ impl PaperClient {
         // Add exact title match result from Semantic Scholar if available
         if let Ok(ss_paper) = exact_result {
             let exact_paper = AcademicPaper::from_semantic_scholar(ss_paper);
-            // Avoid duplicates: only add if no paper with the same title already exists
-            let normalized_exact = self.normalize_title(&exact_paper.title);
+            // Avoid duplicates: only add if no paper with a matching title already exists
             let already_exists = papers
                 .iter()
-                .any(|p| self.normalize_title(&p.title) == normalized_exact);
+                .any(|p| self.titles_match(&p.title, &exact_paper.title));
             if !already_exists {
                 papers.push(exact_paper);
             }
@@ -350,9 +1457,25 @@ impl PaperClient {
             return Err(AppError::PaperNotFound("No papers found".to_string()));
         }
 
-        // Find best match
-        let (idx, distance) = self
-            .find_best_match_by_title(&papers, title)
+        Ok(self.top_title_matches(papers, title, threshold, k))
+    }
+
+    /// Search papers by title and return the best match
+    ///
+    /// Searches both arXiv and Semantic Scholar (general search + exact title search),
+    /// then finds the best matching paper based on Levenshtein distance.
+    /// Returns an error if no paper matches within the threshold.
+    pub async fn search_by_title_fuzzy(
+        &self,
+        title: &str,
+        threshold: f64,
+    ) -> AppResult<AcademicPaper> {
+        // Rank without a cutoff so the error can report the best distance
+        let (paper, distance) = self
+            .search_title_candidates(title, 1.0, 1)
+            .await?
+            .into_iter()
+            .next()
             .ok_or_else(|| AppError::PaperNotFound("No papers found".to_string()))?;
 
         if distance > threshold {
@@ -362,13 +1485,111 @@ impl PaperClient {
             )));
         }
 
-        Ok(papers.into_iter().nth(idx).unwrap())
+        Ok(paper)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Author;
+    use crate::shared::test_server::serve_responses;
+
+    #[test]
+    fn test_collect_source_results_all_failed() {
+        let result = PaperClient::collect_source_results(vec![
+            (
+                PaperSource::ArXiv,
+                Err(AppError::ArxivError("connection refused".to_string())),
+            ),
+            (
+                PaperSource::SemanticScholar,
+                Err(AppError::SemanticScholarError("HTTP 403".to_string())),
+            ),
+        ]);
+
+        match result {
+            Err(AppError::AllSourcesFailed(errors)) => {
+                assert_eq!(errors.len(), 2);
+                assert!(errors[0].contains("ArXiv") && errors[0].contains("connection refused"));
+                assert!(errors[1].contains("SemanticScholar") && errors[1].contains("HTTP 403"));
+            }
+            _ => panic!("expected AllSourcesFailed"),
+        }
+    }
+
+    #[test]
+    fn test_collect_source_results_partial_failure() {
+        let mut paper = AcademicPaper::new();
+        paper.title = "Attention Is All You Need".to_string();
+
+        let result = PaperClient::collect_source_results(vec![
+            (
+                PaperSource::ArXiv,
+                Err(AppError::ArxivError("timeout".to_string())),
+            ),
+            (PaperSource::SemanticScholar, Ok(vec![paper])),
+        ])
+        .unwrap();
+        assert_eq!(result.papers.len(), 1);
+        assert_eq!(result.sources, vec![PaperSource::SemanticScholar]);
+
+        // Sources that answer with no matches are not failures
+        let empty = PaperClient::collect_source_results(vec![
+            (PaperSource::ArXiv, Ok(Vec::new())),
+            (
+                PaperSource::SemanticScholar,
+                Err(AppError::SemanticScholarError("HTTP 429".to_string())),
+            ),
+        ])
+        .unwrap();
+        assert!(empty.papers.is_empty());
+    }
+
+    #[test]
+    fn test_source_health_from_probe() {
+        assert_eq!(
+            SourceHealth::from_probe(Some(Ok(()))),
+            SourceHealth::Reachable
+        );
+        assert_eq!(SourceHealth::from_probe::<()>(None), SourceHealth::TimedOut);
+        assert!(matches!(
+            SourceHealth::from_probe::<()>(Some(Err(AppError::SemanticScholarError(
+                "Fetch details failed: HTTP 429 Too Many Requests".to_string()
+            )))),
+            SourceHealth::RateLimited(_)
+        ));
+        assert!(matches!(
+            SourceHealth::from_probe::<()>(Some(Err(AppError::ArxivError(
+                "connection refused".to_string()
+            )))),
+            SourceHealth::Unreachable(_)
+        ));
+    }
+
+    #[test]
+    fn test_split_total_counts_takes_largest_reported_total() {
+        let (results, total) = PaperClient::split_total_counts(vec![
+            (PaperSource::ArXiv, Ok((Vec::new(), Some(3421)))),
+            (PaperSource::SemanticScholar, Ok((Vec::new(), Some(12_800)))),
+            (
+                PaperSource::Dblp,
+                Err(AppError::DblpError("HTTP 503".to_string())),
+            ),
+        ]);
+        assert_eq!(total, Some(12_800));
+        assert_eq!(results.len(), 3);
+        assert!(results[2].1.is_err());
+
+        let (_, unknown) = PaperClient::split_total_counts(vec![
+            (PaperSource::ArXiv, Ok((Vec::new(), None))),
+            (
+                PaperSource::SemanticScholar,
+                Err(AppError::SemanticScholarError("timeout".to_string())),
+            ),
+        ]);
+        assert_eq!(unknown, None);
+    }
 
     #[test]
     fn test_normalize_title() {
@@ -378,6 +1599,26 @@ mod tests {
         assert_eq!(normalized, "attention is all you need");
     }
 
+    #[test]
+    fn test_related_query_prefers_title_then_abstract() {
+        let mut paper = AcademicPaper::new();
+        paper.title = "  Attention Is All You Need ".to_string();
+        paper.abstract_text = "The dominant sequence transduction models".to_string();
+        assert_eq!(
+            PaperClient::related_query(&paper).as_deref(),
+            Some("Attention Is All You Need")
+        );
+
+        paper.title.clear();
+        assert_eq!(
+            PaperClient::related_query(&paper).as_deref(),
+            Some("The dominant sequence transduction models")
+        );
+
+        paper.abstract_text.clear();
+        assert_eq!(PaperClient::related_query(&paper), None);
+    }
+
     #[test]
     fn test_titles_match() {
         let client = PaperClient::new();
@@ -385,6 +1626,263 @@ mod tests {
         assert!(!client.titles_match("attention is all you need", "bert pretraining"));
     }
 
+    #[test]
+    fn test_titles_match_punctuation_and_case_variants() {
+        let client = PaperClient::new();
+        assert!(client.titles_match("Attention is All You Need", "Attention Is All you Need."));
+        assert!(client.titles_match(
+            "BERT: Pre-training of Deep Bidirectional Transformers",
+            "BERT - Pre-training of Deep Bidirectional Transformers"
+        ));
+        assert!(client.titles_match(
+            "Deep Residual Learning for Image Recognition",
+            "Deep Residual Learning for Image Recognitions"
+        ));
+    }
+
+    #[test]
+    fn test_titles_match_subtitle_variants() {
+        let client = PaperClient::new();
+        assert!(client.titles_match(
+            "Language Models are Few-Shot Learners",
+            "Language Models are Few-Shot Learners: GPT-3"
+        ));
+        // Short main titles are not distinctive enough to ignore the subtitle
+        assert!(!client.titles_match(
+            "BERT: Pre-training of Deep Bidirectional Transformers",
+            "BERT: A Review"
+        ));
+    }
+
+    #[test]
+    fn test_titles_match_respects_threshold() {
+        let strict = PaperClient::new().with_dedup_threshold(1.0);
+        assert!(!strict.titles_match(
+            "Deep Residual Learning for Image Recognition",
+            "Deep Residual Learning for Image Recognitions"
+        ));
+
+        let loose = PaperClient::new().with_dedup_threshold(0.5);
+        assert!(loose.titles_match("Attention Is All You Need", "Attention Is What You Need"));
+    }
+
+    /// Records exercising each dedup strategy: an exact-title copy, a
+    /// near-identical title, a DOI-only match and an unrelated paper
+    fn dedup_strategy_input() -> Vec<AcademicPaper> {
+        let mut preprint = titled("Attention Is All You Need");
+        preprint.arxiv_id = "1706.03762".to_string();
+        let mut ss_copy = titled("Attention is all you need!");
+        ss_copy.ss_id = "ss123".to_string();
+        let mut typo = titled("Attention Is All You Needs");
+        typo.doi = "10.5555/3295222.3295349".to_string();
+        let mut proceedings = titled("Transformer: Attention-Only Sequence Transduction");
+        proceedings.doi = "https://doi.org/10.5555/3295222.3295349".to_string();
+        let unrelated = titled("Deep Residual Learning for Image Recognition");
+        vec![preprint, ss_copy, typo, proceedings, unrelated]
+    }
+
+    fn dedup_titles(strategy: DedupStrategy) -> Vec<String> {
+        PaperClient::new()
+            .with_dedup_strategy(strategy)
+            .deduplicate_papers(dedup_strategy_input())
+            .into_iter()
+            .map(|paper| paper.title)
+            .collect()
+    }
+
+    #[test]
+    fn test_dedup_strategy_exact_title() {
+        assert_eq!(
+            dedup_titles(DedupStrategy::ExactTitle),
+            [
+                "Attention Is All You Need",
+                "Attention Is All You Needs",
+                "Transformer: Attention-Only Sequence Transduction",
+                "Deep Residual Learning for Image Recognition",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dedup_strategy_fuzzy_title() {
+        assert_eq!(
+            dedup_titles(DedupStrategy::FuzzyTitle(DEFAULT_DEDUP_THRESHOLD)),
+            [
+                "Attention Is All You Need",
+                "Transformer: Attention-Only Sequence Transduction",
+                "Deep Residual Learning for Image Recognition",
+            ]
+        );
+        assert_eq!(
+            dedup_titles(DedupStrategy::FuzzyTitle(DEFAULT_DEDUP_THRESHOLD)),
+            dedup_titles(DedupStrategy::default())
+        );
+    }
+
+    #[test]
+    fn test_dedup_strategy_by_identifier() {
+        assert_eq!(
+            dedup_titles(DedupStrategy::ByIdentifier),
+            [
+                "Attention Is All You Need",
+                "Attention is all you need!",
+                "Attention Is All You Needs",
+                "Deep Residual Learning for Image Recognition",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dedup_strategy_none() {
+        assert_eq!(dedup_titles(DedupStrategy::None).len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_search_cache_hit_skips_sources() {
+        let client = PaperClient::new().with_search_cache(Duration::from_secs(60));
+        let params = SearchParams::new().with_query("attention is all you need");
+        let mut cached = SearchResult::new();
+        cached.papers.push(titled("Cached Paper"));
+        cached.sources.push(PaperSource::ArXiv);
+        client.search_cache.as_ref().unwrap().insert(
+            &CacheKey::new(&params, client.dedup_strategy, client.use_dblp),
+            &cached,
+        );
+
+        // A source call would fail offline or return different papers
+        let result = client
+            .search(SearchParams::new().with_query("  Attention Is All You Need "))
+            .await
+            .unwrap();
+        assert_eq!(result.papers.len(), 1);
+        assert_eq!(result.papers[0].title, "Cached Paper");
+    }
+
+    #[test]
+    fn test_deduplicate_keeps_richer_record() {
+        let client = PaperClient::new();
+
+        let mut sparse = AcademicPaper::new();
+        sparse.title = "Attention Is All You Need.".to_string();
+
+        let mut rich = AcademicPaper::new();
+        rich.title = "Attention is all you need".to_string();
+        rich.ss_id = "ss123".to_string();
+        rich.citations_count = 1000;
+
+        let result = client.deduplicate_papers(vec![sparse, rich]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].title, "Attention is all you need");
+        assert_eq!(result[0].ss_id, "ss123");
+        assert_eq!(result[0].citations_count, 1000);
+    }
+
+    fn titled(title: &str) -> AcademicPaper {
+        let mut paper = AcademicPaper::new();
+        paper.title = title.to_string();
+        paper
+    }
+
+    fn duplicate_title_result() -> SearchResult {
+        let mut ss_copy = titled("Attention is all you need");
+        ss_copy.ss_id = "ss123".to_string();
+        PaperClient::collect_source_results(vec![
+            (
+                PaperSource::ArXiv,
+                Ok(vec![
+                    titled("Attention Is All You Need."),
+                    titled("BERT: Pre-training of Deep Bidirectional Transformers"),
+                ]),
+            ),
+            (PaperSource::SemanticScholar, Ok(vec![ss_copy])),
+        ])
+        .unwrap()
+    }
+
+    fn sources(result: &SearchResult) -> Vec<Option<PaperSource>> {
+        result.papers.iter().map(|p| p.source).collect()
+    }
+
+    #[test]
+    fn test_refine_result_without_dedup_keeps_both_copies() {
+        let client = PaperClient::new();
+        let params = SearchParams::new()
+            .with_query("attention")
+            .with_deduplicate(false);
+
+        let result = client.refine_result(duplicate_title_result(), &params);
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            sources(&result),
+            vec![
+                Some(PaperSource::ArXiv),
+                Some(PaperSource::ArXiv),
+                Some(PaperSource::SemanticScholar)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_refine_result_dedup_attributes_merged_papers_to_both() {
+        let client = PaperClient::new();
+        let params = SearchParams::new().with_query("attention");
+        assert!(params.deduplicate);
+
+        let result = client.refine_result(duplicate_title_result(), &params);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.papers[0].ss_id, "ss123");
+        assert_eq!(
+            sources(&result),
+            vec![Some(PaperSource::Both), Some(PaperSource::ArXiv)]
+        );
+    }
+
+    #[test]
+    fn test_top_title_matches_ranks_by_distance() {
+        let client = PaperClient::new();
+        let papers = vec![
+            titled("BERT: Pre-training of Deep Bidirectional Transformers"),
+            titled("Attention Is All You Need"),
+            titled("Attention Is All You Need in Speech Separation"),
+            titled("Atention Is All You Need"),
+        ];
+
+        let matches = client.top_title_matches(papers, "Attention Is All You Need", 1.0, 3);
+        let titles: Vec<&str> = matches.iter().map(|(p, _)| p.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Attention Is All You Need",
+                "Atention Is All You Need",
+                "Attention Is All You Need in Speech Separation",
+            ]
+        );
+        assert!(matches.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert!(matches[0].1 < 0.01);
+    }
+
+    #[test]
+    fn test_top_title_matches_threshold_cutoff() {
+        let client = PaperClient::new();
+        let papers = vec![
+            titled("Attention Is All You Need"),
+            titled("Atention Is All You Need"),
+            titled("BERT: Pre-training of Deep Bidirectional Transformers"),
+        ];
+
+        let matches = client.top_title_matches(papers, "Attention Is All You Need", 0.1, 10);
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().all(|(_, distance)| *distance <= 0.1));
+
+        let none = client.top_title_matches(
+            vec![titled("Deep Residual Learning")],
+            "Attention Is All You Need",
+            0.1,
+            10,
+        );
+        assert!(none.is_empty());
+    }
+
     #[test]
     fn test_find_best_match_by_title() {
         let client = PaperClient::new();
@@ -416,6 +1914,597 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_deduplicate_keeps_same_title_different_works_separate() {
+        use chrono::{Local, TimeZone};
+
+        let client = PaperClient::new();
+
+        let mut paper1 = AcademicPaper::new();
+        paper1.title = "Introduction".to_string();
+        paper1.authors = vec![Author::from_arxiv_name("Alice Smith")];
+        paper1.published_date = Local.with_ymd_and_hms(2005, 3, 1, 0, 0, 0).unwrap();
+
+        let mut paper2 = AcademicPaper::new();
+        paper2.title = "Introduction".to_string();
+        paper2.authors = vec![Author::from_arxiv_name("Bob Jones")];
+        paper2.published_date = Local.with_ymd_and_hms(2019, 8, 1, 0, 0, 0).unwrap();
+
+        let result = client.deduplicate_papers(vec![paper1, paper2]);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].authors[0].name, "Alice Smith");
+        assert_eq!(result[1].authors[0].name, "Bob Jones");
+    }
+
+    #[test]
+    fn test_deduplicate_merges_same_title_with_shared_author() {
+        let client = PaperClient::new();
+
+        let mut paper1 = AcademicPaper::new();
+        paper1.title = "Attention Is All You Need".to_string();
+        paper1.authors = vec![Author::from_arxiv_name("Ashish Vaswani")];
+
+        let mut paper2 = AcademicPaper::new();
+        paper2.title = "Attention is all you need".to_string();
+        paper2.authors = vec![
+            Author::from_arxiv_name("A. Vaswani"),
+            Author::from_arxiv_name("N. Shazeer"),
+        ];
+
+        let result = client.deduplicate_papers(vec![paper1, paper2]);
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn test_deduplicate_keeps_distinct_arxiv_ids_apart() {
+        let mut part1 = titled("Convergence of Stochastic Gradient Methods: Part I");
+        part1.authors = vec![Author::from_arxiv_name("Alice Smith")];
+        part1.arxiv_id = "2101.00001".to_string();
+
+        let mut part2 = part1.clone();
+        part2.title = "Convergence of Stochastic Gradient Methods: Part II".to_string();
+        part2.arxiv_id = "2103.00002".to_string();
+
+        let client = PaperClient::new();
+        assert!(client.titles_match(&part1.title, &part2.title));
+        let result = client.deduplicate_papers(vec![part1.clone(), part2.clone()]);
+        assert_eq!(result.len(), 2);
+
+        // Same title, different arXiv IDs: kept apart by exact-title dedup too
+        part2.title = part1.title.clone();
+        let result = client
+            .with_dedup_strategy(DedupStrategy::ExactTitle)
+            .deduplicate_papers(vec![part1.clone(), part2]);
+        assert_eq!(result.len(), 2);
+
+        // Another version of the same preprint is still merged
+        let mut version2 = part1.clone();
+        version2.arxiv_id = "2101.00001v2".to_string();
+        assert_eq!(
+            PaperClient::new()
+                .deduplicate_papers(vec![part1, version2])
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_surname_handles_family_given_form() {
+        assert_eq!(
+            PaperClient::surname("LeCun, Yann").as_deref(),
+            Some("lecun")
+        );
+        assert_eq!(PaperClient::surname("Yann LeCun").as_deref(), Some("lecun"));
+        assert_eq!(
+            PaperClient::surname("van der Berg, Jan").as_deref(),
+            Some("berg")
+        );
+
+        let mut paper1 = AcademicPaper::new();
+        paper1.authors = vec![Author::from_arxiv_name("LeCun, Yann")];
+        let mut paper2 = AcademicPaper::new();
+        paper2.authors = vec![Author::from_arxiv_name("Yann LeCun")];
+        assert!(PaperClient::authors_overlap(&paper1, &paper2));
+
+        paper2.authors = vec![Author::from_arxiv_name("Yann Dauphin")];
+        assert!(!PaperClient::authors_overlap(&paper1, &paper2));
+    }
+
+    #[test]
+    fn test_deduplicate_merges_near_identical_abstracts() {
+        let client = PaperClient::new();
+        let abstract_text = "The dominant sequence transduction models are based on complex \
+            recurrent or convolutional neural networks that include an encoder and a decoder. \
+            We propose a new simple network architecture, the Transformer, based solely on \
+            attention mechanisms, dispensing with recurrence and convolutions entirely.";
+
+        let mut preprint = AcademicPaper::new();
+        preprint.title = "Attention Is All You Need".to_string();
+        preprint.abstract_text = abstract_text.to_string();
+        preprint.arxiv_id = "1706.03762".to_string();
+
+        let mut journal = AcademicPaper::new();
+        journal.title = "Transformers: Sequence Transduction Using Attention Only".to_string();
+        journal.abstract_text = abstract_text.replace("new simple", "novel simple");
+        journal.ss_id = "204e3073870fae3d05bcbc2f6a8e263d9b72e776".to_string();
+
+        let result = client.deduplicate_papers(vec![preprint, journal]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].arxiv_id, "1706.03762");
+        assert_eq!(result[0].ss_id, "204e3073870fae3d05bcbc2f6a8e263d9b72e776");
+
+        // Papers without abstracts are never merged on abstracts
+        let mut first = AcademicPaper::new();
+        first.title = "Graph Attention Networks".to_string();
+        let mut second = AcademicPaper::new();
+        second.title = "Deep Residual Learning".to_string();
+        assert_eq!(client.deduplicate_papers(vec![first, second]).len(), 2);
+    }
+
+    #[test]
+    fn test_deduplicate_keeps_placeholder_abstracts_apart() {
+        let client = PaperClient::new();
+        let mut first = titled("Graph Attention Networks");
+        first.abstract_text = "No abstract available.".to_string();
+        let mut second = titled("Deep Residual Learning");
+        second.abstract_text = "No abstract available".to_string();
+        assert_eq!(client.deduplicate_papers(vec![first, second]).len(), 2);
+    }
+
+    #[test]
+    fn test_abstract_match_requires_compatible_records() {
+        let client = PaperClient::new();
+        let abstract_text = "We study the problem of learning representations of graphs with             attention, propose a masked self-attention layer over node neighborhoods, and show             state of the art results on transductive and inductive benchmarks.";
+        let paper = |title: &str, arxiv_id: &str, doi: &str, author: &str| {
+            let mut paper = titled(title);
+            paper.abstract_text = abstract_text.to_string();
+            paper.arxiv_id = arxiv_id.to_string();
+            paper.doi = doi.to_string();
+            paper.authors = vec![Author::from_arxiv_name(author)];
+            paper
+        };
+
+        let preprint = paper(
+            "Graph Attention Networks",
+            "1710.10903v3",
+            "",
+            "Petar Velickovic",
+        );
+        let journal = paper(
+            "Attention on Graphs",
+            "1710.10903",
+            "10.1000/gat.2018",
+            "Petar Velickovic",
+        );
+        assert!(client.abstracts_match(&preprint, &journal));
+
+        let other_arxiv = paper("Attention on Graphs", "1801.00001", "", "Petar Velickovic");
+        assert!(!client.abstracts_match(&preprint, &other_arxiv));
+
+        let other_doi = paper(
+            "Attention on Graphs",
+            "",
+            "10.1000/other",
+            "Petar Velickovic",
+        );
+        assert!(!client.abstracts_match(&journal, &other_doi));
+
+        let other_authors = paper("Attention on Graphs", "", "", "Kaiming He");
+        assert!(!client.abstracts_match(&preprint, &other_authors));
+    }
+
+    #[test]
+    fn test_filter_by_min_citations() {
+        let make = |title: &str, ss_id: &str, citations: i32| {
+            let mut paper = AcademicPaper::new();
+            paper.title = title.to_string();
+            paper.ss_id = ss_id.to_string();
+            paper.citations_count = citations;
+            paper
+        };
+        let papers = vec![
+            make("Highly cited", "ss1", 500),
+            make("Exactly at threshold", "ss2", 100),
+            make("Rarely cited", "ss3", 3),
+            make("Known zero", "ss4", 0),
+            make("arXiv only", "", 0),
+        ];
+
+        let kept = |params: &SearchParams| -> Vec<String> {
+            papers
+                .iter()
+                .filter(|p| PaperClient::meets_min_citations(p, params))
+                .map(|p| p.title.clone())
+                .collect()
+        };
+
+        let params = SearchParams::new().with_min_citations(100);
+        assert_eq!(kept(&params), vec!["Highly cited", "Exactly at threshold"]);
+
+        let params = params.with_keep_unknown_citations(true);
+        assert_eq!(
+            kept(&params),
+            vec!["Highly cited", "Exactly at threshold", "arXiv only"]
+        );
+
+        // No filter configured: everything is kept
+        assert_eq!(kept(&SearchParams::new()).len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_crawl_citations_bfs_dedups_and_bounds_fan_out() {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        fn paper(id: &str) -> AcademicPaper {
+            let mut paper = AcademicPaper::new();
+            paper.ss_id = id.to_string();
+            paper.title = format!("Paper {}", id);
+            paper
+        }
+
+        // root <- a, b, c ; a <- d, b ; b <- d ; d <- e (beyond depth 2)
+        let cited_by: HashMap<&str, Vec<&str>> = HashMap::from([
+            ("root", vec!["a", "b", "c"]),
+            ("a", vec!["d", "b"]),
+            ("b", vec!["d"]),
+            ("c", vec![]),
+            ("d", vec!["e"]),
+        ]);
+        let calls = Mutex::new(Vec::new());
+        let fetch = |node: AcademicPaper| {
+            calls.lock().unwrap().push(node.ss_id.clone());
+            let citing: Vec<AcademicPaper> = cited_by
+                .get(node.ss_id.as_str())
+                .map(|ids| ids.iter().map(|id| paper(id)).collect())
+                .unwrap_or_default();
+            async move { Ok::<_, AppError>(citing) }
+        };
+
+        let root = CitationGraph::from_root(&paper("root")).unwrap();
+        let graph = PaperClient::crawl_citations(root, 2, 10, 4, fetch, |_| {})
+            .await
+            .unwrap();
+
+        let node_ids: Vec<&str> = graph.nodes.iter().map(|n| n.ss_id.as_str()).collect();
+        assert_eq!(node_ids, vec!["root", "a", "b", "c", "d"]);
+        let edge = |from: &str, to: &str| (from.to_string(), to.to_string());
+        assert_eq!(
+            graph.edges,
+            vec![
+                edge("a", "root"),
+                edge("b", "root"),
+                edge("c", "root"),
+                edge("d", "a"),
+                edge("b", "a"),
+                edge("d", "b"),
+            ]
+        );
+        // Each node is expanded once; level-2 papers are not expanded
+        assert_eq!(*calls.lock().unwrap(), vec!["root", "a", "b", "c"]);
+        assert!(graph.pending.is_empty());
+
+        // Fan-out bound and depth 1
+        let root = CitationGraph::from_root(&paper("root")).unwrap();
+        let graph = PaperClient::crawl_citations(
+            root,
+            1,
+            2,
+            4,
+            |node: AcademicPaper| {
+                let citing: Vec<AcademicPaper> = cited_by[node.ss_id.as_str()]
+                    .iter()
+                    .map(|id| paper(id))
+                    .collect();
+                async move { Ok(citing) }
+            },
+            |_| {},
+        )
+        .await
+        .unwrap();
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.edges.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_crawl_citations_skips_failed_nodes_below_root() {
+        let mut root = AcademicPaper::new();
+        root.ss_id = "root".to_string();
+
+        let graph = PaperClient::crawl_citations(
+            CitationGraph::from_root(&root).unwrap(),
+            2,
+            10,
+            1,
+            |node: AcademicPaper| async move {
+                match node.ss_id.as_str() {
+                    "root" => {
+                        let mut a = AcademicPaper::new();
+                        a.ss_id = "a".to_string();
+                        Ok(vec![a])
+                    }
+                    _ => Err(AppError::SemanticScholarError("rate limited".to_string())),
+                }
+            },
+            |_| {},
+        )
+        .await
+        .unwrap();
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.edges, vec![("a".to_string(), "root".to_string())]);
+        assert_eq!(graph.pending, vec![("a".to_string(), 1)]);
+
+        let failed = PaperClient::crawl_citations(
+            CitationGraph::from_root(&root).unwrap(),
+            2,
+            10,
+            1,
+            |_: AcademicPaper| async {
+                Err::<Vec<AcademicPaper>, _>(AppError::SemanticScholarError("down".to_string()))
+            },
+            |_| {},
+        )
+        .await;
+        assert!(failed.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_crawl_citations_resumes_pending_nodes() {
+        use std::sync::Mutex;
+
+        fn paper(id: &str) -> AcademicPaper {
+            let mut paper = AcademicPaper::new();
+            paper.ss_id = id.to_string();
+            paper
+        }
+
+        // First run: the lookup for "a" fails, so it stays pending
+        let mut checkpoints = Vec::new();
+        let graph = PaperClient::crawl_citations(
+            CitationGraph::from_root(&paper("root")).unwrap(),
+            2,
+            10,
+            1,
+            |node: AcademicPaper| async move {
+                match node.ss_id.as_str() {
+                    "root" => Ok(vec![paper("a"), paper("b")]),
+                    "b" => Ok(vec![paper("c")]),
+                    _ => Err(AppError::SemanticScholarError("rate limited".to_string())),
+                }
+            },
+            |graph: &CitationGraph| checkpoints.push(graph.pending.clone()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            checkpoints,
+            vec![
+                vec![("a".to_string(), 1), ("b".to_string(), 1)],
+                vec![("a".to_string(), 1)],
+            ]
+        );
+
+        // The saved graph round-trips and resuming only looks up "a"
+        let saved: CitationGraph =
+            serde_json::from_str(&serde_json::to_string(&graph).unwrap()).unwrap();
+        let calls = Mutex::new(Vec::new());
+        let resumed = PaperClient::crawl_citations(
+            saved,
+            2,
+            10,
+            1,
+            |node: AcademicPaper| {
+                calls.lock().unwrap().push(node.ss_id.clone());
+                async move { Ok(vec![paper("d"), paper("c")]) }
+            },
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(*calls.lock().unwrap(), vec!["a"]);
+        assert!(resumed.pending.is_empty());
+        let node_ids: Vec<&str> = resumed.nodes.iter().map(|n| n.ss_id.as_str()).collect();
+        assert_eq!(node_ids, vec!["root", "a", "b", "c", "d"]);
+        assert!(resumed.edges.contains(&("c".to_string(), "a".to_string())));
+        assert_eq!(resumed.edges.len(), 5);
+    }
+
+    // Counts calls and returns a fixed text instead of downloading a PDF
+    struct CountingExtractor(Arc<std::sync::atomic::AtomicUsize>);
+
+    #[async_trait]
+    impl PaperTextExtractor for CountingExtractor {
+        async fn extract(&self, _paper: &AcademicPaper) -> AppResult<PaperText> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(PaperText {
+                plain_text: "extracted".to_string(),
+                ..Default::default()
+            })
+        }
+    }
+
+    // Serve one CrossRef work record (untitled, so no enrichment lookups run)
+    async fn serve_crossref_work() -> String {
+        let (url, _server) =
+            serve_responses(vec![(200, r#"{"message": {"DOI": "10.1234/example"}}"#)]).await;
+        url
+    }
+
+    #[tokio::test]
+    async fn test_fetch_by_doi_runs_auto_extract() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut client = PaperClient::new().with_text_extractor(CountingExtractor(calls.clone()));
+        client.crossref = CrossRefClient::new().with_base_url(serve_crossref_work().await);
+
+        let paper = client.fetch_by_doi("10.1234/example").await.unwrap();
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(paper.extracted_text.unwrap().plain_text, "extracted");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_by_doi_skips_disabled_auto_extract() {
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut client = PaperClient::new()
+            .with_text_extractor(CountingExtractor(calls.clone()))
+            .with_auto_extract(false);
+        client.crossref = CrossRefClient::new().with_base_url(serve_crossref_work().await);
+
+        let paper = client.fetch_by_doi("10.1234/example").await.unwrap();
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert!(paper.extracted_text.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_bounded_preserves_order_and_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        let ids: Vec<PaperId> = (0..20)
+            .map(|i| {
+                if i % 5 == 4 {
+                    PaperId::Doi(format!("bad-{}", i))
+                } else {
+                    PaperId::Arxiv(format!("2401.{:05}", i))
+                }
+            })
+            .collect();
+
+        // Mock lookup: later IDs finish first, DOIs fail
+        let results = PaperClient::run_bounded(&ids, 3, |id| {
+            let in_flight = &in_flight;
+            let max_in_flight = &max_in_flight;
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+                let delay = match id {
+                    PaperId::Arxiv(s) => 40 - s[5..].parse::<u64>().unwrap() * 2,
+                    _ => 1,
+                };
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                match id {
+                    PaperId::Arxiv(arxiv_id) => {
+                        let mut paper = AcademicPaper::new();
+                        paper.arxiv_id = arxiv_id.clone();
+                        Ok(paper)
+                    }
+                    other => Err(AppError::PaperNotFound(other.to_string())),
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(results.len(), ids.len());
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 3);
+        for (id, result) in ids.iter().zip(&results) {
+            match (id, result) {
+                (PaperId::Arxiv(expected), Ok(paper)) => assert_eq!(&paper.arxiv_id, expected),
+                (PaperId::Doi(_), Err(AppError::PaperNotFound(msg))) => {
+                    assert!(msg.starts_with("DOI:"))
+                }
+                other => panic!("unexpected result pairing: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_filter_open_access() {
+        let make = |title: &str| {
+            let mut paper = AcademicPaper::new();
+            paper.title = title.to_string();
+            paper
+        };
+        let mut flagged = make("Flagged open access");
+        flagged.is_open_access = true;
+        let mut with_pdf = make("Has PDF URL");
+        with_pdf.open_access_pdf_url = Some("https://example.com/paper.pdf".to_string());
+        let mut arxiv = make("arXiv preprint");
+        arxiv.arxiv_id = "1706.03762".to_string();
+        let mut empty_url = make("Empty PDF URL");
+        empty_url.open_access_pdf_url = Some(String::new());
+        let closed = make("Paywalled");
+
+        let titles: Vec<String> = [flagged, with_pdf, arxiv, empty_url, closed]
+            .into_iter()
+            .filter(PaperClient::has_accessible_pdf)
+            .map(|p| p.title)
+            .collect();
+        assert_eq!(
+            titles,
+            vec!["Flagged open access", "Has PDF URL", "arXiv preprint"]
+        );
+    }
+
+    #[test]
+    fn test_filter_submitted_after_sorts_newest_first() {
+        use chrono::{Local, TimeZone};
+
+        let dated = |title: &str, year: i32, month: u32| {
+            let mut paper = AcademicPaper::new();
+            paper.title = title.to_string();
+            paper.published_date = Local.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap();
+            paper
+        };
+        let since = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let mut result = PaperClient::collect_source_results(vec![
+            (
+                PaperSource::ArXiv,
+                Ok(vec![
+                    dated("January", 2024, 1),
+                    dated("Last year", 2023, 12),
+                ]),
+            ),
+            (
+                PaperSource::SemanticScholar,
+                Ok(vec![dated("March", 2024, 3)]),
+            ),
+        ])
+        .unwrap();
+        result.retain(|p| p.published_date >= since);
+        result.sort_newest_first();
+
+        let titles: Vec<&str> = result.papers.iter().map(|p| p.title.as_str()).collect();
+        assert_eq!(titles, vec!["March", "January"]);
+        assert_eq!(
+            sources(&result),
+            vec![Some(PaperSource::SemanticScholar), Some(PaperSource::ArXiv)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_source_stream_yields_fastest_first() {
+        use futures::StreamExt;
+        use futures::future::Either;
+        use std::time::Duration;
+
+        let slow_arxiv = async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let mut paper = AcademicPaper::new();
+            paper.title = "From arXiv".to_string();
+            (PaperSource::ArXiv, Ok::<_, AppError>(vec![paper]))
+        };
+        let fast_ss = async {
+            let mut paper = AcademicPaper::new();
+            paper.title = "From Semantic Scholar".to_string();
+            (PaperSource::SemanticScholar, Ok::<_, AppError>(vec![paper]))
+        };
+
+        let results: Vec<SourceSearchResult> =
+            PaperClient::source_stream([Either::Left(slow_arxiv), Either::Right(fast_ss)])
+                .collect()
+                .await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, PaperSource::SemanticScholar);
+        assert_eq!(results[1].0, PaperSource::ArXiv);
+        let ss_papers = results[0].1.as_ref().unwrap();
+        assert_eq!(ss_papers[0].title, "From Semantic Scholar");
+    }
+
     #[test]
     fn test_deduplicate_merges_instead_of_dropping() {
         let client = PaperClient::new();
@@ -466,5 +2555,42 @@ mod tests {
         assert_eq!(merged.influential_citation_count, 8_000);
         assert_eq!(merged.references_count, 50);
         assert_eq!(merged.ss_id, "ss789");
+        assert_eq!(merged.source, Some(PaperSource::Both));
+    }
+
+    #[tokio::test]
+    async fn test_pdf_extractor_uses_http_config() {
+        let (url, server) = serve_responses(vec![(404, "")]).await;
+
+        let config = ClientConfig::new().with_user_agent("paper-test-agent/1.0");
+        let client = PaperClient::new().with_http_config(&config).unwrap();
+        let result = client
+            .pdf_extractor(ExtractionConfig::default())
+            .extract_from_url(&url)
+            .await;
+        assert!(result.is_err());
+        let requests = server.await.unwrap();
+        assert!(
+            requests[0]
+                .to_lowercase()
+                .contains("user-agent: paper-test-agent/1.0")
+        );
+    }
+
+    #[test]
+    fn test_with_http_config_proxy() {
+        let config = ClientConfig::new()
+            .with_proxy("http://proxy.example.com:8080")
+            .with_timeout(std::time::Duration::from_secs(10));
+        let client = PaperClient::new().with_http_config(&config);
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_is_author_id() {
+        assert!(PaperClient::is_author_id("1751762"));
+        assert!(!PaperClient::is_author_id("Yoshua Bengio"));
+        assert!(!PaperClient::is_author_id("1751762a"));
+        assert!(!PaperClient::is_author_id(""));
     }
 }
@@ -1,19 +1,148 @@
 //! Search parameters and result types for paper queries
 
-use crate::models::AcademicPaper;
+use super::arxiv::resolve_arxiv_category;
+use crate::models::{AcademicPaper, PaperSource, PublicationStatus};
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{DateTime, Local};
 use derive_new::new;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
 
-/// Source of paper data
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum PaperSource {
-    ArXiv,
-    SemanticScholar,
-    Both,
+/// New-style arXiv ID, e.g. "1706.03762" or "2301.00001v2"
+static ARXIV_NEW_ID_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{4}\.\d{4,5}(v\d+)?$").expect("valid arXiv regex"));
+
+/// Old-style arXiv ID, e.g. "cs.CL/0001001" or "hep-th/9901001v1"
+static ARXIV_OLD_ID_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[a-z][a-z\-]*(\.[A-Z]{2})?/\d{7}(v\d+)?$").expect("valid arXiv regex")
+});
+
+/// Semantic Scholar paper ID (40 hex characters)
+static SS_ID_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[0-9a-fA-F]{40}$").expect("valid SS ID regex"));
+
+/// Identifier for a direct paper lookup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaperId {
+    /// arXiv ID (e.g., "1706.03762")
+    Arxiv(String),
+    /// Semantic Scholar paper ID
+    Ss(String),
+    /// Digital Object Identifier (resolved via CrossRef)
+    Doi(String),
+}
+
+impl PaperId {
+    /// Detect the identifier type of a raw ID
+    ///
+    /// Recognizes DOIs ("10." prefix, optionally as a doi.org URL or with a
+    /// "doi:" prefix), new- and old-style arXiv IDs (optionally versioned, as
+    /// an abs URL or with an "arXiv:" prefix) and 40-character hex Semantic
+    /// Scholar IDs. An "ss:" prefix marks any Semantic Scholar ID. Returns
+    /// `None` for anything else.
+    pub fn detect(raw: &str) -> Option<Self> {
+        let id = raw.trim();
+
+        if let Some(ss_id) = strip_prefix_ignore_case(id, "ss:") {
+            let ss_id = ss_id.trim();
+            return (!ss_id.is_empty()).then(|| PaperId::Ss(ss_id.to_string()));
+        }
+
+        for prefix in ["https://doi.org/", "http://doi.org/", "doi:"] {
+            if let Some(doi) = strip_prefix_ignore_case(id, prefix) {
+                return Some(PaperId::Doi(doi.trim().to_string()));
+            }
+        }
+        if id.starts_with("10.") && id.contains('/') {
+            return Some(PaperId::Doi(id.to_string()));
+        }
+
+        let arxiv = strip_prefix_ignore_case(id, "arxiv:").unwrap_or_else(|| {
+            id.trim_start_matches("https://arxiv.org/abs/")
+                .trim_start_matches("http://arxiv.org/abs/")
+        });
+        if is_arxiv_id(arxiv) {
+            return Some(PaperId::Arxiv(arxiv.to_string()));
+        }
+
+        if SS_ID_PATTERN.is_match(id) {
+            return Some(PaperId::Ss(id.to_lowercase()));
+        }
+        None
+    }
+
+    /// Parse a list of identifiers, one per line
+    ///
+    /// Each line is read with [`detect`](Self::detect). Blank lines and lines
+    /// starting with `#` are skipped. Fails on the first unrecognized line,
+    /// reporting its (1-based) line number.
+    pub fn parse_list(content: &str) -> AppResult<Vec<Self>> {
+        content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(line_number, line)| {
+                Self::detect(line).ok_or_else(|| {
+                    AppError::InternalAppError(format!(
+                        "line {}: unrecognized paper identifier: {}",
+                        line_number, line
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+/// Whether `id` is a bare new- or old-style arXiv ID (optionally versioned)
+pub(crate) fn is_arxiv_id(id: &str) -> bool {
+    ARXIV_NEW_ID_PATTERN.is_match(id) || ARXIV_OLD_ID_PATTERN.is_match(id)
+}
+
+/// Strip an ASCII prefix, ignoring case
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    s.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &s[prefix.len()..])
+}
+
+/// Identifiers of one paper across sources (empty when unknown)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaperIds {
+    /// arXiv paper ID (without version)
+    pub arxiv_id: String,
+    /// Semantic Scholar paper ID
+    pub ss_id: String,
+    /// Digital Object Identifier
+    pub doi: String,
+}
+
+impl std::fmt::Display for PaperId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaperId::Arxiv(id) => write!(f, "arXiv:{}", id),
+            PaperId::Ss(id) => write!(f, "SS:{}", id),
+            PaperId::Doi(doi) => write!(f, "DOI:{}", doi),
+        }
+    }
+}
+
+/// Ordering applied to merged search results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortKey {
+    /// Keep the source order (arXiv results first, then Semantic Scholar)
+    #[default]
+    Relevance,
+    /// Publication date, newest first
+    Date,
+    /// Citation count, most cited first
+    Citations,
 }
 
 /// Unified search parameters for paper queries
-#[derive(Debug, Clone, Default, new)]
+#[derive(Debug, Clone, Default, Serialize, new)]
 pub struct SearchParams {
     /// Full-text query string
     #[new(default)]
@@ -39,10 +168,21 @@ pub struct SearchParams {
     #[new(default)]
     pub ss_id: Option<String>,
 
+    /// Fetch by DOI (resolved via CrossRef)
+    #[new(default)]
+    pub doi: Option<String>,
+
     /// Maximum number of results
     #[new(value = "10")]
     pub max_results: usize,
 
+    /// Number of results to skip in each source (for paging)
+    ///
+    /// Applied per source before merging, so deduplication only covers the
+    /// current page; a paper may reappear on a later page.
+    #[new(default)]
+    pub offset: usize,
+
     /// Filter by arXiv categories (e.g., "cs.AI", "cs.CL")
     #[new(default)]
     pub categories: Vec<String>,
@@ -51,9 +191,51 @@ pub struct SearchParams {
     #[new(default)]
     pub min_citations: Option<u32>,
 
+    /// Keep papers with unknown citation counts when `min_citations` is set
+    ///
+    /// Papers never matched on Semantic Scholar (e.g. arXiv-only results) have
+    /// no citation data. They are dropped by default.
+    #[new(default)]
+    pub keep_unknown_citations: bool,
+
+    /// Only return papers with an accessible PDF
+    ///
+    /// Papers with an arXiv ID count as open access (the arXiv PDF endpoint
+    /// is always available).
+    #[new(default)]
+    pub open_access_only: bool,
+
+    /// Only return papers with this publication status (see `AcademicPaper::publication_status`)
+    #[new(default)]
+    pub publication_status: Option<PublicationStatus>,
+
     /// Year filter (e.g., "2023" or "2020-2023")
     #[new(default)]
     pub year: Option<String>,
+
+    /// Only return papers submitted on or after this time, newest first
+    #[new(default)]
+    pub submitted_after: Option<DateTime<Local>>,
+
+    /// Merge duplicate papers across sources (default: true)
+    ///
+    /// When false, `PaperClient::search` returns the raw per-source results;
+    /// each paper's `source` tells where the copy came from.
+    #[new(value = "true")]
+    pub deduplicate: bool,
+
+    /// Ordering of the merged results (default: source order)
+    #[new(default)]
+    pub sort: SortKey,
+
+    /// Request total match counts from sources that do not report them with
+    /// their results (default: false)
+    ///
+    /// Semantic Scholar, arXiv category feeds and DBLP include the total in the
+    /// search response. arXiv keyword searches need one extra request, which
+    /// counts against the arXiv rate limit.
+    #[new(default)]
+    pub count_total: bool,
 }
 
 impl SearchParams {
@@ -82,8 +264,15 @@ impl SearchParams {
     }
 
     /// Set the arXiv ID for direct fetch
+    ///
+    /// Accepts abs URLs and versioned IDs; `"1706.03762v5"` fetches version 5,
+    /// an unversioned ID fetches the latest version.
     pub fn with_arxiv_id(mut self, id: impl Into<String>) -> Self {
-        self.arxiv_id = Some(id.into());
+        let (id, version) = AcademicPaper::parse_arxiv_id(&id.into());
+        self.arxiv_id = Some(match version {
+            Some(version) => format!("{}v{}", id, version),
+            None => id,
+        });
         self
     }
 
@@ -93,15 +282,36 @@ impl SearchParams {
         self
     }
 
+    /// Set the DOI for direct fetch
+    pub fn with_doi(mut self, doi: impl Into<String>) -> Self {
+        self.doi = Some(doi.into());
+        self
+    }
+
     /// Set the maximum number of results
     pub fn with_max_results(mut self, n: usize) -> Self {
         self.max_results = n;
         self
     }
 
+    /// Skip the first `offset` results of each source (see [`offset`](Self::offset))
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
     /// Add arXiv category filter
+    ///
+    /// Accepts a category code ("cs.CL") or a human-readable name ("natural
+    /// language processing"), resolved with [`resolve_arxiv_category`].
+    /// Unrecognized values are kept as given.
     pub fn with_category(mut self, category: impl Into<String>) -> Self {
-        self.categories.push(category.into());
+        let category = category.into();
+        let category = match resolve_arxiv_category(&category) {
+            Some(code) => code.to_string(),
+            None => category,
+        };
+        self.categories.push(category);
         self
     }
 
@@ -111,28 +321,128 @@ impl SearchParams {
         self
     }
 
+    /// Keep papers without citation data when filtering by `min_citations`
+    pub fn with_keep_unknown_citations(mut self, keep: bool) -> Self {
+        self.keep_unknown_citations = keep;
+        self
+    }
+
+    /// Only return open-access papers
+    pub fn with_open_access_only(mut self, open_access_only: bool) -> Self {
+        self.open_access_only = open_access_only;
+        self
+    }
+
+    /// Only return papers with the given publication status
+    pub fn with_publication_status(mut self, status: PublicationStatus) -> Self {
+        self.publication_status = Some(status);
+        self
+    }
+
     /// Set year filter
     pub fn with_year(mut self, year: impl Into<String>) -> Self {
         self.year = Some(year.into());
         self
     }
 
+    /// Only return papers submitted on or after `since` (sorted newest first)
+    pub fn with_submitted_after(mut self, since: DateTime<Local>) -> Self {
+        self.submitted_after = Some(since);
+        self
+    }
+
+    /// Enable or disable cross-source deduplication
+    pub fn with_deduplicate(mut self, deduplicate: bool) -> Self {
+        self.deduplicate = deduplicate;
+        self
+    }
+
+    /// Set the ordering of the merged results
+    pub fn with_sort(mut self, sort: SortKey) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Request total match counts from every source (see [`Self::count_total`])
+    pub fn with_count_total(mut self, count_total: bool) -> Self {
+        self.count_total = count_total;
+        self
+    }
+
     /// Check if this is a direct ID lookup
     pub fn is_id_lookup(&self) -> bool {
-        self.arxiv_id.is_some() || self.ss_id.is_some()
+        self.arxiv_id.is_some() || self.ss_id.is_some() || self.doi.is_some()
     }
 
     /// Check if any search criteria are set
+    ///
+    /// Categories count as a criterion on their own (see
+    /// [`is_category_listing`](Self::is_category_listing)).
     pub fn has_search_criteria(&self) -> bool {
+        self.has_text_criteria() || !self.categories.is_empty()
+    }
+
+    /// Check if any textual criteria (query, title, author, abstract) are set
+    pub fn has_text_criteria(&self) -> bool {
         self.query.is_some()
             || self.title.is_some()
             || self.author.is_some()
             || self.abstract_contains.is_some()
     }
+
+    /// Check if this is a category-only listing (e.g. the latest cs.CL submissions)
+    ///
+    /// Only arXiv supports these; results come newest first.
+    pub fn is_category_listing(&self) -> bool {
+        !self.categories.is_empty() && !self.has_text_criteria() && !self.is_id_lookup()
+    }
+
+    /// Check that the parameters describe a single, well-formed request
+    ///
+    /// Requires `max_results >= 1`, at most one ID (arXiv, Semantic Scholar
+    /// or DOI), no search criteria alongside an ID, and at least one
+    /// criterion when no ID is given.
+    pub fn validate(&self) -> AppResult<()> {
+        let invalid = |message: &str| Err(AppError::InternalAppError(message.to_string()));
+
+        if self.max_results == 0 {
+            return invalid("max_results must be at least 1");
+        }
+
+        let id_count = [
+            self.arxiv_id.is_some(),
+            self.ss_id.is_some(),
+            self.doi.is_some(),
+        ]
+        .into_iter()
+        .filter(|&set| set)
+        .count();
+        if id_count > 1 {
+            return invalid("arxiv_id, ss_id and doi are mutually exclusive; set only one");
+        }
+        if id_count == 1 && self.has_search_criteria() {
+            return invalid(
+                "an ID lookup cannot be combined with query, title, author, abstract or category criteria",
+            );
+        }
+        if id_count == 0 && !self.has_search_criteria() {
+            return invalid(
+                "no search criteria: set a query, title, author, abstract text, category or an ID",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validate and return the parameters (see [`validate`](Self::validate))
+    pub fn build(self) -> AppResult<Self> {
+        self.validate()?;
+        Ok(self)
+    }
 }
 
 /// Search result with papers and metadata
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SearchResult {
     /// Found papers
     pub papers: Vec<AcademicPaper>,
@@ -140,7 +450,12 @@ pub struct SearchResult {
     /// Sources that returned results
     pub sources: Vec<PaperSource>,
 
-    /// Total count (if available from API)
+    /// Total matches available across sources (largest per-source total), if known
+    ///
+    /// Counted before deduplication and client-side filters, so it is an
+    /// upper bound useful for paging rather than an exact figure. Only sources
+    /// that report a total with their results contribute, unless
+    /// [`SearchParams::count_total`] is set.
     pub total_count: Option<usize>,
 }
 
@@ -159,4 +474,239 @@ impl SearchResult {
     pub fn len(&self) -> usize {
         self.papers.len()
     }
+
+    /// Keep only papers matching `keep`
+    pub fn retain(&mut self, keep: impl FnMut(&AcademicPaper) -> bool) {
+        self.papers.retain(keep);
+    }
+
+    /// Sort papers by publication date, newest first
+    pub fn sort_newest_first(&mut self) {
+        self.papers
+            .sort_by(|a, b| b.published_date.cmp(&a.published_date));
+    }
+
+    /// Order papers by `key`
+    ///
+    /// Sorts are stable, so ties keep their source order; `Relevance`
+    /// leaves the order unchanged.
+    pub fn sort(&mut self, key: SortKey) {
+        match key {
+            SortKey::Relevance => {}
+            SortKey::Date => self.sort_newest_first(),
+            SortKey::Citations => self
+                .papers
+                .sort_by(|a, b| b.citations_count.cmp(&a.citations_count)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validation_message(params: SearchParams) -> String {
+        match params.build() {
+            Err(AppError::InternalAppError(message)) => message,
+            other => panic!("expected InternalAppError, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    fn sort_fixture() -> SearchResult {
+        let paper = |title: &str, date: &str, citations: i32, source: PaperSource| {
+            let mut paper = AcademicPaper::new();
+            paper.title = title.to_string();
+            paper.published_date = crate::shared::utils::datetime_from_str(date);
+            paper.citations_count = citations;
+            paper.source = Some(source);
+            paper
+        };
+        SearchResult {
+            papers: vec![
+                paper("A", "2019-05-01", 10, PaperSource::ArXiv),
+                paper("B", "2023-01-15", 500, PaperSource::ArXiv),
+                paper("C", "2021-07-30", 10, PaperSource::SemanticScholar),
+                paper("D", "2017-06-12", 90000, PaperSource::Both),
+            ],
+            ..SearchResult::default()
+        }
+    }
+
+    fn titles(result: &SearchResult) -> Vec<&str> {
+        result.papers.iter().map(|p| p.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_by_each_key() {
+        let mut result = sort_fixture();
+        result.sort(SortKey::Relevance);
+        assert_eq!(titles(&result), ["A", "B", "C", "D"]);
+
+        let mut result = sort_fixture();
+        result.sort(SortKey::Date);
+        assert_eq!(titles(&result), ["B", "C", "A", "D"]);
+        assert_eq!(result.papers[0].source, Some(PaperSource::ArXiv));
+        assert_eq!(result.papers[1].source, Some(PaperSource::SemanticScholar));
+
+        let mut result = sort_fixture();
+        result.sort(SortKey::Citations);
+        // Ties (A and C) keep their source order
+        assert_eq!(titles(&result), ["D", "B", "A", "C"]);
+        assert_eq!(result.papers[0].source, Some(PaperSource::Both));
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_params() {
+        assert!(
+            SearchParams::new()
+                .with_query("transformer")
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            SearchParams::new()
+                .with_arxiv_id("1706.03762")
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            SearchParams::new()
+                .with_doi("10.1145/3065386")
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_max_results() {
+        let message =
+            validation_message(SearchParams::new().with_query("bert").with_max_results(0));
+        assert!(message.contains("max_results"));
+    }
+
+    #[test]
+    fn test_validate_rejects_multiple_ids() {
+        let message = validation_message(
+            SearchParams::new()
+                .with_arxiv_id("1706.03762")
+                .with_ss_id("204e3073870fae3d05bcbc2f6a8e263d9b72e776"),
+        );
+        assert!(message.contains("mutually exclusive"));
+    }
+
+    #[test]
+    fn test_validate_rejects_id_with_query() {
+        let message = validation_message(
+            SearchParams::new()
+                .with_arxiv_id("1706.03762")
+                .with_query("attention"),
+        );
+        assert!(message.contains("cannot be combined"));
+    }
+
+    #[test]
+    fn test_validate_rejects_missing_criteria() {
+        let message = validation_message(SearchParams::new().with_max_results(50));
+        assert!(message.contains("no search criteria"));
+    }
+
+    #[test]
+    fn test_category_only_is_listing() {
+        let params = SearchParams::new().with_category("cs.CL");
+        assert!(params.validate().is_ok());
+        assert!(params.is_category_listing());
+        assert!(
+            !params
+                .clone()
+                .with_query("transformer".to_string())
+                .is_category_listing()
+        );
+    }
+
+    #[test]
+    fn test_detect_arxiv_ids() {
+        assert_eq!(
+            PaperId::detect("1706.03762"),
+            Some(PaperId::Arxiv("1706.03762".to_string()))
+        );
+        assert_eq!(
+            PaperId::detect("arXiv:2301.00001v2"),
+            Some(PaperId::Arxiv("2301.00001v2".to_string()))
+        );
+        assert_eq!(
+            PaperId::detect("https://arxiv.org/abs/1706.03762v7"),
+            Some(PaperId::Arxiv("1706.03762v7".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_detect_old_style_arxiv_ids() {
+        assert_eq!(
+            PaperId::detect("cs.CL/0001001"),
+            Some(PaperId::Arxiv("cs.CL/0001001".to_string()))
+        );
+        assert_eq!(
+            PaperId::detect("hep-th/9901001v1"),
+            Some(PaperId::Arxiv("hep-th/9901001v1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_detect_dois() {
+        assert_eq!(
+            PaperId::detect("10.1145/3065386"),
+            Some(PaperId::Doi("10.1145/3065386".to_string()))
+        );
+        assert_eq!(
+            PaperId::detect("https://doi.org/10.48550/arXiv.1706.03762"),
+            Some(PaperId::Doi("10.48550/arXiv.1706.03762".to_string()))
+        );
+        assert_eq!(
+            PaperId::detect("DOI:10.1145/3065386"),
+            Some(PaperId::Doi("10.1145/3065386".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_detect_ss_ids_and_unknown() {
+        assert_eq!(
+            PaperId::detect("204e3073870fae3d05bcbc2f6a8e263d9b72e776"),
+            Some(PaperId::Ss(
+                "204e3073870fae3d05bcbc2f6a8e263d9b72e776".to_string()
+            ))
+        );
+        assert_eq!(PaperId::detect("204e3073"), None);
+        assert_eq!(PaperId::detect("Attention Is All You Need"), None);
+        assert_eq!(PaperId::detect(""), None);
+
+        assert_eq!(
+            PaperId::detect("ss: CorpusId:13756489"),
+            Some(PaperId::Ss("CorpusId:13756489".to_string()))
+        );
+        assert_eq!(PaperId::detect("ss:"), None);
+    }
+
+    #[test]
+    fn test_parse_id_list() {
+        let content = "# papers to analyze\n\
+            arxiv:1706.03762\n\
+            \n\
+            \t1810.04805v2  \n\
+            doi:10.1145/3065386\n\
+            \n\
+            SS:204e3073870fae3d05bcbc2f6a8e263d9b72e776\n";
+        assert_eq!(
+            PaperId::parse_list(content).unwrap(),
+            vec![
+                PaperId::Arxiv("1706.03762".to_string()),
+                PaperId::Arxiv("1810.04805v2".to_string()),
+                PaperId::Doi("10.1145/3065386".to_string()),
+                PaperId::Ss("204e3073870fae3d05bcbc2f6a8e263d9b72e776".to_string()),
+            ]
+        );
+        assert!(PaperId::parse_list("\n  \n").unwrap().is_empty());
+
+        let error = PaperId::parse_list("1706.03762\n\nnot an id\n").unwrap_err();
+        assert!(error.to_string().contains("line 3"));
+    }
 }
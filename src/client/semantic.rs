@@ -1,16 +1,119 @@
 //! Semantic Scholar API client wrapper
 
+use crate::models::{AcademicPaper, Author};
 use crate::shared::errors::{AppError, AppResult};
+use chrono::Datelike;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use ss_tools::structs::{AuthorField, Paper as SsPaper, PaperField};
 use ss_tools::{QueryParams as SsQueryParams, SemanticScholar};
 
 use super::search::SearchParams;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// Maximum page size accepted by the citations/references endpoints
+pub const MAX_CITATION_PAGE_SIZE: usize = 1000;
+
+/// Maximum page size accepted by the author papers endpoint
+pub const MAX_AUTHOR_PAPERS_PAGE_SIZE: usize = 1000;
+
+/// Default number of Semantic Scholar requests allowed in flight at once
+///
+/// Kept low so parallel citation/reference fetches stay under the public
+/// rate limit.
+pub const DEFAULT_MAX_CONCURRENT_SS_REQUESTS: usize = 2;
+
+/// Maximum number of papers returned by the recommendations endpoint
+pub const MAX_RECOMMENDATIONS: usize = 500;
+
+/// Environment variable holding the Semantic Scholar API key
+pub const SS_API_KEY_ENV: &str = "SEMANTIC_SCHOLAR_API_KEY";
+
+/// Header carrying the API key on Semantic Scholar requests
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// Semantic Scholar Academic Graph API base URL (paper search and author endpoints)
+const GRAPH_API_BASE: &str = "https://api.semanticscholar.org/graph/v1";
+
+/// Semantic Scholar Recommendations API base URL (single-paper seed)
+const RECOMMENDATIONS_API_BASE: &str =
+    "https://api.semanticscholar.org/recommendations/v1/papers/forpaper";
+
+/// Author fields requested from the author endpoints
+const AUTHOR_FIELDS: &str = "authorId,name,affiliations,paperCount,citationCount,hIndex";
+
+/// Paper fields requested from the paper search endpoint
+const SEARCH_PAPER_FIELDS: &str = "paperId,title,abstract,url,venue,year,referenceCount,\
+citationCount,influentialCitationCount,isOpenAccess,openAccessPdf,publicationDate,journal,\
+citationStyles,externalIds,authors.authorId,authors.name,authors.affiliations,\
+authors.paperCount,authors.citationCount,authors.hIndex";
+
+/// Paper fields requested from the author papers and recommendations endpoints
+const AUTHOR_PAPER_FIELDS: &str = "paperId,title,abstract,url,venue,year,referenceCount,\
+citationCount,influentialCitationCount,isOpenAccess,openAccessPdf,publicationDate,journal,\
+citationStyles,externalIds,authors";
 
 /// Client for Semantic Scholar API operations
+#[derive(Clone)]
 pub struct SemanticScholarClient {
     client: SemanticScholar,
+    http_client: reqwest::Client,
+    graph_api_base: String,
+    api_key: Option<String>,
     retry_count: u64,
     wait_time: u64,
+    limiter: Arc<Semaphore>,
+}
+
+/// Author record as returned by the author endpoints
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthorRecord {
+    author_id: Option<String>,
+    name: Option<String>,
+    affiliations: Option<Vec<String>>,
+    paper_count: Option<u64>,
+    citation_count: Option<u64>,
+    h_index: Option<u64>,
+}
+
+impl From<AuthorRecord> for Author {
+    fn from(record: AuthorRecord) -> Self {
+        Self {
+            ss_id: record.author_id.unwrap_or_default(),
+            name: record.name.unwrap_or_default(),
+            h_index: record.h_index.unwrap_or(0) as i32,
+            affiliations: record.affiliations.unwrap_or_default(),
+            paper_count: record.paper_count.unwrap_or(0) as i32,
+            citation_count: record.citation_count.unwrap_or(0) as i32,
+        }
+    }
+}
+
+/// `{"data": [...]}` envelope used by the list endpoints
+#[derive(Debug, Deserialize)]
+struct DataPage<T> {
+    #[serde(default)]
+    data: Vec<T>,
+}
+
+/// Page of the paper search endpoint (`{"total": N, "data": [...]}`)
+#[derive(Debug, Deserialize)]
+struct SearchPage {
+    #[serde(default)]
+    total: usize,
+    #[serde(default)]
+    data: Vec<SsPaper>,
+}
+
+/// `{"recommendedPapers": [...]}` envelope used by the recommendations endpoint
+#[derive(Debug, Deserialize)]
+struct RecommendationsPage {
+    #[serde(rename = "recommendedPapers", default)]
+    recommended_papers: Vec<SsPaper>,
 }
 
 impl Default for SemanticScholarClient {
@@ -21,14 +124,49 @@ impl Default for SemanticScholarClient {
 
 impl SemanticScholarClient {
     /// Create a new Semantic Scholar client
+    ///
+    /// Uses the API key from `SEMANTIC_SCHOLAR_API_KEY` when it is set.
     pub fn new() -> Self {
-        Self {
+        let client = Self {
             client: SemanticScholar::new(),
+            http_client: reqwest::Client::new(),
+            graph_api_base: GRAPH_API_BASE.to_string(),
+            api_key: None,
             retry_count: 3,
             wait_time: 5,
+            limiter: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_SS_REQUESTS)),
+        };
+        match std::env::var(SS_API_KEY_ENV) {
+            Ok(key) if !key.trim().is_empty() => client.with_api_key(key),
+            _ => client,
         }
     }
 
+    /// Authenticate requests with an API key for higher rate limits
+    ///
+    /// The key is sent as the `x-api-key` header by both the `ss-tools` client
+    /// and the search and author requests this client sends itself.
+    pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
+        let key = key.into();
+        self.client.api_key = key.clone();
+        self.api_key = Some(key);
+        self
+    }
+
+    /// API key in use, if any
+    pub fn api_key(&self) -> Option<&str> {
+        self.api_key.as_deref()
+    }
+
+    /// Use a preconfigured HTTP client for paper search and the author endpoints
+    ///
+    /// Paper details, citations and references go through `ss-tools`, which
+    /// manages its own client.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
     /// Set retry configuration
     pub fn with_retry_config(mut self, retry_count: u64, wait_time: u64) -> Self {
         self.retry_count = retry_count;
@@ -36,6 +174,23 @@ impl SemanticScholarClient {
         self
     }
 
+    /// Limit how many requests may be in flight at once
+    ///
+    /// Every API call made through this client (search, details, citations,
+    /// references, author and recommendation endpoints) waits for a permit.
+    pub fn with_max_concurrent_requests(mut self, max_concurrent: usize) -> Self {
+        self.limiter = Arc::new(Semaphore::new(max_concurrent.max(1)));
+        self
+    }
+
+    /// Run a request once a concurrency permit is available
+    async fn limited<T>(&self, request: impl Future<Output = T>) -> T {
+        // The semaphore is never closed, so acquiring cannot fail; the permit
+        // is held until the request completes
+        let _permit = self.limiter.acquire().await;
+        request.await
+    }
+
     /// Default paper fields to request from the Semantic Scholar API.
     ///
     /// Includes all commonly used fields plus ExternalIds for cross-referencing
@@ -70,27 +225,45 @@ impl SemanticScholarClient {
 
     /// Search papers by title or query
     pub async fn search(&self, params: &SearchParams) -> AppResult<Vec<SsPaper>> {
-        let query_text = self.build_query_text(params)?;
-        let mut query_params = SsQueryParams::default();
-        query_params.query_text(&query_text);
-        query_params.fields(Self::default_paper_fields());
-        query_params.limit(params.max_results as u64);
+        let (papers, _) = self.search_with_total(params).await?;
+        Ok(papers)
+    }
 
+    /// Like [`search`](Self::search), also returning the total match count
+    /// from the same response
+    pub async fn search_with_total(
+        &self,
+        params: &SearchParams,
+    ) -> AppResult<(Vec<SsPaper>, Option<usize>)> {
+        let url = format!("{}/paper/search", self.graph_api_base);
+        let page: SearchPage = self
+            .get_json(&url, &self.search_query(params)?, "Search failed")
+            .await?;
+        Ok((page.data, Some(page.total)))
+    }
+
+    /// Query parameters of the paper search endpoint for `params`
+    fn search_query(&self, params: &SearchParams) -> AppResult<Vec<(&'static str, String)>> {
+        let (offset, limit) = Self::result_window(params);
+        let mut query = vec![
+            ("query", self.build_query_text(params)?),
+            ("fields", SEARCH_PAPER_FIELDS.to_string()),
+            ("offset", offset.to_string()),
+            ("limit", limit.to_string()),
+        ];
         if let Some(ref year) = params.year {
-            query_params.year(year);
+            query.push(("year", year.clone()));
+        } else if let Some(since) = params.submitted_after {
+            // The closest supported filter; `PaperClient::search` trims to the exact date
+            query.push(("year", format!("{}-", since.year())));
         }
-
         if let Some(min_citations) = params.min_citations {
-            query_params.min_citation_count(min_citations);
+            query.push(("minCitationCount", min_citations.to_string()));
         }
-
-        let mut client = self.client.clone();
-        let papers = client
-            .query_papers_by_title(query_params, self.retry_count, self.wait_time)
-            .await
-            .map_err(|e| AppError::SemanticScholarError(format!("Search failed: {}", e)))?;
-
-        Ok(papers)
+        if params.open_access_only {
+            query.push(("openAccessPdf", String::new()));
+        }
+        Ok(query)
     }
 
     /// Search for a single paper by exact title match
@@ -100,8 +273,8 @@ impl SemanticScholarClient {
         query_params.fields(Self::default_paper_fields());
 
         let mut client = self.client.clone();
-        let paper = client
-            .query_a_paper_by_title(query_params, self.retry_count, self.wait_time)
+        let paper = self
+            .limited(client.query_a_paper_by_title(query_params, self.retry_count, self.wait_time))
             .await
             .map_err(|e| {
                 AppError::SemanticScholarError(format!("Exact title search failed: {}", e))
@@ -117,8 +290,8 @@ impl SemanticScholarClient {
         query_params.fields(Self::default_paper_fields());
 
         let mut client = self.client.clone();
-        let paper = client
-            .query_paper_details(query_params, self.retry_count, self.wait_time)
+        let paper = self
+            .limited(client.query_paper_details(query_params, self.retry_count, self.wait_time))
             .await
             .map_err(|e| AppError::SemanticScholarError(format!("Fetch details failed: {}", e)))?;
 
@@ -126,49 +299,272 @@ impl SemanticScholarClient {
     }
 
     /// Fetch papers that cite the given paper
-    pub async fn fetch_citations(&self, paper_id: &str) -> AppResult<Vec<SsPaper>> {
-        let mut query_params = SsQueryParams::default();
-        query_params.paper_id(paper_id);
+    ///
+    /// With `limit: None` a single page of the API's default size is
+    /// returned; with `Some(limit)` result pages are followed until `limit`
+    /// papers are collected or the citations run out.
+    pub async fn fetch_citations(
+        &self,
+        paper_id: &str,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<SsPaper>> {
+        match limit {
+            None => self.citations_page(paper_id, None).await,
+            Some(limit) => {
+                paginate(limit, MAX_CITATION_PAGE_SIZE, |offset, page_limit| {
+                    self.citations_page(paper_id, Some((offset, page_limit)))
+                })
+                .await
+            }
+        }
+    }
+
+    /// Fetch papers referenced by the given paper
+    ///
+    /// `limit` works as in [`Self::fetch_citations`].
+    pub async fn fetch_references(
+        &self,
+        paper_id: &str,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<SsPaper>> {
+        match limit {
+            None => self.references_page(paper_id, None).await,
+            Some(limit) => {
+                paginate(limit, MAX_CITATION_PAGE_SIZE, |offset, page_limit| {
+                    self.references_page(paper_id, Some((offset, page_limit)))
+                })
+                .await
+            }
+        }
+    }
 
+    /// Fetch one page of citing papers, at `(offset, limit)` when given
+    async fn citations_page(
+        &self,
+        paper_id: &str,
+        page: Option<(usize, usize)>,
+    ) -> AppResult<Vec<SsPaper>> {
         let mut client = self.client.clone();
-        let response = client
-            .query_paper_citations(query_params, self.retry_count, self.wait_time)
+        let response = self
+            .limited(client.query_paper_citations(
+                Self::page_params(paper_id, page),
+                self.retry_count,
+                self.wait_time,
+            ))
             .await
             .map_err(|e| {
                 AppError::SemanticScholarError(format!("Fetch citations failed: {}", e))
             })?;
 
         // Extract papers from response data
-        let papers = response
+        Ok(response
             .data
             .into_iter()
             .filter_map(|rd| rd.citing_paper)
-            .collect();
-
-        Ok(papers)
+            .collect())
     }
 
-    /// Fetch papers referenced by the given paper
-    pub async fn fetch_references(&self, paper_id: &str) -> AppResult<Vec<SsPaper>> {
-        let mut query_params = SsQueryParams::default();
-        query_params.paper_id(paper_id);
-
+    /// Fetch one page of referenced papers, at `(offset, limit)` when given
+    async fn references_page(
+        &self,
+        paper_id: &str,
+        page: Option<(usize, usize)>,
+    ) -> AppResult<Vec<SsPaper>> {
         let mut client = self.client.clone();
-        let response = client
-            .query_paper_references(query_params, self.retry_count, self.wait_time)
+        let response = self
+            .limited(client.query_paper_references(
+                Self::page_params(paper_id, page),
+                self.retry_count,
+                self.wait_time,
+            ))
             .await
             .map_err(|e| {
                 AppError::SemanticScholarError(format!("Fetch references failed: {}", e))
             })?;
 
         // Extract papers from response data
-        let papers = response
+        Ok(response
             .data
             .into_iter()
             .filter_map(|rd| rd.citing_paper)
-            .collect();
+            .collect())
+    }
 
-        Ok(papers)
+    /// Query parameters for one citations/references page
+    fn page_params(paper_id: &str, page: Option<(usize, usize)>) -> SsQueryParams {
+        let mut query_params = SsQueryParams::default();
+        query_params.paper_id(paper_id);
+        if let Some((offset, limit)) = page {
+            query_params.offset(offset as u64);
+            query_params.limit(limit as u64);
+        }
+        query_params
+    }
+
+    /// Search authors by name
+    ///
+    /// Returns the raw candidates in API order; use
+    /// [`rank_author_candidates`] to pick the most likely match.
+    pub async fn search_authors(&self, name: &str, limit: usize) -> AppResult<Vec<Author>> {
+        let url = format!("{}/author/search", self.graph_api_base);
+        let page: DataPage<AuthorRecord> = self
+            .get_json(
+                &url,
+                &[
+                    ("query", name.to_string()),
+                    ("fields", AUTHOR_FIELDS.to_string()),
+                    ("limit", limit.to_string()),
+                ],
+                "Author search failed",
+            )
+            .await?;
+        Ok(page.data.into_iter().map(Author::from).collect())
+    }
+
+    /// Fetch an author profile by Semantic Scholar author ID
+    pub async fn fetch_author(&self, author_id: &str) -> AppResult<Author> {
+        let url = format!("{}/author/{}", self.graph_api_base, author_id);
+        let record: AuthorRecord = self
+            .get_json(
+                &url,
+                &[("fields", AUTHOR_FIELDS.to_string())],
+                "Fetch author failed",
+            )
+            .await?;
+        Ok(record.into())
+    }
+
+    /// Fetch up to `limit` papers written by the given author
+    pub async fn fetch_author_papers(
+        &self,
+        author_id: &str,
+        limit: usize,
+    ) -> AppResult<Vec<AcademicPaper>> {
+        let url = format!("{}/author/{}/papers", self.graph_api_base, author_id);
+        let papers = paginate(limit, MAX_AUTHOR_PAPERS_PAGE_SIZE, |offset, page_limit| {
+            let url = url.clone();
+            async move {
+                let page: DataPage<SsPaper> = self
+                    .get_json(
+                        &url,
+                        &[
+                            ("fields", AUTHOR_PAPER_FIELDS.to_string()),
+                            ("offset", offset.to_string()),
+                            ("limit", page_limit.to_string()),
+                        ],
+                        "Fetch author papers failed",
+                    )
+                    .await?;
+                Ok(page.data)
+            }
+        })
+        .await?;
+
+        Ok(papers
+            .into_iter()
+            .map(AcademicPaper::from_semantic_scholar)
+            .collect())
+    }
+
+    /// Fetch up to `limit` papers recommended for the given paper
+    ///
+    /// `paper_id` is anything the recommendations endpoint accepts as a seed:
+    /// a Semantic Scholar paper ID or a prefixed external ID such as
+    /// `arXiv:1706.03762`. Results are returned in the API's relevance order.
+    pub async fn fetch_recommendations(
+        &self,
+        paper_id: &str,
+        limit: usize,
+    ) -> AppResult<Vec<AcademicPaper>> {
+        let url = format!("{}/{}", RECOMMENDATIONS_API_BASE, paper_id);
+        let page: RecommendationsPage = self
+            .get_json(
+                &url,
+                &[
+                    ("fields", AUTHOR_PAPER_FIELDS.to_string()),
+                    ("limit", limit.min(MAX_RECOMMENDATIONS).to_string()),
+                ],
+                "Fetch recommendations failed",
+            )
+            .await?;
+
+        Ok(page
+            .recommended_papers
+            .into_iter()
+            .map(AcademicPaper::from_semantic_scholar)
+            .collect())
+    }
+
+    /// GET a Graph API endpoint and decode the JSON body
+    ///
+    /// Like the `ss-tools` calls, makes up to `retry_count` attempts: network
+    /// errors, 429s and 5xx responses are retried after `wait_time` seconds,
+    /// doubling the wait after each failure.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, String)],
+        context: &str,
+    ) -> AppResult<T> {
+        let attempts = self.retry_count.max(1);
+        let mut wait = Duration::from_secs(self.wait_time);
+        let mut last_error = String::new();
+
+        for attempt in 1..=attempts {
+            let mut request = self.http_client.get(url).query(query);
+            if let Some(key) = &self.api_key {
+                request = request.header(API_KEY_HEADER, key);
+            }
+            match self.limited(request.send()).await {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() {
+                        return response.json::<T>().await.map_err(|e| {
+                            AppError::SemanticScholarError(format!("{}: {}", context, e))
+                        });
+                    }
+                    if status == reqwest::StatusCode::NOT_FOUND {
+                        return Err(AppError::SemanticScholarError(format!(
+                            "{}: not found ({})",
+                            context, url
+                        )));
+                    }
+                    if !(status.is_server_error()
+                        || status == reqwest::StatusCode::TOO_MANY_REQUESTS)
+                    {
+                        return Err(AppError::SemanticScholarError(format!(
+                            "{}: HTTP {}",
+                            context, status
+                        )));
+                    }
+                    last_error = format!("HTTP {}", status);
+                }
+                Err(e) => last_error = e.to_string(),
+            }
+
+            if attempt < attempts {
+                tracing::warn!(
+                    "{} (attempt {}/{}): {}; retrying in {:?}",
+                    context,
+                    attempt,
+                    attempts,
+                    last_error,
+                    wait
+                );
+                tokio::time::sleep(wait).await;
+                wait *= 2;
+            }
+        }
+
+        Err(AppError::SemanticScholarError(format!(
+            "{}: {}",
+            context, last_error
+        )))
+    }
+
+    /// `(offset, limit)` of the requested search page
+    fn result_window(params: &SearchParams) -> (u64, u64) {
+        (params.offset as u64, params.max_results as u64)
     }
 
     /// Build query text from search params
@@ -188,9 +584,292 @@ impl SemanticScholarClient {
     }
 }
 
+/// Rank author search candidates for `name`, best match first
+///
+/// Authors whose name matches exactly (case- and whitespace-insensitive) come
+/// first; ties are broken by paper count, then h-index, then citation count.
+/// This favours the established researcher over homonymous authors with a
+/// handful of misattributed papers.
+pub fn rank_author_candidates(name: &str, mut candidates: Vec<Author>) -> Vec<Author> {
+    let target = normalize_author_name(name);
+    candidates.sort_by(|a, b| {
+        let a_exact = normalize_author_name(&a.name) == target;
+        let b_exact = normalize_author_name(&b.name) == target;
+        b_exact
+            .cmp(&a_exact)
+            .then(b.paper_count.cmp(&a.paper_count))
+            .then(b.h_index.cmp(&a.h_index))
+            .then(b.citation_count.cmp(&a.citation_count))
+    });
+    candidates
+}
+
+/// Lowercase and collapse whitespace/periods in an author name
+fn normalize_author_name(name: &str) -> String {
+    name.split(|c: char| c.is_whitespace() || c == '.')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Collect up to `limit` items from an offset/limit paged source
+///
+/// `fetch_page(offset, page_limit)` is called until `limit` items are
+/// collected or a page comes back short (no more results).
+async fn paginate<T, F, Fut>(limit: usize, page_size: usize, mut fetch_page: F) -> AppResult<Vec<T>>
+where
+    F: FnMut(usize, usize) -> Fut,
+    Fut: Future<Output = AppResult<Vec<T>>>,
+{
+    let mut items = Vec::new();
+    while items.len() < limit {
+        let page_limit = page_size.min(limit - items.len());
+        let page = fetch_page(items.len(), page_limit).await?;
+        let fetched = page.len();
+        items.extend(page);
+        if fetched < page_limit {
+            break;
+        }
+    }
+    items.truncate(limit);
+    Ok(items)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::test_server::{request_line, serve_responses};
+
+    #[test]
+    fn test_with_api_key_stores_key() {
+        let client = SemanticScholarClient::new().with_api_key("test-key");
+        assert_eq!(client.api_key(), Some("test-key"));
+        assert_eq!(client.client.api_key, "test-key");
+    }
+
+    #[tokio::test]
+    async fn test_limited_caps_concurrent_requests() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let client = SemanticScholarClient::new().with_max_concurrent_requests(2);
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+
+        let requests = (0..8).map(|_| {
+            client.limited(async {
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+            })
+        });
+        futures::future::join_all(requests).await;
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+        assert_eq!(client.limiter.available_permits(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retries_server_errors() {
+        let (url, server) =
+            serve_responses(vec![(503, "busy"), (429, "slow"), (200, r#"{"total": 7}"#)]).await;
+        let client = SemanticScholarClient::new().with_retry_config(3, 0);
+
+        let page: SearchPage = client
+            .get_json(&url, &[], "Count request failed")
+            .await
+            .unwrap();
+        assert_eq!(page.total, 7);
+        assert_eq!(server.await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_does_not_retry_not_found() {
+        let (url, server) = serve_responses(vec![(404, "")]).await;
+        let client = SemanticScholarClient::new().with_retry_config(3, 0);
+
+        let result: AppResult<SearchPage> = client.get_json(&url, &[], "Fetch author failed").await;
+        assert!(result.unwrap_err().to_string().contains("not found"));
+        assert_eq!(server.await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_max_concurrent_requests_is_at_least_one() {
+        let client = SemanticScholarClient::new().with_max_concurrent_requests(0);
+        assert_eq!(client.limiter.available_permits(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_concatenates_pages_and_truncates() {
+        let source: Vec<usize> = (0..25).collect();
+        let mut requests = Vec::new();
+
+        let items = paginate(22, 10, |offset, limit| {
+            requests.push((offset, limit));
+            let page: Vec<usize> = source.iter().skip(offset).take(limit).copied().collect();
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items, (0..22).collect::<Vec<_>>());
+        assert_eq!(requests, vec![(0, 10), (10, 10), (20, 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_when_source_is_exhausted() {
+        let source: Vec<usize> = (0..15).collect();
+        let mut calls = 0;
+
+        let items = paginate(100, 10, |offset, limit| {
+            calls += 1;
+            let page: Vec<usize> = source.iter().skip(offset).take(limit).copied().collect();
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items.len(), 15);
+        assert_eq!(calls, 2);
+    }
+
+    fn author(name: &str, paper_count: i32, h_index: i32) -> Author {
+        Author {
+            name: name.to_string(),
+            paper_count,
+            h_index,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rank_author_candidates_prefers_exact_name_then_paper_count() {
+        let candidates = vec![
+            author("Yoshua Bengio", 3, 1),
+            author("Y. Bengio", 900, 200),
+            author("Yoshua Bengio", 950, 210),
+            author("Samy Bengio", 400, 90),
+        ];
+
+        let ranked = rank_author_candidates("yoshua  bengio", candidates);
+        let summary: Vec<(&str, i32)> = ranked
+            .iter()
+            .map(|a| (a.name.as_str(), a.paper_count))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                ("Yoshua Bengio", 950),
+                ("Yoshua Bengio", 3),
+                ("Y. Bengio", 900),
+                ("Samy Bengio", 400),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rank_author_candidates_breaks_ties_by_h_index() {
+        let candidates = vec![author("Jane Doe", 10, 2), author("Jane Doe", 10, 8)];
+
+        let ranked = rank_author_candidates("Jane Doe", candidates);
+        assert_eq!(ranked[0].h_index, 8);
+    }
+
+    #[test]
+    fn test_author_record_conversion() {
+        let record: AuthorRecord = serde_json::from_value(serde_json::json!({
+            "authorId": "1751762",
+            "name": "Yoshua Bengio",
+            "affiliations": ["Mila"],
+            "paperCount": 950,
+            "citationCount": 500000,
+            "hIndex": 210
+        }))
+        .unwrap();
+
+        let author = Author::from(record);
+        assert_eq!(author.ss_id, "1751762");
+        assert_eq!(author.h_index, 210);
+        assert_eq!(author.affiliations, vec!["Mila"]);
+        assert_eq!(author.paper_count, 950);
+    }
+
+    #[test]
+    fn test_recommendations_page_parses_sample_response() {
+        let page: RecommendationsPage = serde_json::from_value(serde_json::json!({
+            "recommendedPapers": [
+                {
+                    "paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776",
+                    "title": "Attention is All you Need",
+                    "abstract": "The dominant sequence transduction models...",
+                    "year": 2017,
+                    "citationCount": 100000,
+                    "externalIds": {"ArXiv": "1706.03762"},
+                    "authors": [{"authorId": "40348417", "name": "Ashish Vaswani"}]
+                },
+                {
+                    "paperId": "df2b0e26d0599ce3e70df8a9da02e51594e0e992",
+                    "title": "BERT: Pre-training of Deep Bidirectional Transformers",
+                    "year": 2019
+                }
+            ]
+        }))
+        .unwrap();
+
+        let papers: Vec<AcademicPaper> = page
+            .recommended_papers
+            .into_iter()
+            .map(AcademicPaper::from_semantic_scholar)
+            .collect();
+        assert_eq!(papers.len(), 2);
+        assert_eq!(papers[0].ss_id, "204e3073870fae3d05bcbc2f6a8e263d9b72e776");
+        assert_eq!(papers[0].title, "Attention is All you Need");
+        assert_eq!(
+            papers[1].title,
+            "BERT: Pre-training of Deep Bidirectional Transformers"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_sends_offset_and_limit() {
+        let (url, server) = serve_responses(vec![(200, r#"{"total": 120, "data": []}"#)]).await;
+        let mut client = SemanticScholarClient::new();
+        client.graph_api_base = url;
+        let params = SearchParams::new()
+            .with_query("transformer")
+            .with_max_results(25)
+            .with_offset(50);
+
+        let (papers, total) = client.search_with_total(&params).await.unwrap();
+        assert!(papers.is_empty());
+        assert_eq!(total, Some(120));
+
+        let requests = server.await.unwrap();
+        let line = request_line(&requests[0]);
+        assert!(line.starts_with("GET /paper/search?"));
+        assert!(line.contains("offset=50"));
+        assert!(line.contains("limit=25"));
+    }
+
+    #[test]
+    fn test_search_page_parses_sample_response() {
+        let page: SearchPage = serde_json::from_value(serde_json::json!({
+            "total": 3421,
+            "offset": 0,
+            "next": 1,
+            "data": [{"paperId": "204e3073870fae3d05bcbc2f6a8e263d9b72e776"}]
+        }))
+        .unwrap();
+        assert_eq!(page.total, 3421);
+    }
+
+    #[test]
+    fn test_recommendations_page_defaults_to_empty() {
+        let page: RecommendationsPage = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(page.recommended_papers.is_empty());
+    }
 
     #[test]
     fn test_build_query_text_with_query() {
@@ -1,6 +1,7 @@
 //! Unpaywall API client for resolving open access PDF URLs via DOI
 
 use crate::shared::errors::{AppError, AppResult};
+use crate::shared::utils::normalize_doi;
 use serde::Deserialize;
 
 /// Unpaywall API client
@@ -15,6 +16,19 @@ pub struct UnpaywallClient {
 #[derive(Deserialize)]
 struct UnpaywallResponse {
     best_oa_location: Option<OaLocation>,
+    #[serde(default)]
+    oa_locations: Vec<OaLocation>,
+}
+
+impl UnpaywallResponse {
+    /// PDF URL of the best OA location, else of the first other location with one
+    fn best_pdf_url(self) -> Option<String> {
+        self.best_oa_location
+            .into_iter()
+            .chain(self.oa_locations)
+            .filter_map(|loc| loc.url_for_pdf)
+            .find(|url| !url.is_empty())
+    }
 }
 
 #[derive(Deserialize)]
@@ -31,6 +45,12 @@ impl UnpaywallClient {
         }
     }
 
+    /// Use a preconfigured HTTP client (proxy, timeout, user agent)
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
     /// Create a client from the `UNPAYWALL_EMAIL` environment variable
     ///
     /// Returns `None` if the env var is not set or empty.
@@ -41,15 +61,24 @@ impl UnpaywallClient {
             .map(Self::new)
     }
 
+    /// Resolve a DOI to its best open access PDF URL with a one-off client
+    ///
+    /// Unpaywall requires an `email` on every request; use
+    /// [`from_env`](Self::from_env) to read it from `UNPAYWALL_EMAIL`.
+    pub async fn best_oa_pdf(doi: &str, email: &str) -> AppResult<Option<String>> {
+        Self::new(email).resolve_pdf_url(doi).await
+    }
+
     /// Resolve a DOI to an open access PDF URL via the Unpaywall API
     ///
-    /// Returns `Ok(None)` if the DOI has no open access PDF available.
+    /// Returns `Ok(None)` if the DOI is invalid or has no open access PDF
+    /// available.
     pub async fn resolve_pdf_url(&self, doi: &str) -> AppResult<Option<String>> {
-        if doi.is_empty() {
+        let Some(doi) = normalize_doi(doi) else {
             return Ok(None);
-        }
+        };
 
-        let encoded_doi = urlencoding::encode(doi);
+        let encoded_doi = urlencoding::encode(&doi);
         let url = format!(
             "https://api.unpaywall.org/v2/{}?email={}",
             encoded_doi, self.email
@@ -75,15 +104,21 @@ impl UnpaywallClient {
             )));
         }
 
-        let data: UnpaywallResponse = response
-            .json()
+        let body = response
+            .text()
             .await
-            .map_err(|e| AppError::UnpaywallError(format!("Parse failed: {}", e)))?;
+            .map_err(|e| AppError::UnpaywallError(format!("Request failed: {}", e)))?;
+        Self::parse_pdf_url(&body)
+    }
 
-        Ok(data
-            .best_oa_location
-            .and_then(|loc| loc.url_for_pdf)
-            .filter(|url| !url.is_empty()))
+    /// Extract the open access PDF URL from an Unpaywall response body
+    ///
+    /// Prefers `best_oa_location` and falls back to the other `oa_locations`
+    /// when the best one has no direct PDF link.
+    fn parse_pdf_url(body: &str) -> AppResult<Option<String>> {
+        let data: UnpaywallResponse = serde_json::from_str(body)
+            .map_err(|e| AppError::UnpaywallError(format!("Parse failed: {}", e)))?;
+        Ok(data.best_pdf_url())
     }
 }
 
@@ -99,6 +134,54 @@ mod tests {
         assert_eq!(client.email, "test@example.com");
     }
 
+    #[test]
+    fn test_parse_pdf_url_from_sample_response() {
+        let body = r#"{
+            "doi": "10.1038/nature12373",
+            "is_oa": true,
+            "best_oa_location": {
+                "url": "https://www.nature.com/articles/nature12373",
+                "url_for_pdf": "https://www.nature.com/articles/nature12373.pdf",
+                "host_type": "publisher"
+            },
+            "oa_locations": [
+                {
+                    "url_for_pdf": "https://www.nature.com/articles/nature12373.pdf",
+                    "host_type": "publisher"
+                },
+                {
+                    "url_for_pdf": "https://europepmc.org/articles/pmc4221854?pdf=render",
+                    "host_type": "repository"
+                }
+            ]
+        }"#;
+        assert_eq!(
+            UnpaywallClient::parse_pdf_url(body).unwrap().as_deref(),
+            Some("https://www.nature.com/articles/nature12373.pdf")
+        );
+
+        // Best location without a PDF link falls back to the other locations
+        let body = r#"{
+            "best_oa_location": { "url": "https://example.org/landing", "url_for_pdf": null },
+            "oa_locations": [
+                { "url_for_pdf": null },
+                { "url_for_pdf": "https://repo.example.org/paper.pdf" }
+            ]
+        }"#;
+        assert_eq!(
+            UnpaywallClient::parse_pdf_url(body).unwrap().as_deref(),
+            Some("https://repo.example.org/paper.pdf")
+        );
+
+        let closed = r#"{"is_oa": false, "best_oa_location": null, "oa_locations": []}"#;
+        assert_eq!(UnpaywallClient::parse_pdf_url(closed).unwrap(), None);
+
+        assert!(matches!(
+            UnpaywallClient::parse_pdf_url("not json"),
+            Err(AppError::UnpaywallError(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_resolve_empty_doi() {
         let client = UnpaywallClient::new("test@example.com");
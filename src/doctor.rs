@@ -0,0 +1,539 @@
+//! Preflight diagnostics for providers and paper sources
+//!
+//! Runs a lightweight connectivity/credential check against each configured
+//! LLM provider and paper source, and collects the outcomes into a report
+//! with actionable messages. Every check is bounded by [`CHECK_TIMEOUT`].
+
+use crate::agents::LlmProvider;
+use crate::agents::providers::{
+    AnthropicProvider, AzureOpenAiProvider, BedrockProvider, CohereProvider, OllamaProvider,
+    OpenAiCompatibleProvider, OpenAiProvider,
+};
+use crate::client::{PaperClient, PaperSource, SourceHealth};
+use crate::shared::config::{Config, LlmProviderType};
+use crate::shared::errors::{AppError, AppResult};
+use std::future::Future;
+use std::time::Duration;
+
+/// Maximum time a single check may take before it is reported as failed
+pub const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// The check succeeded
+    Pass,
+    /// The check failed and needs attention
+    Fail,
+    /// The check was not run (e.g. provider not configured)
+    Skip,
+}
+
+impl CheckStatus {
+    /// Label used in the report table
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::Skip => "SKIP",
+        }
+    }
+}
+
+/// Result of a single diagnostic check
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    /// Name of the provider or source that was checked
+    pub name: String,
+    /// Outcome
+    pub status: CheckStatus,
+    /// Human-readable, actionable detail
+    pub message: String,
+}
+
+impl CheckResult {
+    fn new(name: &str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Collected results of all diagnostic checks
+#[derive(Debug, Clone, Default)]
+pub struct DoctorReport {
+    /// Individual check results, in execution order
+    pub checks: Vec<CheckResult>,
+}
+
+impl DoctorReport {
+    /// True if no check failed
+    pub fn is_healthy(&self) -> bool {
+        self.failures() == 0
+    }
+
+    /// Number of failed checks
+    pub fn failures(&self) -> usize {
+        self.count(CheckStatus::Fail)
+    }
+
+    /// Number of checks with the given outcome
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+
+    /// One-line tally, e.g. "7 passed, 1 failed, 3 skipped"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} passed, {} failed, {} skipped",
+            self.count(CheckStatus::Pass),
+            self.count(CheckStatus::Fail),
+            self.count(CheckStatus::Skip)
+        )
+    }
+
+    /// Render the report as a plain-text pass/fail table
+    pub fn to_table(&self) -> String {
+        let name_width = self
+            .checks
+            .iter()
+            .map(|c| c.name.len())
+            .chain(std::iter::once("Check".len()))
+            .max()
+            .unwrap_or(0);
+
+        let mut table = format!("{:<name_width$}  Status  Detail\n", "Check");
+        for check in &self.checks {
+            table.push_str(&format!(
+                "{:<name_width$}  {:<6}  {}\n",
+                check.name,
+                check.status.as_str(),
+                check.message
+            ));
+        }
+        table
+    }
+}
+
+/// Run all checks for the given configuration
+///
+/// Missing credentials for the default provider are reported as failures;
+/// other unconfigured providers are skipped.
+pub async fn run_checks(config: &Config) -> DoctorReport {
+    let default_provider = config.default_llm_provider;
+    let mut report = DoctorReport::default();
+
+    report.checks.push(
+        with_timeout(
+            "OpenAI",
+            CHECK_TIMEOUT,
+            check_api_provider(
+                "OpenAI",
+                "OPENAI_API_KEY",
+                config.openai_api_key.as_deref(),
+                default_provider == LlmProviderType::OpenAi,
+                OpenAiProvider::from_env,
+            ),
+        )
+        .await,
+    );
+    report.checks.push(
+        with_timeout(
+            "Azure OpenAI",
+            CHECK_TIMEOUT,
+            check_api_provider(
+                "Azure OpenAI",
+                "AZURE_OPENAI_API_KEY",
+                config.azure_openai_api_key.as_deref(),
+                default_provider == LlmProviderType::AzureOpenAi,
+                AzureOpenAiProvider::from_env,
+            ),
+        )
+        .await,
+    );
+    report.checks.push(
+        with_timeout(
+            "OpenAI-compatible",
+            CHECK_TIMEOUT,
+            check_openai_compatible(config),
+        )
+        .await,
+    );
+    report.checks.push(
+        with_timeout(
+            "Anthropic",
+            CHECK_TIMEOUT,
+            check_api_provider(
+                "Anthropic",
+                "ANTHROPIC_API_KEY",
+                config.anthropic_api_key.as_deref(),
+                default_provider == LlmProviderType::Anthropic,
+                AnthropicProvider::from_env,
+            ),
+        )
+        .await,
+    );
+    report.checks.push(
+        with_timeout(
+            "Bedrock",
+            CHECK_TIMEOUT,
+            check_api_provider(
+                "Bedrock",
+                "AWS_REGION",
+                config.aws_region.as_deref(),
+                default_provider == LlmProviderType::Bedrock,
+                BedrockProvider::from_env,
+            ),
+        )
+        .await,
+    );
+    report.checks.push(
+        with_timeout(
+            "Cohere",
+            CHECK_TIMEOUT,
+            check_api_provider(
+                "Cohere",
+                "COHERE_API_KEY",
+                config.cohere_api_key.as_deref(),
+                default_provider == LlmProviderType::Cohere,
+                CohereProvider::from_env,
+            ),
+        )
+        .await,
+    );
+    report
+        .checks
+        .push(with_timeout("Ollama", CHECK_TIMEOUT, check_ollama(config)).await);
+    report.checks.extend(
+        PaperClient::new()
+            .health_check(CHECK_TIMEOUT)
+            .await
+            .into_iter()
+            .map(|(source, health)| source_check_result(source, health, CHECK_TIMEOUT)),
+    );
+
+    report
+}
+
+/// Check an API-key based LLM provider
+///
+/// `required` marks the provider as the one in use, so a missing key is a
+/// failure rather than a skip.
+pub async fn check_api_provider<P, F>(
+    name: &str,
+    env_var: &str,
+    api_key: Option<&str>,
+    required: bool,
+    make_provider: F,
+) -> CheckResult
+where
+    P: LlmProvider,
+    F: FnOnce() -> AppResult<P>,
+{
+    if api_key.is_none_or(|k| k.trim().is_empty()) {
+        let status = if required {
+            CheckStatus::Fail
+        } else {
+            CheckStatus::Skip
+        };
+        return CheckResult::new(name, status, format!("{} is not set", env_var));
+    }
+
+    let provider = match make_provider() {
+        Ok(provider) => provider,
+        Err(e) => return CheckResult::new(name, CheckStatus::Fail, e.to_string()),
+    };
+
+    match provider.health_check().await {
+        Ok(()) => CheckResult::new(
+            name,
+            CheckStatus::Pass,
+            format!("{} accepted (model: {})", env_var, provider.default_model()),
+        ),
+        Err(e) => CheckResult::new(name, CheckStatus::Fail, describe_key_error(env_var, &e)),
+    }
+}
+
+/// Check the OpenAI-compatible endpoint at `config.openai_compat_base_url`
+///
+/// The endpoint comes from the configuration rather than the environment, so
+/// a `--base-url` override applied to `config` is the one probed.
+async fn check_openai_compatible(config: &Config) -> CheckResult {
+    check_api_provider(
+        "OpenAI-compatible",
+        "OPENAI_COMPAT_BASE_URL",
+        config.openai_compat_base_url.as_deref(),
+        config.default_llm_provider == LlmProviderType::OpenAiCompatible,
+        || {
+            let base_url = config.openai_compat_base_url.as_deref().unwrap_or_default();
+            let model = config
+                .openai_compat_model
+                .as_deref()
+                .filter(|m| !m.trim().is_empty())
+                .ok_or_else(|| {
+                    AppError::ConfigError(
+                        "OPENAI_COMPAT_MODEL environment variable not set".to_string(),
+                    )
+                })?;
+            Ok(OpenAiCompatibleProvider::new(
+                base_url,
+                "OPENAI_COMPAT_API_KEY",
+                model,
+            ))
+        },
+    )
+    .await
+}
+
+/// Check the local Ollama server
+async fn check_ollama(config: &Config) -> CheckResult {
+    let name = "Ollama";
+    let in_use = config.default_llm_provider == LlmProviderType::Ollama;
+    if !in_use && config.ollama_base_url.is_none() {
+        return CheckResult::new(name, CheckStatus::Skip, "OLLAMA_BASE_URL is not set");
+    }
+
+    let provider = match OllamaProvider::from_env() {
+        Ok(provider) => provider,
+        Err(e) => return CheckResult::new(name, CheckStatus::Fail, e.to_string()),
+    };
+
+    match provider.health_check().await {
+        Ok(()) => CheckResult::new(
+            name,
+            CheckStatus::Pass,
+            format!("reachable at {}", config.ollama_url()),
+        ),
+        Err(e) => CheckResult::new(
+            name,
+            CheckStatus::Fail,
+            format!(
+                "not usable at {} (is `ollama serve` running and the model pulled?): {}",
+                config.ollama_url(),
+                e
+            ),
+        ),
+    }
+}
+
+/// Run a check, failing it if it does not finish within `timeout`
+pub async fn with_timeout(
+    name: &str,
+    timeout: Duration,
+    check: impl Future<Output = CheckResult>,
+) -> CheckResult {
+    tokio::time::timeout(timeout, check)
+        .await
+        .unwrap_or_else(|_| CheckResult::new(name, CheckStatus::Fail, timeout_message(timeout)))
+}
+
+/// Detail reported for a check that did not finish within `timeout`
+fn timeout_message(timeout: Duration) -> String {
+    format!(
+        "no response within {}s (check the network connection and proxy settings)",
+        timeout.as_secs()
+    )
+}
+
+/// Turn a paper source probe from [`PaperClient::health_check`] into a check result
+fn source_check_result(
+    source: PaperSource,
+    health: SourceHealth,
+    timeout: Duration,
+) -> CheckResult {
+    let name = match source {
+        PaperSource::ArXiv => "arXiv",
+        PaperSource::SemanticScholar => "Semantic Scholar",
+        PaperSource::CrossRef => "CrossRef",
+        PaperSource::Dblp => "DBLP",
+        PaperSource::Both => "Paper sources",
+    };
+    match health {
+        SourceHealth::Reachable => CheckResult::new(name, CheckStatus::Pass, "API reachable"),
+        SourceHealth::RateLimited(error) => CheckResult::new(
+            name,
+            CheckStatus::Fail,
+            format!("rate limited (HTTP 429), try again later: {}", error),
+        ),
+        SourceHealth::Unreachable(error) => CheckResult::new(
+            name,
+            CheckStatus::Fail,
+            format!("API unreachable: {}", error),
+        ),
+        SourceHealth::TimedOut => {
+            CheckResult::new(name, CheckStatus::Fail, timeout_message(timeout))
+        }
+    }
+}
+
+/// Turn a provider error into an actionable message for a configured key
+fn describe_key_error(env_var: &str, error: &AppError) -> String {
+    match error {
+        AppError::LlmAuth(_) => {
+            format!(
+                "{} set but returned 401/403 (check that the key is valid)",
+                env_var
+            )
+        }
+        AppError::LlmRateLimited { .. } => {
+            format!(
+                "{} set but returned 429 (rate limited or out of quota)",
+                env_var
+            )
+        }
+        AppError::LlmServer(status) => {
+            format!(
+                "{} set but provider returned {} (try again later)",
+                env_var, status
+            )
+        }
+        other => format!("{} set but request failed: {}", env_var, other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_server::{request_line, serve_responses};
+
+    #[tokio::test]
+    async fn test_missing_key_reports_failure() {
+        let result = check_api_provider(
+            "OpenAI",
+            "OPENAI_API_KEY",
+            None,
+            true,
+            OpenAiProvider::from_env,
+        )
+        .await;
+
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.message, "OPENAI_API_KEY is not set");
+    }
+
+    #[tokio::test]
+    async fn test_missing_optional_key_is_skipped() {
+        let result = check_api_provider(
+            "Anthropic",
+            "ANTHROPIC_API_KEY",
+            Some(""),
+            false,
+            AnthropicProvider::from_env,
+        )
+        .await;
+
+        assert_eq!(result.status, CheckStatus::Skip);
+    }
+
+    #[tokio::test]
+    async fn test_openai_compatible_check_probes_configured_base_url() {
+        let (url, server) = serve_responses(vec![(
+            200,
+            r#"{"choices":[{"message":{"role":"assistant","content":"pong"}}]}"#,
+        )])
+        .await;
+
+        let config = Config {
+            default_llm_provider: LlmProviderType::OpenAiCompatible,
+            openai_compat_base_url: Some(format!("{}/v1", url)),
+            openai_compat_model: Some("test-model".to_string()),
+            ..Config::default()
+        };
+        let result = check_openai_compatible(&config).await;
+
+        assert_eq!(result.status, CheckStatus::Pass, "{}", result.message);
+        assert!(result.message.contains("test-model"));
+        let requests = server.await.unwrap();
+        assert!(request_line(&requests[0]).starts_with("POST /v1/chat/completions "));
+    }
+
+    #[test]
+    fn test_describe_key_error() {
+        let error = AppError::from_llm_message("OpenAI API error: 401 Unauthorized");
+        assert_eq!(
+            describe_key_error("OPENAI_API_KEY", &error),
+            "OPENAI_API_KEY set but returned 401/403 (check that the key is valid)"
+        );
+
+        let error = AppError::LlmRateLimited { retry_after: None };
+        assert_eq!(
+            describe_key_error("ANTHROPIC_API_KEY", &error),
+            "ANTHROPIC_API_KEY set but returned 429 (rate limited or out of quota)"
+        );
+    }
+
+    #[test]
+    fn test_report_table() {
+        let report = DoctorReport {
+            checks: vec![
+                CheckResult::new("OpenAI", CheckStatus::Fail, "OPENAI_API_KEY is not set"),
+                CheckResult::new("arXiv", CheckStatus::Pass, "API reachable"),
+            ],
+        };
+
+        assert!(!report.is_healthy());
+        assert_eq!(report.failures(), 1);
+
+        let table = report.to_table();
+        assert!(table.contains("OpenAI  FAIL    OPENAI_API_KEY is not set"));
+        assert!(table.contains("arXiv   PASS    API reachable"));
+    }
+
+    #[test]
+    fn test_report_summary_counts_each_status() {
+        let report = DoctorReport {
+            checks: vec![
+                CheckResult::new("OpenAI", CheckStatus::Pass, "OPENAI_API_KEY accepted"),
+                CheckResult::new("Cohere", CheckStatus::Skip, "COHERE_API_KEY is not set"),
+                CheckResult::new("Ollama", CheckStatus::Fail, "not usable"),
+                CheckResult::new("arXiv", CheckStatus::Pass, "API reachable"),
+            ],
+        };
+
+        assert_eq!(report.count(CheckStatus::Pass), 2);
+        assert_eq!(report.summary(), "2 passed, 1 failed, 1 skipped");
+        assert!(DoctorReport::default().is_healthy());
+    }
+
+    #[test]
+    fn test_source_check_result_reports_rate_limits() {
+        let timeout = Duration::from_secs(10);
+        let pass = source_check_result(PaperSource::ArXiv, SourceHealth::Reachable, timeout);
+        assert_eq!(
+            (pass.name.as_str(), pass.status),
+            ("arXiv", CheckStatus::Pass)
+        );
+
+        let limited = source_check_result(
+            PaperSource::SemanticScholar,
+            SourceHealth::RateLimited("Fetch details failed: HTTP 429".to_string()),
+            timeout,
+        );
+        assert_eq!(limited.status, CheckStatus::Fail);
+        assert!(limited.message.starts_with("rate limited (HTTP 429)"));
+
+        let slow = source_check_result(
+            PaperSource::SemanticScholar,
+            SourceHealth::TimedOut,
+            timeout,
+        );
+        assert!(slow.message.starts_with("no response within 10s"));
+    }
+
+    #[tokio::test]
+    async fn test_with_timeout_fails_slow_check() {
+        let slow = async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            CheckResult::new("arXiv", CheckStatus::Pass, "API reachable")
+        };
+        let result = with_timeout("arXiv", Duration::from_millis(10), slow).await;
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.name, "arXiv");
+
+        let fast = async { CheckResult::new("arXiv", CheckStatus::Pass, "API reachable") };
+        let result = with_timeout("arXiv", Duration::from_secs(1), fast).await;
+        assert_eq!(result.status, CheckStatus::Pass);
+    }
+}
@@ -3,10 +3,13 @@
 //! This module provides structures for exporting academic paper data
 //! in a format optimized for LLM/AI agent consumption.
 
-use crate::models::AcademicPaper;
-use chrono::{DateTime, Local};
+use crate::models::{AcademicPaper, ExtractedReference, PaperSource, PublicationStatus};
+use crate::shared::errors::{AppError, AppResult};
+use chrono::{DateTime, Local, Offset, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
 
 /// Current schema version for export format
 pub const EXPORT_SCHEMA_VERSION: &str = "1.0.0";
@@ -50,6 +53,20 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
             <xs:documentation xml:lang="ja">この論文が参照している論文のリスト（オプション）</xs:documentation>
           </xs:annotation>
         </xs:element>
+        <xs:element name="extracted-references" minOccurs="0">
+          <xs:annotation>
+            <xs:documentation xml:lang="ja">PDFから抽出された参考文献のうちSemantic Scholarの参照リストにないもの（オプション）</xs:documentation>
+          </xs:annotation>
+          <xs:complexType>
+            <xs:sequence>
+              <xs:element name="reference" type="ExtractedReferenceType" maxOccurs="unbounded">
+                <xs:annotation>
+                  <xs:documentation xml:lang="ja">個別の参考文献</xs:documentation>
+                </xs:annotation>
+              </xs:element>
+            </xs:sequence>
+          </xs:complexType>
+        </xs:element>
         <xs:element name="keywords-data" type="KeywordsDataType" minOccurs="0">
           <xs:annotation>
             <xs:documentation xml:lang="ja">LLMで抽出されたキーワードとトピック（オプション）</xs:documentation>
@@ -60,6 +77,16 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
             <xs:documentation xml:lang="ja">研究分野における位置づけ情報（オプション）</xs:documentation>
           </xs:annotation>
         </xs:element>
+        <xs:element name="section-summaries" minOccurs="0">
+          <xs:annotation>
+            <xs:documentation xml:lang="ja">主要セクションごとのLLM要約（オプション）</xs:documentation>
+          </xs:annotation>
+          <xs:complexType>
+            <xs:sequence>
+              <xs:element name="section-summary" type="SectionSummaryType" maxOccurs="unbounded"/>
+            </xs:sequence>
+          </xs:complexType>
+        </xs:element>
       </xs:sequence>
       <xs:attribute name="schema-version" type="xs:string" use="required">
         <xs:annotation>
@@ -90,6 +117,30 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
           <xs:documentation xml:lang="ja">エクスポート時に使用されたオプション</xs:documentation>
         </xs:annotation>
       </xs:element>
+      <xs:element name="size-metrics" minOccurs="0">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">論文の分量（単語数と読了時間の目安）</xs:documentation>
+        </xs:annotation>
+        <xs:complexType>
+          <xs:sequence>
+            <xs:element name="abstract-word-count" type="xs:nonNegativeInteger">
+              <xs:annotation>
+                <xs:documentation xml:lang="ja">アブストラクトの単語数</xs:documentation>
+              </xs:annotation>
+            </xs:element>
+            <xs:element name="full-text-word-count" type="xs:nonNegativeInteger" minOccurs="0">
+              <xs:annotation>
+                <xs:documentation xml:lang="ja">抽出した本文の単語数（本文抽出時のみ）</xs:documentation>
+              </xs:annotation>
+            </xs:element>
+            <xs:element name="estimated-reading-minutes" type="xs:nonNegativeInteger" minOccurs="0">
+              <xs:annotation>
+                <xs:documentation xml:lang="ja">本文の推定読了時間（分、毎分約220語で計算）</xs:documentation>
+              </xs:annotation>
+            </xs:element>
+          </xs:sequence>
+        </xs:complexType>
+      </xs:element>
       <xs:element name="warnings" minOccurs="0">
         <xs:annotation>
           <xs:documentation xml:lang="ja">エクスポート処理中に発生した警告メッセージ</xs:documentation>
@@ -153,6 +204,11 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
           <xs:documentation xml:lang="ja">使用されたLLMモデル名</xs:documentation>
         </xs:annotation>
       </xs:element>
+      <xs:element name="include-provenance" type="xs:boolean" minOccurs="0">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">論文の取得元（プロベナンス）を記録したかどうか</xs:documentation>
+        </xs:annotation>
+      </xs:element>
     </xs:sequence>
   </xs:complexType>
 
@@ -259,6 +315,11 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
           <xs:documentation xml:lang="ja">アブストラクトの日本語訳</xs:documentation>
         </xs:annotation>
       </xs:element>
+      <xs:element name="abstract-original" type="xs:string" minOccurs="0">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">英語に翻訳する前の原文アブストラクト</xs:documentation>
+        </xs:annotation>
+      </xs:element>
       <xs:element name="url" type="xs:string">
         <xs:annotation>
           <xs:documentation xml:lang="ja">論文のURL</xs:documentation>
@@ -269,6 +330,25 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
           <xs:documentation xml:lang="ja">掲載ジャーナル名または会議名</xs:documentation>
         </xs:annotation>
       </xs:element>
+      <xs:element name="publication-status" type="PublicationStatusType">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">出版状況（プレプリント／出版済み）</xs:documentation>
+        </xs:annotation>
+      </xs:element>
+      <xs:element name="source" minOccurs="0">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">論文の取得元（複数ソースの統合は Both、include-provenance 指定時のみ）</xs:documentation>
+        </xs:annotation>
+        <xs:simpleType>
+          <xs:restriction base="xs:string">
+            <xs:enumeration value="ArXiv"/>
+            <xs:enumeration value="SemanticScholar"/>
+            <xs:enumeration value="CrossRef"/>
+            <xs:enumeration value="Dblp"/>
+            <xs:enumeration value="Both"/>
+          </xs:restriction>
+        </xs:simpleType>
+      </xs:element>
       <xs:element name="primary-category" type="xs:string" minOccurs="0">
         <xs:annotation>
           <xs:documentation xml:lang="ja">主要arXivカテゴリ（例：cs.CL, cs.AI）</xs:documentation>
@@ -467,6 +547,20 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
           <xs:documentation xml:lang="ja">分析に使用したLLMモデル</xs:documentation>
         </xs:annotation>
       </xs:element>
+      <xs:element name="warnings" minOccurs="0">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">分析の警告（論文に根拠のない項目、除外したプレースホルダーなど）</xs:documentation>
+        </xs:annotation>
+        <xs:complexType>
+          <xs:sequence>
+            <xs:element name="warning" type="xs:string" maxOccurs="unbounded">
+              <xs:annotation>
+                <xs:documentation xml:lang="ja">個別の警告メッセージ</xs:documentation>
+              </xs:annotation>
+            </xs:element>
+          </xs:sequence>
+        </xs:complexType>
+      </xs:element>
     </xs:sequence>
   </xs:complexType>
 
@@ -647,6 +741,23 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
     </xs:restriction>
   </xs:simpleType>
 
+  <!-- 出版状況型 -->
+  <xs:simpleType name="PublicationStatusType">
+    <xs:annotation>
+      <xs:documentation xml:lang="ja">
+        論文の出版状況:
+        - preprint: arXivのみで公開されたプレプリント
+        - published: 出版社DOIとarXiv以外の掲載先を持つ
+        - unknown: メタデータ不足で判定不能
+      </xs:documentation>
+    </xs:annotation>
+    <xs:restriction base="xs:string">
+      <xs:enumeration value="preprint"/>
+      <xs:enumeration value="published"/>
+      <xs:enumeration value="unknown"/>
+    </xs:restriction>
+  </xs:simpleType>
+
   <!-- 引用論文型 -->
   <xs:complexType name="CitationsType">
     <xs:annotation>
@@ -851,6 +962,25 @@ pub const EXPORTED_PAPER_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
     </xs:sequence>
   </xs:complexType>
 
+  <!-- セクション要約型 -->
+  <xs:complexType name="SectionSummaryType">
+    <xs:annotation>
+      <xs:documentation xml:lang="ja">1つのセクションの1〜2文の要約</xs:documentation>
+    </xs:annotation>
+    <xs:sequence>
+      <xs:element name="section-title" type="xs:string">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">セクションタイトル</xs:documentation>
+        </xs:annotation>
+      </xs:element>
+      <xs:element name="summary" type="xs:string">
+        <xs:annotation>
+          <xs:documentation xml:lang="ja">要約</xs:documentation>
+        </xs:annotation>
+      </xs:element>
+    </xs:sequence>
+  </xs:complexType>
+
   <!-- 研究コンテキスト型 -->
   <xs:complexType name="ResearchContextType">
     <xs:annotation>
@@ -911,6 +1041,349 @@ pub fn get_xml_schema() -> &'static str {
     EXPORTED_PAPER_XSD
 }
 
+/// Prune a serialized export down to the requested dot-separated field paths
+///
+/// Each path (e.g. `paper.analysis.summary`) keeps that field together with its
+/// enclosing objects; everything else is dropped. A path crossing an array
+/// (e.g. `paper.authors.name`) is applied to every element. Paths that do not
+/// resolve are logged as warnings and skipped.
+pub fn project_export_fields(value: serde_json::Value, paths: &[&str]) -> serde_json::Value {
+    let mut projected = serde_json::Value::Object(serde_json::Map::new());
+    for path in paths {
+        let path = path.trim();
+        if path.is_empty() {
+            continue;
+        }
+        let segments: Vec<&str> = path.split('.').collect();
+        match project_path(&value, &segments) {
+            Some(subtree) => merge_projection(&mut projected, subtree),
+            None => tracing::warn!("Unknown export field path: {}", path),
+        }
+    }
+    projected
+}
+
+/// Extract the subtree of `value` addressed by `segments`, keeping its parents
+fn project_path(value: &serde_json::Value, segments: &[&str]) -> Option<serde_json::Value> {
+    let Some((head, rest)) = segments.split_first() else {
+        return Some(value.clone());
+    };
+    match value {
+        serde_json::Value::Object(map) => {
+            let child = project_path(map.get(*head)?, rest)?;
+            let mut wrapped = serde_json::Map::new();
+            wrapped.insert((*head).to_string(), child);
+            Some(serde_json::Value::Object(wrapped))
+        }
+        serde_json::Value::Array(items) => {
+            let projected: Vec<Option<serde_json::Value>> = items
+                .iter()
+                .map(|item| project_path(item, segments))
+                .collect();
+            // An empty array has nothing to resolve against, so keep it as-is
+            if !items.is_empty() && projected.iter().all(Option::is_none) {
+                return None;
+            }
+            Some(serde_json::Value::Array(
+                projected
+                    .into_iter()
+                    .map(|item| item.unwrap_or(serde_json::Value::Null))
+                    .collect(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Deep-merge a projected subtree into the accumulated projection
+fn merge_projection(into: &mut serde_json::Value, from: serde_json::Value) {
+    match (into, from) {
+        (serde_json::Value::Object(target), serde_json::Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(existing) => merge_projection(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (serde_json::Value::Array(target), serde_json::Value::Array(source)) => {
+            for (existing, value) in target.iter_mut().zip(source) {
+                merge_projection(existing, value);
+            }
+        }
+        (slot, value) if slot.is_null() => *slot = value,
+        _ => {}
+    }
+}
+
+/// JSON Schema (draft 2020-12) for the JSON export of [`ExportedPaper`]
+///
+/// The JSON counterpart of [`EXPORTED_PAPER_XSD`]. Objects allow additional
+/// properties so that exports from newer tool versions still validate.
+pub fn exported_paper_json_schema() -> serde_json::Value {
+    use serde_json::json;
+
+    let string_array = json!({ "type": "array", "items": { "type": "string" } });
+    let date_time = json!({ "type": "string", "format": "date-time" });
+    let nullable_string = json!({ "type": ["string", "null"] });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "https://github.com/akitenkrad/rs-academic-paper-interpreter/exported-paper.schema.json",
+        "title": "ExportedPaper",
+        "description": "学術論文の分析結果エクスポート（JSON形式）",
+        "type": "object",
+        "required": ["schema_version", "export_metadata", "paper"],
+        "properties": {
+            "schema_version": { "type": "string", "description": "スキーマバージョン" },
+            "export_metadata": { "$ref": "#/$defs/ExportMetadata" },
+            "paper": { "$ref": "#/$defs/AcademicPaper" },
+            "publication_status": { "$ref": "#/$defs/PublicationStatus" },
+            "source": {
+                "enum": ["ArXiv", "SemanticScholar", "CrossRef", "Dblp", "Both"],
+                "description": "論文の取得元（include_provenance 指定時のみ）"
+            },
+            "citations": { "$ref": "#/$defs/RelatedPapers" },
+            "references": { "$ref": "#/$defs/RelatedPapers" },
+            "keywords": { "$ref": "#/$defs/KeywordsData" },
+            "research_context": { "$ref": "#/$defs/ResearchContext" },
+            "section_summaries": {
+                "type": "array",
+                "items": { "$ref": "#/$defs/SectionSummary" }
+            },
+            "extracted_references": {
+                "type": "array",
+                "items": { "$ref": "#/$defs/ExtractedReference" }
+            }
+        },
+        "$defs": {
+            "PublicationStatus": { "enum": ["preprint", "published", "unknown"] },
+            "ExportMetadata": {
+                "type": "object",
+                "required": ["exported_at", "tool_version", "options", "warnings"],
+                "properties": {
+                    "exported_at": date_time,
+                    "tool_version": { "type": "string" },
+                    "options": {
+                        "type": "object",
+                        "required": [
+                            "analyzed", "text_extracted", "citations_included",
+                            "references_included", "keywords_extracted", "max_citations"
+                        ],
+                        "properties": {
+                            "analyzed": { "type": "boolean" },
+                            "text_extracted": { "type": "boolean" },
+                            "citations_included": { "type": "boolean" },
+                            "references_included": { "type": "boolean" },
+                            "keywords_extracted": { "type": "boolean" },
+                            "max_citations": { "type": "integer", "minimum": 0 },
+                            "llm_provider": { "type": "string" },
+                            "llm_model": { "type": "string" },
+                            "include_provenance": { "type": "boolean" }
+                        }
+                    },
+                    "warnings": string_array,
+                    "size_metrics": {
+                        "type": "object",
+                        "required": ["abstract_word_count"],
+                        "properties": {
+                            "abstract_word_count": { "type": "integer", "minimum": 0 },
+                            "full_text_word_count": { "type": "integer", "minimum": 0 },
+                            "estimated_reading_minutes": { "type": "integer", "minimum": 0 }
+                        }
+                    }
+                }
+            },
+            "Author": {
+                "type": "object",
+                "required": ["name"],
+                "properties": {
+                    "ss_id": { "type": "string" },
+                    "name": { "type": "string" },
+                    "h_index": { "type": "integer" },
+                    "affiliations": string_array,
+                    "paper_count": { "type": "integer" },
+                    "citation_count": { "type": "integer" }
+                }
+            },
+            "AcademicPaper": {
+                "type": "object",
+                "required": ["ss_id", "arxiv_id", "doi", "title", "authors", "abstract_text", "published_date"],
+                "properties": {
+                    "ss_id": { "type": "string" },
+                    "arxiv_id": { "type": "string" },
+                    "arxiv_version": { "type": "integer", "minimum": 1 },
+                    "doi": { "type": "string" },
+                    "title": { "type": "string" },
+                    "authors": { "type": "array", "items": { "$ref": "#/$defs/Author" } },
+                    "abstract_text": { "type": "string" },
+                    "abstract_text_ja": { "type": "string" },
+                    "abstract_text_original": { "type": "string" },
+                    "url": { "type": "string" },
+                    "journal": { "type": "string" },
+                    "primary_category": { "type": "string" },
+                    "categories": string_array,
+                    "published_date": date_time,
+                    "bibtex": { "type": "string" },
+                    "citations_count": { "type": "integer" },
+                    "references_count": { "type": "integer" },
+                    "influential_citation_count": { "type": "integer" },
+                    "metrics_known": { "type": "boolean" },
+                    "is_open_access": { "type": "boolean" },
+                    "open_access_pdf_url": nullable_string,
+                    "analysis": {
+                        "oneOf": [{ "type": "null" }, { "$ref": "#/$defs/PaperAnalysis" }]
+                    },
+                    "extracted_text": {
+                        "oneOf": [{ "type": "null" }, { "$ref": "#/$defs/PaperText" }]
+                    },
+                    "created_at": date_time,
+                    "updated_at": date_time
+                }
+            },
+            "PaperAnalysis": {
+                "type": "object",
+                "required": ["summary", "analyzed_at", "provider", "model"],
+                "properties": {
+                    "summary": { "type": "string" },
+                    "background_and_purpose": { "type": "string" },
+                    "methodology": { "type": "string" },
+                    "datasets": { "type": "array", "items": { "type": "object" } },
+                    "results": { "type": "string" },
+                    "advantages_limitations_and_future_work": { "type": "string" },
+                    "key_contributions": string_array,
+                    "tasks": string_array,
+                    "analyzed_at": date_time,
+                    "provider": { "type": "string" },
+                    "model": { "type": "string" },
+                    "warnings": string_array,
+                    "request_stats": {
+                        "type": "object",
+                        "properties": {
+                            "attempts": { "type": "integer", "minimum": 0 },
+                            "total_latency_ms": { "type": "integer", "minimum": 0 },
+                            "retried": { "type": "boolean" }
+                        }
+                    }
+                }
+            },
+            "PaperText": {
+                "type": "object",
+                "required": ["plain_text", "sections"],
+                "properties": {
+                    "plain_text": { "type": "string" },
+                    "sections": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "required": ["index", "title", "content"],
+                            "properties": {
+                                "index": { "type": "integer" },
+                                "title": { "type": "string" },
+                                "content": { "type": "string" },
+                                "importance": { "type": "string" },
+                                "math_content": nullable_string,
+                                "captions": { "type": ["array", "null"], "items": { "type": "string" } }
+                            }
+                        }
+                    },
+                    "markdown": { "type": "string" },
+                    "extracted_at": date_time,
+                    "source_url": { "type": "string" },
+                    "extracted_references": {
+                        "type": ["array", "null"],
+                        "items": { "$ref": "#/$defs/ExtractedReference" }
+                    }
+                }
+            },
+            "ExtractedReference": {
+                "type": "object",
+                "required": ["title"],
+                "properties": {
+                    "authors": string_array,
+                    "title": { "type": "string" },
+                    "year": { "type": ["integer", "null"] },
+                    "venue": nullable_string,
+                    "doi": nullable_string,
+                    "url": nullable_string,
+                    "arxiv_id": nullable_string,
+                    "volume": nullable_string,
+                    "pages": nullable_string
+                }
+            },
+            "PaperSummary": {
+                "type": "object",
+                "required": ["title"],
+                "properties": {
+                    "ss_id": { "type": "string" },
+                    "arxiv_id": { "type": "string" },
+                    "doi": { "type": "string" },
+                    "title": { "type": "string" },
+                    "authors": string_array,
+                    "year": { "type": "integer" },
+                    "venue": { "type": "string" },
+                    "citation_count": { "type": "integer" },
+                    "influential_citation_count": { "type": "integer" },
+                    "metrics_known": { "type": "boolean" },
+                    "abstract_snippet": { "type": "string" },
+                    "url": { "type": "string" }
+                }
+            },
+            "RelatedPapers": {
+                "description": "引用論文または参照論文の一覧と統計",
+                "type": "object",
+                "required": ["total_count", "fetched_count", "papers"],
+                "properties": {
+                    "total_count": { "type": "integer" },
+                    "fetched_count": { "type": "integer", "minimum": 0 },
+                    "papers": { "type": "array", "items": { "$ref": "#/$defs/PaperSummary" } },
+                    "statistics": { "type": "object" }
+                }
+            },
+            "KeywordsData": {
+                "type": "object",
+                "properties": {
+                    "keywords": string_array,
+                    "topics": string_array,
+                    "technical_terms": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "required": ["term"],
+                            "properties": {
+                                "term": { "type": "string" },
+                                "definition": { "type": "string" }
+                            }
+                        }
+                    },
+                    "methods": string_array,
+                    "datasets": string_array
+                }
+            },
+            "ResearchContext": {
+                "type": "object",
+                "properties": {
+                    "primary_field": { "type": "string" },
+                    "sub_fields": string_array,
+                    "research_type": { "type": "string" },
+                    "positioning": { "type": "string" },
+                    "related_directions": string_array
+                }
+            },
+            "SectionSummary": {
+                "type": "object",
+                "required": ["section_title", "summary"],
+                "properties": {
+                    "section_title": { "type": "string" },
+                    "summary": { "type": "string" }
+                }
+            }
+        }
+    })
+}
+
 /// Comprehensive exported paper data for AI/LLM consumption
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExportedPaper {
@@ -923,6 +1396,14 @@ pub struct ExportedPaper {
     /// Main paper data
     pub paper: AcademicPaper,
 
+    /// Preprint vs. published classification of the paper
+    #[serde(default)]
+    pub publication_status: PublicationStatus,
+
+    /// Source the paper came from (only with `ExportOptions::include_provenance`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<PaperSource>,
+
     /// Papers citing this paper (if requested)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub citations: Option<CitationData>,
@@ -938,6 +1419,17 @@ pub struct ExportedPaper {
     /// Research positioning context (if requested)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub research_context: Option<ResearchContext>,
+
+    /// Summaries of the key sections of the extracted text (if requested)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section_summaries: Option<Vec<SectionSummary>>,
+
+    /// References parsed from the PDF that are not in `references`
+    ///
+    /// Filled by [`collect_extracted_references`](Self::collect_extracted_references);
+    /// the only reference list for papers Semantic Scholar does not know.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extracted_references: Vec<ExtractedReference>,
 }
 
 impl ExportedPaper {
@@ -950,12 +1442,88 @@ impl ExportedPaper {
                 tool_version: env!("CARGO_PKG_VERSION").to_string(),
                 options,
                 warnings: Vec::new(),
+                size_metrics: PaperSizeMetrics::from_paper(&paper),
             },
+            publication_status: paper.publication_status(),
+            source: paper.source.filter(|_| options.include_provenance),
             paper,
             citations: None,
             references: None,
             keywords: None,
             research_context: None,
+            section_summaries: None,
+            extracted_references: Vec::new(),
+        }
+    }
+
+    /// Copy PDF-extracted references to `extracted_references`
+    ///
+    /// References already present in the Semantic Scholar `references`
+    /// (same DOI, or same title ignoring case and punctuation) are skipped,
+    /// as are duplicates within the extracted list. Call after text
+    /// extraction and reference fetching.
+    pub fn collect_extracted_references(&mut self) {
+        let Some(extracted) = self
+            .paper
+            .extracted_text
+            .as_ref()
+            .and_then(|text| text.extracted_references.as_ref())
+        else {
+            return;
+        };
+
+        let mut seen_dois: HashSet<String> = HashSet::new();
+        let mut seen_titles: HashSet<String> = HashSet::new();
+        for known in self.references.iter().flat_map(|r| &r.papers) {
+            if !known.doi.is_empty() {
+                seen_dois.insert(known.doi.to_lowercase());
+            }
+            seen_titles.insert(reference_title_key(&known.title));
+        }
+
+        self.extracted_references = extracted
+            .iter()
+            .filter(|r| {
+                let doi = r.doi.as_deref().unwrap_or("").trim().to_lowercase();
+                let title = reference_title_key(&r.title);
+                if (!doi.is_empty() && seen_dois.contains(&doi))
+                    || (!title.is_empty() && seen_titles.contains(&title))
+                {
+                    return false;
+                }
+                if !doi.is_empty() {
+                    seen_dois.insert(doi);
+                }
+                if !title.is_empty() {
+                    seen_titles.insert(title);
+                }
+                true
+            })
+            .cloned()
+            .collect();
+    }
+
+    /// Recompute `export_metadata.size_metrics` after `paper` was replaced or extended
+    pub fn refresh_size_metrics(&mut self) {
+        self.export_metadata.size_metrics = PaperSizeMetrics::from_paper(&self.paper);
+    }
+
+    /// Rebase the export's timestamps onto UTC
+    ///
+    /// Serialized timestamps then end in `Z`, so exports made in different
+    /// time zones diff cleanly. Calendar dates (`published_date`, arXiv
+    /// version dates) are left untouched.
+    pub fn normalize_timestamps_to_utc(&mut self) {
+        let metadata = &mut self.export_metadata;
+        metadata.exported_at = utc_instant(&metadata.exported_at);
+        let paper = &mut self.paper;
+        paper.created_at = utc_instant(&paper.created_at);
+        paper.updated_at = utc_instant(&paper.updated_at);
+        if let Some(ref mut analysis) = paper.analysis {
+            analysis.analyzed_at = utc_instant(&analysis.analyzed_at);
+        }
+        if let Some(ref mut text) = paper.extracted_text {
+            text.extracted_at = utc_instant(&text.extracted_at);
         }
     }
 
@@ -964,6 +1532,36 @@ impl ExportedPaper {
         self.export_metadata.warnings.push(warning);
     }
 
+    /// Load a previous JSON export
+    pub fn from_json_file(path: impl AsRef<Path>) -> AppResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AppError::InternalAppError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Requested stages whose results are not present yet
+    ///
+    /// Used to resume an interrupted export: stages whose output already
+    /// exists (analysis, extracted text, citations, ...) are skipped.
+    /// Metadata fetching and writing are not included.
+    pub fn pending_stages(&self, requested: &ExportOptions) -> Vec<ExportStage> {
+        requested
+            .planned_stages()
+            .into_iter()
+            .filter(|stage| match stage {
+                ExportStage::ExtractingText => !self.paper.has_extracted_text(),
+                ExportStage::Analyzing => !self.paper.is_analyzed(),
+                ExportStage::FetchingCitations => self.citations.is_none(),
+                ExportStage::FetchingReferences => self.references.is_none(),
+                ExportStage::SummarizingSections => self.section_summaries.is_none(),
+                ExportStage::ExtractingKeywords => self.keywords.is_none(),
+                ExportStage::FetchingMetadata | ExportStage::Writing => false,
+            })
+            .collect()
+    }
+
     /// Convert to XML format with all paper information
     pub fn to_xml(&self) -> String {
         let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
@@ -976,9 +1574,7 @@ impl ExportedPaper {
         xml.push_str("  <export-metadata>\n");
         xml.push_str(&format!(
             "    <exported-at>{}</exported-at>\n",
-            self.export_metadata
-                .exported_at
-                .format("%Y-%m-%dT%H:%M:%S%z")
+            export_timestamp(&self.export_metadata.exported_at)
         ));
         xml.push_str(&format!(
             "    <tool-version>{}</tool-version>\n",
@@ -1021,7 +1617,29 @@ impl ExportedPaper {
                 escape_xml(model)
             ));
         }
+        if self.export_metadata.options.include_provenance {
+            xml.push_str("      <include-provenance>true</include-provenance>\n");
+        }
         xml.push_str("    </options>\n");
+        let size = &self.export_metadata.size_metrics;
+        xml.push_str("    <size-metrics>\n");
+        xml.push_str(&format!(
+            "      <abstract-word-count>{}</abstract-word-count>\n",
+            size.abstract_word_count
+        ));
+        if let Some(words) = size.full_text_word_count {
+            xml.push_str(&format!(
+                "      <full-text-word-count>{}</full-text-word-count>\n",
+                words
+            ));
+        }
+        if let Some(minutes) = size.estimated_reading_minutes {
+            xml.push_str(&format!(
+                "      <estimated-reading-minutes>{}</estimated-reading-minutes>\n",
+                minutes
+            ));
+        }
+        xml.push_str("    </size-metrics>\n");
         if !self.export_metadata.warnings.is_empty() {
             xml.push_str("    <warnings>\n");
             for warning in &self.export_metadata.warnings {
@@ -1077,6 +1695,15 @@ impl ExportedPaper {
             xml.push_str("  </references>\n");
         }
 
+        // PDF-extracted references not covered by Semantic Scholar
+        if !self.extracted_references.is_empty() {
+            xml.push_str("\n  <extracted-references>\n");
+            for r in &self.extracted_references {
+                xml.push_str(&extracted_reference_to_xml(r, 4));
+            }
+            xml.push_str("  </extracted-references>\n");
+        }
+
         // Keywords
         if let Some(ref keywords) = self.keywords {
             xml.push_str("\n  <keywords-data>\n");
@@ -1152,6 +1779,24 @@ impl ExportedPaper {
             xml.push_str("  </research-context>\n");
         }
 
+        // Section summaries
+        if let Some(ref summaries) = self.section_summaries {
+            xml.push_str("\n  <section-summaries>\n");
+            for summary in summaries {
+                xml.push_str("    <section-summary>\n");
+                xml.push_str(&format!(
+                    "      <section-title>{}</section-title>\n",
+                    escape_xml(&summary.section_title)
+                ));
+                xml.push_str(&format!(
+                    "      <summary>{}</summary>\n",
+                    escape_xml(&summary.summary)
+                ));
+                xml.push_str("    </section-summary>\n");
+            }
+            xml.push_str("  </section-summaries>\n");
+        }
+
         xml.push_str("</exported-paper>");
         xml
     }
@@ -1240,6 +1885,12 @@ impl ExportedPaper {
                 escape_xml(&paper.abstract_text_ja)
             ));
         }
+        if !paper.abstract_text_original.is_empty() {
+            xml.push_str(&format!(
+                "      <abstract-original>{}</abstract-original>\n",
+                escape_xml(&paper.abstract_text_original)
+            ));
+        }
         xml.push_str(&format!("      <url>{}</url>\n", escape_xml(&paper.url)));
         if !paper.journal.is_empty() {
             xml.push_str(&format!(
@@ -1247,6 +1898,15 @@ impl ExportedPaper {
                 escape_xml(&paper.journal)
             ));
         }
+        xml.push_str(&format!(
+            "      <publication-status>{}</publication-status>\n",
+            paper.publication_status()
+        ));
+        if let Some(source) = self.source
+            && let Ok(serde_json::Value::String(name)) = serde_json::to_value(source)
+        {
+            xml.push_str(&format!("      <source>{}</source>\n", escape_xml(&name)));
+        }
         if !paper.primary_category.is_empty() {
             xml.push_str(&format!(
                 "      <primary-category>{}</primary-category>\n",
@@ -1351,7 +2011,7 @@ impl ExportedPaper {
             }
             xml.push_str(&format!(
                 "      <analyzed-at>{}</analyzed-at>\n",
-                analysis.analyzed_at.format("%Y-%m-%dT%H:%M:%S%z")
+                export_timestamp(&analysis.analyzed_at)
             ));
             xml.push_str(&format!(
                 "      <provider>{}</provider>\n",
@@ -1361,6 +2021,16 @@ impl ExportedPaper {
                 "      <model>{}</model>\n",
                 escape_xml(&analysis.model)
             ));
+            if !analysis.warnings.is_empty() {
+                xml.push_str("      <warnings>\n");
+                for warning in &analysis.warnings {
+                    xml.push_str(&format!(
+                        "        <warning>{}</warning>\n",
+                        escape_xml(warning)
+                    ));
+                }
+                xml.push_str("      </warnings>\n");
+            }
             xml.push_str("    </analysis>\n");
         }
 
@@ -1369,7 +2039,7 @@ impl ExportedPaper {
             xml.push_str("\n    <extracted-text>\n");
             xml.push_str(&format!(
                 "      <extracted-at>{}</extracted-at>\n",
-                text.extracted_at.format("%Y-%m-%dT%H:%M:%S%z")
+                export_timestamp(&text.extracted_at)
             ));
             xml.push_str(&format!(
                 "      <source-url>{}</source-url>\n",
@@ -1419,49 +2089,7 @@ impl ExportedPaper {
             {
                 xml.push_str("      <extracted-references>\n");
                 for r in refs {
-                    xml.push_str("        <reference>\n");
-                    if !r.authors.is_empty() {
-                        xml.push_str("          <authors>\n");
-                        for author in &r.authors {
-                            xml.push_str(&format!(
-                                "            <author>{}</author>\n",
-                                escape_xml(author)
-                            ));
-                        }
-                        xml.push_str("          </authors>\n");
-                    }
-                    xml.push_str(&format!(
-                        "          <title>{}</title>\n",
-                        escape_xml(&r.title)
-                    ));
-                    if let Some(year) = r.year {
-                        xml.push_str(&format!("          <year>{}</year>\n", year));
-                    }
-                    if let Some(ref venue) = r.venue {
-                        xml.push_str(&format!("          <venue>{}</venue>\n", escape_xml(venue)));
-                    }
-                    if let Some(ref doi) = r.doi {
-                        xml.push_str(&format!("          <doi>{}</doi>\n", escape_xml(doi)));
-                    }
-                    if let Some(ref url) = r.url {
-                        xml.push_str(&format!("          <url>{}</url>\n", escape_xml(url)));
-                    }
-                    if let Some(ref arxiv_id) = r.arxiv_id {
-                        xml.push_str(&format!(
-                            "          <arxiv-id>{}</arxiv-id>\n",
-                            escape_xml(arxiv_id)
-                        ));
-                    }
-                    if let Some(ref volume) = r.volume {
-                        xml.push_str(&format!(
-                            "          <volume>{}</volume>\n",
-                            escape_xml(volume)
-                        ));
-                    }
-                    if let Some(ref pages) = r.pages {
-                        xml.push_str(&format!("          <pages>{}</pages>\n", escape_xml(pages)));
-                    }
-                    xml.push_str("        </reference>\n");
+                    xml.push_str(&extracted_reference_to_xml(r, 8));
                 }
                 xml.push_str("      </extracted-references>\n");
             }
@@ -1471,11 +2099,11 @@ impl ExportedPaper {
         // Timestamps
         xml.push_str(&format!(
             "\n    <created-at>{}</created-at>\n",
-            paper.created_at.format("%Y-%m-%dT%H:%M:%S%z")
+            export_timestamp(&paper.created_at)
         ));
         xml.push_str(&format!(
             "    <updated-at>{}</updated-at>\n",
-            paper.updated_at.format("%Y-%m-%dT%H:%M:%S%z")
+            export_timestamp(&paper.updated_at)
         ));
 
         xml
@@ -1545,41 +2173,378 @@ impl ExportedPaper {
         xml.push_str(&format!("{}</paper-summary>\n", ind));
         xml
     }
-}
 
-/// Escape XML special characters
-fn escape_xml(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-        .replace('\'', "&apos;")
-}
+    /// Convert to a self-contained HTML report
+    ///
+    /// The page uses inline CSS and inline SVG charts only, so it renders
+    /// offline and can be shared as a single file.
+    pub fn to_html(&self) -> String {
+        let paper = &self.paper;
+        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"ja\">\n<head>\n");
+        html.push_str("<meta charset=\"UTF-8\">\n");
+        html.push_str(&format!("<title>{}</title>\n", escape_xml(&paper.title)));
+        html.push_str(&format!("<style>{}</style>\n", HTML_REPORT_CSS));
+        html.push_str("</head>\n<body>\n");
 
-/// Export operation metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExportMetadata {
-    /// When the export was performed
-    pub exported_at: DateTime<Local>,
+        // Header
+        html.push_str(&format!("<h1>{}</h1>\n", escape_xml(&paper.title)));
+        let authors: Vec<&str> = paper.authors.iter().map(|a| a.name.as_str()).collect();
+        html.push_str(&format!(
+            "<p class=\"authors\">{}</p>\n",
+            escape_xml(&authors.join(", "))
+        ));
+        html.push_str("<dl class=\"meta\">\n");
+        let mut meta = vec![
+            (
+                "Published",
+                paper.published_date.format("%Y-%m-%d").to_string(),
+            ),
+            ("Status", self.publication_status.to_string()),
+        ];
+        for (label, value) in [
+            ("Venue", &paper.journal),
+            ("arXiv", &paper.arxiv_id),
+            ("DOI", &paper.doi),
+        ] {
+            if !value.is_empty() {
+                meta.push((label, value.clone()));
+            }
+        }
+        if paper.metrics_known {
+            meta.push(("Citations", paper.citations_count.to_string()));
+        }
+        for (label, value) in meta {
+            html.push_str(&format!(
+                "<dt>{}</dt><dd>{}</dd>\n",
+                label,
+                escape_xml(&value)
+            ));
+        }
+        html.push_str("</dl>\n");
+        // Only web URLs become links, so e.g. `javascript:` URLs stay inert text
+        if is_web_url(&paper.url) {
+            html.push_str(&format!(
+                "<p><a href=\"{}\">{}</a></p>\n",
+                escape_xml(paper.url.trim()),
+                escape_xml(&paper.url)
+            ));
+        } else if !paper.url.is_empty() {
+            html.push_str(&format!("<p>{}</p>\n", escape_xml(&paper.url)));
+        }
 
-    /// Tool version
-    pub tool_version: String,
+        if !paper.abstract_text.is_empty() {
+            html.push_str("<h2>Abstract</h2>\n");
+            html.push_str(&format!("<p>{}</p>\n", escape_xml(&paper.abstract_text)));
+        }
 
-    /// Options used for this export
-    pub options: ExportOptions,
+        // Analysis
+        if let Some(ref analysis) = paper.analysis {
+            for (heading, text) in [
+                ("Summary", &analysis.summary),
+                ("Background and Purpose", &analysis.background_and_purpose),
+                ("Methodology", &analysis.methodology),
+                ("Results", &analysis.results),
+                (
+                    "Advantages, Limitations and Future Work",
+                    &analysis.advantages_limitations_and_future_work,
+                ),
+            ] {
+                if !text.is_empty() {
+                    html.push_str(&format!("<h2>{}</h2>\n", heading));
+                    html.push_str(&format!("<p>{}</p>\n", escape_xml(text)));
+                }
+            }
+            html_list(&mut html, "Key Contributions", &analysis.key_contributions);
+            let datasets: Vec<String> = analysis.datasets.iter().map(|d| d.name.clone()).collect();
+            html_list(&mut html, "Datasets", &datasets);
+            html_list(&mut html, "Tasks", &analysis.tasks);
+            html.push_str(&format!(
+                "<p class=\"note\">Analyzed by {} ({}) at {}</p>\n",
+                escape_xml(&analysis.provider),
+                escape_xml(&analysis.model),
+                analysis.analyzed_at.format("%Y-%m-%d %H:%M")
+            ));
+        }
 
-    /// Any warnings or notes about the export
-    pub warnings: Vec<String>,
-}
+        // Keyword tag cloud: earlier keywords are more relevant and drawn larger
+        if let Some(ref keywords) = self.keywords {
+            let tags: Vec<&String> = keywords.keywords.iter().chain(&keywords.topics).collect();
+            if !tags.is_empty() {
+                html.push_str("<h2>Keywords</h2>\n<div class=\"tags\">\n");
+                for (rank, tag) in tags.iter().enumerate() {
+                    let size = (1.6 - 0.1 * rank as f64).max(0.9);
+                    html.push_str(&format!(
+                        "<span class=\"tag\" style=\"font-size: {:.1}em\">{}</span>\n",
+                        size,
+                        escape_xml(tag)
+                    ));
+                }
+                html.push_str("</div>\n");
+            }
+        }
 
-/// Options used during export (for reproducibility)
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct ExportOptions {
-    /// Whether LLM analysis was performed
-    pub analyzed: bool,
+        // Citation network charts
+        if let Some(ref citations) = self.citations {
+            html.push_str(&format!(
+                "<h2>Citations by Year</h2>\n<p class=\"note\">{} of {} citing papers fetched</p>\n",
+                citations.fetched_count, citations.total_count
+            ));
+            html.push_str(&svg_year_histogram(&citations.statistics.by_year));
+        }
+        if let Some(ref references) = self.references {
+            html.push_str(&format!(
+                "<h2>References by Year</h2>\n<p class=\"note\">{} of {} references fetched</p>\n",
+                references.fetched_count, references.total_count
+            ));
+            html.push_str(&svg_year_histogram(&references.statistics.by_year));
+        }
 
-    /// Whether PDF text was extracted
-    pub text_extracted: bool,
+        let warnings: Vec<String> = self
+            .export_metadata
+            .warnings
+            .iter()
+            .chain(paper.analysis.iter().flat_map(|a| &a.warnings))
+            .cloned()
+            .collect();
+        html_list(&mut html, "Warnings", &warnings);
+
+        html.push_str(&format!(
+            "<footer>Exported {} by academic-paper-interpreter {}</footer>\n",
+            self.export_metadata.exported_at.format("%Y-%m-%d %H:%M"),
+            escape_xml(&self.export_metadata.tool_version)
+        ));
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+/// Stylesheet embedded in HTML reports
+const HTML_REPORT_CSS: &str = "body{font-family:system-ui,sans-serif;max-width:860px;\
+margin:2em auto;padding:0 1em;line-height:1.6;color:#222}\
+h1{font-size:1.6em;margin-bottom:0.2em}h2{font-size:1.2em;margin-top:1.6em;\
+border-bottom:1px solid #ddd}.authors{color:#555}\
+.meta{display:grid;grid-template-columns:max-content auto;gap:0.2em 1em}\
+.meta dt{font-weight:bold}.meta dd{margin:0}.note{color:#777;font-size:0.9em}\
+.tags{line-height:2.2}.tag{display:inline-block;margin:0 0.3em;padding:0 0.5em;\
+background:#eef3fb;border-radius:4px}.chart rect{fill:#4a78c2}\
+.chart text{font-size:10px;fill:#444}footer{margin-top:3em;color:#999;font-size:0.8em}";
+
+/// Drawing area of the year histograms in HTML reports (pixels)
+const CHART_BAR_AREA_HEIGHT: u32 = 120;
+const CHART_BAR_WIDTH: u32 = 28;
+const CHART_BAR_GAP: u32 = 6;
+const CHART_LABEL_HEIGHT: u32 = 32;
+
+/// Append a headed bullet list to an HTML report (nothing if `items` is empty)
+fn html_list(html: &mut String, heading: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    html.push_str(&format!("<h2>{}</h2>\n<ul>\n", heading));
+    for item in items {
+        html.push_str(&format!("<li>{}</li>\n", escape_xml(item)));
+    }
+    html.push_str("</ul>\n");
+}
+
+/// Scale counts to bar heights, the largest count filling `max_height`
+///
+/// Non-zero counts always get at least one pixel so they stay visible.
+fn scaled_bar_heights(counts: &[usize], max_height: u32) -> Vec<u32> {
+    let max_count = counts.iter().copied().max().unwrap_or(0);
+    counts
+        .iter()
+        .map(|&count| {
+            if count == 0 || max_count == 0 {
+                0
+            } else {
+                ((count as f64 / max_count as f64) * max_height as f64)
+                    .round()
+                    .max(1.0) as u32
+            }
+        })
+        .collect()
+}
+
+/// Render a year distribution as an inline SVG bar chart, oldest year first
+fn svg_year_histogram(by_year: &HashMap<i32, usize>) -> String {
+    if by_year.is_empty() {
+        return "<p class=\"note\">No dated papers.</p>\n".to_string();
+    }
+    let mut years: Vec<(i32, usize)> = by_year.iter().map(|(&y, &c)| (y, c)).collect();
+    years.sort();
+    let counts: Vec<usize> = years.iter().map(|&(_, count)| count).collect();
+    let heights = scaled_bar_heights(&counts, CHART_BAR_AREA_HEIGHT);
+
+    let step = CHART_BAR_WIDTH + CHART_BAR_GAP;
+    let width = step * years.len() as u32 + CHART_BAR_GAP;
+    let height = CHART_BAR_AREA_HEIGHT + CHART_LABEL_HEIGHT;
+    let mut svg = format!(
+        "<svg class=\"chart\" xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+        width, height, width, height
+    );
+    for (i, ((year, count), bar_height)) in years.iter().zip(heights).enumerate() {
+        let x = CHART_BAR_GAP + step * i as u32;
+        let center = x + CHART_BAR_WIDTH / 2;
+        svg.push_str(&format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"><title>{}: {}</title></rect>\n",
+            x,
+            CHART_BAR_AREA_HEIGHT - bar_height,
+            CHART_BAR_WIDTH,
+            bar_height,
+            year,
+            count
+        ));
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n",
+            center,
+            CHART_BAR_AREA_HEIGHT + 12,
+            count
+        ));
+        svg.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n",
+            center,
+            CHART_BAR_AREA_HEIGHT + 26,
+            year
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Convert a PDF-extracted reference to an XML `<reference>` element
+fn extracted_reference_to_xml(r: &ExtractedReference, indent: usize) -> String {
+    let ind = " ".repeat(indent);
+    let mut xml = format!("{}<reference>\n", ind);
+    if !r.authors.is_empty() {
+        xml.push_str(&format!("{}  <authors>\n", ind));
+        for author in &r.authors {
+            xml.push_str(&format!(
+                "{}    <author>{}</author>\n",
+                ind,
+                escape_xml(author)
+            ));
+        }
+        xml.push_str(&format!("{}  </authors>\n", ind));
+    }
+    xml.push_str(&format!(
+        "{}  <title>{}</title>\n",
+        ind,
+        escape_xml(&r.title)
+    ));
+    if let Some(year) = r.year {
+        xml.push_str(&format!("{}  <year>{}</year>\n", ind, year));
+    }
+    for (tag, value) in [
+        ("venue", &r.venue),
+        ("doi", &r.doi),
+        ("url", &r.url),
+        ("arxiv-id", &r.arxiv_id),
+        ("volume", &r.volume),
+        ("pages", &r.pages),
+    ] {
+        if let Some(value) = value {
+            xml.push_str(&format!(
+                "{}  <{}>{}</{}>\n",
+                ind,
+                tag,
+                escape_xml(value),
+                tag
+            ));
+        }
+    }
+    xml.push_str(&format!("{}</reference>\n", ind));
+    xml
+}
+
+/// Lowercased alphanumerics of a title, for duplicate detection
+fn reference_title_key(title: &str) -> String {
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Format a timestamp for export as RFC 3339 (`Z` suffix when in UTC)
+pub fn export_timestamp(dt: &DateTime<Local>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// The same instant with a zero UTC offset
+fn utc_instant(dt: &DateTime<Local>) -> DateTime<Local> {
+    DateTime::from_naive_utc_and_offset(dt.naive_utc(), Utc.fix())
+}
+
+/// Whether `url` uses the http or https scheme
+fn is_web_url(url: &str) -> bool {
+    let url = url.trim().to_ascii_lowercase();
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+/// Escape XML special characters
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Export operation metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportMetadata {
+    /// When the export was performed
+    pub exported_at: DateTime<Local>,
+
+    /// Tool version
+    pub tool_version: String,
+
+    /// Options used for this export
+    pub options: ExportOptions,
+
+    /// Any warnings or notes about the export
+    pub warnings: Vec<String>,
+
+    /// Length of the exported paper
+    #[serde(default)]
+    pub size_metrics: PaperSizeMetrics,
+}
+
+/// Word counts and reading time of a paper, for triage
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaperSizeMetrics {
+    /// Words in the abstract
+    pub abstract_word_count: usize,
+
+    /// Words in the extracted full text (if extracted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_text_word_count: Option<usize>,
+
+    /// Estimated minutes to read the full text (if extracted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_reading_minutes: Option<u32>,
+}
+
+impl PaperSizeMetrics {
+    /// Compute the metrics of a paper
+    pub fn from_paper(paper: &AcademicPaper) -> Self {
+        Self {
+            abstract_word_count: paper.abstract_word_count(),
+            full_text_word_count: paper.full_text_word_count(),
+            estimated_reading_minutes: paper.estimated_reading_minutes(),
+        }
+    }
+}
+
+/// Options used during export (for reproducibility)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportOptions {
+    /// Whether LLM analysis was performed
+    pub analyzed: bool,
+
+    /// Whether PDF text was extracted
+    pub text_extracted: bool,
 
     /// Whether citations were included
     pub citations_included: bool,
@@ -1590,6 +2555,10 @@ pub struct ExportOptions {
     /// Whether keywords were extracted
     pub keywords_extracted: bool,
 
+    /// Whether key sections were summarized
+    #[serde(default)]
+    pub sections_summarized: bool,
+
     /// Maximum number of citations/references fetched
     pub max_citations: usize,
 
@@ -1600,6 +2569,136 @@ pub struct ExportOptions {
     /// LLM model used (if any)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub llm_model: Option<String>,
+
+    /// Whether the paper's source (provenance) was recorded
+    #[serde(default)]
+    pub include_provenance: bool,
+}
+
+impl ExportOptions {
+    /// Carry over the stages and LLM settings of a previous export
+    ///
+    /// Stage flags are OR-ed, so a resumed export still records the work done
+    /// by the earlier run; LLM settings are kept unless set again.
+    pub fn merge_from(&mut self, previous: &ExportOptions) {
+        self.analyzed |= previous.analyzed;
+        self.text_extracted |= previous.text_extracted;
+        self.citations_included |= previous.citations_included;
+        self.references_included |= previous.references_included;
+        self.keywords_extracted |= previous.keywords_extracted;
+        self.sections_summarized |= previous.sections_summarized;
+        self.max_citations = self.max_citations.max(previous.max_citations);
+        self.include_provenance |= previous.include_provenance;
+        if self.llm_provider.is_none() {
+            self.llm_provider = previous.llm_provider.clone();
+        }
+        if self.llm_model.is_none() {
+            self.llm_model = previous.llm_model.clone();
+        }
+    }
+
+    /// Stages an export with these options runs, in execution order
+    pub fn planned_stages(&self) -> Vec<ExportStage> {
+        let mut stages = vec![ExportStage::FetchingMetadata];
+        if self.text_extracted {
+            stages.push(ExportStage::ExtractingText);
+        }
+        if self.analyzed {
+            stages.push(ExportStage::Analyzing);
+        }
+        if self.sections_summarized {
+            stages.push(ExportStage::SummarizingSections);
+        }
+        if self.citations_included {
+            stages.push(ExportStage::FetchingCitations);
+        }
+        if self.references_included {
+            stages.push(ExportStage::FetchingReferences);
+        }
+        if self.keywords_extracted {
+            stages.push(ExportStage::ExtractingKeywords);
+        }
+        stages.push(ExportStage::Writing);
+        stages
+    }
+}
+
+/// A stage of the export pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportStage {
+    /// Looking up paper metadata
+    FetchingMetadata,
+    /// Extracting full text from the PDF
+    ExtractingText,
+    /// Running LLM analysis
+    Analyzing,
+    /// Summarizing key sections of the extracted text
+    SummarizingSections,
+    /// Fetching citing papers
+    FetchingCitations,
+    /// Fetching referenced papers
+    FetchingReferences,
+    /// Extracting keywords and research context
+    ExtractingKeywords,
+    /// Serializing and writing the output file
+    Writing,
+}
+
+impl ExportStage {
+    /// Human-readable label passed to progress callbacks
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::FetchingMetadata => "Fetching metadata…",
+            Self::ExtractingText => "Extracting text…",
+            Self::Analyzing => "Analyzing…",
+            Self::SummarizingSections => "Summarizing sections…",
+            Self::FetchingCitations => "Fetching citations…",
+            Self::FetchingReferences => "Fetching references…",
+            Self::ExtractingKeywords => "Extracting keywords…",
+            Self::Writing => "Writing output…",
+        }
+    }
+}
+
+/// Callback type invoked with each stage label
+type ProgressCallback = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Progress hook for the export pipeline
+///
+/// The callback receives the label of each stage as it starts. Without a
+/// callback, reporting is a no-op, so the library does not depend on any
+/// particular progress UI.
+#[derive(Default)]
+pub struct ExportProgress {
+    callback: Option<ProgressCallback>,
+}
+
+impl ExportProgress {
+    /// Create a progress hook with no callback
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the callback invoked with each stage label
+    pub fn with_progress(mut self, callback: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Report that a stage has started
+    pub fn report(&self, stage: ExportStage) {
+        if let Some(callback) = &self.callback {
+            callback(stage.label());
+        }
+    }
+}
+
+impl std::fmt::Debug for ExportProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExportProgress")
+            .field("callback", &self.callback.is_some())
+            .finish()
+    }
 }
 
 /// Citation network data
@@ -1634,6 +2733,193 @@ pub struct ReferenceData {
     pub statistics: ReferenceStatistics,
 }
 
+/// Related-work data (Semantic Scholar recommendations or keyword fallback)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedData {
+    /// Number of related papers fetched
+    pub fetched_count: usize,
+
+    /// Related papers (simplified)
+    pub papers: Vec<PaperSummary>,
+
+    /// Statistics over the related papers
+    pub statistics: CitationStatistics,
+}
+
+impl RelatedData {
+    /// Summarize related papers and compute their statistics
+    pub fn from_papers(papers: &[AcademicPaper]) -> Self {
+        let summaries: Vec<PaperSummary> = papers
+            .iter()
+            .map(PaperSummary::from_academic_paper)
+            .collect();
+        let statistics = CitationStatistics::from_papers(&summaries);
+        Self {
+            fetched_count: summaries.len(),
+            papers: summaries,
+            statistics,
+        }
+    }
+}
+
+/// Citation network around a paper, as crawled by `PaperClient::citation_network`
+///
+/// The first node is the root paper. Each edge is a `(citing, cited)` pair of
+/// node IDs as returned by [`CitationGraph::node_id`]. A saved graph with
+/// `pending` nodes can be continued with `PaperClient::resume_citation_network`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CitationGraph {
+    /// Papers in the network, root first
+    pub nodes: Vec<PaperSummary>,
+
+    /// Directed citation edges (citing paper ID, cited paper ID)
+    pub edges: Vec<(String, String)>,
+
+    /// Nodes whose citing papers are still to be fetched, with their hop
+    /// distance from the root; empty once a crawl has completed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending: Vec<(String, usize)>,
+}
+
+impl CitationGraph {
+    /// Graph holding only `root`, pending expansion
+    pub fn from_root(root: &AcademicPaper) -> AppResult<Self> {
+        let summary = PaperSummary::from_academic_paper(root);
+        let root_id = Self::node_id(&summary).ok_or_else(|| {
+            AppError::PaperNotFound("Paper has no identifier to crawl citations from".to_string())
+        })?;
+        Ok(Self {
+            nodes: vec![summary],
+            edges: Vec::new(),
+            pending: vec![(root_id, 0)],
+        })
+    }
+
+    /// Identifier of the root node
+    pub fn root_id(&self) -> Option<String> {
+        self.nodes.first().and_then(Self::node_id)
+    }
+
+    /// Stable identifier of a node: Semantic Scholar ID, else arXiv ID, else DOI
+    pub fn node_id(paper: &PaperSummary) -> Option<String> {
+        if !paper.ss_id.is_empty() {
+            Some(paper.ss_id.clone())
+        } else if !paper.arxiv_id.is_empty() {
+            Some(format!("arXiv:{}", paper.arxiv_id))
+        } else if !paper.doi.is_empty() {
+            Some(format!("DOI:{}", paper.doi))
+        } else {
+            None
+        }
+    }
+
+    /// Render the graph as GraphML (for Gephi, yEd, NetworkX, ...)
+    ///
+    /// Nodes carry a truncated title label, the publication year and the
+    /// citation count; edges point from the citing to the cited paper.
+    pub fn to_graphml(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        xml.push_str(
+            "  <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n",
+        );
+        xml.push_str("  <key id=\"year\" for=\"node\" attr.name=\"year\" attr.type=\"int\"/>\n");
+        xml.push_str(
+            "  <key id=\"citations\" for=\"node\" attr.name=\"citation_count\" attr.type=\"int\"/>\n",
+        );
+        xml.push_str("  <graph id=\"citations\" edgedefault=\"directed\">\n");
+
+        for (id, paper) in self.identified_nodes() {
+            xml.push_str(&format!("    <node id=\"{}\">\n", escape_xml(&id)));
+            xml.push_str(&format!(
+                "      <data key=\"label\">{}</data>\n",
+                escape_xml(&graph_label(paper, &id))
+            ));
+            if paper.year > 0 {
+                xml.push_str(&format!("      <data key=\"year\">{}</data>\n", paper.year));
+            }
+            xml.push_str(&format!(
+                "      <data key=\"citations\">{}</data>\n",
+                paper.citation_count
+            ));
+            xml.push_str("    </node>\n");
+        }
+
+        for (index, (citing, cited)) in self.edges.iter().enumerate() {
+            xml.push_str(&format!(
+                "    <edge id=\"e{}\" source=\"{}\" target=\"{}\"/>\n",
+                index,
+                escape_xml(citing),
+                escape_xml(cited)
+            ));
+        }
+
+        xml.push_str("  </graph>\n");
+        xml.push_str("</graphml>\n");
+        xml
+    }
+
+    /// Render the graph in Graphviz DOT syntax
+    ///
+    /// Node IDs are quoted and labelled with truncated titles; edges point
+    /// from the citing to the cited paper.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph citations {\n");
+        dot.push_str("  rankdir=BT;\n");
+        dot.push_str("  node [shape=box];\n");
+
+        for (id, paper) in self.identified_nodes() {
+            dot.push_str(&format!(
+                "  \"{}\" [label=\"{}\"];\n",
+                escape_dot(&id),
+                escape_dot(&graph_label(paper, &id))
+            ));
+        }
+        for (citing, cited) in &self.edges {
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\";\n",
+                escape_dot(citing),
+                escape_dot(cited)
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Nodes paired with their IDs, skipping nodes without any identifier
+    fn identified_nodes(&self) -> impl Iterator<Item = (String, &PaperSummary)> {
+        self.nodes
+            .iter()
+            .filter_map(|paper| Self::node_id(paper).map(|id| (id, paper)))
+    }
+}
+
+/// Maximum number of title characters used as a graph node label
+const GRAPH_LABEL_MAX_CHARS: usize = 60;
+
+/// Node label for graph output: the title truncated to [`GRAPH_LABEL_MAX_CHARS`]
+fn graph_label(paper: &PaperSummary, id: &str) -> String {
+    let title = paper.title.trim();
+    if title.is_empty() {
+        return id.to_string();
+    }
+    if title.chars().count() <= GRAPH_LABEL_MAX_CHARS {
+        return title.to_string();
+    }
+    let truncated: String = title.chars().take(GRAPH_LABEL_MAX_CHARS - 3).collect();
+    format!("{}...", truncated.trim_end())
+}
+
+/// Escape a string for use inside a double-quoted DOT identifier
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\r', "")
+        .replace('\n', "\\n")
+}
+
 /// Simplified paper representation for citations/references
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PaperSummary {
@@ -1652,7 +2938,7 @@ pub struct PaperSummary {
     /// Author names (just names for brevity)
     pub authors: Vec<String>,
 
-    /// Publication year
+    /// Publication year (0 if unknown)
     pub year: i32,
 
     /// Journal or venue name
@@ -1664,6 +2950,10 @@ pub struct PaperSummary {
     /// Influential citation count
     pub influential_citation_count: i32,
 
+    /// Whether the counts above were reported by a source (false = unknown)
+    #[serde(default = "crate::models::default_metrics_known")]
+    pub metrics_known: bool,
+
     /// Brief abstract (truncated if too long)
     pub abstract_snippet: String,
 
@@ -1671,13 +2961,25 @@ pub struct PaperSummary {
     pub url: String,
 }
 
+/// Default number of abstract characters kept in [`PaperSummary::abstract_snippet`]
+pub const DEFAULT_ABSTRACT_SNIPPET_CHARS: usize = 500;
+
 impl PaperSummary {
     /// Create a PaperSummary from AcademicPaper
+    ///
+    /// The abstract is cut to [`DEFAULT_ABSTRACT_SNIPPET_CHARS`] characters.
     pub fn from_academic_paper(paper: &AcademicPaper) -> Self {
-        let abstract_snippet = if paper.abstract_text.len() > 500 {
-            format!("{}...", &paper.abstract_text[..500])
-        } else {
-            paper.abstract_text.clone()
+        Self::from_academic_paper_with_limit(paper, DEFAULT_ABSTRACT_SNIPPET_CHARS)
+    }
+
+    /// Create a PaperSummary, keeping at most `max_chars` abstract characters
+    ///
+    /// Characters, not bytes, are counted, so multibyte abstracts are never
+    /// split inside a character. A cut snippet ends with "...".
+    pub fn from_academic_paper_with_limit(paper: &AcademicPaper, max_chars: usize) -> Self {
+        let abstract_snippet = match paper.abstract_text.char_indices().nth(max_chars) {
+            Some((end, _)) => format!("{}...", &paper.abstract_text[..end]),
+            None => paper.abstract_text.clone(),
         };
 
         Self {
@@ -1686,31 +2988,180 @@ impl PaperSummary {
             doi: paper.doi.clone(),
             title: paper.title.clone(),
             authors: paper.authors.iter().map(|a| a.name.clone()).collect(),
-            year: paper
-                .published_date
-                .format("%Y")
-                .to_string()
-                .parse()
-                .unwrap_or(0),
+            year: paper.known_year().unwrap_or(0),
             venue: paper.journal.clone(),
             citation_count: paper.citations_count,
             influential_citation_count: paper.influential_citation_count,
+            metrics_known: paper.metrics_known,
             abstract_snippet,
             url: paper.url.clone(),
         }
     }
 }
 
+/// Canonical names of common ML/NLP/CV venues with their aliases
+///
+/// Each entry is `(canonical, acronyms, phrases)`. Acronyms are matched
+/// against whole words (ignoring attached years such as "ICML'20"), phrases
+/// against whole words of the lowercased venue name that are not continued by
+/// another word ("... Machine Learning and Applications" is not ICML). More
+/// specific entries come first.
+const VENUE_ALIASES: &[(&str, &[&str], &[&str])] = &[
+    (
+        "NeurIPS",
+        &["neurips", "nips"],
+        &["neural information processing systems"],
+    ),
+    (
+        "ICML",
+        &["icml"],
+        &["international conference on machine learning"],
+    ),
+    (
+        "ICLR",
+        &["iclr"],
+        &["international conference on learning representations"],
+    ),
+    (
+        "TACL",
+        &["tacl"],
+        &["transactions of the association for computational linguistics"],
+    ),
+    (
+        "NAACL",
+        &["naacl"],
+        &["north american chapter of the association for computational linguistics"],
+    ),
+    (
+        "EACL",
+        &["eacl"],
+        &["european chapter of the association for computational linguistics"],
+    ),
+    (
+        "EMNLP",
+        &["emnlp"],
+        &["empirical methods in natural language processing"],
+    ),
+    (
+        "ACL",
+        &["acl"],
+        &["annual meeting of the association for computational linguistics"],
+    ),
+    (
+        "COLING",
+        &["coling"],
+        &["international conference on computational linguistics"],
+    ),
+    (
+        "AAAI",
+        &["aaai"],
+        &["aaai conference on artificial intelligence"],
+    ),
+    (
+        "IJCAI",
+        &["ijcai"],
+        &["international joint conference on artificial intelligence"],
+    ),
+    (
+        "CVPR",
+        &["cvpr"],
+        &["computer vision and pattern recognition"],
+    ),
+    (
+        "ICCV",
+        &["iccv"],
+        &["international conference on computer vision"],
+    ),
+    (
+        "ECCV",
+        &["eccv"],
+        &["european conference on computer vision"],
+    ),
+    (
+        "TPAMI",
+        &["tpami", "pami"],
+        &["pattern analysis and machine intelligence"],
+    ),
+    ("JMLR", &["jmlr"], &["journal of machine learning research"]),
+    (
+        "KDD",
+        &["kdd", "sigkdd"],
+        &["knowledge discovery and data mining"],
+    ),
+    (
+        "SIGIR",
+        &["sigir"],
+        &["research and development in information retrieval"],
+    ),
+    (
+        "WWW",
+        &[],
+        &["the web conference", "world wide web conference"],
+    ),
+    ("arXiv", &["arxiv"], &[]),
+];
+
+/// Map a venue name to a canonical form so aliases are counted together
+///
+/// "NeurIPS", "NIPS 2017" and "Advances in Neural Information Processing
+/// Systems" all become "NeurIPS". Workshops are kept apart from the main
+/// venue ("NeurIPS 2020 Workshop on ..." becomes "NeurIPS Workshop"). Venues
+/// not in the alias table are returned trimmed with whitespace collapsed.
+pub fn normalize_venue(raw: &str) -> String {
+    let collapsed = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lower = collapsed.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.trim_end_matches(|c: char| c.is_ascii_digit()))
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let is_workshop = words
+        .iter()
+        .any(|word| *word == "workshop" || *word == "workshops");
+
+    VENUE_ALIASES
+        .iter()
+        .find(|(_, acronyms, phrases)| {
+            acronyms.iter().any(|acronym| words.contains(acronym))
+                || phrases
+                    .iter()
+                    .any(|phrase| contains_venue_phrase(&lower, phrase))
+        })
+        .map_or(collapsed, |(canonical, _, _)| {
+            if is_workshop {
+                format!("{} Workshop", canonical)
+            } else {
+                canonical.to_string()
+            }
+        })
+}
+
+/// Check whether `phrase` occurs in `lower` as whole words, followed by
+/// nothing, a volume number, punctuation or "workshop(s)" but no other word
+fn contains_venue_phrase(lower: &str, phrase: &str) -> bool {
+    lower.match_indices(phrase).any(|(start, _)| {
+        let starts_word = lower[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        let rest = lower[start + phrase.len()..].trim_start();
+        let ends_name =
+            rest.starts_with("workshop") || rest.chars().next().is_none_or(|c| !c.is_alphabetic());
+        starts_word && ends_name
+    })
+}
+
 /// Statistics about citations
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CitationStatistics {
     /// Year distribution of citations
     pub by_year: HashMap<i32, usize>,
 
-    /// Top venues citing this paper
+    /// Top venues citing this paper (aliases merged by [`normalize_venue`])
     pub top_venues: Vec<(String, usize)>,
 
-    /// Average citations of citing papers
+    /// Average citations of citing papers (papers with unknown metrics excluded)
     pub avg_citation_count: f64,
 
     /// Most influential citing papers (top 5 by citation count)
@@ -1723,15 +3174,20 @@ impl CitationStatistics {
         let mut by_year: HashMap<i32, usize> = HashMap::new();
         let mut venues: HashMap<String, usize> = HashMap::new();
         let mut total_citations = 0i64;
+        let mut known_count = 0usize;
 
         for paper in papers {
             if paper.year > 0 {
                 *by_year.entry(paper.year).or_insert(0) += 1;
             }
             if !paper.venue.is_empty() {
-                *venues.entry(paper.venue.clone()).or_insert(0) += 1;
+                *venues.entry(normalize_venue(&paper.venue)).or_insert(0) += 1;
+            }
+            // Unknown counts are not zero citations; leave them out of the average
+            if paper.metrics_known {
+                total_citations += paper.citation_count as i64;
+                known_count += 1;
             }
-            total_citations += paper.citation_count as i64;
         }
 
         // Sort venues by count and take top 10
@@ -1748,10 +3204,10 @@ impl CitationStatistics {
             .map(|p| p.title.clone())
             .collect();
 
-        let avg_citation_count = if papers.is_empty() {
+        let avg_citation_count = if known_count == 0 {
             0.0
         } else {
-            total_citations as f64 / papers.len() as f64
+            total_citations as f64 / known_count as f64
         };
 
         Self {
@@ -1772,26 +3228,41 @@ pub struct ReferenceStatistics {
     /// Oldest and newest reference years
     pub year_range: Option<(i32, i32)>,
 
-    /// Top venues referenced
+    /// Top venues referenced (aliases merged by [`normalize_venue`])
     pub top_venues: Vec<(String, usize)>,
+
+    /// Median publication year of the references
+    #[serde(default)]
+    pub median_reference_year: Option<i32>,
+
+    /// Fraction of dated references published within 5 years of the citing paper
+    #[serde(default)]
+    pub references_last_5yr_ratio: f64,
 }
 
+/// Window used for `ReferenceStatistics::references_last_5yr_ratio`
+const RECENT_REFERENCE_YEARS: i32 = 5;
+
 impl ReferenceStatistics {
-    /// Calculate statistics from a list of papers
-    pub fn from_papers(papers: &[PaperSummary]) -> Self {
+    /// Calculate statistics from the references of a paper published in `citing_year`
+    ///
+    /// When `citing_year` is unknown (0), recency is measured against the newest reference.
+    pub fn from_papers(papers: &[PaperSummary], citing_year: i32) -> Self {
         let mut by_year: HashMap<i32, usize> = HashMap::new();
         let mut venues: HashMap<String, usize> = HashMap::new();
         let mut min_year: Option<i32> = None;
         let mut max_year: Option<i32> = None;
+        let mut years: Vec<i32> = Vec::new();
 
         for paper in papers {
             if paper.year > 0 {
+                years.push(paper.year);
                 *by_year.entry(paper.year).or_insert(0) += 1;
                 min_year = Some(min_year.map_or(paper.year, |m| m.min(paper.year)));
                 max_year = Some(max_year.map_or(paper.year, |m| m.max(paper.year)));
             }
             if !paper.venue.is_empty() {
-                *venues.entry(paper.venue.clone()).or_insert(0) += 1;
+                *venues.entry(normalize_venue(&paper.venue)).or_insert(0) += 1;
             }
         }
 
@@ -1805,10 +3276,35 @@ impl ReferenceStatistics {
             _ => None,
         };
 
+        years.sort_unstable();
+        let median_reference_year = match years.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(years[n / 2]),
+            n => Some((years[n / 2 - 1] + years[n / 2]) / 2),
+        };
+
+        let reference_year = if citing_year > 0 {
+            Some(citing_year)
+        } else {
+            max_year
+        };
+        let references_last_5yr_ratio = match reference_year {
+            Some(reference_year) if !years.is_empty() => {
+                let recent = years
+                    .iter()
+                    .filter(|&&year| reference_year - year <= RECENT_REFERENCE_YEARS)
+                    .count();
+                recent as f64 / years.len() as f64
+            }
+            _ => 0.0,
+        };
+
         Self {
             by_year,
             year_range,
             top_venues,
+            median_reference_year,
+            references_last_5yr_ratio,
         }
     }
 }
@@ -1843,6 +3339,16 @@ pub struct TechnicalTerm {
     pub definition: Option<String>,
 }
 
+/// LLM summary of one section of the extracted text
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionSummary {
+    /// Title of the summarized section
+    pub section_title: String,
+
+    /// 1-2 sentence summary
+    pub summary: String,
+}
+
 /// Research field positioning
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResearchContext {
@@ -1862,9 +3368,328 @@ pub struct ResearchContext {
     pub related_directions: Vec<String>,
 }
 
+/// Index of a batch export written to a directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportManifest {
+    /// When the batch was exported
+    pub exported_at: DateTime<Local>,
+
+    /// Version of the tool that produced the batch
+    pub tool_version: String,
+
+    /// One entry per written file, in search result order
+    pub papers: Vec<ManifestEntry>,
+}
+
+impl ExportManifest {
+    /// Create a manifest for the given entries
+    pub fn new(papers: Vec<ManifestEntry>) -> Self {
+        Self {
+            exported_at: Local::now(),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            papers,
+        }
+    }
+
+    /// Rebase `exported_at` onto UTC (see [`ExportedPaper::normalize_timestamps_to_utc`])
+    pub fn normalize_timestamps_to_utc(&mut self) {
+        self.exported_at = utc_instant(&self.exported_at);
+    }
+}
+
+/// Manifest entry for one exported paper
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// File name relative to the output directory
+    pub file: String,
+
+    /// Paper title
+    pub title: String,
+
+    /// arXiv ID (empty if unknown)
+    pub arxiv_id: String,
+
+    /// Semantic Scholar ID (empty if unknown)
+    pub ss_id: String,
+
+    /// DOI (empty if unknown)
+    pub doi: String,
+
+    /// Number of export warnings for this paper
+    pub warning_count: usize,
+}
+
+impl ManifestEntry {
+    /// Create an entry for an exported paper written to `file`
+    pub fn from_exported(file: impl Into<String>, exported: &ExportedPaper) -> Self {
+        Self {
+            file: file.into(),
+            title: exported.paper.title.clone(),
+            arxiv_id: exported.paper.arxiv_id.clone(),
+            ss_id: exported.paper.ss_id.clone(),
+            doi: exported.paper.doi.clone(),
+            warning_count: exported.export_metadata.warnings.len(),
+        }
+    }
+}
+
+/// File stem for a paper in a batch export
+///
+/// Uses the arXiv ID, then the Semantic Scholar ID, then the DOI, sanitized
+/// with [`sanitize_file_stem`]. Falls back to `"paper"` when none is known.
+pub fn export_file_stem(paper: &AcademicPaper) -> String {
+    let id = [&paper.arxiv_id, &paper.ss_id, &paper.doi]
+        .into_iter()
+        .find(|id| !id.trim().is_empty());
+    match id.map(|id| sanitize_file_stem(id)) {
+        Some(stem) if !stem.is_empty() => stem,
+        _ => "paper".to_string(),
+    }
+}
+
+/// Make an identifier safe to use as a file name
+///
+/// Characters other than ASCII alphanumerics, `.`, `-` and `_` (notably the
+/// `/` in old-style arXiv IDs such as `cs.CL/0001001` and in DOIs) are
+/// replaced with `_`. Leading dots are stripped so the file is not hidden.
+pub fn sanitize_file_stem(id: &str) -> String {
+    let sanitized: String = id
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    sanitized.trim_start_matches('.').to_string()
+}
+
+/// Pick a file name not in `taken`, appending `-2`, `-3`, ... on collision
+///
+/// The chosen name is added to `taken`.
+pub fn unique_file_name(stem: &str, extension: &str, taken: &mut HashSet<String>) -> String {
+    let mut name = format!("{}.{}", stem, extension);
+    let mut suffix = 2;
+    while taken.contains(&name) {
+        name = format!("{}-{}.{}", stem, suffix, extension);
+        suffix += 1;
+    }
+    taken.insert(name.clone());
+    name
+}
+
+/// Names of the files already in `dir`, for seeding [`unique_file_name`]
+///
+/// A directory that does not exist yet has no files.
+pub fn existing_file_names(dir: &Path) -> std::io::Result<HashSet<String>> {
+    match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(HashSet::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Streaming NDJSON writer for batch exports
+///
+/// Writes one compact `ExportedPaper` per line and flushes after each record,
+/// so a batch can be piped into downstream tools without holding every
+/// export in memory.
+pub struct ExportWriter<W: Write> {
+    writer: W,
+    count: usize,
+}
+
+impl<W: Write> ExportWriter<W> {
+    /// Wrap an output stream
+    pub fn new(writer: W) -> Self {
+        Self { writer, count: 0 }
+    }
+
+    /// Write a single export as one line
+    pub fn write(&mut self, exported: &ExportedPaper) -> AppResult<()> {
+        serde_json::to_writer(&mut self.writer, exported)?;
+        self.writer
+            .write_all(b"\n")
+            .and_then(|_| self.writer.flush())
+            .map_err(|e| AppError::InternalAppError(format!("Failed to write export: {}", e)))?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Number of records written so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Recover the underlying stream
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_utc_export_timestamps_end_in_z() {
+        let mut paper = AcademicPaper::new();
+        paper.title = "Attention Is All You Need".to_string();
+        let mut exported = ExportedPaper::new(paper, ExportOptions::default());
+        let before = exported.export_metadata.exported_at;
+        exported.normalize_timestamps_to_utc();
+
+        // Same instant, zero offset
+        assert_eq!(exported.export_metadata.exported_at, before);
+        let value = serde_json::to_value(&exported).unwrap();
+        for timestamp in [
+            &value["export_metadata"]["exported_at"],
+            &value["paper"]["created_at"],
+            &value["paper"]["updated_at"],
+        ] {
+            let timestamp = timestamp.as_str().unwrap();
+            assert!(timestamp.ends_with('Z'), "{}", timestamp);
+        }
+
+        let rendered = export_timestamp(&exported.export_metadata.exported_at);
+        assert!(rendered.ends_with('Z'));
+        assert!(
+            exported
+                .to_xml()
+                .contains(&format!("<exported-at>{}</exported-at>", rendered))
+        );
+    }
+
+    #[test]
+    fn test_export_writer_ndjson() {
+        let mut first = AcademicPaper::new();
+        first.title = "Attention Is All You Need".to_string();
+        first.abstract_text = "Line one.\nLine two.".to_string();
+        let mut second = AcademicPaper::new();
+        second.title = "BERT".to_string();
+
+        let mut writer = ExportWriter::new(Vec::new());
+        for paper in [first, second] {
+            writer
+                .write(&ExportedPaper::new(paper, ExportOptions::default()))
+                .unwrap();
+        }
+        assert_eq!(writer.count(), 2);
+
+        let output = String::from_utf8(writer.into_inner()).unwrap();
+        assert!(output.ends_with('\n'));
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let parsed: Vec<ExportedPaper> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed[0].paper.title, "Attention Is All You Need");
+        assert_eq!(parsed[0].paper.abstract_text, "Line one.\nLine two.");
+        assert_eq!(parsed[1].paper.title, "BERT");
+    }
+
+    #[test]
+    fn test_sanitize_file_stem_old_style_arxiv_id() {
+        assert_eq!(sanitize_file_stem("cs.CL/0001001"), "cs.CL_0001001");
+        assert_eq!(sanitize_file_stem("10.1145/3065386"), "10.1145_3065386");
+        assert_eq!(sanitize_file_stem("2106.09685v2"), "2106.09685v2");
+        assert_eq!(sanitize_file_stem("../etc/passwd"), "_etc_passwd");
+    }
+
+    #[test]
+    fn test_export_file_stem_prefers_arxiv_then_ss_id() {
+        let mut paper = AcademicPaper::new();
+        assert_eq!(export_file_stem(&paper), "paper");
+
+        paper.doi = "10.1145/3065386".to_string();
+        assert_eq!(export_file_stem(&paper), "10.1145_3065386");
+
+        paper.ss_id = "abc123".to_string();
+        assert_eq!(export_file_stem(&paper), "abc123");
+
+        paper.arxiv_id = "hep-th/9901001".to_string();
+        assert_eq!(export_file_stem(&paper), "hep-th_9901001");
+    }
+
+    #[test]
+    fn test_unique_file_name_appends_suffix() {
+        let mut taken = HashSet::new();
+        assert_eq!(unique_file_name("paper", "json", &mut taken), "paper.json");
+        assert_eq!(
+            unique_file_name("paper", "json", &mut taken),
+            "paper-2.json"
+        );
+        assert_eq!(
+            unique_file_name("paper", "json", &mut taken),
+            "paper-3.json"
+        );
+        assert_eq!(unique_file_name("other", "json", &mut taken), "other.json");
+    }
+
+    #[test]
+    fn test_existing_file_names_seed_unique_file_name() {
+        let dir = std::env::temp_dir().join(format!("export-names-test-{}", std::process::id()));
+        assert!(existing_file_names(&dir).unwrap().is_empty());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("paper.json"), "{}").unwrap();
+        let mut taken = existing_file_names(&dir).unwrap();
+        assert_eq!(
+            unique_file_name("paper", "json", &mut taken),
+            "paper-2.json"
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_export_progress_reports_stages_in_order() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        let progress = ExportProgress::new()
+            .with_progress(move |label| sink.lock().unwrap().push(label.to_string()));
+
+        let options = ExportOptions {
+            analyzed: true,
+            text_extracted: true,
+            citations_included: true,
+            keywords_extracted: true,
+            ..Default::default()
+        };
+        for stage in options.planned_stages() {
+            progress.report(stage);
+        }
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![
+                "Fetching metadata…",
+                "Extracting text…",
+                "Analyzing…",
+                "Fetching citations…",
+                "Extracting keywords…",
+                "Writing output…",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_export_progress_without_callback_is_noop() {
+        let progress = ExportProgress::new();
+        progress.report(ExportStage::Analyzing);
+        assert_eq!(
+            ExportOptions::default().planned_stages(),
+            vec![ExportStage::FetchingMetadata, ExportStage::Writing]
+        );
+    }
 
     fn create_test_paper() -> AcademicPaper {
         let mut paper = AcademicPaper::new();
@@ -1887,6 +3712,19 @@ mod tests {
         assert_eq!(summary.citation_count, 100);
     }
 
+    #[test]
+    fn test_paper_summary_undated_year_is_unknown() {
+        let mut paper = create_test_paper();
+        paper.published_date = crate::shared::utils::datetime_from_str("");
+
+        let summary = PaperSummary::from_academic_paper(&paper);
+        assert_eq!(summary.year, 0);
+
+        let stats = ReferenceStatistics::from_papers(&[summary], 2020);
+        assert!(stats.by_year.is_empty());
+        assert!(stats.year_range.is_none());
+    }
+
     #[test]
     fn test_abstract_truncation() {
         let long_abstract = "a".repeat(600);
@@ -1898,6 +3736,27 @@ mod tests {
         assert!(summary.abstract_snippet.ends_with("..."));
     }
 
+    #[test]
+    fn test_abstract_truncation_multibyte() {
+        // 499 ASCII bytes then 3-byte characters: byte 500 falls inside "本"
+        let mut paper = AcademicPaper::new();
+        paper.abstract_text = format!("{}{}", "a".repeat(499), "本".repeat(100));
+        assert!(!paper.abstract_text.is_char_boundary(500));
+
+        let summary = PaperSummary::from_academic_paper(&paper);
+        assert_eq!(summary.abstract_snippet.chars().count(), 503);
+        assert!(summary.abstract_snippet.ends_with("本..."));
+
+        let summary = PaperSummary::from_academic_paper_with_limit(&paper, 501);
+        assert_eq!(
+            summary.abstract_snippet,
+            format!("{}本本...", "a".repeat(499))
+        );
+
+        let summary = PaperSummary::from_academic_paper_with_limit(&paper, 1000);
+        assert_eq!(summary.abstract_snippet, paper.abstract_text);
+    }
+
     #[test]
     fn test_citation_statistics() {
         let papers = vec![
@@ -1931,6 +3790,162 @@ mod tests {
         assert_eq!(stats.most_influential[0], "Paper 3");
     }
 
+    #[test]
+    fn test_resume_round_trip_is_noop_when_complete() {
+        use crate::models::PaperAnalysis;
+
+        let requested = ExportOptions {
+            analyzed: true,
+            citations_included: true,
+            keywords_extracted: true,
+            max_citations: 10,
+            ..Default::default()
+        };
+
+        let mut paper = create_test_paper();
+        paper.set_analysis(PaperAnalysis {
+            summary: "Summary".to_string(),
+            methodology: "Method".to_string(),
+            ..Default::default()
+        });
+        let mut exported = ExportedPaper::new(paper, requested.clone());
+        assert_eq!(
+            exported.pending_stages(&requested),
+            vec![
+                ExportStage::FetchingCitations,
+                ExportStage::ExtractingKeywords
+            ]
+        );
+
+        exported.citations = Some(CitationData {
+            total_count: 100,
+            fetched_count: 0,
+            papers: Vec::new(),
+            statistics: CitationStatistics::from_papers(&[]),
+        });
+        exported.keywords = Some(KeywordsData {
+            keywords: vec!["attention".to_string()],
+            ..Default::default()
+        });
+
+        let path = std::env::temp_dir().join(format!(
+            "api-export-resume-{}-{}.json",
+            std::process::id(),
+            Local::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::write(&path, serde_json::to_string(&exported).unwrap()).unwrap();
+        let reloaded = ExportedPaper::from_json_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(reloaded.paper.title, "Test Paper");
+        assert!(reloaded.paper.is_analyzed());
+        assert!(reloaded.pending_stages(&requested).is_empty());
+
+        // Asking for more work than the file holds leaves only the new stages
+        let more = ExportOptions {
+            references_included: true,
+            ..requested
+        };
+        assert_eq!(
+            reloaded.pending_stages(&more),
+            vec![ExportStage::FetchingReferences]
+        );
+    }
+
+    #[test]
+    fn test_from_json_file_missing() {
+        let result = ExportedPaper::from_json_file("/nonexistent/export.json");
+        assert!(matches!(result, Err(AppError::InternalAppError(_))));
+    }
+
+    #[test]
+    fn test_export_options_merge_from() {
+        let previous = ExportOptions {
+            analyzed: true,
+            citations_included: true,
+            max_citations: 50,
+            llm_provider: Some("openai".to_string()),
+            ..Default::default()
+        };
+        let mut current = ExportOptions {
+            keywords_extracted: true,
+            max_citations: 20,
+            ..Default::default()
+        };
+        current.merge_from(&previous);
+
+        assert!(current.analyzed && current.citations_included && current.keywords_extracted);
+        assert!(!current.references_included);
+        assert_eq!(current.max_citations, 50);
+        assert_eq!(current.llm_provider.as_deref(), Some("openai"));
+    }
+
+    #[test]
+    fn test_citation_statistics_excludes_unknown_metrics() {
+        let known = |title: &str, citations: i32| PaperSummary {
+            title: title.to_string(),
+            citation_count: citations,
+            metrics_known: true,
+            ..Default::default()
+        };
+        let mut arxiv_only = AcademicPaper::new();
+        arxiv_only.title = "arXiv only".to_string();
+        let unknown = PaperSummary::from_academic_paper(&arxiv_only);
+        assert!(!unknown.metrics_known);
+
+        let papers = vec![known("Paper 1", 100), known("Paper 2", 50), unknown];
+        let stats = CitationStatistics::from_papers(&papers);
+        assert!((stats.avg_citation_count - 75.0).abs() < f64::EPSILON);
+        assert_eq!(stats.most_influential.len(), 3);
+    }
+
+    #[test]
+    fn test_reference_statistics_recency() {
+        let reference = |year: i32| PaperSummary {
+            year,
+            ..Default::default()
+        };
+        // Undated references are ignored for recency
+        let papers: Vec<_> = [1998, 2012, 2015, 2017, 2019, 0]
+            .into_iter()
+            .map(reference)
+            .collect();
+
+        let stats = ReferenceStatistics::from_papers(&papers, 2020);
+        assert_eq!(stats.median_reference_year, Some(2015));
+        // 2015, 2017 and 2019 fall within 5 years of 2020
+        assert!((stats.references_last_5yr_ratio - 0.6).abs() < f64::EPSILON);
+
+        let stats = ReferenceStatistics::from_papers(&papers[..4], 2020);
+        assert_eq!(stats.median_reference_year, Some(2013));
+        assert!((stats.references_last_5yr_ratio - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_reference_statistics_unknown_citing_year() {
+        let papers = vec![
+            PaperSummary {
+                year: 2000,
+                ..Default::default()
+            },
+            PaperSummary {
+                year: 2010,
+                ..Default::default()
+            },
+        ];
+
+        let stats = ReferenceStatistics::from_papers(&papers, 0);
+        assert!((stats.references_last_5yr_ratio - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_reference_statistics_empty() {
+        let stats = ReferenceStatistics::from_papers(&[], 2020);
+        assert_eq!(stats.median_reference_year, None);
+        assert_eq!(stats.references_last_5yr_ratio, 0.0);
+        assert!(stats.year_range.is_none());
+    }
+
     #[test]
     fn test_exported_paper_new() {
         let paper = AcademicPaper::new();
@@ -1941,4 +3956,437 @@ mod tests {
         assert!(exported.citations.is_none());
         assert!(exported.references.is_none());
     }
+
+    fn tiny_citation_graph() -> CitationGraph {
+        let summary = |ss_id: &str, title: &str| PaperSummary {
+            ss_id: ss_id.to_string(),
+            title: title.to_string(),
+            year: 2020,
+            ..Default::default()
+        };
+        CitationGraph {
+            nodes: vec![
+                summary("root", "Attention Is All You Need"),
+                summary("a", "BERT: \"Deep\" <Bidirectional> & Transformers"),
+                summary("b", &"Very long title ".repeat(10)),
+            ],
+            edges: vec![
+                ("a".to_string(), "root".to_string()),
+                ("b".to_string(), "root".to_string()),
+                ("b".to_string(), "a".to_string()),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_citation_graph_to_graphml_is_well_formed() {
+        use quick_xml::events::Event;
+
+        let xml = tiny_citation_graph().to_graphml();
+        let mut reader = quick_xml::Reader::from_str(&xml);
+        let (mut nodes, mut edges) = (0, 0);
+        loop {
+            match reader.read_event() {
+                Ok(Event::Eof) => break,
+                Ok(Event::Start(e)) | Ok(Event::Empty(e)) => match e.name().as_ref() {
+                    b"node" => nodes += 1,
+                    b"edge" => edges += 1,
+                    _ => {}
+                },
+                Ok(_) => {}
+                Err(e) => panic!("malformed GraphML: {}", e),
+            }
+        }
+        assert_eq!((nodes, edges), (3, 3));
+        assert!(xml.contains("edgedefault=\"directed\""));
+        assert!(xml.contains("&quot;Deep&quot; &lt;Bidirectional&gt; &amp; Transformers"));
+        assert!(xml.contains("<edge id=\"e2\" source=\"b\" target=\"a\"/>"));
+    }
+
+    #[test]
+    fn test_citation_graph_to_dot() {
+        let dot = tiny_citation_graph().to_dot();
+        assert!(dot.starts_with("digraph citations {\n"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("  \"root\" [label=\"Attention Is All You Need\"];\n"));
+        assert!(dot.contains("[label=\"BERT: \\\"Deep\\\" <Bidirectional> & Transformers\"]"));
+        assert!(dot.contains("  \"b\" -> \"a\";\n"));
+        assert_eq!(dot.matches(" -> ").count(), 3);
+
+        // Long titles are truncated to the label limit
+        let long_label = dot
+            .lines()
+            .find(|line| line.starts_with("  \"b\" [label="))
+            .unwrap();
+        assert!(long_label.ends_with("...\"];"));
+        assert!(long_label.chars().count() < GRAPH_LABEL_MAX_CHARS + 20);
+    }
+
+    #[test]
+    fn test_project_keeps_requested_nested_fields() {
+        let mut paper = create_test_paper();
+        paper.authors = vec![crate::models::Author {
+            name: "Alice".to_string(),
+            h_index: 10,
+            ..Default::default()
+        }];
+        let mut exported = ExportedPaper::new(paper, ExportOptions::default());
+        exported.keywords = Some(KeywordsData {
+            keywords: vec!["transformer".to_string()],
+            topics: vec!["nlp".to_string()],
+            ..Default::default()
+        });
+        let value = serde_json::to_value(&exported).unwrap();
+
+        let projected = project_export_fields(
+            value,
+            &[
+                "paper.title",
+                "paper.arxiv_id",
+                "paper.authors.name",
+                "keywords.keywords",
+            ],
+        );
+
+        assert_eq!(
+            projected,
+            serde_json::json!({
+                "paper": {
+                    "title": "Test Paper",
+                    "arxiv_id": "2106.09685",
+                    "authors": [{ "name": "Alice" }]
+                },
+                "keywords": { "keywords": ["transformer"] }
+            })
+        );
+    }
+
+    #[test]
+    fn test_project_skips_unknown_paths() {
+        let value = serde_json::to_value(ExportedPaper::new(
+            create_test_paper(),
+            ExportOptions::default(),
+        ))
+        .unwrap();
+
+        let projected = project_export_fields(
+            value,
+            &[
+                "paper.title",
+                "paper.no_such_field",
+                "paper.title.deeper",
+                "",
+            ],
+        );
+        assert_eq!(
+            projected,
+            serde_json::json!({ "paper": { "title": "Test Paper" } })
+        );
+    }
+
+    #[test]
+    fn test_exported_paper_validates_against_json_schema() {
+        let schema = exported_paper_json_schema();
+        let validator = jsonschema::validator_for(&schema).expect("schema compiles");
+
+        let fresh = ExportedPaper::new(AcademicPaper::new(), ExportOptions::default());
+        let instance = serde_json::to_value(&fresh).unwrap();
+        let errors: Vec<String> = validator
+            .iter_errors(&instance)
+            .map(|e| e.to_string())
+            .collect();
+        assert!(errors.is_empty(), "schema errors: {:?}", errors);
+
+        let full = serde_json::to_value(ExportedPaper::new(
+            create_test_paper(),
+            ExportOptions::default(),
+        ))
+        .unwrap();
+        assert!(validator.is_valid(&full));
+
+        let mut broken = instance;
+        broken["paper"]["title"] = serde_json::json!(42);
+        assert!(!validator.is_valid(&broken));
+        assert!(!validator.is_valid(&serde_json::json!({ "paper": {} })));
+    }
+
+    #[test]
+    fn test_provenance_only_exported_on_request() {
+        let mut paper = AcademicPaper::new();
+        paper.source = Some(PaperSource::Both);
+
+        let plain = ExportedPaper::new(paper.clone(), ExportOptions::default());
+        assert!(plain.source.is_none());
+        assert!(
+            !serde_json::to_string(&plain)
+                .unwrap()
+                .contains("\"source\"")
+        );
+
+        let options = ExportOptions {
+            include_provenance: true,
+            ..Default::default()
+        };
+        let exported = ExportedPaper::new(paper, options);
+        assert_eq!(exported.source, Some(PaperSource::Both));
+        assert!(
+            serde_json::to_string(&exported)
+                .unwrap()
+                .contains("\"source\":\"Both\"")
+        );
+        assert!(exported.to_xml().contains("<source>Both</source>"));
+    }
+
+    #[test]
+    fn test_size_metrics_in_export_metadata() {
+        let mut paper = AcademicPaper::new();
+        paper.abstract_text = "We propose a new simple network architecture.".to_string();
+        let mut exported = ExportedPaper::new(paper, ExportOptions::default());
+        assert_eq!(exported.export_metadata.size_metrics.abstract_word_count, 7);
+        assert_eq!(
+            exported.export_metadata.size_metrics.full_text_word_count,
+            None
+        );
+
+        exported.paper.extracted_text = Some(crate::models::PaperText {
+            plain_text: "word ".repeat(500),
+            sections: vec![crate::models::PaperSection {
+                title: "Introduction".to_string(),
+                content: "word ".repeat(500),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        exported.refresh_size_metrics();
+        assert_eq!(
+            exported.export_metadata.size_metrics,
+            PaperSizeMetrics {
+                abstract_word_count: 7,
+                full_text_word_count: Some(500),
+                estimated_reading_minutes: Some(3),
+            }
+        );
+
+        let xml = exported.to_xml();
+        assert!(xml.contains("<abstract-word-count>7</abstract-word-count>"));
+        assert!(xml.contains("<full-text-word-count>500</full-text-word-count>"));
+        assert!(xml.contains("<estimated-reading-minutes>3</estimated-reading-minutes>"));
+    }
+
+    #[test]
+    fn test_scaled_bar_heights_relative_to_max() {
+        assert_eq!(
+            scaled_bar_heights(&[10, 5, 1, 0], 120),
+            vec![120, 60, 12, 0]
+        );
+        // Tiny but non-zero counts stay visible
+        assert_eq!(scaled_bar_heights(&[1000, 1], 120), vec![120, 1]);
+        assert!(scaled_bar_heights(&[], 120).is_empty());
+    }
+
+    #[test]
+    fn test_svg_year_histogram_orders_years_and_scales_bars() {
+        let by_year = HashMap::from([(2021, 2), (2019, 4)]);
+        let svg = svg_year_histogram(&by_year);
+
+        let first = svg.find("<title>2019: 4</title>").unwrap();
+        let second = svg.find("<title>2021: 2</title>").unwrap();
+        assert!(first < second);
+        assert!(svg.contains(&format!("height=\"{}\"", CHART_BAR_AREA_HEIGHT)));
+        assert!(svg.contains(&format!("height=\"{}\"", CHART_BAR_AREA_HEIGHT / 2)));
+    }
+
+    #[test]
+    fn test_to_html_is_self_contained() {
+        let mut paper = AcademicPaper::new();
+        paper.title = "Attention <Is> All You Need".to_string();
+        let mut exported = ExportedPaper::new(paper, ExportOptions::default());
+        exported.keywords = Some(KeywordsData {
+            keywords: vec!["transformer".to_string()],
+            ..Default::default()
+        });
+
+        let html = exported.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>Attention &lt;Is&gt; All You Need</h1>"));
+        assert!(html.contains(">transformer</span>"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("<link"));
+    }
+
+    #[test]
+    fn test_to_html_links_only_web_urls() {
+        let html_for = |url: &str| {
+            let mut paper = AcademicPaper::new();
+            paper.url = url.to_string();
+            ExportedPaper::new(paper, ExportOptions::default()).to_html()
+        };
+
+        let html = html_for("https://arxiv.org/abs/1706.03762");
+        assert!(html.contains("<a href=\"https://arxiv.org/abs/1706.03762\">"));
+        assert!(
+            html_for("HTTP://example.com/paper").contains("<a href=\"HTTP://example.com/paper\">")
+        );
+
+        for url in [
+            "javascript:alert(1)",
+            " JavaScript:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "vbscript:msgbox",
+        ] {
+            let html = html_for(url);
+            assert!(!html.contains("<a href"), "{url} must not be linked");
+            assert!(!html.contains("<script>"));
+        }
+        assert!(html_for("javascript:alert(1)").contains("<p>javascript:alert(1)</p>"));
+    }
+
+    #[test]
+    fn test_collect_extracted_references_skips_known() {
+        let extracted = |title: &str, doi: Option<&str>| ExtractedReference {
+            title: title.to_string(),
+            doi: doi.map(str::to_string),
+            ..Default::default()
+        };
+        let mut paper = AcademicPaper::new();
+        paper.extracted_text = Some(crate::models::PaperText {
+            extracted_references: Some(vec![
+                extracted("Attention is all you need.", None),
+                extracted("Some Workshop Paper", Some("10.1000/XYZ")),
+                extracted("A Paper Only In The PDF", None),
+                extracted("A paper only in the PDF", None),
+            ]),
+            ..Default::default()
+        });
+
+        let mut exported = ExportedPaper::new(paper, ExportOptions::default());
+        exported.references = Some(ReferenceData {
+            total_count: 2,
+            fetched_count: 2,
+            papers: vec![
+                PaperSummary {
+                    title: "Attention Is All You Need".to_string(),
+                    ..Default::default()
+                },
+                PaperSummary {
+                    title: "Renamed Workshop Paper".to_string(),
+                    doi: "10.1000/xyz".to_string(),
+                    ..Default::default()
+                },
+            ],
+            statistics: ReferenceStatistics::default(),
+        });
+        exported.collect_extracted_references();
+
+        assert_eq!(exported.extracted_references.len(), 1);
+        assert_eq!(
+            exported.extracted_references[0].title,
+            "A Paper Only In The PDF"
+        );
+
+        let json = serde_json::to_value(&exported).unwrap();
+        assert_eq!(
+            json["extracted_references"][0]["title"],
+            "A Paper Only In The PDF"
+        );
+        let xml = exported.to_xml();
+        assert!(xml.contains(
+            "  <extracted-references>\n    <reference>\n      <title>A Paper Only In The PDF</title>"
+        ));
+    }
+
+    #[test]
+    fn test_collect_extracted_references_without_ss_references() {
+        let mut paper = AcademicPaper::new();
+        paper.extracted_text = Some(crate::models::PaperText {
+            extracted_references: Some(vec![ExtractedReference {
+                title: "Unindexed Preprint".to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        let mut exported = ExportedPaper::new(paper, ExportOptions::default());
+        exported.collect_extracted_references();
+        assert_eq!(exported.extracted_references.len(), 1);
+    }
+
+    #[test]
+    fn test_normalize_venue_aliases() {
+        for raw in [
+            "NeurIPS",
+            "Neural Information Processing Systems",
+            "Advances in Neural Information Processing Systems",
+            "NIPS 2017",
+        ] {
+            assert_eq!(normalize_venue(raw), "NeurIPS", "{}", raw);
+        }
+        assert_eq!(
+            normalize_venue(
+                "Proceedings of the 58th Annual Meeting of the Association for Computational Linguistics"
+            ),
+            "ACL"
+        );
+        assert_eq!(
+            normalize_venue("Trans. Assoc. Comput. Linguistics (TACL)"),
+            "TACL"
+        );
+        assert_eq!(normalize_venue("ICML'20"), "ICML");
+        assert_eq!(normalize_venue("NAACL-HLT"), "NAACL");
+        assert_eq!(
+            normalize_venue("  Nature   Communications "),
+            "Nature Communications"
+        );
+    }
+
+    #[test]
+    fn test_normalize_venue_keeps_similar_names_and_workshops_apart() {
+        assert_eq!(normalize_venue("ICMLA"), "ICMLA");
+        assert_eq!(
+            normalize_venue("International Conference on Machine Learning and Applications"),
+            "International Conference on Machine Learning and Applications"
+        );
+        assert_eq!(
+            normalize_venue("Proceedings of the 37th International Conference on Machine Learning"),
+            "ICML"
+        );
+        assert_eq!(
+            normalize_venue("Advances in Neural Information Processing Systems 33"),
+            "NeurIPS"
+        );
+        assert_eq!(
+            normalize_venue("NeurIPS 2020 Workshop on Deep Reinforcement Learning"),
+            "NeurIPS Workshop"
+        );
+        assert_eq!(
+            normalize_venue("Conference on Computer Vision and Pattern Recognition Workshops"),
+            "CVPR Workshop"
+        );
+    }
+
+    #[test]
+    fn test_statistics_merge_venue_aliases() {
+        let papers: Vec<PaperSummary> = [
+            "NeurIPS",
+            "Neural Information Processing Systems",
+            "Advances in Neural Information Processing Systems",
+            "ICML",
+        ]
+        .into_iter()
+        .map(|venue| PaperSummary {
+            venue: venue.to_string(),
+            ..Default::default()
+        })
+        .collect();
+
+        let stats = CitationStatistics::from_papers(&papers);
+        assert_eq!(stats.top_venues[0], ("NeurIPS".to_string(), 3));
+        assert_eq!(stats.top_venues.len(), 2);
+
+        let stats = ReferenceStatistics::from_papers(&papers, 2020);
+        assert_eq!(stats.top_venues[0], ("NeurIPS".to_string(), 3));
+        // The raw venue is kept on the summaries
+        assert_eq!(papers[1].venue, "Neural Information Processing Systems");
+    }
 }
@@ -0,0 +1,394 @@
+//! Local paper library with embedding-based semantic search
+
+use crate::agents::EmbeddingProvider;
+use crate::client::PaperClient;
+use crate::models::AcademicPaper;
+use crate::shared::errors::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A paper stored in a [`PaperIndex`] with its embedding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// The indexed paper (without extracted full text)
+    pub paper: AcademicPaper,
+
+    /// Embedding of the paper's title and abstract
+    pub embedding: Vec<f32>,
+}
+
+/// In-memory paper library searchable by embedding similarity
+///
+/// Search is brute-force cosine similarity over every entry, which is fast
+/// enough for a personal library of a few thousand papers. The index is
+/// persisted as a single JSON file with [`save`](Self::save) /
+/// [`load`](Self::load). All embeddings must come from the same model; the
+/// index records it and rejects providers using another one.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaperIndex {
+    /// Embedding model of the indexed vectors (unset until the first
+    /// [`add_batch`](Self::add_batch))
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+
+    entries: Vec<IndexEntry>,
+}
+
+impl PaperIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of indexed papers
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index has no papers
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Indexed papers and their embeddings
+    pub fn entries(&self) -> &[IndexEntry] {
+        &self.entries
+    }
+
+    /// Embedding model the index was built with, if recorded
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    /// Fail unless `model` is the embedding model the index was built with
+    ///
+    /// An index with no recorded model accepts any model.
+    pub fn check_model(&self, model: &str) -> AppResult<()> {
+        match self.model.as_deref() {
+            Some(indexed) if indexed != model => Err(AppError::ConfigError(format!(
+                "Index was built with embedding model '{}', not '{}'",
+                indexed, model
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Text embedded for a paper: its title and abstract
+    pub fn embedding_text(paper: &AcademicPaper) -> String {
+        format!("{}\n\n{}", paper.title, paper.abstract_text)
+    }
+
+    /// Embed a paper with `provider` and add it to the index
+    pub async fn add(
+        &mut self,
+        paper: AcademicPaper,
+        provider: &dyn EmbeddingProvider,
+    ) -> AppResult<()> {
+        self.add_batch(vec![paper], provider).await
+    }
+
+    /// Embed several papers in one provider call and add them to the index
+    ///
+    /// Fails if `provider` uses another embedding model than the index; an
+    /// index without a recorded model adopts the provider's.
+    pub async fn add_batch(
+        &mut self,
+        papers: Vec<AcademicPaper>,
+        provider: &dyn EmbeddingProvider,
+    ) -> AppResult<()> {
+        self.check_model(provider.embedding_model())?;
+        let texts: Vec<String> = papers.iter().map(Self::embedding_text).collect();
+        let embeddings = provider.embed(&texts).await?;
+        if embeddings.len() != papers.len() {
+            return Err(AppError::InternalAppError(format!(
+                "Expected {} embeddings, got {}",
+                papers.len(),
+                embeddings.len()
+            )));
+        }
+        for (paper, embedding) in papers.into_iter().zip(embeddings) {
+            self.insert(paper, embedding)?;
+        }
+        self.model = Some(provider.embedding_model().to_string());
+        Ok(())
+    }
+
+    /// Add a paper with a precomputed embedding
+    ///
+    /// A paper already in the index (same arXiv ID, DOI, Semantic Scholar ID
+    /// or title) is replaced. The extracted full text is dropped to keep the
+    /// index file small. Fails if the embedding dimension differs from the
+    /// entries already indexed.
+    pub fn insert(&mut self, mut paper: AcademicPaper, embedding: Vec<f32>) -> AppResult<()> {
+        if let Some(first) = self.entries.first()
+            && first.embedding.len() != embedding.len()
+        {
+            return Err(AppError::InternalAppError(format!(
+                "Embedding dimension {} does not match the index dimension {}",
+                embedding.len(),
+                first.embedding.len()
+            )));
+        }
+        paper.extracted_text = None;
+        let entry = IndexEntry { paper, embedding };
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| same_paper(&e.paper, &entry.paper))
+        {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+        Ok(())
+    }
+
+    /// The `k` papers most similar to `query_embedding`, best first
+    pub fn search(&self, query_embedding: &[f32], k: usize) -> Vec<(&AcademicPaper, f32)> {
+        let mut scored: Vec<(&AcademicPaper, f32)> = self
+            .entries
+            .iter()
+            .map(|e| (&e.paper, cosine_similarity(query_embedding, &e.embedding)))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored.truncate(k);
+        scored
+    }
+
+    /// Embed `query` with `provider` and return the `k` most similar papers
+    ///
+    /// Fails if `provider` uses another embedding model than the index or
+    /// returns a vector of another dimension.
+    pub async fn search_text(
+        &self,
+        query: &str,
+        k: usize,
+        provider: &dyn EmbeddingProvider,
+    ) -> AppResult<Vec<(&AcademicPaper, f32)>> {
+        self.check_model(provider.embedding_model())?;
+        let embedding = provider
+            .embed(&[query.to_string()])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AppError::InternalAppError("No embedding returned".to_string()))?;
+        if let Some(first) = self.entries.first()
+            && first.embedding.len() != embedding.len()
+        {
+            return Err(AppError::InternalAppError(format!(
+                "Query embedding dimension {} does not match the index dimension {}",
+                embedding.len(),
+                first.embedding.len()
+            )));
+        }
+        Ok(self.search(&embedding, k))
+    }
+
+    /// Write the index to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> AppResult<()> {
+        let path = path.as_ref();
+        std::fs::write(path, serde_json::to_string(self)?).map_err(|e| {
+            AppError::InternalAppError(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    /// Read an index written by [`save`](Self::save)
+    pub fn load(path: impl AsRef<Path>) -> AppResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AppError::InternalAppError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Like [`load`](Self::load), but a missing file yields an empty index
+    pub fn load_or_default(path: impl AsRef<Path>) -> AppResult<Self> {
+        if path.as_ref().exists() {
+            Self::load(path)
+        } else {
+            Ok(Self::new())
+        }
+    }
+}
+
+/// Cosine similarity of two vectors (0.0 for mismatched or zero vectors)
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Whether two papers share an identifier, or a title when the first has none
+fn same_paper(a: &AcademicPaper, b: &AcademicPaper) -> bool {
+    PaperClient::identifiers_match(a, b)
+        || (a.arxiv_id.is_empty()
+            && a.doi.is_empty()
+            && a.ss_id.is_empty()
+            && !a.title.is_empty()
+            && a.title.to_lowercase() == b.title.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::MockProvider;
+
+    fn paper(arxiv_id: &str, title: &str) -> AcademicPaper {
+        let mut paper = AcademicPaper::new();
+        paper.arxiv_id = arxiv_id.to_string();
+        paper.title = title.to_string();
+        paper
+    }
+
+    fn synthetic_index() -> PaperIndex {
+        let mut index = PaperIndex::new();
+        index
+            .insert(paper("0001.00001", "East"), vec![1.0, 0.0, 0.0])
+            .unwrap();
+        index
+            .insert(paper("0001.00002", "North-east"), vec![0.7, 0.7, 0.0])
+            .unwrap();
+        index
+            .insert(paper("0001.00003", "North"), vec![0.0, 1.0, 0.0])
+            .unwrap();
+        index
+            .insert(paper("0001.00004", "Up"), vec![0.0, 0.0, 1.0])
+            .unwrap();
+        index
+    }
+
+    #[test]
+    fn test_search_orders_by_cosine_similarity() {
+        let index = synthetic_index();
+
+        let results = index.search(&[0.9, 0.1, 0.0], 3);
+        let titles: Vec<&str> = results.iter().map(|(p, _)| p.title.as_str()).collect();
+        assert_eq!(titles, vec!["East", "North-east", "North"]);
+        assert!(results[0].1 > results[1].1 && results[1].1 > results[2].1);
+
+        assert_eq!(index.search(&[0.0, 0.0, 2.0], 1)[0].0.title, "Up");
+        assert_eq!(index.search(&[1.0, 0.0, 0.0], 10).len(), 4);
+    }
+
+    #[test]
+    fn test_insert_replaces_same_paper_and_checks_dimension() {
+        let mut index = synthetic_index();
+        index
+            .insert(paper("0001.00001", "East (v2)"), vec![0.0, -1.0, 0.0])
+            .unwrap();
+        assert_eq!(index.len(), 4);
+        assert_eq!(index.entries()[0].paper.title, "East (v2)");
+
+        assert!(index.insert(paper("", "Flat"), vec![1.0, 0.0]).is_err());
+        assert_eq!(index.len(), 4);
+
+        // DOIs are compared after normalization
+        let mut journal = paper("", "Journal Version");
+        journal.doi = "https://doi.org/10.1000/ABC".to_string();
+        index.insert(journal, vec![0.5, 0.5, 0.0]).unwrap();
+        let mut same = paper("", "Journal Version (corrected)");
+        same.doi = "10.1000/abc".to_string();
+        index.insert(same, vec![0.5, 0.5, 0.0]).unwrap();
+        assert_eq!(index.len(), 5);
+    }
+
+    // Embeds every text as the same vector under a configurable model name
+    struct FixedProvider {
+        model: &'static str,
+        dimension: usize,
+    }
+
+    #[async_trait::async_trait]
+    impl EmbeddingProvider for FixedProvider {
+        fn embedding_model(&self) -> &str {
+            self.model
+        }
+
+        async fn embed(&self, texts: &[String]) -> AppResult<Vec<Vec<f32>>> {
+            Ok(texts.iter().map(|_| vec![1.0; self.dimension]).collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_index_rejects_other_models_and_dimensions() {
+        let small = FixedProvider {
+            model: "small",
+            dimension: 3,
+        };
+        let mut index = PaperIndex::new();
+        index
+            .add(paper("0001.00001", "East"), &small)
+            .await
+            .unwrap();
+        assert_eq!(index.model(), Some("small"));
+
+        let large = FixedProvider {
+            model: "large",
+            dimension: 3,
+        };
+        assert!(
+            index
+                .add(paper("0001.00002", "North"), &large)
+                .await
+                .is_err()
+        );
+        assert!(index.search_text("east", 1, &large).await.is_err());
+
+        // An index without a recorded model still checks the dimension
+        let legacy = synthetic_index();
+        let wide = FixedProvider {
+            model: "small",
+            dimension: 4,
+        };
+        assert!(legacy.search_text("east", 1, &wide).await.is_err());
+        assert!(legacy.search_text("east", 1, &small).await.is_ok());
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let index = synthetic_index();
+        let path = std::env::temp_dir().join(format!("paper-index-{}.json", std::process::id()));
+
+        index.save(&path).unwrap();
+        let loaded = PaperIndex::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.len(), index.len());
+        assert_eq!(loaded.model(), index.model());
+        assert_eq!(loaded.entries()[1].embedding, vec![0.7, 0.7, 0.0]);
+        assert_eq!(
+            loaded.search(&[0.0, 1.0, 0.1], 1)[0].0.arxiv_id,
+            "0001.00003"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_and_search_text_with_provider() {
+        let mut transformer = paper("1706.03762", "Attention Is All You Need");
+        transformer.abstract_text = "A sequence transduction model based on attention.".into();
+        let mut resnet = paper("1512.03385", "Deep Residual Learning for Image Recognition");
+        resnet.abstract_text = "Residual networks ease training of deep image models.".into();
+
+        let mut index = PaperIndex::new();
+        index
+            .add_batch(vec![transformer, resnet], &MockProvider::new())
+            .await
+            .unwrap();
+
+        let results = index
+            .search_text(
+                "image recognition with residual networks",
+                1,
+                &MockProvider::new(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results[0].0.arxiv_id, "1512.03385");
+    }
+}
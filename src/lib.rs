@@ -38,28 +38,48 @@
 
 pub mod agents;
 pub mod client;
+pub mod doctor;
 pub mod export;
+pub mod index;
+#[cfg(feature = "mcp")]
+pub mod mcp;
 pub mod models;
 pub mod pdf;
 pub mod shared;
 
 // Re-export main types at crate root
-pub use client::{PaperClient, PaperSource, SearchParams, SearchResult};
+pub use client::{
+    ARXIV_CATEGORIES, DEFAULT_SEARCH_CACHE_TTL, DedupStrategy, PaperClient, PaperId, PaperIds,
+    PaperSource, PaperTextExtractor, SearchParams, SearchResult, SortKey, SourceHealth,
+    SourceSearchResult, resolve_arxiv_category,
+};
 pub use export::{
-    CitationData, CitationStatistics, EXPORT_SCHEMA_VERSION, EXPORTED_PAPER_XSD, ExportMetadata,
-    ExportOptions, ExportedPaper, KeywordsData, PaperSummary, ReferenceData, ReferenceStatistics,
-    ResearchContext, TechnicalTerm, get_xml_schema,
+    CitationData, CitationGraph, CitationStatistics, DEFAULT_ABSTRACT_SNIPPET_CHARS,
+    EXPORT_SCHEMA_VERSION, EXPORTED_PAPER_XSD, ExportManifest, ExportMetadata, ExportOptions,
+    ExportProgress, ExportStage, ExportWriter, ExportedPaper, KeywordsData, ManifestEntry,
+    PaperSizeMetrics, PaperSummary, ReferenceData, ReferenceStatistics, RelatedData,
+    ResearchContext, SectionSummary, TechnicalTerm, existing_file_names, export_file_stem,
+    export_timestamp, exported_paper_json_schema, get_xml_schema, normalize_venue,
+    project_export_fields, sanitize_file_stem, unique_file_name,
 };
+pub use index::{IndexEntry, PaperIndex, cosine_similarity};
 pub use models::{
-    AcademicPaper, Author, DatasetInfo, ExtractedReference, PaperAnalysis, PaperSection, PaperText,
+    AcademicPaper, AnalysisDiff, ArxivVersion, Author, AuthorProfile, ComparisonVerdict,
+    DatasetInfo, ExtractedReference, PaperAnalysis, PaperComparison, PaperSection, PaperText,
+    PublicationStatus, RequestStats, SEARCH_CONTEXT_CHARS, SectionMatch, TextChunk,
+    ValidationIssue,
 };
-pub use client::UnpaywallClient;
-pub use pdf::{ExtractionConfig, PdfExtractor, PdfUrlResolver};
-pub use shared::config::Config;
+pub use client::{CrossRefClient, DblpClient, UnpaywallClient};
+pub use pdf::{ExtractionConfig, PageRange, PdfExtractor, PdfUrlResolver};
+pub use shared::config::{ClientConfig, Config, OllamaKeepAlive};
 pub use shared::errors::{AppError, AppResult};
 
 // Re-export agent types
-pub use agents::{AnalysisAgent, LlmConfig, LlmProvider, Message, MessageRole, PaperAnalyzer};
+pub use agents::{
+    AnalysisAgent, AnalysisLanguage, DynPaperAnalyzer, EmbeddingProvider, LlmConfig, LlmProvider,
+    Message, MessageRole, PaperAnalyzer, PromptConfig, ResponseFormat, RetryingProvider,
+    SectionSummaries, build_provider,
+};
 
 /// Prelude module for convenient imports
 pub mod prelude {
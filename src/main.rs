@@ -1,19 +1,28 @@
 //! CLI entry point for Academic Paper Interpreter
 
-use academic_paper_interpreter::agents::providers::{
-    AnthropicProvider, OllamaProvider, OpenAiProvider,
-};
+use academic_paper_interpreter::agents::providers::{MockProvider, OllamaProvider, build_provider};
+use academic_paper_interpreter::doctor;
 use academic_paper_interpreter::shared::config::LlmProviderType;
 use academic_paper_interpreter::shared::logger::init_logger;
+use academic_paper_interpreter::shared::utils::generate_progress_bar;
 use academic_paper_interpreter::{
-    AcademicPaper, CitationData, CitationStatistics, ExportOptions, ExportedPaper,
-    ExtractionConfig, KeywordsData, LlmProvider, PaperAnalyzer, PaperClient, PaperSummary,
-    PdfExtractor, ReferenceData, ReferenceStatistics, ResearchContext, SearchParams,
-    get_xml_schema,
+    ARXIV_CATEGORIES, AcademicPaper, AnalysisDiff, AnalysisLanguage, ArxivVersion, AuthorProfile,
+    CitationData, CitationGraph, CitationStatistics, ComparisonVerdict, Config, EmbeddingProvider,
+    ExportManifest, ExportOptions, ExportProgress, ExportStage, ExportWriter, ExportedPaper,
+    ExtractionConfig, KeywordsData, LlmConfig, LlmProvider, ManifestEntry, PageRange,
+    PaperAnalysis, PaperAnalyzer, PaperClient, PaperComparison, PaperId, PaperIndex, PaperSection,
+    PaperSource, PaperSummary, PdfExtractor, PublicationStatus, ReferenceData, ReferenceStatistics,
+    RelatedData, ResearchContext, SearchParams, SectionMatch, SectionSummaries, SortKey,
+    existing_file_names, export_file_stem, exported_paper_json_schema, get_xml_schema,
+    project_export_fields, unique_file_name,
 };
+use chrono::{DateTime, Local, NaiveDate, TimeZone};
 use clap::{Parser, Subcommand, ValueEnum};
+use futures::StreamExt;
+use regex::RegexBuilder;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::io::{BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
 /// Academic Paper Interpreter - Search, fetch, and analyze academic papers with LLM
 #[derive(Parser)]
@@ -24,6 +33,15 @@ struct Cli {
     #[arg(long, default_value = "warn", global = true)]
     log_level: String,
 
+    /// Endpoint for the openai-compatible provider, e.g. https://api.deepseek.com/v1
+    /// (overrides OPENAI_COMPAT_BASE_URL)
+    #[arg(long, global = true)]
+    base_url: Option<String>,
+
+    /// Language of the analysis prompts and output (ja, en)
+    #[arg(long, value_enum, default_value = "ja", global = true)]
+    prompt_language: LanguageArg,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -48,7 +66,11 @@ enum Commands {
         #[arg(short = 'n', long, default_value = "10")]
         max_results: usize,
 
-        /// Filter by arXiv category (e.g., cs.AI, cs.CL)
+        /// Skip this many results per source (page N starts at N * max-results)
+        #[arg(long, default_value = "0")]
+        offset: usize,
+
+        /// Filter by arXiv category code or name (e.g., cs.CL, "natural language processing")
         #[arg(short, long)]
         category: Option<String>,
 
@@ -56,12 +78,45 @@ enum Commands {
         #[arg(short, long)]
         year: Option<String>,
 
+        /// Also search DBLP (computer science; results have no abstracts)
+        #[arg(long)]
+        dblp: bool,
+
+        /// Only return open-access papers (arXiv papers always qualify)
+        #[arg(long)]
+        open_access: bool,
+
+        /// Only return published papers (publisher DOI and a non-arXiv venue)
+        #[arg(long, conflicts_with = "preprints_only")]
+        published_only: bool,
+
+        /// Only return arXiv preprints
+        #[arg(long)]
+        preprints_only: bool,
+
+        /// Only return papers submitted on or after this date (YYYY-MM-DD), newest first
+        #[arg(long, value_parser = parse_since_date)]
+        since: Option<DateTime<Local>>,
+
+        /// Keep duplicate papers from different sources (shows each paper's source)
+        #[arg(long)]
+        no_dedupe: bool,
+
+        /// Order of the merged results
+        #[arg(long, value_enum, default_value = "relevance")]
+        sort: SortArg,
+
+        /// Report the total number of matches (one extra request per source
+        /// that does not include it in its results)
+        #[arg(long)]
+        count_total: bool,
+
         /// Output format
         #[arg(short, long, value_enum, default_value = "text")]
         output: OutputFormat,
     },
 
-    /// Fetch paper by arXiv ID or Semantic Scholar ID
+    /// Fetch paper by arXiv ID, Semantic Scholar ID, or DOI
     Fetch {
         /// arXiv paper ID (e.g., 2106.09685)
         #[arg(long)]
@@ -71,11 +126,146 @@ enum Commands {
         #[arg(long)]
         ss: Option<String>,
 
+        /// DOI (resolved via CrossRef, e.g., 10.1145/3065386)
+        #[arg(long)]
+        doi: Option<String>,
+
+        /// Search by paper title (uses fuzzy matching with Levenshtein distance)
+        #[arg(short = 't', long)]
+        title: Option<String>,
+
+        /// Similarity threshold for title matching (0.0 = exact match, 1.0 = no match required)
+        #[arg(long, default_value = "0.3")]
+        threshold: f64,
+
+        /// With --title, choose among the closest matches instead of taking the best one
+        /// (needs a terminal; otherwise the best match is used)
+        #[arg(long)]
+        interactive: bool,
+
+        /// Skip automatic PDF text extraction (metadata only, much faster)
+        #[arg(long)]
+        no_extract: bool,
+
+        /// Also print a citation in this style (text output)
+        #[arg(long, value_enum)]
+        citation_style: Option<CitationStyle>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Resolve an arXiv ID, DOI or Semantic Scholar ID to all three
+    Resolve {
+        /// Any paper identifier (e.g., 1706.03762, 10.1145/3065386, or a 40-character SS ID)
+        id: String,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// List the revision history (versions) of an arXiv paper
+    Versions {
+        /// arXiv paper ID (a version suffix is ignored)
+        #[arg(long)]
+        arxiv: String,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Find papers by approximate title and list ranked candidates
+    Find {
+        /// Paper title (rough guesses are fine)
+        #[arg(short = 't', long)]
+        title: String,
+
+        /// Maximum title distance for a candidate (0.0 = exact, 1.0 = anything)
+        #[arg(long, default_value = "0.3")]
+        threshold: f64,
+
+        /// Number of candidates to list
+        #[arg(short = 'n', long, default_value = "5")]
+        candidates: usize,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Look up an author profile and recent papers on Semantic Scholar
+    Author {
+        /// Author name (e.g., "Yoshua Bengio")
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Semantic Scholar author ID
+        #[arg(long)]
+        id: Option<String>,
+
+        /// Maximum number of papers to list
+        #[arg(short = 'n', long, default_value = "10")]
+        max_papers: usize,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Find papers related to a paper (Semantic Scholar recommendations)
+    Related {
+        /// arXiv paper ID (e.g., 2106.09685)
+        #[arg(long)]
+        arxiv: Option<String>,
+
+        /// Semantic Scholar paper ID
+        #[arg(long)]
+        ss: Option<String>,
+
+        /// Maximum number of related papers
+        #[arg(short = 'n', long, default_value = "10")]
+        limit: usize,
+
         /// Output format
         #[arg(short, long, value_enum, default_value = "text")]
         output: OutputFormat,
     },
 
+    /// Crawl the citation network of a paper (papers citing it, and so on)
+    Graph {
+        /// arXiv paper ID (e.g., 2106.09685)
+        #[arg(long)]
+        arxiv: Option<String>,
+
+        /// Semantic Scholar paper ID
+        #[arg(long)]
+        ss: Option<String>,
+
+        /// Number of citation hops to follow
+        #[arg(long, default_value = "1")]
+        depth: usize,
+
+        /// Maximum number of citing papers followed per node
+        #[arg(long, default_value = "20")]
+        max_per_node: usize,
+
+        /// Graph format (json, graphml for Gephi/yEd, dot for Graphviz)
+        #[arg(short, long, value_enum, default_value = "json")]
+        format: GraphFormat,
+
+        /// Output file path (prints to stdout if omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Continue the crawl saved in the JSON output file, fetching only
+        /// papers still pending (JSON output is saved after every hop)
+        #[arg(long, requires = "output")]
+        resume: bool,
+    },
+
     /// Analyze paper using LLM
     Analyze {
         /// arXiv paper ID (e.g., 2106.09685)
@@ -86,7 +276,121 @@ enum Commands {
         #[arg(long)]
         ss: Option<String>,
 
-        /// LLM provider (openai, anthropic, ollama)
+        /// LLM provider (openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock)
+        #[arg(short, long, value_enum)]
+        provider: Option<ProviderArg>,
+
+        /// Model name (e.g., gpt-5.2, claude-3-opus-20240229)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Sampling temperature (0.0-2.0; ignored by OpenAI reasoning models such as o1/o3/gpt-5)
+        #[arg(long)]
+        temperature: Option<f32>,
+
+        /// Maximum number of tokens to generate per LLM call
+        #[arg(long)]
+        max_tokens: Option<u32>,
+
+        /// Print what changed against a previous analysis (export JSON, `analyze -o json` output or bare analysis JSON)
+        #[arg(long)]
+        compare_with: Option<PathBuf>,
+
+        /// Analyze every paper ID in this file, one per line (arxiv:/ss:/doi: prefixes optional,
+        /// blank lines and # comments skipped)
+        #[arg(long, conflicts_with_all = ["arxiv", "ss", "compare_with"], requires = "output_dir")]
+        input_file: Option<PathBuf>,
+
+        /// Directory for the per-paper results of --input-file (JSON unless -o xml/toml)
+        #[arg(long)]
+        output_dir: Option<PathBuf>,
+
+        /// Number of papers analyzed concurrently with --input-file
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Extract keywords and research context without running a full analysis
+    Keywords {
+        /// arXiv paper ID (e.g., 2106.09685)
+        #[arg(long)]
+        arxiv: Option<String>,
+
+        /// Semantic Scholar paper ID
+        #[arg(long)]
+        ss: Option<String>,
+
+        /// Search by paper title (uses fuzzy matching with Levenshtein distance)
+        #[arg(short = 't', long)]
+        title: Option<String>,
+
+        /// Similarity threshold for title matching (0.0 = exact match, 1.0 = no match required)
+        #[arg(long, default_value = "0.3")]
+        threshold: f64,
+
+        /// LLM provider (openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock)
+        #[arg(short, long, value_enum)]
+        provider: Option<ProviderArg>,
+
+        /// Model name (e.g., gpt-5.2, claude-3-opus-20240229)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Sampling temperature (0.0-2.0; ignored by OpenAI reasoning models such as o1/o3/gpt-5)
+        #[arg(long)]
+        temperature: Option<f32>,
+
+        /// Maximum number of tokens to generate per LLM call
+        #[arg(long)]
+        max_tokens: Option<u32>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Compare two papers head to head using LLM
+    Compare {
+        /// arXiv paper IDs (pass exactly two: --arxiv <id1> --arxiv <id2>)
+        #[arg(long, required = true, num_args = 1)]
+        arxiv: Vec<String>,
+
+        /// LLM provider (openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock)
+        #[arg(short, long, value_enum)]
+        provider: Option<ProviderArg>,
+
+        /// Model name (e.g., gpt-5.2, claude-3-opus-20240229)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Sampling temperature (0.0-2.0; ignored by OpenAI reasoning models such as o1/o3/gpt-5)
+        #[arg(long)]
+        temperature: Option<f32>,
+
+        /// Maximum number of tokens to generate per LLM call
+        #[arg(long)]
+        max_tokens: Option<u32>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+
+    /// Synthesize several papers into one related-work style overview using LLM
+    Synthesize {
+        /// arXiv paper IDs (repeat for each paper: --arxiv <id1> --arxiv <id2> ...)
+        #[arg(long, required = true, num_args = 1)]
+        arxiv: Vec<String>,
+
+        /// Topic to focus the synthesis on
+        #[arg(long)]
+        focus: Option<String>,
+
+        /// LLM provider (openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock)
         #[arg(short, long, value_enum)]
         provider: Option<ProviderArg>,
 
@@ -94,6 +398,33 @@ enum Commands {
         #[arg(short, long)]
         model: Option<String>,
 
+        /// Sampling temperature (0.0-2.0; ignored by OpenAI reasoning models such as o1/o3/gpt-5)
+        #[arg(long)]
+        temperature: Option<f32>,
+
+        /// Maximum number of tokens to generate per LLM call
+        #[arg(long)]
+        max_tokens: Option<u32>,
+    },
+
+    /// Search a paper's extracted full text and show matches with their sections
+    Grep {
+        /// arXiv paper ID (e.g., 2106.09685)
+        #[arg(long)]
+        arxiv: String,
+
+        /// Term to search for
+        #[arg(short = 'e', long)]
+        pattern: String,
+
+        /// Treat the pattern as a regular expression
+        #[arg(long)]
+        regex: bool,
+
+        /// Match case-insensitively
+        #[arg(short, long)]
+        ignore_case: bool,
+
         /// Output format
         #[arg(short, long, value_enum, default_value = "text")]
         output: OutputFormat,
@@ -117,6 +448,11 @@ enum Commands {
         #[arg(long, default_value = "0.3")]
         threshold: f64,
 
+        /// With --title, choose among the closest matches instead of taking the best one
+        /// (needs a terminal; otherwise the best match is used)
+        #[arg(long)]
+        interactive: bool,
+
         /// Output file path
         #[arg(short, long, default_value = "paper.xml")]
         output: PathBuf,
@@ -141,7 +477,7 @@ enum Commands {
         #[arg(long, default_value = "50")]
         max_citations: usize,
 
-        /// LLM provider (openai, anthropic, ollama)
+        /// LLM provider (openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock)
         #[arg(short, long, value_enum)]
         provider: Option<ProviderArg>,
 
@@ -149,15 +485,31 @@ enum Commands {
         #[arg(short, long)]
         model: Option<String>,
 
+        /// Sampling temperature (0.0-2.0; ignored by OpenAI reasoning models such as o1/o3/gpt-5)
+        #[arg(long)]
+        temperature: Option<f32>,
+
+        /// Maximum number of tokens to generate per LLM call
+        #[arg(long)]
+        max_tokens: Option<u32>,
+
         /// Extract keywords and topics via LLM
         #[arg(short = 'k', long)]
         extract_keywords: bool,
 
+        /// Summarize each key section of the extracted text via LLM (implies --extract-text)
+        #[arg(long)]
+        section_summaries: bool,
+
+        /// Print the extracted body to stdout with keywords in bold (requires -e and -k)
+        #[arg(long, requires_all = ["extract_text", "extract_keywords"])]
+        highlight: bool,
+
         /// Compact JSON output (no pretty printing)
         #[arg(long)]
         compact: bool,
 
-        /// Output format (json or xml)
+        /// Output format (json, xml or html)
         #[arg(short = 'f', long, value_enum, default_value = "xml")]
         format: ExportFormat,
 
@@ -176,80 +528,561 @@ enum Commands {
         /// Local PDF file path for text extraction (skips online PDF resolution)
         #[arg(long)]
         pdf: Option<PathBuf>,
+
+        /// Only keep text from these PDF pages (e.g., 1-20, 5-); page spans are estimated
+        #[arg(long)]
+        pages: Option<PageRange>,
+
+        /// Resume from an existing JSON output file, running only the missing stages
+        #[arg(long)]
+        resume: bool,
+
+        /// Only keep these comma-separated field paths in JSON output
+        /// (e.g., paper.title,paper.analysis.summary,keywords.keywords)
+        #[arg(long, value_delimiter = ',')]
+        fields: Vec<String>,
     },
-}
 
-#[derive(Clone, Copy, ValueEnum)]
-enum OutputFormat {
-    /// Human-readable text format
-    Text,
-    /// JSON format
-    Json,
-    /// XML format
-    Xml,
-    /// TOML format
-    Toml,
-}
+    /// Search papers and export each result as a JSON file into a directory
+    ExportSearch {
+        /// Full-text search query
+        #[arg(short, long)]
+        query: Option<String>,
 
-#[derive(Clone, Copy, ValueEnum, Debug)]
-enum ExportFormat {
-    /// JSON format
-    Json,
-    /// XML format with structured sections
-    Xml,
-}
+        /// Search in paper titles
+        #[arg(short, long)]
+        title: Option<String>,
 
-#[derive(Clone, Copy, ValueEnum)]
-enum ProviderArg {
-    Openai,
-    Anthropic,
-    Ollama,
-}
+        /// Search by author name
+        #[arg(short, long)]
+        author: Option<String>,
 
-impl From<ProviderArg> for LlmProviderType {
-    fn from(p: ProviderArg) -> Self {
-        match p {
-            ProviderArg::Openai => LlmProviderType::OpenAi,
-            ProviderArg::Anthropic => LlmProviderType::Anthropic,
-            ProviderArg::Ollama => LlmProviderType::Ollama,
-        }
-    }
-}
+        /// Maximum number of results
+        #[arg(short = 'n', long, default_value = "10")]
+        max_results: usize,
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let cli = Cli::parse();
-    init_logger(&cli.log_level)?;
+        /// Filter by arXiv category code or name (e.g., cs.CL, "natural language processing")
+        #[arg(short, long)]
+        category: Option<String>,
 
-    match cli.command {
-        Commands::Search {
-            query,
-            title,
-            author,
-            max_results,
+        /// Year filter (e.g., 2023 or 2020-2023)
+        #[arg(short, long)]
+        year: Option<String>,
+
+        /// Also search DBLP (computer science; results have no abstracts)
+        #[arg(long)]
+        dblp: bool,
+
+        /// Only return open-access papers (arXiv papers always qualify)
+        #[arg(long)]
+        open_access: bool,
+
+        /// Directory to write into (one JSON file per paper, or papers.ndjson)
+        ///
+        /// Required for --format json; NDJSON goes to stdout when omitted.
+        #[arg(long)]
+        output_dir: Option<PathBuf>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "json")]
+        format: BatchFormat,
+
+        /// Also write manifest.json listing all exported files (--format json only)
+        #[arg(long)]
+        manifest: bool,
+
+        /// Run LLM analysis on each paper
+        #[arg(long)]
+        analyze: bool,
+
+        /// Extract full text from each paper's PDF
+        #[arg(short, long)]
+        extract_text: bool,
+
+        /// Extract keywords and topics via LLM
+        #[arg(short = 'k', long)]
+        extract_keywords: bool,
+
+        /// LLM provider (openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock)
+        #[arg(short, long, value_enum)]
+        provider: Option<ProviderArg>,
+
+        /// Model name for LLM analysis
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Sampling temperature (0.0-2.0; ignored by OpenAI reasoning models such as o1/o3/gpt-5)
+        #[arg(long)]
+        temperature: Option<f32>,
+
+        /// Maximum number of tokens to generate per LLM call
+        #[arg(long)]
+        max_tokens: Option<u32>,
+
+        /// Number of papers processed concurrently
+        #[arg(long, default_value = "4")]
+        concurrency: usize,
+
+        /// Compact JSON output (no pretty printing)
+        #[arg(long)]
+        compact: bool,
+
+        /// Record which source (arXiv, Semantic Scholar, DBLP, Both) each paper came from
+        #[arg(long)]
+        provenance: bool,
+    },
+
+    /// Build and query a local paper library with semantic (embedding) search
+    Index {
+        #[command(subcommand)]
+        command: IndexCommand,
+    },
+
+    /// Check provider credentials and source connectivity before a batch run
+    Doctor,
+
+    /// List known arXiv categories (code and name) for --category
+    Categories,
+
+    /// Print the schema describing the export format
+    Schema {
+        /// Schema language (json: JSON Schema for JSON exports, xsd: XML Schema for XML exports)
+        #[arg(long, value_enum, default_value = "json")]
+        format: SchemaFormat,
+    },
+
+    /// Run a Model Context Protocol server on stdio (search/fetch/analyze tools)
+    #[cfg(feature = "mcp")]
+    ServeMcp {
+        /// LLM provider for the analyze tool (openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock)
+        #[arg(short, long, value_enum)]
+        provider: Option<ProviderArg>,
+
+        /// Model name for the analyze tool
+        #[arg(short, long)]
+        model: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable text format
+    Text,
+    /// JSON format
+    Json,
+    /// XML format
+    Xml,
+    /// TOML format
+    Toml,
+}
+
+#[derive(Clone, Copy, ValueEnum, Debug)]
+enum CitationStyle {
+    /// "Authors (year). Title"
+    Plain,
+    /// APA 7th edition reference
+    Apa,
+    /// MLA 9th edition works-cited entry
+    Mla,
+}
+
+impl CitationStyle {
+    fn format(self, paper: &AcademicPaper) -> String {
+        match self {
+            CitationStyle::Plain => paper.to_citation(),
+            CitationStyle::Apa => paper.to_apa(),
+            CitationStyle::Mla => paper.to_mla(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum, Debug)]
+enum ExportFormat {
+    /// JSON format
+    Json,
+    /// XML format with structured sections
+    Xml,
+    /// Self-contained HTML report with inline charts
+    Html,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum GraphFormat {
+    /// JSON with `nodes` and `edges`
+    Json,
+    /// GraphML (Gephi, yEd, NetworkX)
+    Graphml,
+    /// Graphviz DOT
+    Dot,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SchemaFormat {
+    /// JSON Schema (draft 2020-12)
+    Json,
+    /// XML Schema (XSD)
+    Xsd,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BatchFormat {
+    /// One JSON file per paper
+    Json,
+    /// Newline-delimited JSON, one compact paper per line
+    Ndjson,
+}
+
+#[derive(Subcommand)]
+enum IndexCommand {
+    /// Fetch papers by arXiv ID, embed them and add them to the index
+    Add {
+        /// arXiv paper IDs (repeatable: --arxiv <id1> --arxiv <id2>)
+        #[arg(long, required = true, num_args = 1)]
+        arxiv: Vec<String>,
+
+        /// Index file (created if missing)
+        #[arg(long, default_value = "paper-index.json")]
+        index: PathBuf,
+
+        /// Embedding provider
+        #[arg(short, long, value_enum, default_value = "ollama")]
+        provider: EmbeddingArg,
+    },
+
+    /// Find the indexed papers most similar to a query
+    Search {
+        /// Free-text query
+        query: String,
+
+        /// Number of results
+        #[arg(short, default_value = "5")]
+        k: usize,
+
+        /// Index file
+        #[arg(long, default_value = "paper-index.json")]
+        index: PathBuf,
+
+        /// Embedding provider (must match the one used to build the index)
+        #[arg(short, long, value_enum, default_value = "ollama")]
+        provider: EmbeddingArg,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "text")]
+        output: OutputFormat,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EmbeddingArg {
+    /// Ollama /api/embed (OLLAMA_EMBEDDING_MODEL, default: nomic-embed-text)
+    Ollama,
+    /// Offline hashed bag-of-words vectors (demos and CI; not semantic)
+    Mock,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SortArg {
+    /// Source order (arXiv first, then Semantic Scholar)
+    Relevance,
+    /// Publication date, newest first
+    Date,
+    /// Citation count, most cited first
+    Citations,
+}
+
+impl From<SortArg> for SortKey {
+    fn from(sort: SortArg) -> Self {
+        match sort {
+            SortArg::Relevance => SortKey::Relevance,
+            SortArg::Date => SortKey::Date,
+            SortArg::Citations => SortKey::Citations,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LanguageArg {
+    /// Japanese prompts and output
+    Ja,
+    /// English prompts and output
+    En,
+}
+
+impl From<LanguageArg> for AnalysisLanguage {
+    fn from(language: LanguageArg) -> Self {
+        match language {
+            LanguageArg::Ja => AnalysisLanguage::Japanese,
+            LanguageArg::En => AnalysisLanguage::English,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ProviderArg {
+    Openai,
+    AzureOpenai,
+    /// Any OpenAI-compatible endpoint (OPENAI_COMPAT_* variables or --base-url)
+    OpenaiCompatible,
+    Anthropic,
+    Bedrock,
+    Cohere,
+    Ollama,
+    /// Offline canned responses (demos and CI; not for real analysis)
+    Mock,
+}
+
+impl From<ProviderArg> for LlmProviderType {
+    fn from(p: ProviderArg) -> Self {
+        match p {
+            ProviderArg::Openai => LlmProviderType::OpenAi,
+            ProviderArg::AzureOpenai => LlmProviderType::AzureOpenAi,
+            ProviderArg::OpenaiCompatible => LlmProviderType::OpenAiCompatible,
+            ProviderArg::Anthropic => LlmProviderType::Anthropic,
+            ProviderArg::Bedrock => LlmProviderType::Bedrock,
+            ProviderArg::Cohere => LlmProviderType::Cohere,
+            ProviderArg::Ollama => LlmProviderType::Ollama,
+            ProviderArg::Mock => LlmProviderType::Mock,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    init_logger(&cli.log_level)?;
+
+    match cli.command {
+        Commands::Search {
+            query,
+            title,
+            author,
+            max_results,
+            offset,
             category,
             year,
+            dblp,
+            open_access,
+            published_only,
+            preprints_only,
+            since,
+            no_dedupe,
+            sort,
+            count_total,
+            output,
+        } => {
+            let publication_status = if published_only {
+                Some(PublicationStatus::Published)
+            } else if preprints_only {
+                Some(PublicationStatus::Preprint)
+            } else {
+                None
+            };
+            cmd_search(
+                query,
+                title,
+                author,
+                max_results,
+                offset,
+                category,
+                year,
+                dblp,
+                open_access,
+                publication_status,
+                since,
+                no_dedupe,
+                sort.into(),
+                count_total,
+                output,
+            )
+            .await?;
+        }
+        Commands::Fetch {
+            arxiv,
+            ss,
+            doi,
+            title,
+            threshold,
+            interactive,
+            no_extract,
+            citation_style,
+            output,
+        } => {
+            cmd_fetch(
+                arxiv,
+                ss,
+                doi,
+                title,
+                threshold,
+                interactive,
+                no_extract,
+                citation_style,
+                output,
+            )
+            .await?;
+        }
+        Commands::Resolve { id, output } => {
+            cmd_resolve(&id, output).await?;
+        }
+        Commands::Versions { arxiv, output } => {
+            cmd_versions(&arxiv, output).await?;
+        }
+        Commands::Find {
+            title,
+            threshold,
+            candidates,
+            output,
+        } => {
+            cmd_find(&title, threshold, candidates, output).await?;
+        }
+        Commands::Author {
+            name,
+            id,
+            max_papers,
+            output,
+        } => {
+            cmd_author(name, id, max_papers, output).await?;
+        }
+        Commands::Related {
+            arxiv,
+            ss,
+            limit,
             output,
         } => {
-            cmd_search(query, title, author, max_results, category, year, output).await?;
+            cmd_related(arxiv, ss, limit, output).await?;
         }
-        Commands::Fetch { arxiv, ss, output } => {
-            cmd_fetch(arxiv, ss, output).await?;
+        Commands::Graph {
+            arxiv,
+            ss,
+            depth,
+            max_per_node,
+            format,
+            output,
+            resume,
+        } => {
+            cmd_graph(arxiv, ss, depth, max_per_node, format, output, resume).await?;
         }
         Commands::Analyze {
             arxiv,
             ss,
             provider,
             model,
+            temperature,
+            max_tokens,
+            compare_with,
+            input_file,
+            output_dir,
+            concurrency,
+            output,
+        } => {
+            if let (Some(input_file), Some(output_dir)) = (input_file, output_dir) {
+                cmd_analyze_batch(
+                    input_file,
+                    output_dir,
+                    concurrency,
+                    provider,
+                    cli.base_url.as_deref(),
+                    cli.prompt_language.into(),
+                    build_llm_config(model.as_deref(), temperature, max_tokens)?,
+                    output,
+                )
+                .await?;
+            } else {
+                cmd_analyze(
+                    arxiv,
+                    ss,
+                    provider,
+                    cli.base_url.as_deref(),
+                    cli.prompt_language.into(),
+                    model,
+                    temperature,
+                    max_tokens,
+                    compare_with,
+                    output,
+                )
+                .await?;
+            }
+        }
+        Commands::Keywords {
+            arxiv,
+            ss,
+            title,
+            threshold,
+            provider,
+            model,
+            temperature,
+            max_tokens,
+            output,
+        } => {
+            cmd_keywords(
+                arxiv,
+                ss,
+                title,
+                threshold,
+                provider,
+                cli.base_url.as_deref(),
+                cli.prompt_language.into(),
+                model,
+                temperature,
+                max_tokens,
+                output,
+            )
+            .await?;
+        }
+        Commands::Compare {
+            arxiv,
+            provider,
+            model,
+            temperature,
+            max_tokens,
             output,
         } => {
-            cmd_analyze(arxiv, ss, provider, model, output).await?;
+            cmd_compare(
+                arxiv,
+                provider,
+                cli.base_url.as_deref(),
+                cli.prompt_language.into(),
+                model,
+                temperature,
+                max_tokens,
+                output,
+            )
+            .await?;
+        }
+        Commands::Synthesize {
+            arxiv,
+            focus,
+            provider,
+            model,
+            temperature,
+            max_tokens,
+        } => {
+            cmd_synthesize(
+                arxiv,
+                focus,
+                provider,
+                cli.base_url.as_deref(),
+                cli.prompt_language.into(),
+                model,
+                temperature,
+                max_tokens,
+            )
+            .await?;
+        }
+        Commands::Grep {
+            arxiv,
+            pattern,
+            regex,
+            ignore_case,
+            output,
+        } => {
+            cmd_grep(arxiv, pattern, regex, ignore_case, output).await?;
         }
         Commands::Export {
             arxiv,
             ss,
             title,
             threshold,
+            interactive,
             output,
             analyze,
             extract_text,
@@ -259,18 +1092,26 @@ async fn main() -> anyhow::Result<()> {
             provider,
             model,
             extract_keywords,
+            section_summaries,
             compact,
             format,
             with_schema,
             no_math_markup,
             no_extract_references,
             pdf,
+            pages,
+            resume,
+            temperature,
+            max_tokens,
+            fields,
+            highlight,
         } => {
             cmd_export(
                 arxiv,
                 ss,
                 title,
                 threshold,
+                interactive,
                 output,
                 analyze,
                 extract_text,
@@ -278,65 +1119,200 @@ async fn main() -> anyhow::Result<()> {
                 include_references,
                 max_citations,
                 provider,
+                cli.base_url.as_deref(),
+                cli.prompt_language.into(),
                 model,
                 extract_keywords,
+                section_summaries,
                 compact,
                 format,
                 with_schema,
                 no_math_markup,
                 no_extract_references,
                 pdf,
+                pages,
+                resume,
+                temperature,
+                max_tokens,
+                &fields,
+                highlight,
+            )
+            .await?;
+        }
+        Commands::ExportSearch {
+            query,
+            title,
+            author,
+            max_results,
+            category,
+            year,
+            dblp,
+            open_access,
+            output_dir,
+            format,
+            manifest,
+            analyze,
+            extract_text,
+            extract_keywords,
+            provider,
+            model,
+            temperature,
+            max_tokens,
+            concurrency,
+            compact,
+            provenance,
+        } => {
+            let params = build_search_params(
+                query,
+                title,
+                author,
+                max_results,
+                category,
+                year,
+                open_access,
+            )?;
+            let options = ExportOptions {
+                analyzed: analyze,
+                text_extracted: extract_text,
+                keywords_extracted: extract_keywords,
+                llm_model: model.clone(),
+                include_provenance: provenance,
+                ..Default::default()
+            };
+            let llm_config = build_llm_config(model.as_deref(), temperature, max_tokens)?;
+            cmd_export_search(
+                params,
+                dblp,
+                output_dir,
+                format,
+                manifest,
+                options,
+                provider,
+                cli.base_url.as_deref(),
+                cli.prompt_language.into(),
+                llm_config,
+                concurrency,
+                compact,
+            )
+            .await?;
+        }
+        Commands::Index { command } => match command {
+            IndexCommand::Add {
+                arxiv,
+                index,
+                provider,
+            } => {
+                cmd_index_add(arxiv, index, provider).await?;
+            }
+            IndexCommand::Search {
+                query,
+                k,
+                index,
+                provider,
+                output,
+            } => {
+                cmd_index_search(&query, k, index, provider, output).await?;
+            }
+        },
+        Commands::Doctor => {
+            cmd_doctor(cli.base_url.as_deref()).await?;
+        }
+        Commands::Categories => {
+            for (code, name, _) in ARXIV_CATEGORIES {
+                println!("{:<16} {}", code, name);
+            }
+        }
+        Commands::Schema { format } => match format {
+            SchemaFormat::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&exported_paper_json_schema())?
+            ),
+            SchemaFormat::Xsd => print!("{}", get_xml_schema()),
+        },
+        #[cfg(feature = "mcp")]
+        Commands::ServeMcp { provider, model } => {
+            cmd_serve_mcp(
+                provider,
+                cli.base_url.as_deref(),
+                cli.prompt_language.into(),
+                model,
             )
             .await?;
         }
     }
 
-    Ok(())
-}
-
-async fn cmd_search(
-    query: Option<String>,
-    title: Option<String>,
-    author: Option<String>,
-    max_results: usize,
-    category: Option<String>,
-    year: Option<String>,
-    output: OutputFormat,
-) -> anyhow::Result<()> {
-    if query.is_none() && title.is_none() && author.is_none() {
-        anyhow::bail!("At least one of --query, --title, or --author is required");
-    }
-
-    let client = PaperClient::new();
-    let mut params = SearchParams::new().with_max_results(max_results);
+    Ok(())
+}
 
-    if let Some(q) = query {
-        params = params.with_query(q);
-    }
-    if let Some(t) = title {
-        params = params.with_title(t);
-    }
-    if let Some(a) = author {
-        params = params.with_author(a);
-    }
-    if let Some(c) = category {
-        params = params.with_category(c);
-    }
-    if let Some(y) = year {
-        params = params.with_year(y);
-    }
+#[allow(clippy::too_many_arguments)]
+async fn cmd_search(
+    query: Option<String>,
+    title: Option<String>,
+    author: Option<String>,
+    max_results: usize,
+    offset: usize,
+    category: Option<String>,
+    year: Option<String>,
+    dblp: bool,
+    open_access: bool,
+    publication_status: Option<PublicationStatus>,
+    since: Option<DateTime<Local>>,
+    no_dedupe: bool,
+    sort: SortKey,
+    count_total: bool,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let client = PaperClient::new().with_dblp(dblp);
+    let params = build_search_params(
+        query,
+        title,
+        author,
+        max_results,
+        category,
+        year,
+        open_access,
+    )?
+    .with_offset(offset)
+    .with_deduplicate(!no_dedupe)
+    .with_sort(sort)
+    .with_count_total(count_total);
+    let params = apply_search_filters(params, publication_status, since);
 
     let result = client.search(params).await?;
 
     match output {
         OutputFormat::Text => {
-            println!("Found {} papers:\n", result.papers.len());
+            match result.total_count {
+                Some(total) => println!(
+                    "Found {} of {} papers:\n",
+                    result.papers.len(),
+                    format_thousands(total)
+                ),
+                None => println!("Found {} papers:\n", result.papers.len()),
+            }
             for (i, paper) in result.papers.iter().enumerate() {
+                if no_dedupe && let Some(source) = paper.source {
+                    println!("[{:?}]", source);
+                }
                 print_paper_summary(i + 1, paper);
             }
         }
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&result.papers)?);
+            #[derive(Serialize)]
+            struct JsonSearchResult<'a> {
+                total_count: Option<usize>,
+                papers: &'a [AcademicPaper],
+                /// Source of each paper (only without deduplication)
+                #[serde(skip_serializing_if = "Option::is_none")]
+                paper_sources: Option<Vec<Option<PaperSource>>>,
+            }
+            let wrapper = JsonSearchResult {
+                total_count: result.total_count,
+                papers: &result.papers,
+                paper_sources: no_dedupe
+                    .then(|| result.papers.iter().map(|paper| paper.source).collect()),
+            };
+            println!("{}", serde_json::to_string_pretty(&wrapper)?);
         }
         OutputFormat::Xml => {
             let wrapper = PapersWrapper {
@@ -360,16 +1336,320 @@ async fn cmd_search(
     Ok(())
 }
 
+/// Format a count with comma thousands separators ("3,421")
+fn format_thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(c);
+    }
+    formatted
+}
+
+/// Parse a `--since` date (YYYY-MM-DD) as local midnight
+fn parse_since_date(s: &str) -> Result<DateTime<Local>, String> {
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|e| format!("invalid date '{}' (expected YYYY-MM-DD): {}", s, e))?;
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|time| Local.from_local_datetime(&time).earliest())
+        .ok_or_else(|| format!("invalid local date: {}", s))
+}
+
+/// Apply the `search` command's publication-status and `--since` filters
+fn apply_search_filters(
+    mut params: SearchParams,
+    publication_status: Option<PublicationStatus>,
+    since: Option<DateTime<Local>>,
+) -> SearchParams {
+    if let Some(status) = publication_status {
+        params = params.with_publication_status(status);
+    }
+    if let Some(since) = since {
+        params = params.with_submitted_after(since);
+    }
+    params
+}
+
+/// Build search parameters from the shared search flags
+fn build_search_params(
+    query: Option<String>,
+    title: Option<String>,
+    author: Option<String>,
+    max_results: usize,
+    category: Option<String>,
+    year: Option<String>,
+    open_access: bool,
+) -> anyhow::Result<SearchParams> {
+    if query.is_none() && title.is_none() && author.is_none() && category.is_none() {
+        anyhow::bail!("At least one of --query, --title, --author, or --category is required");
+    }
+
+    let mut params = SearchParams::new()
+        .with_max_results(max_results)
+        .with_open_access_only(open_access);
+
+    if let Some(q) = query {
+        params = params.with_query(q);
+    }
+    if let Some(t) = title {
+        params = params.with_title(t);
+    }
+    if let Some(a) = author {
+        params = params.with_author(a);
+    }
+    if let Some(c) = category {
+        params = params.with_category(c);
+    }
+    if let Some(y) = year {
+        params = params.with_year(y);
+    }
+    Ok(params)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn cmd_export_search(
+    params: SearchParams,
+    dblp: bool,
+    output_dir: Option<PathBuf>,
+    format: BatchFormat,
+    write_manifest: bool,
+    mut options: ExportOptions,
+    provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
+    llm_config: LlmConfig,
+    concurrency: usize,
+    compact: bool,
+) -> anyhow::Result<()> {
+    match format {
+        BatchFormat::Json if output_dir.is_none() => {
+            anyhow::bail!("--output-dir is required for --format json")
+        }
+        BatchFormat::Ndjson if write_manifest => {
+            anyhow::bail!("--manifest requires --format json")
+        }
+        _ => {}
+    }
+
+    let client = PaperClient::new().with_dblp(dblp);
+    let result = client.search(params).await?;
+    if let Some(dir) = &output_dir {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let provider_type = resolve_provider_type(provider_arg)?;
+    if options.analyzed || options.keywords_extracted {
+        options.llm_provider = Some(provider_type.to_string());
+    }
+
+    eprintln!(
+        "Exporting {} papers to {}",
+        result.papers.len(),
+        output_dir
+            .as_ref()
+            .map_or("stdout".to_string(), |dir| dir.display().to_string())
+    );
+    let progress_bar = generate_progress_bar(result.papers.len(), Some("Exporting".to_string()));
+
+    let use_utc = Config::use_utc_from_env();
+
+    // Process papers concurrently; results come back in search order
+    let (client, options, llm_config) = (&client, &options, &llm_config);
+    let pb = progress_bar.clone();
+    let exports = futures::stream::iter(result.papers)
+        .map(move |paper| {
+            let pb = pb.clone();
+            async move {
+                let mut exported = export_search_paper(
+                    client,
+                    paper,
+                    options,
+                    provider_type,
+                    base_url,
+                    language,
+                    llm_config,
+                )
+                .await;
+                if use_utc {
+                    exported.normalize_timestamps_to_utc();
+                }
+                pb.set_message(exported.paper.title.clone());
+                pb.inc(1);
+                exported
+            }
+        })
+        .buffered(concurrency.max(1));
+
+    let exported_count = if let (BatchFormat::Json, Some(output_dir)) = (format, &output_dir) {
+        let exported: Vec<ExportedPaper> = exports.collect().await;
+        progress_bar.finish_and_clear();
+
+        // Never overwrite papers exported by an earlier run into the same directory
+        let mut taken = existing_file_names(output_dir)?;
+        let mut entries = Vec::with_capacity(exported.len());
+        for paper in &exported {
+            let file_name = unique_file_name(&export_file_stem(&paper.paper), "json", &mut taken);
+            let content = if compact {
+                serde_json::to_string(paper)?
+            } else {
+                serde_json::to_string_pretty(paper)?
+            };
+            std::fs::write(output_dir.join(&file_name), content)?;
+            for warning in &paper.export_metadata.warnings {
+                eprintln!("  {}: {}", file_name, warning);
+            }
+            entries.push(ManifestEntry::from_exported(file_name, paper));
+        }
+
+        if write_manifest {
+            let manifest_path = output_dir.join("manifest.json");
+            let mut manifest = ExportManifest::new(entries);
+            if use_utc {
+                manifest.normalize_timestamps_to_utc();
+            }
+            std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+            eprintln!("Manifest written to: {}", manifest_path.display());
+        }
+        exported.len()
+    } else {
+        // Write each paper as soon as it is ready instead of collecting the batch
+        let output: Box<dyn std::io::Write> = match &output_dir {
+            Some(dir) => Box::new(std::fs::File::create(dir.join("papers.ndjson"))?),
+            None => Box::new(std::io::stdout()),
+        };
+        let mut writer = ExportWriter::new(output);
+        let mut exports = std::pin::pin!(exports);
+        while let Some(paper) = exports.next().await {
+            for warning in &paper.export_metadata.warnings {
+                eprintln!("  {}: {}", paper.paper.title, warning);
+            }
+            writer.write(&paper)?;
+        }
+        progress_bar.finish_and_clear();
+        writer.count()
+    };
+
+    eprintln!("Exported {} papers", exported_count);
+    Ok(())
+}
+
+/// Run the per-paper stages of `export-search`, recording failures as warnings
+async fn export_search_paper(
+    client: &PaperClient,
+    mut paper: AcademicPaper,
+    options: &ExportOptions,
+    provider_type: LlmProviderType,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
+    llm_config: &LlmConfig,
+) -> ExportedPaper {
+    let mut warnings = Vec::new();
+
+    if options.text_extracted
+        && !paper.has_extracted_text()
+        && let Err(e) = client.extract_text(&mut paper).await
+    {
+        warnings.push(format!("Text extraction failed: {}", e));
+    }
+
+    if options.analyzed {
+        let analyze_result = match provider_from_env(provider_type, base_url) {
+            Ok(provider) => analyze_with_provider(provider, &mut paper, language, llm_config).await,
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = analyze_result {
+            warnings.push(format!("LLM analysis failed: {}", e));
+        }
+    }
+
+    let mut keywords = None;
+    if options.keywords_extracted {
+        let keywords_result = match provider_from_env(provider_type, base_url) {
+            Ok(provider) => {
+                extract_keywords_with_provider(provider, &paper, language, llm_config).await
+            }
+            Err(e) => Err(e.into()),
+        };
+        match keywords_result {
+            Ok(result) => keywords = Some(result),
+            Err(e) => warnings.push(format!("Keyword extraction failed: {}", e)),
+        }
+    }
+
+    let mut exported = ExportedPaper::new(paper, options.clone());
+    exported.collect_extracted_references();
+    if let Some((keywords, context)) = keywords {
+        exported.keywords = Some(keywords);
+        exported.research_context = Some(context);
+    }
+    for warning in warnings {
+        exported.add_warning(warning);
+    }
+    exported
+}
+
+/// Provider from `--provider`, then `LLM_PROVIDER` (an unknown name is an
+/// error), defaulting to OpenAI
+fn resolve_provider_type(
+    provider_arg: Option<ProviderArg>,
+) -> academic_paper_interpreter::AppResult<LlmProviderType> {
+    match provider_arg {
+        Some(arg) => Ok(arg.into()),
+        None => Config::parse_provider_from_env(),
+    }
+}
+
+/// Build the selected provider from environment configuration
+///
+/// `base_url` (`--base-url`) takes precedence over OPENAI_COMPAT_BASE_URL.
+fn provider_from_env(
+    provider_type: LlmProviderType,
+    base_url: Option<&str>,
+) -> academic_paper_interpreter::AppResult<Box<dyn LlmProvider>> {
+    let mut config = Config::from_env()?;
+    if let Some(url) = base_url {
+        config.openai_compat_base_url = Some(url.to_string());
+    }
+    build_provider(provider_type, &config)
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn cmd_fetch(
     arxiv: Option<String>,
     ss: Option<String>,
+    doi: Option<String>,
+    title: Option<String>,
+    threshold: f64,
+    interactive: bool,
+    no_extract: bool,
+    citation_style: Option<CitationStyle>,
     output: OutputFormat,
 ) -> anyhow::Result<()> {
-    if arxiv.is_none() && ss.is_none() {
-        anyhow::bail!("Either --arxiv or --ss is required");
+    let client = PaperClient::new().with_auto_extract(!no_extract);
+
+    if arxiv.is_none()
+        && ss.is_none()
+        && doi.is_none()
+        && let Some(title) = title
+    {
+        let mut paper = find_by_title(&client, &title, threshold, interactive).await?;
+        // Title search returns metadata only; extract like a fetch by ID would
+        if !no_extract
+            && !paper.has_extracted_text()
+            && let Err(e) = client.extract_text(&mut paper).await
+        {
+            eprintln!("Warning: Text extraction failed: {}", e);
+        }
+        print_validation_warnings(&paper);
+        return print_fetched_paper(&paper, citation_style, output);
+    }
+    if arxiv.is_none() && ss.is_none() && doi.is_none() {
+        anyhow::bail!("One of --arxiv, --ss, --doi, or --title is required");
     }
 
-    let client = PaperClient::new();
     let mut params = SearchParams::new();
 
     if let Some(id) = arxiv {
@@ -378,123 +1658,1115 @@ async fn cmd_fetch(
     if let Some(id) = ss {
         params = params.with_ss_id(id);
     }
+    if let Some(doi) = doi {
+        params = params.with_doi(doi);
+    }
+
+    let result = client.search(params).await?;
+
+    if result.papers.is_empty() {
+        anyhow::bail!("Paper not found");
+    }
+
+    let paper = &result.papers[0];
+    print_validation_warnings(paper);
+    print_fetched_paper(paper, citation_style, output)
+}
+
+/// Print a fetched paper in the requested format
+///
+/// A citation in `citation_style` follows the text output.
+fn print_fetched_paper(
+    paper: &AcademicPaper,
+    citation_style: Option<CitationStyle>,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Text => {
+            print_paper_detail(paper);
+            if let Some(style) = citation_style {
+                println!();
+                println!("Citation:");
+                println!("{}", style.format(paper));
+            }
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(paper)?);
+        }
+        OutputFormat::Xml => {
+            println!("{}", to_xml(paper)?);
+        }
+        OutputFormat::Toml => {
+            println!("{}", to_toml(paper)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Number of candidates offered by `--interactive`
+const INTERACTIVE_CANDIDATES: usize = 10;
+
+/// Find a paper by title, letting the user choose among the closest matches
+///
+/// The candidates come from `PaperClient::search_title_candidates`. Without
+/// `interactive`, or when stdin/stderr is not a terminal, the best match
+/// within `threshold` is taken as before.
+async fn find_by_title(
+    client: &PaperClient,
+    title: &str,
+    threshold: f64,
+    interactive: bool,
+) -> anyhow::Result<AcademicPaper> {
+    let is_terminal = std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
+    if !interactive || !is_terminal {
+        if interactive {
+            eprintln!("Not a terminal; selecting the best title match automatically");
+        }
+        return Ok(client.search_by_title_fuzzy(title, threshold).await?);
+    }
+
+    let mut candidates = client
+        .search_title_candidates(title, threshold, INTERACTIVE_CANDIDATES)
+        .await?;
+    if candidates.is_empty() {
+        anyhow::bail!(
+            "No paper found matching '{}' within threshold {:.2}",
+            title,
+            threshold
+        );
+    }
+
+    let options: Vec<String> = candidates
+        .iter()
+        .map(|(paper, distance)| {
+            let id = if paper.arxiv_id.is_empty() {
+                &paper.doi
+            } else {
+                &paper.arxiv_id
+            };
+            let year = paper
+                .known_year()
+                .map_or_else(|| "n.d.".to_string(), |year| year.to_string());
+            format!("[{:.2}] {} ({}, {})", distance, paper.title, year, id)
+        })
+        .collect();
+    match prompt_choice(
+        &mut std::io::stdin().lock(),
+        &mut std::io::stderr(),
+        &options,
+    )? {
+        Some(index) => Ok(candidates.swap_remove(index).0),
+        None => anyhow::bail!("No paper selected"),
+    }
+}
+
+/// Ask the user to pick one of `options` by number, returning its zero-based index
+///
+/// Invalid answers repeat the prompt; an empty answer, `q` or end of input cancels.
+fn prompt_choice<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    options: &[String],
+) -> std::io::Result<Option<usize>> {
+    for (i, option) in options.iter().enumerate() {
+        writeln!(output, "{:>3}) {}", i + 1, option)?;
+    }
+    loop {
+        write!(output, "Select [1-{}, q to cancel]: ", options.len())?;
+        output.flush()?;
+
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            return Ok(None);
+        }
+        let answer = answer.trim();
+        if answer.is_empty() || answer.eq_ignore_ascii_case("q") {
+            return Ok(None);
+        }
+        match answer.parse::<usize>() {
+            Ok(n) if (1..=options.len()).contains(&n) => return Ok(Some(n - 1)),
+            _ => writeln!(output, "Invalid choice: {}", answer)?,
+        }
+    }
+}
+
+async fn cmd_resolve(id: &str, output: OutputFormat) -> anyhow::Result<()> {
+    let client = PaperClient::new();
+    let ids = client.resolve_ids(id).await?;
+
+    match output {
+        OutputFormat::Text => {
+            let or_unknown = |value: &str| {
+                if value.is_empty() {
+                    "-".to_string()
+                } else {
+                    value.to_string()
+                }
+            };
+            println!("arXiv: {}", or_unknown(&ids.arxiv_id));
+            println!("SS ID: {}", or_unknown(&ids.ss_id));
+            println!("DOI:   {}", or_unknown(&ids.doi));
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&ids)?);
+        }
+        OutputFormat::Xml => {
+            println!("{}", to_xml(&ids)?);
+        }
+        OutputFormat::Toml => {
+            println!("{}", to_toml(&ids)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Wrapper for arXiv versions (for XML/TOML root element)
+#[derive(Serialize)]
+struct VersionsWrapper<'a> {
+    #[serde(rename = "version")]
+    versions: &'a [ArxivVersion],
+}
+
+async fn cmd_versions(arxiv_id: &str, output: OutputFormat) -> anyhow::Result<()> {
+    let client = PaperClient::new();
+    let versions = client.fetch_arxiv_versions(arxiv_id).await?;
+
+    match output {
+        OutputFormat::Text => {
+            for v in &versions {
+                println!(
+                    "v{}  {}  ({})",
+                    v.version,
+                    v.submitted_at.format("%Y-%m-%d %H:%M"),
+                    v.size
+                );
+            }
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&versions)?);
+        }
+        OutputFormat::Xml => {
+            println!(
+                "{}",
+                to_xml(&VersionsWrapper {
+                    versions: &versions
+                })?
+            );
+        }
+        OutputFormat::Toml => {
+            println!(
+                "{}",
+                to_toml(&VersionsWrapper {
+                    versions: &versions
+                })?
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn cmd_graph(
+    arxiv: Option<String>,
+    ss: Option<String>,
+    depth: usize,
+    max_per_node: usize,
+    format: GraphFormat,
+    output: Option<PathBuf>,
+    resume: bool,
+) -> anyhow::Result<()> {
+    if arxiv.is_none() && ss.is_none() {
+        anyhow::bail!("Either --arxiv or --ss is required");
+    }
+    if resume && !matches!(format, GraphFormat::Json) {
+        anyhow::bail!("--resume requires --format json");
+    }
+
+    let client = PaperClient::new();
+    let mut params = SearchParams::new();
+    if let Some(id) = arxiv {
+        params = params.with_arxiv_id(id);
+    }
+    if let Some(id) = ss {
+        params = params.with_ss_id(id);
+    }
+
+    let result = client.search(params).await?;
+    let Some(paper) = result.papers.into_iter().next() else {
+        anyhow::bail!("Paper not found");
+    };
+
+    let mut graph = CitationGraph::from_root(&paper)?;
+    if let Some(path) = output.as_ref().filter(|path| resume && path.exists()) {
+        let saved: CitationGraph = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        if saved.root_id() != graph.root_id() {
+            anyhow::bail!(
+                "saved graph in {} is not rooted at '{}'; drop --resume or choose another output file",
+                path.display(),
+                paper.title
+            );
+        }
+        eprintln!(
+            "Resuming citation crawl from {} ({} paper(s) pending)",
+            path.display(),
+            saved.pending.len()
+        );
+        graph = saved;
+    }
+
+    eprintln!(
+        "Crawling citations of '{}' ({} hop(s), up to {} per paper)...",
+        paper.title, depth, max_per_node
+    );
+    // Save JSON output after every hop so an interrupted crawl can be resumed
+    let checkpoint = |graph: &CitationGraph| {
+        if let (Some(path), GraphFormat::Json) = (&output, format)
+            && let Err(e) = serde_json::to_string_pretty(graph)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Ok(std::fs::write(path, content)?))
+        {
+            eprintln!("Warning: failed to save crawl progress: {}", e);
+        }
+    };
+    let graph = client
+        .resume_citation_network(graph, depth, max_per_node, checkpoint)
+        .await?;
+    if !graph.pending.is_empty() {
+        eprintln!(
+            "Warning: citations of {} paper(s) could not be fetched; rerun with --resume to retry",
+            graph.pending.len()
+        );
+    }
+    let content = match format {
+        GraphFormat::Json => serde_json::to_string_pretty(&graph)?,
+        GraphFormat::Graphml => graph.to_graphml(),
+        GraphFormat::Dot => graph.to_dot(),
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, content)?;
+            eprintln!(
+                "Citation graph ({} papers, {} citations) written to: {}",
+                graph.nodes.len(),
+                graph.edges.len(),
+                path.display()
+            );
+        }
+        None => println!("{}", content.trim_end()),
+    }
+
+    Ok(())
+}
+
+/// Title-search candidate with its distance to the query
+#[derive(Serialize)]
+struct TitleCandidate<'a> {
+    distance: f64,
+    paper: &'a AcademicPaper,
+}
+
+/// Wrapper for ranked candidates (for XML/TOML root element)
+#[derive(Serialize)]
+struct CandidatesWrapper<'a> {
+    #[serde(rename = "candidate")]
+    candidates: Vec<TitleCandidate<'a>>,
+}
+
+async fn cmd_find(
+    title: &str,
+    threshold: f64,
+    candidates: usize,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let client = PaperClient::new();
+    let matches = client
+        .search_title_candidates(title, threshold, candidates)
+        .await?;
+    if matches.is_empty() {
+        anyhow::bail!(
+            "No paper found matching '{}' within threshold {:.2}",
+            title,
+            threshold
+        );
+    }
+
+    let wrapper = CandidatesWrapper {
+        candidates: matches
+            .iter()
+            .map(|(paper, distance)| TitleCandidate {
+                distance: *distance,
+                paper,
+            })
+            .collect(),
+    };
+
+    match output {
+        OutputFormat::Text => {
+            for (i, (paper, distance)) in matches.iter().enumerate() {
+                println!("[distance {:.3}]", distance);
+                print_paper_summary(i + 1, paper);
+            }
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&wrapper.candidates)?);
+        }
+        OutputFormat::Xml => {
+            println!("{}", to_xml(&wrapper)?);
+        }
+        OutputFormat::Toml => {
+            println!("{}", to_toml(&wrapper)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn cmd_author(
+    name: Option<String>,
+    id: Option<String>,
+    max_papers: usize,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let Some(name_or_id) = id.or(name) else {
+        anyhow::bail!("Either --name or --id is required");
+    };
+
+    let client = PaperClient::new();
+    let profile = client.author_profile(&name_or_id, max_papers).await?;
+
+    match output {
+        OutputFormat::Text => {
+            print_author_profile(&profile);
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&profile)?);
+        }
+        OutputFormat::Xml => {
+            println!("{}", to_xml(&profile)?);
+        }
+        OutputFormat::Toml => {
+            println!("{}", to_toml(&profile)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn cmd_related(
+    arxiv: Option<String>,
+    ss: Option<String>,
+    limit: usize,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    if arxiv.is_none() && ss.is_none() {
+        anyhow::bail!("Either --arxiv or --ss is required");
+    }
+
+    let client = PaperClient::new();
+    let mut params = SearchParams::new();
+    if let Some(id) = arxiv {
+        params = params.with_arxiv_id(id);
+    }
+    if let Some(id) = ss {
+        params = params.with_ss_id(id);
+    }
+
+    let result = client.search(params).await?;
+    let Some(paper) = result.papers.into_iter().next() else {
+        anyhow::bail!("Paper not found");
+    };
+
+    let related = client.find_related(&paper, limit).await?;
+    let data = RelatedData::from_papers(&related);
+
+    match output {
+        OutputFormat::Text => {
+            println!("Papers related to: {}\n", paper.title);
+            for (i, related_paper) in related.iter().enumerate() {
+                print_paper_summary(i + 1, related_paper);
+            }
+            print_related_statistics(&data.statistics);
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&data)?);
+        }
+        OutputFormat::Xml => {
+            println!("{}", to_xml(&data)?);
+        }
+        OutputFormat::Toml => {
+            println!("{}", to_toml(&data)?);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_related_statistics(statistics: &CitationStatistics) {
+    if !statistics.top_venues.is_empty() {
+        println!("Top venues:");
+        for (venue, count) in &statistics.top_venues {
+            println!("  {} ({})", venue, count);
+        }
+    }
+    let mut years: Vec<_> = statistics.by_year.iter().collect();
+    years.sort();
+    if !years.is_empty() {
+        println!("By year:");
+        for (year, count) in years {
+            println!("  {}: {}", year, count);
+        }
+    }
+    println!(
+        "Average citation count: {:.1}",
+        statistics.avg_citation_count
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn cmd_analyze(
+    arxiv: Option<String>,
+    ss: Option<String>,
+    provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
+    model: Option<String>,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    compare_with: Option<PathBuf>,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    if arxiv.is_none() && ss.is_none() {
+        anyhow::bail!("One of --arxiv, --ss, or --input-file is required");
+    }
+    let llm_config = build_llm_config(model.as_deref(), temperature, max_tokens)?;
+
+    // Load the earlier analysis up front so a bad path fails before any LLM call
+    let previous_analysis = compare_with
+        .as_deref()
+        .map(load_previous_analysis)
+        .transpose()?;
+
+    // Fetch paper first
+    let client = PaperClient::new();
+    let mut params = SearchParams::new();
+
+    if let Some(id) = arxiv {
+        params = params.with_arxiv_id(id);
+    }
+    if let Some(id) = ss {
+        params = params.with_ss_id(id);
+    }
+
+    let result = client.search(params).await?;
+
+    if result.papers.is_empty() {
+        anyhow::bail!("Paper not found");
+    }
+
+    let mut paper = result.papers.into_iter().next().unwrap();
+
+    // Determine provider
+    let provider_type = resolve_provider_type(provider_arg)?;
+
+    // Analyze with appropriate provider
+    let provider = provider_from_env(provider_type, base_url)?;
+    analyze_with_provider(provider, &mut paper, language, &llm_config).await?;
+
+    if let Some(previous) = previous_analysis {
+        let Some(ref current) = paper.analysis else {
+            anyhow::bail!("Analysis produced no result to compare");
+        };
+        let diff = previous.diff(current);
+        match output {
+            OutputFormat::Text => print_analysis_diff(&diff),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&diff)?),
+            OutputFormat::Xml => println!("{}", to_xml(&diff)?),
+            OutputFormat::Toml => println!("{}", to_toml(&diff)?),
+        }
+        return Ok(());
+    }
+
+    match output {
+        OutputFormat::Text => {
+            print_paper_with_analysis(&paper);
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&paper)?);
+        }
+        OutputFormat::Xml => {
+            println!("{}", to_xml(&paper)?);
+        }
+        OutputFormat::Toml => {
+            println!("{}", to_toml(&paper)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetch and analyze every paper listed in `input_file`, writing one file per paper
+///
+/// Papers go through `PaperAnalyzer::analyze_batch`, `concurrency` at a time,
+/// and each result is written as soon as it is ready. A paper that fails to
+/// fetch or analyze is reported and skipped; the command fails only if none
+/// succeeds. Files already in `output_dir` are never overwritten.
+#[allow(clippy::too_many_arguments)]
+async fn cmd_analyze_batch(
+    input_file: PathBuf,
+    output_dir: PathBuf,
+    concurrency: usize,
+    provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
+    llm_config: LlmConfig,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let content = std::fs::read_to_string(&input_file)?;
+    let ids = PaperId::parse_list(&content)?;
+    if ids.is_empty() {
+        anyhow::bail!("No paper IDs in {}", input_file.display());
+    }
+    std::fs::create_dir_all(&output_dir)?;
+
+    // Surface a missing key or bad provider before fetching anything
+    let provider_type = resolve_provider_type(provider_arg)?;
+    let analyzer = PaperAnalyzer::from_boxed(provider_from_env(provider_type, base_url)?)
+        .with_language(language)
+        .with_config(llm_config);
+    let client = PaperClient::new();
+
+    let extension = match output {
+        OutputFormat::Xml => "xml",
+        OutputFormat::Toml => "toml",
+        OutputFormat::Text | OutputFormat::Json => "json",
+    };
+    let mut taken = existing_file_names(&output_dir)?;
+    let progress_bar = generate_progress_bar(ids.len(), Some("Analyzing".to_string()));
+    let mut failures = 0;
+    let mut analyzed = 0;
+    let mut results = std::pin::pin!(analyzer.analyze_batch(&client, &ids, concurrency));
+    while let Some((index, result)) = results.next().await {
+        progress_bar.inc(1);
+        let paper = match result {
+            Ok(paper) => paper,
+            Err(e) => {
+                failures += 1;
+                progress_bar.println(format!("  {}: {}", ids[index], e));
+                continue;
+            }
+        };
+        progress_bar.set_message(paper.title.clone());
+        let file_name = unique_file_name(&export_file_stem(&paper), extension, &mut taken);
+        let content = match output {
+            OutputFormat::Xml => to_xml(&paper)?,
+            OutputFormat::Toml => to_toml(&paper)?,
+            OutputFormat::Text | OutputFormat::Json => serde_json::to_string_pretty(&paper)?,
+        };
+        std::fs::write(output_dir.join(&file_name), content)?;
+        analyzed += 1;
+    }
+    progress_bar.finish_and_clear();
+
+    eprintln!(
+        "Analyzed {} of {} papers ({} failed); results in {}",
+        analyzed,
+        ids.len(),
+        failures,
+        output_dir.display()
+    );
+    if analyzed == 0 {
+        anyhow::bail!("No paper could be analyzed");
+    }
+    Ok(())
+}
+
+/// Read a previously saved analysis for `analyze --compare-with`
+///
+/// Accepts an export file (`paper.analysis`), the JSON printed by
+/// `analyze -o json` (`analysis`), or a bare `PaperAnalysis` object.
+fn load_previous_analysis(path: &std::path::Path) -> anyhow::Result<PaperAnalysis> {
+    let content = std::fs::read_to_string(path)?;
+    let value: serde_json::Value = serde_json::from_str(&content)?;
+    let analysis = if let Some(paper) = value.get("paper") {
+        paper.get("analysis").cloned()
+    } else if let Some(analysis) = value.get("analysis") {
+        Some(analysis.clone())
+    } else {
+        Some(value)
+    };
+    match analysis {
+        Some(analysis) if !analysis.is_null() => Ok(serde_json::from_value(analysis)?),
+        _ => anyhow::bail!("No analysis found in {}", path.display()),
+    }
+}
+
+/// Print an analysis diff in human-readable form
+fn print_analysis_diff(diff: &AnalysisDiff) {
+    let model_name = |model: &str| {
+        if model.is_empty() {
+            "unknown model".to_string()
+        } else {
+            model.to_string()
+        }
+    };
+    println!(
+        "Comparing analysis by {} (previous) with {} (current)\n",
+        model_name(&diff.previous_model),
+        model_name(&diff.current_model)
+    );
+    if diff.is_empty() {
+        println!("No changes.");
+        return;
+    }
+    if !diff.changed_sections.is_empty() {
+        println!("Changed sections: {}", diff.changed_sections.join(", "));
+    }
+    for (heading, items, marker) in [
+        ("Key contributions", &diff.added_key_contributions, "+"),
+        ("Key contributions", &diff.removed_key_contributions, "-"),
+        ("Tasks", &diff.added_tasks, "+"),
+        ("Tasks", &diff.removed_tasks, "-"),
+    ] {
+        for item in items {
+            println!("{} [{}] {}", marker, heading, item);
+        }
+    }
+}
+
+/// Keyword extraction result (for structured output)
+#[derive(Serialize)]
+struct KeywordsOutput {
+    title: String,
+    keywords: KeywordsData,
+    research_context: ResearchContext,
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn cmd_keywords(
+    arxiv: Option<String>,
+    ss: Option<String>,
+    title: Option<String>,
+    threshold: f64,
+    provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
+    model: Option<String>,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    if arxiv.is_none() && ss.is_none() && title.is_none() {
+        anyhow::bail!("Either --arxiv, --ss, or --title is required");
+    }
+    let llm_config = build_llm_config(model.as_deref(), temperature, max_tokens)?;
+
+    // Fetch paper first
+    let client = PaperClient::new();
+    let paper = if let Some(ref title_query) = title {
+        eprintln!(
+            "Searching for paper: \"{}\" (threshold: {:.2})",
+            title_query, threshold
+        );
+        client.search_by_title_fuzzy(title_query, threshold).await?
+    } else {
+        let mut params = SearchParams::new();
+        if let Some(id) = arxiv {
+            params = params.with_arxiv_id(id);
+        }
+        if let Some(id) = ss {
+            params = params.with_ss_id(id);
+        }
+
+        let result = client.search(params).await?;
+        match result.papers.into_iter().next() {
+            Some(paper) => paper,
+            None => anyhow::bail!("Paper not found"),
+        }
+    };
+    eprintln!("Title: \"{}\"", paper.title);
+
+    // Extract with appropriate provider
+    let provider_type = resolve_provider_type(provider_arg)?;
+    let (keywords, research_context) = extract_keywords_with_provider(
+        provider_from_env(provider_type, base_url)?,
+        &paper,
+        language,
+        &llm_config,
+    )
+    .await?;
+
+    let result = KeywordsOutput {
+        title: paper.title,
+        keywords,
+        research_context,
+    };
+
+    match output {
+        OutputFormat::Text => {
+            print_keywords(&result);
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+        }
+        OutputFormat::Xml => {
+            println!("{}", to_xml(&result)?);
+        }
+        OutputFormat::Toml => {
+            println!("{}", to_toml(&result)?);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_keywords(result: &KeywordsOutput) {
+    let keywords = &result.keywords;
+    let context = &result.research_context;
+
+    println!("Title: {}", result.title);
+    println!();
+    println!("=== Keywords ===");
+    println!("Keywords: {}", keywords.keywords.join(", "));
+    println!("Topics:   {}", keywords.topics.join(", "));
+    println!("Methods:  {}", keywords.methods.join(", "));
+    println!("Datasets: {}", keywords.datasets.join(", "));
+
+    if !keywords.technical_terms.is_empty() {
+        println!();
+        println!("Technical terms:");
+        for term in &keywords.technical_terms {
+            match &term.definition {
+                Some(definition) => println!("  - {}: {}", term.term, definition),
+                None => println!("  - {}", term.term),
+            }
+        }
+    }
 
-    let result = client.search(params).await?;
+    println!();
+    println!("=== Research Context ===");
+    println!("Primary field: {}", context.primary_field);
+    println!("Sub-fields:    {}", context.sub_fields.join(", "));
+    println!("Research type: {}", context.research_type);
+    println!();
+    println!("Positioning:");
+    println!("{}", context.positioning);
 
-    if result.papers.is_empty() {
-        anyhow::bail!("Paper not found");
+    if !context.related_directions.is_empty() {
+        println!();
+        println!("Related directions:");
+        for direction in &context.related_directions {
+            println!("  - {}", direction);
+        }
     }
+}
 
-    let paper = &result.papers[0];
+#[allow(clippy::too_many_arguments)]
+async fn cmd_compare(
+    arxiv: Vec<String>,
+    provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
+    model: Option<String>,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let [id_a, id_b] = <[String; 2]>::try_from(arxiv).map_err(|ids| {
+        anyhow::anyhow!("--arxiv must be given exactly twice (got {})", ids.len())
+    })?;
+    let llm_config = build_llm_config(model.as_deref(), temperature, max_tokens)?;
+
+    let client = PaperClient::new();
+    let (paper_a, paper_b) = futures::try_join!(
+        client.fetch_by_arxiv_id(&id_a),
+        client.fetch_by_arxiv_id(&id_b)
+    )?;
+    eprintln!("A: \"{}\"", paper_a.title);
+    eprintln!("B: \"{}\"", paper_b.title);
+
+    let provider_type = resolve_provider_type(provider_arg)?;
+    let comparison = compare_with_provider(
+        provider_from_env(provider_type, base_url)?,
+        &paper_a,
+        &paper_b,
+        language,
+        &llm_config,
+    )
+    .await?;
 
     match output {
         OutputFormat::Text => {
-            print_paper_detail(paper);
+            print_comparison(&comparison);
         }
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(paper)?);
+            println!("{}", serde_json::to_string_pretty(&comparison)?);
         }
         OutputFormat::Xml => {
-            println!("{}", to_xml(paper)?);
+            println!("{}", to_xml(&comparison)?);
         }
         OutputFormat::Toml => {
-            println!("{}", to_toml(paper)?);
+            println!("{}", to_toml(&comparison)?);
         }
     }
 
     Ok(())
 }
 
-async fn cmd_analyze(
-    arxiv: Option<String>,
-    ss: Option<String>,
+#[allow(clippy::too_many_arguments)]
+async fn cmd_synthesize(
+    arxiv: Vec<String>,
+    focus: Option<String>,
     provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
     model: Option<String>,
-    output: OutputFormat,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
 ) -> anyhow::Result<()> {
-    if arxiv.is_none() && ss.is_none() {
-        anyhow::bail!("Either --arxiv or --ss is required");
+    if arxiv.len() < 2 {
+        anyhow::bail!("--arxiv must be given at least twice (got {})", arxiv.len());
     }
+    let llm_config = build_llm_config(model.as_deref(), temperature, max_tokens)?;
 
-    // Fetch paper first
     let client = PaperClient::new();
-    let mut params = SearchParams::new();
-
-    if let Some(id) = arxiv {
-        params = params.with_arxiv_id(id);
-    }
-    if let Some(id) = ss {
-        params = params.with_ss_id(id);
+    let papers =
+        futures::future::try_join_all(arxiv.iter().map(|id| client.fetch_by_arxiv_id(id))).await?;
+    for (i, paper) in papers.iter().enumerate() {
+        eprintln!("[{}] \"{}\"", i + 1, paper.title);
     }
 
-    let result = client.search(params).await?;
+    let provider_type = resolve_provider_type(provider_arg)?;
+    let synthesis = synthesize_with_provider(
+        provider_from_env(provider_type, base_url)?,
+        &papers,
+        focus.as_deref(),
+        language,
+        &llm_config,
+    )
+    .await?;
+
+    println!("{}", synthesis);
+    Ok(())
+}
 
-    if result.papers.is_empty() {
-        anyhow::bail!("Paper not found");
+fn print_comparison(comparison: &PaperComparison) {
+    println!("A: {}", comparison.paper_a);
+    println!("B: {}", comparison.paper_b);
+    println!();
+    println!("Shared tasks: {}", comparison.shared_tasks.join(", "));
+
+    if !comparison.methodological_differences.is_empty() {
+        println!();
+        println!("Methodological differences:");
+        for difference in &comparison.methodological_differences {
+            println!("  - {}", difference);
+        }
     }
 
-    let mut paper = result.papers.into_iter().next().unwrap();
+    println!();
+    let verdict = match comparison.advances {
+        ComparisonVerdict::A => "A advances over B",
+        ComparisonVerdict::B => "B advances over A",
+        ComparisonVerdict::Neither => "Neither clearly advances over the other",
+    };
+    println!("Verdict: {}", verdict);
+    println!("{}", comparison.rationale);
+}
 
-    // Determine provider
-    let provider_type = provider_arg.map(LlmProviderType::from).unwrap_or_else(|| {
-        std::env::var("LLM_PROVIDER")
-            .ok()
-            .and_then(|s| match s.as_str() {
-                "openai" => Some(LlmProviderType::OpenAi),
-                "anthropic" => Some(LlmProviderType::Anthropic),
-                "ollama" => Some(LlmProviderType::Ollama),
-                _ => None,
-            })
-            .unwrap_or(LlmProviderType::OpenAi)
-    });
+/// Wrapper for section matches (for XML/TOML root element)
+#[derive(Serialize)]
+struct MatchesWrapper<'a> {
+    #[serde(rename = "match")]
+    matches: &'a [SectionMatch],
+}
 
-    // Analyze with appropriate provider
-    match provider_type {
-        LlmProviderType::OpenAi => {
-            let provider = OpenAiProvider::from_env()?;
-            analyze_with_provider(provider, &mut paper, model.as_deref()).await?;
-        }
-        LlmProviderType::Anthropic => {
-            let provider = AnthropicProvider::from_env()?;
-            analyze_with_provider(provider, &mut paper, model.as_deref()).await?;
-        }
-        LlmProviderType::Ollama => {
-            let provider = OllamaProvider::from_env()?;
-            analyze_with_provider(provider, &mut paper, model.as_deref()).await?;
-        }
+async fn cmd_grep(
+    arxiv: String,
+    pattern: String,
+    use_regex: bool,
+    ignore_case: bool,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let regex = if use_regex {
+        Some(
+            RegexBuilder::new(&pattern)
+                .case_insensitive(ignore_case)
+                .build()?,
+        )
+    } else {
+        None
+    };
+
+    let client = PaperClient::new();
+    let mut paper = client.fetch_by_arxiv_id(&arxiv).await?;
+    // Fetching extracts text when it can; retry so failures are reported
+    if !paper.has_extracted_text() {
+        client.extract_text(&mut paper).await?;
     }
+    let Some(text) = paper.extracted_text.as_ref() else {
+        anyhow::bail!("No text could be extracted from the PDF");
+    };
+
+    let matches = match &regex {
+        Some(regex) => text.search_regex(regex),
+        None => text.search(&pattern, ignore_case),
+    };
 
     match output {
         OutputFormat::Text => {
-            print_paper_with_analysis(&paper);
+            println!("{}: {} matches\n", paper.title, matches.len());
+            for m in &matches {
+                println!(
+                    "[{}] {} (char {})",
+                    m.section_index, m.section_title, m.offset
+                );
+                println!("    ...{}...", m.snippet);
+            }
         }
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&paper)?);
+            println!("{}", serde_json::to_string_pretty(&matches)?);
         }
         OutputFormat::Xml => {
-            println!("{}", to_xml(&paper)?);
+            println!("{}", to_xml(&MatchesWrapper { matches: &matches })?);
         }
         OutputFormat::Toml => {
-            println!("{}", to_toml(&paper)?);
+            println!("{}", to_toml(&MatchesWrapper { matches: &matches })?);
         }
     }
 
     Ok(())
 }
 
-async fn analyze_with_provider<P: LlmProvider>(
-    provider: P,
-    paper: &mut AcademicPaper,
+/// Build the LLM configuration from the CLI model and sampling flags
+fn build_llm_config(
     model: Option<&str>,
-) -> anyhow::Result<()> {
-    let mut analyzer = PaperAnalyzer::new(provider);
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+) -> anyhow::Result<LlmConfig> {
+    let mut config = LlmConfig::new();
     if let Some(m) = model {
-        analyzer = analyzer.with_model(m);
+        config = config.with_model(m);
+    }
+    if let Some(temp) = temperature {
+        if !(0.0..=2.0).contains(&temp) {
+            anyhow::bail!("--temperature must be between 0.0 and 2.0 (got {})", temp);
+        }
+        config = config.with_temperature(temp);
+    }
+    if let Some(tokens) = max_tokens {
+        if tokens == 0 {
+            anyhow::bail!("--max-tokens must be greater than 0");
+        }
+        config = config.with_max_tokens(tokens);
+    }
+    Ok(config)
+}
+
+async fn analyze_with_provider(
+    provider: Box<dyn LlmProvider>,
+    paper: &mut AcademicPaper,
+    language: AnalysisLanguage,
+    config: &LlmConfig,
+) -> anyhow::Result<()> {
+    // Prefer the extracted PDF body over the abstract when it is available
+    PaperAnalyzer::from_boxed(provider)
+        .with_language(language)
+        .with_config(config.clone())
+        .analyze_paper(paper)
+        .await?;
+    Ok(())
+}
+
+#[cfg(feature = "mcp")]
+async fn cmd_serve_mcp(
+    provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
+    model: Option<String>,
+) -> anyhow::Result<()> {
+    let llm_config = build_llm_config(model.as_deref(), None, None)?;
+    let provider_type = resolve_provider_type(provider_arg)?;
+
+    serve_mcp_with_provider(
+        provider_from_env(provider_type, base_url),
+        language,
+        &llm_config,
+    )
+    .await
+}
+
+#[cfg(feature = "mcp")]
+async fn serve_mcp_with_provider(
+    provider: academic_paper_interpreter::AppResult<Box<dyn LlmProvider>>,
+    language: AnalysisLanguage,
+    config: &LlmConfig,
+) -> anyhow::Result<()> {
+    let mut server = academic_paper_interpreter::mcp::McpServer::new(PaperClient::new());
+    // Search/fetch work without LLM credentials; only the analyze tool needs them
+    match provider {
+        Ok(provider) => {
+            server = server.with_analyzer(
+                PaperAnalyzer::from_boxed(provider)
+                    .with_language(language)
+                    .with_config(config.clone()),
+            );
+        }
+        Err(e) => eprintln!("LLM provider unavailable, analyze tool disabled: {}", e),
     }
-    analyzer.analyze_and_update(paper).await?;
+    server.run_stdio().await?;
     Ok(())
 }
 
+async fn cmd_doctor(base_url: Option<&str>) -> anyhow::Result<()> {
+    let mut config = Config::from_env()?;
+    if let Some(url) = base_url {
+        config.openai_compat_base_url = Some(url.to_string());
+    }
+
+    eprintln!("Running preflight checks...\n");
+    let report = doctor::run_checks(&config).await;
+    print!("{}", report.to_table());
+    println!("\n{}", report.summary());
+
+    if !report.is_healthy() {
+        anyhow::bail!("{} check(s) failed", report.failures());
+    }
+
+    println!("All checks passed.");
+    Ok(())
+}
+
+fn print_author_profile(profile: &AuthorProfile) {
+    let author = &profile.author;
+    println!("Name: {}", author.name);
+    println!("SS ID: {}", author.ss_id);
+    if !author.affiliations.is_empty() {
+        println!("Affiliations: {}", author.affiliations.join(", "));
+    }
+    println!("Papers: {}", author.paper_count);
+    println!("Citations: {}", author.citation_count);
+    println!("h-index: {}", author.h_index);
+    println!();
+
+    println!("Recent papers:\n");
+    for (i, paper) in profile.papers.iter().enumerate() {
+        print_paper_summary(i + 1, paper);
+    }
+
+    if !profile.other_candidates.is_empty() {
+        println!("Other authors with a similar name (use --id to select):");
+        for candidate in &profile.other_candidates {
+            println!(
+                "  {} (ID: {}, papers: {}, h-index: {})",
+                candidate.name, candidate.ss_id, candidate.paper_count, candidate.h_index
+            );
+        }
+    }
+}
+
 fn print_paper_summary(index: usize, paper: &AcademicPaper) {
     println!("{}. {}", index, paper.title);
     println!(
@@ -649,6 +2921,105 @@ fn to_xml<T: Serialize>(data: &T) -> anyhow::Result<String> {
     Ok(buffer)
 }
 
+/// Build the embedding provider selected on the command line
+fn embedding_provider(arg: EmbeddingArg) -> anyhow::Result<Box<dyn EmbeddingProvider>> {
+    Ok(match arg {
+        EmbeddingArg::Ollama => Box::new(OllamaProvider::from_env()?),
+        EmbeddingArg::Mock => Box::new(MockProvider::new()),
+    })
+}
+
+async fn cmd_index_add(
+    arxiv: Vec<String>,
+    index_path: PathBuf,
+    provider_arg: EmbeddingArg,
+) -> anyhow::Result<()> {
+    let provider = embedding_provider(provider_arg)?;
+    let mut index = PaperIndex::load_or_default(&index_path)?;
+    index.check_model(provider.embedding_model())?;
+
+    let client = PaperClient::new().with_auto_extract(false);
+    let papers =
+        futures::future::try_join_all(arxiv.iter().map(|id| client.fetch_by_arxiv_id(id))).await?;
+    for paper in &papers {
+        eprintln!("Adding \"{}\"", paper.title);
+    }
+
+    index.add_batch(papers, provider.as_ref()).await?;
+    index.save(&index_path)?;
+    eprintln!(
+        "Index {} now has {} papers",
+        index_path.display(),
+        index.len()
+    );
+    Ok(())
+}
+
+/// One `index search` result
+#[derive(Serialize)]
+struct IndexHit {
+    score: f32,
+    arxiv_id: String,
+    title: String,
+    published_date: String,
+}
+
+/// `index search` results (for XML/TOML root element)
+#[derive(Serialize)]
+struct IndexSearchOutput {
+    query: String,
+    #[serde(rename = "hit")]
+    hits: Vec<IndexHit>,
+}
+
+async fn cmd_index_search(
+    query: &str,
+    k: usize,
+    index_path: PathBuf,
+    provider_arg: EmbeddingArg,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let index = PaperIndex::load(&index_path)?;
+    if index.is_empty() {
+        anyhow::bail!("Index {} has no papers", index_path.display());
+    }
+    let provider = embedding_provider(provider_arg)?;
+    let results = index.search_text(query, k, provider.as_ref()).await?;
+
+    let hits: Vec<IndexHit> = results
+        .into_iter()
+        .map(|(paper, score)| IndexHit {
+            score,
+            arxiv_id: paper.arxiv_id.clone(),
+            title: paper.title.clone(),
+            published_date: paper.published_date.format("%Y-%m-%d").to_string(),
+        })
+        .collect();
+    let result = IndexSearchOutput {
+        query: query.to_string(),
+        hits,
+    };
+
+    match output {
+        OutputFormat::Text => {
+            for (rank, hit) in result.hits.iter().enumerate() {
+                println!(
+                    "{:>2}. [{:.3}] {} ({}, {})",
+                    rank + 1,
+                    hit.score,
+                    hit.title,
+                    hit.arxiv_id,
+                    hit.published_date
+                );
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&result)?),
+        OutputFormat::Xml => println!("{}", to_xml(&result)?),
+        OutputFormat::Toml => println!("{}", to_toml(&result)?),
+    }
+    Ok(())
+}
+
 /// Serialize data to TOML format
 fn to_toml<T: Serialize>(data: &T) -> anyhow::Result<String> {
     Ok(toml::to_string_pretty(data)?)
@@ -661,12 +3032,52 @@ struct PapersWrapper<'a> {
     papers: &'a [AcademicPaper],
 }
 
+/// Check that a resumed export is for the paper given on the command line
+///
+/// Every identifier given must match the saved paper: arXiv IDs ignoring the
+/// version, and titles within the same distance `threshold` as the title lookup.
+fn check_resumed_paper(
+    paper: &AcademicPaper,
+    arxiv: Option<&str>,
+    ss: Option<&str>,
+    title: Option<&str>,
+    threshold: f64,
+) -> anyhow::Result<()> {
+    let mut mismatches = Vec::new();
+    if let Some(arxiv) = arxiv
+        && AcademicPaper::parse_arxiv_id(arxiv).0
+            != AcademicPaper::parse_arxiv_id(&paper.arxiv_id).0
+    {
+        mismatches.push(format!("arXiv ID {}", arxiv));
+    }
+    if let Some(ss) = ss
+        && !ss.trim().eq_ignore_ascii_case(&paper.ss_id)
+    {
+        mismatches.push(format!("Semantic Scholar ID {}", ss));
+    }
+    if let Some(title) = title
+        && PaperClient::new().rank_by_title(std::slice::from_ref(paper), title)[0].1 > threshold
+    {
+        mismatches.push(format!("title \"{}\"", title));
+    }
+
+    if !mismatches.is_empty() {
+        anyhow::bail!(
+            "saved export is for \"{}\", not {}; drop --resume or choose another output file",
+            paper.title,
+            mismatches.join(" / ")
+        );
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn cmd_export(
     arxiv: Option<String>,
     ss: Option<String>,
     title: Option<String>,
     threshold: f64,
+    interactive: bool,
     output_path: PathBuf,
     analyze: bool,
     extract_text: bool,
@@ -674,16 +3085,51 @@ async fn cmd_export(
     include_references: bool,
     max_citations: usize,
     provider_arg: Option<ProviderArg>,
+    base_url: Option<&str>,
+    language: AnalysisLanguage,
     model: Option<String>,
     extract_keywords: bool,
+    section_summaries: bool,
     compact: bool,
     format: ExportFormat,
     with_schema: bool,
     no_math_markup: bool,
     no_extract_references: bool,
     pdf: Option<PathBuf>,
+    pages: Option<PageRange>,
+    resume: bool,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    fields: &[String],
+    highlight: bool,
 ) -> anyhow::Result<()> {
-    if arxiv.is_none() && ss.is_none() && title.is_none() {
+    let llm_config = build_llm_config(model.as_deref(), temperature, max_tokens)?;
+
+    if !fields.is_empty() && !matches!(format, ExportFormat::Json) {
+        anyhow::bail!("--fields requires --format json");
+    }
+
+    // Resume from a previous export (JSON only; XML cannot be read back)
+    let previous = if resume && output_path.exists() {
+        if !matches!(format, ExportFormat::Json) {
+            anyhow::bail!("--resume requires --format json");
+        }
+        let previous = ExportedPaper::from_json_file(&output_path)?;
+        check_resumed_paper(
+            &previous.paper,
+            arxiv.as_deref(),
+            ss.as_deref(),
+            title.as_deref(),
+            threshold,
+        )
+        .map_err(|e| anyhow::anyhow!("{}: {}", output_path.display(), e))?;
+        eprintln!("Resuming export from {}", output_path.display());
+        Some(previous)
+    } else {
+        None
+    };
+
+    if previous.is_none() && arxiv.is_none() && ss.is_none() && title.is_none() {
         anyhow::bail!("Either --arxiv, --ss, or --title is required");
     }
 
@@ -699,8 +3145,8 @@ async fn cmd_export(
         }
     }
 
-    // --pdf implies --extract-text
-    let extract_text = extract_text || pdf.is_some();
+    // --pdf and --section-summaries imply --extract-text
+    let extract_text = extract_text || pdf.is_some() || section_summaries;
 
     // Build export options
     let mut export_options = ExportOptions {
@@ -709,24 +3155,44 @@ async fn cmd_export(
         citations_included: include_citations,
         references_included: include_references,
         keywords_extracted: extract_keywords,
+        sections_summarized: section_summaries,
         max_citations,
         llm_provider: None,
         llm_model: None,
+        include_provenance: false,
+    };
+
+    // Drive a progress bar from the export stage callbacks
+    let progress_bar = generate_progress_bar(
+        export_options.planned_stages().len(),
+        Some("Starting export".to_string()),
+    );
+    let progress = {
+        let pb = progress_bar.clone();
+        ExportProgress::new().with_progress(move |label| {
+            pb.set_message(label.to_string());
+            pb.inc(1);
+        })
     };
 
     // Fetch paper
+    progress.report(ExportStage::FetchingMetadata);
     let client = PaperClient::new();
 
-    let (mut paper, mut paper_not_found_warning) = if let Some(ref title_query) = title {
+    let (mut paper, mut paper_not_found_warning) = if let Some(ref previous) = previous {
+        eprintln!("Title: \"{}\"", previous.paper.title);
+        (previous.paper.clone(), None)
+    } else if let Some(ref title_query) = title {
         // Search by title using fuzzy matching
         eprintln!(
             "Searching for paper: \"{}\" (threshold: {:.2})",
             title_query, threshold
         );
-        match client.search_by_title_fuzzy(title_query, threshold).await {
+        match find_by_title(&client, title_query, threshold, interactive).await {
             Ok(found_paper) => {
                 eprintln!("Title: \"{}\"", found_paper.title);
                 print_source_status(&found_paper);
+                print_validation_warnings(&found_paper);
                 (found_paper, None)
             }
             Err(e) => {
@@ -757,6 +3223,7 @@ async fn cmd_export(
                 let found_paper = result.papers.into_iter().next().unwrap();
                 eprintln!("Title: \"{}\"", found_paper.title);
                 print_source_status(&found_paper);
+                print_validation_warnings(&found_paper);
                 (found_paper, None)
             }
             Ok(_) | Err(_) => {
@@ -780,7 +3247,22 @@ async fn cmd_export(
         }
     };
 
-    let mut exported = ExportedPaper::new(paper.clone(), export_options.clone());
+    let mut exported = match previous {
+        Some(previous) => {
+            export_options.merge_from(&previous.export_metadata.options);
+            previous
+        }
+        None => ExportedPaper::new(paper.clone(), export_options.clone()),
+    };
+
+    // Only run stages whose results are not already present
+    let pending = exported.pending_stages(&export_options);
+    let extract_text = pending.contains(&ExportStage::ExtractingText);
+    let analyze = pending.contains(&ExportStage::Analyzing);
+    let summarize_sections = pending.contains(&ExportStage::SummarizingSections);
+    let include_citations = pending.contains(&ExportStage::FetchingCitations);
+    let include_references = pending.contains(&ExportStage::FetchingReferences);
+    let extract_keywords = pending.contains(&ExportStage::ExtractingKeywords);
 
     // Add warning if paper metadata was not found
     if let Some(warning) = paper_not_found_warning.take() {
@@ -788,66 +3270,42 @@ async fn cmd_export(
     }
 
     // Determine provider type for LLM operations
-    let provider_type = provider_arg.map(LlmProviderType::from).unwrap_or_else(|| {
-        std::env::var("LLM_PROVIDER")
-            .ok()
-            .and_then(|s| match s.as_str() {
-                "openai" => Some(LlmProviderType::OpenAi),
-                "anthropic" => Some(LlmProviderType::Anthropic),
-                "ollama" => Some(LlmProviderType::Ollama),
-                _ => None,
-            })
-            .unwrap_or(LlmProviderType::OpenAi)
-    });
+    let provider_type = resolve_provider_type(provider_arg)?;
 
     // Extract text if requested
-    if extract_text && !paper.has_extracted_text() {
-        let extraction_config = ExtractionConfig::new()
+    if extract_text {
+        let mut extraction_config = ExtractionConfig::new()
             .with_include_math(!no_math_markup)
             .with_extract_references(!no_extract_references);
+        if let Some(range) = pages {
+            extraction_config = extraction_config.with_page_range(range.start, range.end);
+        }
 
-        if let Some(ref pdf_path) = pdf {
-            // Extract from local PDF file
-            let extractor = PdfExtractor::with_config(extraction_config);
-            let path_str = pdf_path.to_string_lossy();
-            match extractor.extract_from_url(&path_str).await {
-                Ok(text) => paper.set_extracted_text(text),
-                Err(e) => {
-                    exported.add_warning(format!("Text extraction failed: {}", e));
-                }
-            }
-        } else {
-            // Extract from online PDF via URL resolution
-            match client
-                .extract_text_with_config(&mut paper, extraction_config)
-                .await
-            {
-                Ok(_) => {}
-                Err(e) => {
-                    exported.add_warning(format!("Text extraction failed: {}", e));
-                }
-            }
+        let pb = progress_bar.clone();
+        let on_section = move |section: &PaperSection| {
+            pb.set_message(format!("Extracting text: {}", section.title));
+        };
+        if let Err(e) = extract_export_text(
+            &client,
+            &mut paper,
+            pdf.as_deref(),
+            extraction_config,
+            &progress,
+            on_section,
+        )
+        .await
+        {
+            exported.add_warning(format!("Text extraction failed: {}", e));
         }
     }
 
     // Run LLM analysis if requested
     if analyze && !paper.is_analyzed() {
-        let analyze_result = match provider_type {
-            LlmProviderType::OpenAi => {
-                let provider = OpenAiProvider::from_env()?;
-                export_options.llm_provider = Some("openai".to_string());
-                analyze_with_provider(provider, &mut paper, model.as_deref()).await
-            }
-            LlmProviderType::Anthropic => {
-                let provider = AnthropicProvider::from_env()?;
-                export_options.llm_provider = Some("anthropic".to_string());
-                analyze_with_provider(provider, &mut paper, model.as_deref()).await
-            }
-            LlmProviderType::Ollama => {
-                let provider = OllamaProvider::from_env()?;
-                export_options.llm_provider = Some("ollama".to_string());
-                analyze_with_provider(provider, &mut paper, model.as_deref()).await
-            }
+        progress.report(ExportStage::Analyzing);
+        let analyze_result = {
+            let provider = provider_from_env(provider_type, base_url)?;
+            export_options.llm_provider = Some(provider_type.to_string());
+            analyze_with_provider(provider, &mut paper, language, &llm_config).await
         };
 
         if let Err(e) = analyze_result {
@@ -856,8 +3314,38 @@ async fn cmd_export(
         export_options.llm_model = model.clone();
     }
 
+    // Summarize key sections if requested
+    if summarize_sections {
+        progress.report(ExportStage::SummarizingSections);
+        let summaries_result = {
+            let provider = provider_from_env(provider_type, base_url)?;
+            summarize_sections_with_provider(provider, &paper, language, &llm_config).await
+        };
+
+        match summaries_result {
+            Ok(results) => {
+                for (section_title, e) in results.failures {
+                    exported.add_warning(format!(
+                        "Summary of section \"{}\" failed: {}",
+                        section_title, e
+                    ));
+                }
+                exported.section_summaries = Some(results.summaries);
+            }
+            Err(e) => {
+                exported.add_warning(format!("Section summarization failed: {}", e));
+            }
+        }
+    }
+
     // Fetch citations and references in parallel
     let (citations_result, references_result) = if include_citations || include_references {
+        if include_citations {
+            progress.report(ExportStage::FetchingCitations);
+        }
+        if include_references {
+            progress.report(ExportStage::FetchingReferences);
+        }
         let citations_future = async {
             if include_citations {
                 fetch_citations(&client, &paper, max_citations).await
@@ -903,19 +3391,10 @@ async fn cmd_export(
 
     // Extract keywords if requested
     if extract_keywords {
-        let keywords_result = match provider_type {
-            LlmProviderType::OpenAi => {
-                let provider = OpenAiProvider::from_env()?;
-                extract_keywords_with_provider(provider, &paper, model.as_deref()).await
-            }
-            LlmProviderType::Anthropic => {
-                let provider = AnthropicProvider::from_env()?;
-                extract_keywords_with_provider(provider, &paper, model.as_deref()).await
-            }
-            LlmProviderType::Ollama => {
-                let provider = OllamaProvider::from_env()?;
-                extract_keywords_with_provider(provider, &paper, model.as_deref()).await
-            }
+        progress.report(ExportStage::ExtractingKeywords);
+        let keywords_result = {
+            let provider = provider_from_env(provider_type, base_url)?;
+            extract_keywords_with_provider(provider, &paper, language, &llm_config).await
         };
 
         match keywords_result {
@@ -930,11 +3409,27 @@ async fn cmd_export(
     }
 
     // Update paper in exported
+    exported.publication_status = paper.publication_status();
     exported.paper = paper;
+    exported.collect_extracted_references();
+    exported.refresh_size_metrics();
     exported.export_metadata.options = export_options;
+    if Config::use_utc_from_env() {
+        exported.normalize_timestamps_to_utc();
+    }
 
     // Output based on format
+    progress.report(ExportStage::Writing);
     let output_content = match format {
+        ExportFormat::Json if !fields.is_empty() => {
+            let paths: Vec<&str> = fields.iter().map(String::as_str).collect();
+            let projected = project_export_fields(serde_json::to_value(&exported)?, &paths);
+            if compact {
+                serde_json::to_string(&projected)?
+            } else {
+                serde_json::to_string_pretty(&projected)?
+            }
+        }
         ExportFormat::Json => {
             if compact {
                 serde_json::to_string(&exported)?
@@ -943,9 +3438,11 @@ async fn cmd_export(
             }
         }
         ExportFormat::Xml => exported.to_xml(),
+        ExportFormat::Html => exported.to_html(),
     };
 
     std::fs::write(&output_path, &output_content)?;
+    progress_bar.finish_and_clear();
 
     // Print export summary
     print_export_summary(&exported, &output_path, output_content.len());
@@ -957,16 +3454,62 @@ async fn cmd_export(
         eprintln!("Schema exported to: {}", schema_path.display());
     }
 
+    if highlight {
+        print_highlighted_body(&exported);
+    }
+
     Ok(())
 }
 
+/// Print the extracted body with the extracted keywords in bold, then per-keyword counts
+fn print_highlighted_body(exported: &ExportedPaper) {
+    let (Some(text), Some(keywords)) = (&exported.paper.extracted_text, &exported.keywords) else {
+        eprintln!("Highlighting skipped: needs both extracted text and keywords");
+        return;
+    };
+
+    let (markdown, counts) = text.highlight_with_counts(&keywords.keywords);
+    println!("{}", markdown);
+
+    eprintln!();
+    eprintln!("--- Keyword Occurrences ---");
+    for (term, count) in counts {
+        eprintln!("{:>5}  {}", count, term);
+    }
+}
+
+/// Run the export's text-extraction stage, reporting it on `progress`
+///
+/// A local `pdf` is parsed directly and reports each section to
+/// `on_section`; otherwise the paper's PDF is resolved and extracted by
+/// `client`.
+async fn extract_export_text(
+    client: &PaperClient,
+    paper: &mut AcademicPaper,
+    pdf: Option<&Path>,
+    config: ExtractionConfig,
+    progress: &ExportProgress,
+    on_section: impl FnMut(&PaperSection),
+) -> academic_paper_interpreter::AppResult<()> {
+    progress.report(ExportStage::ExtractingText);
+    match pdf {
+        Some(pdf_path) => {
+            let text = PdfExtractor::with_config(config)
+                .extract_from_path_with_callback(pdf_path, on_section)
+                .await?;
+            paper.set_extracted_text(text);
+            Ok(())
+        }
+        None => client.extract_text_with_config(paper, config).await,
+    }
+}
+
 async fn fetch_citations(
     client: &PaperClient,
     paper: &AcademicPaper,
     max_citations: usize,
 ) -> anyhow::Result<Option<CitationData>> {
-    let citations = client.fetch_citations(paper).await?;
-    let limited: Vec<_> = citations.into_iter().take(max_citations).collect();
+    let limited = client.fetch_citations(paper, Some(max_citations)).await?;
 
     if limited.is_empty() {
         return Ok(None);
@@ -991,8 +3534,7 @@ async fn fetch_references(
     paper: &AcademicPaper,
     max_citations: usize,
 ) -> anyhow::Result<Option<ReferenceData>> {
-    let references = client.fetch_references(paper).await?;
-    let limited: Vec<_> = references.into_iter().take(max_citations).collect();
+    let limited = client.fetch_references(paper, Some(max_citations)).await?;
 
     if limited.is_empty() {
         return Ok(None);
@@ -1002,7 +3544,7 @@ async fn fetch_references(
         .iter()
         .map(PaperSummary::from_academic_paper)
         .collect();
-    let statistics = ReferenceStatistics::from_papers(&summaries);
+    let statistics = ReferenceStatistics::from_papers(&summaries, paper.known_year().unwrap_or(0));
 
     Ok(Some(ReferenceData {
         total_count: paper.references_count,
@@ -1012,15 +3554,15 @@ async fn fetch_references(
     }))
 }
 
-async fn extract_keywords_with_provider<P: LlmProvider>(
-    provider: P,
+async fn extract_keywords_with_provider(
+    provider: Box<dyn LlmProvider>,
     paper: &AcademicPaper,
-    model: Option<&str>,
+    language: AnalysisLanguage,
+    config: &LlmConfig,
 ) -> anyhow::Result<(KeywordsData, ResearchContext)> {
-    let mut analyzer = PaperAnalyzer::new(provider);
-    if let Some(m) = model {
-        analyzer = analyzer.with_model(m);
-    }
+    let analyzer = PaperAnalyzer::from_boxed(provider)
+        .with_language(language)
+        .with_config(config.clone());
 
     let keywords = analyzer.extract_keywords(paper).await?;
     let context = analyzer
@@ -1030,6 +3572,44 @@ async fn extract_keywords_with_provider<P: LlmProvider>(
     Ok((keywords, context))
 }
 
+async fn summarize_sections_with_provider(
+    provider: Box<dyn LlmProvider>,
+    paper: &AcademicPaper,
+    language: AnalysisLanguage,
+    config: &LlmConfig,
+) -> anyhow::Result<SectionSummaries> {
+    let analyzer = PaperAnalyzer::from_boxed(provider)
+        .with_language(language)
+        .with_config(config.clone());
+    Ok(analyzer.summarize_sections(paper).await?)
+}
+
+async fn compare_with_provider(
+    provider: Box<dyn LlmProvider>,
+    paper_a: &AcademicPaper,
+    paper_b: &AcademicPaper,
+    language: AnalysisLanguage,
+    config: &LlmConfig,
+) -> anyhow::Result<PaperComparison> {
+    let analyzer = PaperAnalyzer::from_boxed(provider)
+        .with_language(language)
+        .with_config(config.clone());
+    Ok(analyzer.compare_papers(paper_a, paper_b).await?)
+}
+
+async fn synthesize_with_provider(
+    provider: Box<dyn LlmProvider>,
+    papers: &[AcademicPaper],
+    focus: Option<&str>,
+    language: AnalysisLanguage,
+    config: &LlmConfig,
+) -> anyhow::Result<String> {
+    let analyzer = PaperAnalyzer::from_boxed(provider)
+        .with_language(language)
+        .with_config(config.clone());
+    Ok(analyzer.synthesize(papers, focus).await?)
+}
+
 /// Print source-specific search status for a paper
 fn print_source_status(paper: &AcademicPaper) {
     if !paper.arxiv_id.is_empty() {
@@ -1044,6 +3624,13 @@ fn print_source_status(paper: &AcademicPaper) {
     }
 }
 
+/// Print metadata problems reported by `AcademicPaper::validate`
+fn print_validation_warnings(paper: &AcademicPaper) {
+    for issue in paper.validate() {
+        eprintln!("Warning: {}", issue);
+    }
+}
+
 /// Format byte size to human-readable string
 fn format_file_size(bytes: usize) -> String {
     const KB: usize = 1024;
@@ -1098,7 +3685,11 @@ fn print_export_summary(exported: &ExportedPaper, output_path: &std::path::Path,
     }
 
     // File size
-    eprintln!("File:      {} ({})", output_path.display(), format_file_size(file_size));
+    eprintln!(
+        "File:      {} ({})",
+        output_path.display(),
+        format_file_size(file_size)
+    );
 
     // Warnings
     if !exported.export_metadata.warnings.is_empty() {
@@ -1108,3 +3699,161 @@ fn print_export_summary(exported: &ExportedPaper, output_path: &std::path::Path,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use academic_paper_interpreter::{AppError, PaperText, PaperTextExtractor};
+    use std::io::Cursor;
+    use std::sync::{Arc, Mutex};
+
+    fn options() -> Vec<String> {
+        vec![
+            "[0.00] Attention Is All You Need".to_string(),
+            "[0.35] Attention Is Not All You Need".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_prompt_choice_selects_and_reprompts() {
+        let mut output = Vec::new();
+        let choice = prompt_choice(&mut Cursor::new("x\n7\n2\n"), &mut output, &options()).unwrap();
+        assert_eq!(choice, Some(1));
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("  1) [0.00] Attention Is All You Need"));
+        assert_eq!(output.matches("Invalid choice").count(), 2);
+    }
+
+    #[test]
+    fn test_prompt_choice_cancel() {
+        for input in ["\n", "q\n", ""] {
+            let choice =
+                prompt_choice(&mut Cursor::new(input), &mut Vec::new(), &options()).unwrap();
+            assert_eq!(choice, None, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_check_resumed_paper() {
+        let mut paper = AcademicPaper::new();
+        paper.title = "Attention Is All You Need".to_string();
+        paper.arxiv_id = "1706.03762".to_string();
+        paper.ss_id = "204e3073870fae3d05bcbc2f6a8e263d9b72e776".to_string();
+
+        assert!(check_resumed_paper(&paper, Some("1706.03762v5"), None, None, 0.3).is_ok());
+        assert!(
+            check_resumed_paper(&paper, None, Some(&paper.ss_id.to_uppercase()), None, 0.3).is_ok()
+        );
+        assert!(
+            check_resumed_paper(&paper, None, None, Some("attention is all you need"), 0.3).is_ok()
+        );
+
+        let err = check_resumed_paper(&paper, Some("1810.04805"), None, None, 0.3).unwrap_err();
+        assert!(err.to_string().contains("arXiv ID 1810.04805"));
+        assert!(check_resumed_paper(&paper, None, Some("0123abcd"), None, 0.3).is_err());
+        assert!(check_resumed_paper(&paper, None, None, Some("BERT: Pre-training"), 0.3).is_err());
+    }
+
+    #[test]
+    fn test_search_since_reaches_search_params() {
+        let cli = Cli::try_parse_from([
+            "academic-paper-interpreter",
+            "search",
+            "--query",
+            "diffusion",
+            "--category",
+            "cs.CV",
+            "--since",
+            "2024-01-01",
+        ])
+        .unwrap();
+        let Commands::Search {
+            query,
+            category,
+            since,
+            ..
+        } = cli.command
+        else {
+            panic!("expected the search command");
+        };
+        let midnight = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(since, Some(midnight));
+
+        let params = build_search_params(query, None, None, 10, category, None, false).unwrap();
+        let params = apply_search_filters(params, None, since);
+        assert_eq!(params.submitted_after, Some(midnight));
+        assert_eq!(params.query.as_deref(), Some("diffusion"));
+        assert_eq!(params.categories, vec!["cs.CV"]);
+
+        for bad in ["2024-13-01", "01/01/2024"] {
+            assert!(
+                Cli::try_parse_from(["academic-paper-interpreter", "search", "--since", bad])
+                    .is_err()
+            );
+        }
+    }
+
+    // Text extractor that returns one section, or fails for untitled papers
+    struct StubExtractor;
+
+    #[async_trait::async_trait]
+    impl PaperTextExtractor for StubExtractor {
+        async fn extract(
+            &self,
+            paper: &AcademicPaper,
+        ) -> academic_paper_interpreter::AppResult<PaperText> {
+            if paper.title.is_empty() {
+                return Err(AppError::PdfExtractionError("no PDF".to_string()));
+            }
+            Ok(PaperText {
+                plain_text: "We propose the Transformer.".to_string(),
+                sections: vec![PaperSection {
+                    title: "Introduction".to_string(),
+                    content: "We propose the Transformer.".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extract_export_text_reports_progress() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        let progress = ExportProgress::new()
+            .with_progress(move |label| sink.lock().unwrap().push(label.to_string()));
+        let client = PaperClient::new().with_text_extractor(StubExtractor);
+
+        let mut paper = AcademicPaper::new();
+        paper.title = "Attention Is All You Need".to_string();
+        extract_export_text(
+            &client,
+            &mut paper,
+            None,
+            ExtractionConfig::new(),
+            &progress,
+            |_| {},
+        )
+        .await
+        .unwrap();
+        assert!(paper.has_extracted_text());
+        assert_eq!(*received.lock().unwrap(), vec!["Extracting text…"]);
+
+        // The stage is reported before a failing extraction returns its error
+        let mut untitled = AcademicPaper::new();
+        let result = extract_export_text(
+            &client,
+            &mut untitled,
+            None,
+            ExtractionConfig::new(),
+            &progress,
+            |_| {},
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::PdfExtractionError(_))));
+        assert!(!untitled.has_extracted_text());
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+}
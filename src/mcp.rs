@@ -0,0 +1,451 @@
+//! Model Context Protocol (MCP) stdio server
+//!
+//! Exposes paper search, fetch and analysis as MCP tools so that MCP clients
+//! (e.g. Claude Desktop) can drive the crate directly. Messages are
+//! newline-delimited JSON-RPC 2.0 on stdin/stdout, as specified by the MCP
+//! stdio transport. Tool results are returned as [`ExportedPaper`] JSON.
+//!
+//! Enabled with the `mcp` feature.
+
+use crate::agents::{AnalysisAgent, LlmProvider, PaperAnalyzer};
+use crate::client::{PaperClient, SearchParams};
+use crate::export::{ExportOptions, ExportedPaper};
+use crate::shared::errors::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+/// MCP protocol revision implemented by this server
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Server name reported during initialization
+pub const SERVER_NAME: &str = "academic-paper-interpreter";
+
+/// Tool name for keyword search
+pub const SEARCH_TOOL: &str = "search_papers";
+
+/// Tool name for arXiv lookups
+pub const FETCH_TOOL: &str = "fetch_paper";
+
+/// Tool name for LLM analysis
+pub const ANALYZE_TOOL: &str = "analyze_paper";
+
+/// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// MCP tool description as returned by `tools/list`
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolDefinition {
+    /// Tool name used in `tools/call`
+    pub name: &'static str,
+    /// Human-readable description shown to the model
+    pub description: &'static str,
+    /// JSON Schema of the tool arguments
+    #[serde(rename = "inputSchema")]
+    pub input_schema: Value,
+}
+
+/// All tools this server can expose
+///
+/// The search tool's input schema mirrors the [`SearchParams`] search fields.
+pub fn tool_definitions() -> Vec<ToolDefinition> {
+    vec![
+        ToolDefinition {
+            name: SEARCH_TOOL,
+            description: "Search academic papers on arXiv and Semantic Scholar. \
+                          At least one of query, title, author or abstract_contains is required.",
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "query": {"type": "string", "description": "Full-text query string"},
+                    "title": {"type": "string", "description": "Search in paper titles"},
+                    "author": {"type": "string", "description": "Search by author name"},
+                    "abstract_contains": {"type": "string", "description": "Search in abstracts"},
+                    "max_results": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "default": 10,
+                        "description": "Maximum number of results"
+                    },
+                    "categories": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "arXiv categories (e.g. cs.AI, cs.CL)"
+                    },
+                    "min_citations": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "description": "Minimum citation count"
+                    },
+                    "keep_unknown_citations": {
+                        "type": "boolean",
+                        "description": "Keep papers with unknown citation counts when min_citations is set"
+                    },
+                    "open_access_only": {
+                        "type": "boolean",
+                        "description": "Only return papers with an accessible PDF"
+                    },
+                    "year": {"type": "string", "description": "Year filter (e.g. 2023 or 2020-2023)"}
+                }
+            }),
+        },
+        ToolDefinition {
+            name: FETCH_TOOL,
+            description: "Fetch a paper by arXiv ID, including Semantic Scholar metrics \
+                          and extracted PDF text when available.",
+            input_schema: paper_id_schema(),
+        },
+        ToolDefinition {
+            name: ANALYZE_TOOL,
+            description: "Fetch a paper by arXiv ID and analyze it with the configured LLM \
+                          (summary, methodology, datasets, results, contributions).",
+            input_schema: paper_id_schema(),
+        },
+    ]
+}
+
+/// Input schema shared by the arXiv ID tools
+fn paper_id_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "arxiv_id": {"type": "string", "description": "arXiv paper ID (e.g. 1706.03762)"}
+        },
+        "required": ["arxiv_id"]
+    })
+}
+
+/// Arguments of the search tool (see [`SearchParams`] for field semantics)
+#[derive(Debug, Default, Deserialize)]
+pub struct SearchToolInput {
+    /// Full-text query string
+    pub query: Option<String>,
+    /// Search in paper titles
+    pub title: Option<String>,
+    /// Search by author name
+    pub author: Option<String>,
+    /// Search in abstracts
+    pub abstract_contains: Option<String>,
+    /// Maximum number of results (defaults to the `SearchParams` default)
+    pub max_results: Option<usize>,
+    /// arXiv categories
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Minimum citation count
+    pub min_citations: Option<u32>,
+    /// Keep papers with unknown citation counts
+    #[serde(default)]
+    pub keep_unknown_citations: bool,
+    /// Only return papers with an accessible PDF
+    #[serde(default)]
+    pub open_access_only: bool,
+    /// Year filter
+    pub year: Option<String>,
+}
+
+impl From<SearchToolInput> for SearchParams {
+    fn from(input: SearchToolInput) -> Self {
+        let mut params = SearchParams::new();
+        params.query = input.query;
+        params.title = input.title;
+        params.author = input.author;
+        params.abstract_contains = input.abstract_contains;
+        if let Some(max_results) = input.max_results {
+            params.max_results = max_results;
+        }
+        params.categories = input.categories;
+        params.min_citations = input.min_citations;
+        params.keep_unknown_citations = input.keep_unknown_citations;
+        params.open_access_only = input.open_access_only;
+        params.year = input.year;
+        params
+    }
+}
+
+/// Arguments of the fetch/analyze tools
+#[derive(Debug, Deserialize)]
+struct PaperToolInput {
+    arxiv_id: String,
+}
+
+/// Incoming JSON-RPC message (request or notification)
+#[derive(Debug, Deserialize)]
+struct JsonRpcMessage {
+    /// Absent for notifications
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// MCP server wrapping a [`PaperClient`] and an optional [`PaperAnalyzer`]
+///
+/// The analyze tool is only listed when an analyzer is configured.
+pub struct McpServer<P: LlmProvider> {
+    client: PaperClient,
+    analyzer: Option<PaperAnalyzer<P>>,
+}
+
+impl<P: LlmProvider> McpServer<P> {
+    /// Create a server exposing the search and fetch tools
+    pub fn new(client: PaperClient) -> Self {
+        Self {
+            client,
+            analyzer: None,
+        }
+    }
+
+    /// Also expose the analyze tool, backed by `analyzer`
+    pub fn with_analyzer(mut self, analyzer: PaperAnalyzer<P>) -> Self {
+        self.analyzer = Some(analyzer);
+        self
+    }
+
+    /// Serve requests from stdin until it is closed
+    ///
+    /// stdout carries protocol messages only; logs must go to stderr.
+    pub async fn run_stdio(&self) -> AppResult<()> {
+        let mut lines = BufReader::new(tokio::io::stdin()).lines();
+        let mut stdout = tokio::io::stdout();
+
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .map_err(|e| AppError::InternalAppError(format!("Failed to read stdin: {}", e)))?
+        {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_message(&line).await {
+                let mut out = serde_json::to_string(&response)?;
+                out.push('\n');
+                stdout
+                    .write_all(out.as_bytes())
+                    .await
+                    .map_err(|e| AppError::InternalAppError(format!("Failed to write: {}", e)))?;
+                stdout
+                    .flush()
+                    .await
+                    .map_err(|e| AppError::InternalAppError(format!("Failed to flush: {}", e)))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handle one JSON-RPC message, returning the response (none for notifications)
+    pub async fn handle_message(&self, line: &str) -> Option<Value> {
+        let message: JsonRpcMessage = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => {
+                return Some(error_response(
+                    Value::Null,
+                    PARSE_ERROR,
+                    format!("Invalid JSON-RPC message: {}", e),
+                ));
+            }
+        };
+
+        let id = message.id?;
+        Some(
+            match self.handle_request(&message.method, message.params).await {
+                Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+                Err((code, message)) => error_response(id, code, message),
+            },
+        )
+    }
+
+    /// Tools currently available on this server
+    pub fn tools(&self) -> Vec<ToolDefinition> {
+        tool_definitions()
+            .into_iter()
+            .filter(|tool| tool.name != ANALYZE_TOOL || self.analyzer.is_some())
+            .collect()
+    }
+
+    async fn handle_request(&self, method: &str, params: Value) -> Result<Value, (i64, String)> {
+        match method {
+            "initialize" => Ok(json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "capabilities": {"tools": {}},
+                "serverInfo": {"name": SERVER_NAME, "version": env!("CARGO_PKG_VERSION")}
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({"tools": self.tools()})),
+            "tools/call" => {
+                let name = params
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .ok_or((INVALID_PARAMS, "Missing tool name".to_string()))?;
+                let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
+                if !self.tools().iter().any(|tool| tool.name == name) {
+                    return Err((INVALID_PARAMS, format!("Unknown tool: {}", name)));
+                }
+
+                // Tool failures are reported in the result so the model can see them
+                Ok(match self.call_tool(name, arguments).await {
+                    Ok(output) => tool_result(output, false),
+                    Err(e) => tool_result(e.to_string(), true),
+                })
+            }
+            _ => Err((METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+        }
+    }
+
+    /// Run a tool and return its JSON output as text
+    async fn call_tool(&self, name: &str, arguments: Value) -> AppResult<String> {
+        match name {
+            SEARCH_TOOL => {
+                let input: SearchToolInput = serde_json::from_value(arguments)?;
+                let result = self.client.search(input.into()).await?;
+                let exported: Vec<ExportedPaper> = result
+                    .papers
+                    .into_iter()
+                    .map(|paper| ExportedPaper::new(paper, ExportOptions::default()))
+                    .collect();
+                Ok(serde_json::to_string_pretty(&exported)?)
+            }
+            FETCH_TOOL => {
+                let input: PaperToolInput = serde_json::from_value(arguments)?;
+                let paper = self.client.fetch_by_arxiv_id(&input.arxiv_id).await?;
+                let options = ExportOptions {
+                    text_extracted: paper.has_extracted_text(),
+                    ..Default::default()
+                };
+                Ok(serde_json::to_string_pretty(&ExportedPaper::new(
+                    paper, options,
+                ))?)
+            }
+            ANALYZE_TOOL => {
+                let Some(analyzer) = &self.analyzer else {
+                    return Err(AppError::ConfigError(
+                        "No LLM provider configured for analysis".to_string(),
+                    ));
+                };
+                let input: PaperToolInput = serde_json::from_value(arguments)?;
+                let mut paper = self.client.fetch_by_arxiv_id(&input.arxiv_id).await?;
+                let analysis = analyzer.analyze(&paper).await?;
+                let options = ExportOptions {
+                    analyzed: true,
+                    text_extracted: paper.has_extracted_text(),
+                    llm_provider: Some(analysis.provider.clone()),
+                    llm_model: Some(analysis.model.clone()),
+                    ..Default::default()
+                };
+                paper.set_analysis(analysis);
+                Ok(serde_json::to_string_pretty(&ExportedPaper::new(
+                    paper, options,
+                ))?)
+            }
+            _ => Err(AppError::ConfigError(format!("Unknown tool: {}", name))),
+        }
+    }
+}
+
+/// `tools/call` result with a single text content block
+fn tool_result(text: String, is_error: bool) -> Value {
+    json!({
+        "content": [{"type": "text", "text": text}],
+        "isError": is_error
+    })
+}
+
+/// JSON-RPC error response
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": code, "message": message}
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::MockProvider;
+
+    #[test]
+    fn test_tool_list_serializes() {
+        let tools = serde_json::to_value(tool_definitions()).unwrap();
+        let names: Vec<&str> = tools
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec![SEARCH_TOOL, FETCH_TOOL, ANALYZE_TOOL]);
+        assert!(tools[0]["description"].is_string());
+        assert_eq!(tools[1]["inputSchema"]["required"], json!(["arxiv_id"]));
+    }
+
+    #[test]
+    fn test_search_tool_schema_mirrors_search_params() {
+        let tool = tool_definitions().remove(0);
+        let properties = tool.input_schema["properties"].as_object().unwrap();
+        for field in [
+            "query",
+            "title",
+            "author",
+            "abstract_contains",
+            "max_results",
+            "categories",
+            "min_citations",
+            "keep_unknown_citations",
+            "open_access_only",
+            "year",
+        ] {
+            assert!(properties.contains_key(field), "missing {}", field);
+        }
+        assert_eq!(properties["categories"]["type"], "array");
+
+        let input: SearchToolInput = serde_json::from_value(json!({
+            "query": "transformer",
+            "max_results": 3,
+            "categories": ["cs.CL"],
+            "open_access_only": true
+        }))
+        .unwrap();
+        let params = SearchParams::from(input);
+        assert_eq!(params.query.as_deref(), Some("transformer"));
+        assert_eq!(params.max_results, 3);
+        assert_eq!(params.categories, vec!["cs.CL"]);
+        assert!(params.open_access_only);
+    }
+
+    #[tokio::test]
+    async fn test_tools_list_hides_analyze_without_analyzer() {
+        let server = McpServer::<MockProvider>::new(PaperClient::new());
+        let response = server
+            .handle_message(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#)
+            .await
+            .unwrap();
+        let tools = response["result"]["tools"].as_array().unwrap();
+        assert_eq!(tools.len(), 2);
+
+        let server = server.with_analyzer(PaperAnalyzer::new(MockProvider::new()));
+        assert_eq!(server.tools().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_handle_message_protocol_errors() {
+        let server = McpServer::<MockProvider>::new(PaperClient::new());
+
+        let response = server.handle_message("not json").await.unwrap();
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+
+        let response = server
+            .handle_message(r#"{"jsonrpc":"2.0","id":2,"method":"resources/list"}"#)
+            .await
+            .unwrap();
+        assert_eq!(response["id"], 2);
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+
+        // Notifications get no response
+        let response = server
+            .handle_message(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#)
+            .await;
+        assert!(response.is_none());
+    }
+}
@@ -1,12 +1,16 @@
 //! Data models for academic papers and related entities
 
+use crate::client::is_arxiv_id;
 use crate::shared::errors::AppResult;
-use crate::shared::utils::datetime_from_str;
+use crate::shared::utils::{datetime_from_str, normalize_doi};
 use arxiv_tools::Paper as ArxivPaper;
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Datelike, Local};
 use derive_new::new;
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
 use ss_tools::structs::Paper as SsPaper;
+use std::collections::HashSet;
+use std::time::Duration;
 
 /// Author information
 #[derive(Debug, Clone, Default, new, Serialize, Deserialize)]
@@ -57,6 +61,21 @@ impl Author {
     }
 }
 
+/// Author profile with publication list, as returned by author lookups
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuthorProfile {
+    /// Best-matching author
+    pub author: Author,
+
+    /// The author's papers, most recent first
+    pub papers: Vec<AcademicPaper>,
+
+    /// Other authors sharing the queried name, best match first
+    ///
+    /// Empty when the profile was looked up by author ID.
+    pub other_candidates: Vec<Author>,
+}
+
 /// Information about a dataset used in research
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DatasetInfo {
@@ -96,7 +115,78 @@ impl DatasetInfo {
 
     /// Check if this dataset info has meaningful content
     pub fn is_valid(&self) -> bool {
-        !self.name.is_empty() && self.name != "記載なし" && self.name != "N/A"
+        !self.name.is_empty() && !is_placeholder(&self.name)
+    }
+}
+
+/// Values models write when the abstract does not state something
+const PLACEHOLDER_VALUES: &[&str] = &[
+    "記載なし",
+    "なし",
+    "不明",
+    "該当なし",
+    "n/a",
+    "na",
+    "none",
+    "unknown",
+    "not mentioned",
+    "not specified",
+    "not stated",
+    "not available",
+];
+
+/// Phrases that mark a short answer as "not in the abstract"
+const PLACEHOLDER_PHRASES: &[&str] = &["記載なし", "記載されていない", "not mentioned"];
+
+/// Answers at most this long that contain a placeholder phrase count as placeholders
+const MAX_PLACEHOLDER_SENTENCE_CHARS: usize = 40;
+
+/// Check whether an LLM answer is a "not mentioned" placeholder rather than content
+///
+/// Matches bare placeholders ("記載なし", "N/A", "Not mentioned.") and short
+/// sentences built around one (e.g. "アブストラクトに記載なし。").
+pub fn is_placeholder(text: &str) -> bool {
+    let normalized = text
+        .trim()
+        .trim_end_matches(['.', '。', '!', '！'])
+        .trim()
+        .to_lowercase();
+    if normalized.is_empty() {
+        return false;
+    }
+    PLACEHOLDER_VALUES.contains(&normalized.as_str())
+        || (normalized.chars().count() <= MAX_PLACEHOLDER_SENTENCE_CHARS
+            && PLACEHOLDER_PHRASES
+                .iter()
+                .any(|phrase| normalized.contains(phrase)))
+}
+
+/// Attempts and latency of one logical LLM request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestStats {
+    /// Number of attempts made, including the final one
+    pub attempts: u32,
+
+    /// Wall-clock time across all attempts, in milliseconds
+    pub total_latency_ms: u64,
+
+    /// Whether the request needed more than one attempt
+    pub retried: bool,
+}
+
+impl RequestStats {
+    /// Stats for a request that succeeded on its first attempt
+    pub fn single(latency: Duration) -> Self {
+        Self::new(1, latency)
+    }
+
+    /// Stats for a request that took `attempts` attempts in total
+    pub fn new(attempts: u32, latency: Duration) -> Self {
+        Self {
+            attempts,
+            total_latency_ms: latency.as_millis() as u64,
+            retried: attempts > 1,
+        }
     }
 }
 
@@ -135,6 +225,21 @@ pub struct PaperAnalysis {
 
     /// Model used for analysis
     pub model: String,
+
+    /// Problems encountered while producing a best-effort analysis
+    ///
+    /// Empty for a regular analysis; set by
+    /// `PaperAnalyzer::analyze_lenient` when fields had to be generated
+    /// separately or could not be generated at all.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+
+    /// Attempts and latency of the LLM request that produced the analysis
+    ///
+    /// Set for analyses from a single combined request; `None` for
+    /// piecewise analyses and analyses loaded from older exports.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_stats: Option<RequestStats>,
 }
 
 impl PaperAnalysis {
@@ -142,6 +247,218 @@ impl PaperAnalysis {
     pub fn is_complete(&self) -> bool {
         !self.summary.is_empty() && !self.methodology.is_empty()
     }
+
+    /// Remove "not mentioned" placeholders and record what was ungrounded
+    ///
+    /// Placeholder entries are dropped from `key_contributions`, `tasks` and
+    /// `datasets`; placeholder text sections are cleared. Each affected field
+    /// adds a message to `warnings` so exports show which parts of the
+    /// analysis the model could not ground in the paper.
+    pub fn strip_placeholders(&mut self) {
+        for (field, text) in [
+            ("summary", &mut self.summary),
+            ("background_and_purpose", &mut self.background_and_purpose),
+            ("methodology", &mut self.methodology),
+            ("results", &mut self.results),
+            (
+                "advantages_limitations_and_future_work",
+                &mut self.advantages_limitations_and_future_work,
+            ),
+        ] {
+            if is_placeholder(text) {
+                self.warnings
+                    .push(format!("{} is not stated in the paper: {}", field, text));
+                text.clear();
+            }
+        }
+
+        for (field, items) in [
+            ("key_contributions", &mut self.key_contributions),
+            ("tasks", &mut self.tasks),
+        ] {
+            let before = items.len();
+            items.retain(|item| !item.trim().is_empty() && !is_placeholder(item));
+            let removed = before - items.len();
+            if removed > 0 {
+                self.warnings.push(format!(
+                    "Removed {} placeholder entries from {}",
+                    removed, field
+                ));
+            }
+        }
+
+        let before = self.datasets.len();
+        self.datasets.retain(DatasetInfo::is_valid);
+        let removed = before - self.datasets.len();
+        if removed > 0 {
+            self.warnings.push(format!(
+                "Removed {} placeholder entries from datasets",
+                removed
+            ));
+        }
+    }
+
+    /// Compare this (earlier) analysis with a newer one of the same paper
+    ///
+    /// Text sections are compared after trimming whitespace; list entries are
+    /// matched case-insensitively, so rewording a contribution shows up as one
+    /// removal plus one addition.
+    pub fn diff(&self, other: &PaperAnalysis) -> AnalysisDiff {
+        let changed_sections = [
+            ("summary", &self.summary, &other.summary),
+            (
+                "background_and_purpose",
+                &self.background_and_purpose,
+                &other.background_and_purpose,
+            ),
+            ("methodology", &self.methodology, &other.methodology),
+            ("results", &self.results, &other.results),
+            (
+                "advantages_limitations_and_future_work",
+                &self.advantages_limitations_and_future_work,
+                &other.advantages_limitations_and_future_work,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, before, after)| before.trim() != after.trim())
+        .map(|(field, _, _)| field.to_string())
+        .collect();
+
+        AnalysisDiff {
+            previous_model: self.model.clone(),
+            current_model: other.model.clone(),
+            changed_sections,
+            added_key_contributions: list_difference(
+                &other.key_contributions,
+                &self.key_contributions,
+            ),
+            removed_key_contributions: list_difference(
+                &self.key_contributions,
+                &other.key_contributions,
+            ),
+            added_tasks: list_difference(&other.tasks, &self.tasks),
+            removed_tasks: list_difference(&self.tasks, &other.tasks),
+        }
+    }
+}
+
+/// Entries of `items` with no case-insensitive match in `other`
+fn list_difference(items: &[String], other: &[String]) -> Vec<String> {
+    let other: HashSet<String> = other.iter().map(|s| s.trim().to_lowercase()).collect();
+    items
+        .iter()
+        .filter(|item| !other.contains(&item.trim().to_lowercase()))
+        .cloned()
+        .collect()
+}
+
+/// Escape a literal for a regex, requiring word boundaries at its word-character ends
+///
+/// A term such as "C++" ends in a non-word character, where `\b` would never
+/// match after it, so boundaries are only added next to word characters.
+fn word_bounded(term: &str) -> String {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let start = if is_word(term.chars().next()) {
+        r"\b"
+    } else {
+        ""
+    };
+    let end = if is_word(term.chars().last()) {
+        r"\b"
+    } else {
+        ""
+    };
+    format!("{}{}{}", start, regex::escape(term), end)
+}
+
+/// Differences between two analyses of a paper, as returned by [`PaperAnalysis::diff`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalysisDiff {
+    /// Model that produced the earlier analysis
+    pub previous_model: String,
+
+    /// Model that produced the newer analysis
+    pub current_model: String,
+
+    /// Names of the text sections whose content changed
+    pub changed_sections: Vec<String>,
+
+    /// Key contributions only in the newer analysis
+    pub added_key_contributions: Vec<String>,
+
+    /// Key contributions only in the earlier analysis
+    pub removed_key_contributions: Vec<String>,
+
+    /// Research tasks only in the newer analysis
+    pub added_tasks: Vec<String>,
+
+    /// Research tasks only in the earlier analysis
+    pub removed_tasks: Vec<String>,
+}
+
+impl AnalysisDiff {
+    /// Whether the two analyses have the same content
+    pub fn is_empty(&self) -> bool {
+        self.changed_sections.is_empty()
+            && self.added_key_contributions.is_empty()
+            && self.removed_key_contributions.is_empty()
+            && self.added_tasks.is_empty()
+            && self.removed_tasks.is_empty()
+    }
+}
+
+/// Which of two compared papers advances over the other
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ComparisonVerdict {
+    /// The first paper advances over the second
+    A,
+    /// The second paper advances over the first
+    B,
+    /// Neither clearly advances over the other (e.g. complementary work)
+    #[default]
+    Neither,
+}
+
+impl std::fmt::Display for ComparisonVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComparisonVerdict::A => write!(f, "a"),
+            ComparisonVerdict::B => write!(f, "b"),
+            ComparisonVerdict::Neither => write!(f, "neither"),
+        }
+    }
+}
+
+/// LLM-generated head-to-head comparison of two papers
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaperComparison {
+    /// Title of the first paper ("A")
+    pub paper_a: String,
+
+    /// Title of the second paper ("B")
+    pub paper_b: String,
+
+    /// Research tasks both papers address
+    pub shared_tasks: Vec<String>,
+
+    /// Differences in methodology and technical approach
+    pub methodological_differences: Vec<String>,
+
+    /// Which paper advances over the other
+    pub advances: ComparisonVerdict,
+
+    /// Why that paper advances over the other (or why neither does)
+    pub rationale: String,
+
+    /// When the comparison was performed
+    pub compared_at: DateTime<Local>,
+
+    /// LLM provider used for the comparison
+    pub provider: String,
+
+    /// Model used for the comparison
+    pub model: String,
 }
 
 /// Importance level of a paper section
@@ -170,6 +487,16 @@ impl SectionImportance {
         }
     }
 
+    /// Selection priority (lower is more important)
+    pub fn priority(&self) -> u8 {
+        match self {
+            Self::Critical => 0,
+            Self::High => 1,
+            Self::Medium => 2,
+            Self::Reference => 3,
+        }
+    }
+
     /// Determine importance from section title
     pub fn from_title(title: &str) -> Self {
         let title_lower = title.to_lowercase();
@@ -210,6 +537,75 @@ impl std::fmt::Display for SectionImportance {
     }
 }
 
+/// DOI prefix arXiv assigns to its own records (not a publisher DOI)
+pub(crate) const ARXIV_DOI_PREFIX: &str = "10.48550/";
+
+/// ISO 639-3 code returned by `AcademicPaper::detect_abstract_language` for English
+pub const ENGLISH_LANGUAGE_CODE: &str = "eng";
+
+/// Distance from the Unix epoch within which a date counts as the epoch fallback
+const EPOCH_TOLERANCE_SECS: i64 = 24 * 60 * 60;
+
+/// Author count above which APA lists the first 19 authors, an ellipsis and the last
+const APA_MAX_LISTED_AUTHORS: usize = 20;
+
+/// Problem found by [`AcademicPaper::validate`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationIssue {
+    /// The title is empty or whitespace
+    EmptyTitle,
+    /// The published date is the 1970-01-01 fallback for a missing date
+    EpochPublishedDate,
+    /// No arXiv ID, DOI or Semantic Scholar ID
+    NoIdentifiers,
+    /// The arXiv ID is not a valid new- or old-style arXiv ID
+    MalformedArxivId(String),
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyTitle => write!(f, "title is empty"),
+            Self::EpochPublishedDate => {
+                write!(f, "published date is missing (1970-01-01 fallback)")
+            }
+            Self::NoIdentifiers => write!(f, "no arXiv ID, DOI or Semantic Scholar ID"),
+            Self::MalformedArxivId(id) => write!(f, "malformed arXiv ID \"{}\"", id),
+        }
+    }
+}
+
+/// Whether a paper has been formally published
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PublicationStatus {
+    /// Only available as an arXiv preprint
+    Preprint,
+    /// Has a publisher DOI and a venue other than arXiv
+    Published,
+    /// Not enough metadata to tell
+    #[default]
+    Unknown,
+}
+
+impl PublicationStatus {
+    /// Get string representation for XML output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Preprint => "preprint",
+            Self::Published => "published",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for PublicationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// A bibliographic reference extracted from a paper's References section.
 ///
 /// This struct represents a single citation/reference that was parsed
@@ -291,6 +687,80 @@ pub struct PaperSection {
     pub captions: Option<Vec<String>>,
 }
 
+#[cfg(test)]
+impl PaperSection {
+    /// Section with importance derived from its title, for tests
+    pub(crate) fn for_test(index: i16, title: &str, content: impl Into<String>) -> Self {
+        PaperSection {
+            index,
+            title: title.to_string(),
+            content: content.into(),
+            importance: SectionImportance::from_title(title),
+            math_content: None,
+            captions: None,
+        }
+    }
+}
+
+/// Fixed-size piece of a section, for embedding/RAG pipelines
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextChunk {
+    /// Title of the section the chunk comes from
+    pub section_title: String,
+
+    /// Index of the source section (`PaperSection::index`)
+    pub section_index: i16,
+
+    /// Chunk text
+    pub content: String,
+
+    /// Start offset in the section content, in characters (inclusive)
+    pub char_start: usize,
+
+    /// End offset in the section content, in characters (exclusive)
+    pub char_end: usize,
+}
+
+/// Characters of context kept on each side of a `PaperText::search` hit
+pub const SEARCH_CONTEXT_CHARS: usize = 80;
+
+/// Reading speed used by [`AcademicPaper::estimated_reading_minutes`]
+pub const READING_WORDS_PER_MINUTE: usize = 220;
+
+/// Count whitespace-separated words (Unicode whitespace, including full-width spaces)
+fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// A search hit within an extracted section
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionMatch {
+    /// Title of the section containing the hit
+    pub section_title: String,
+
+    /// Index of the section (`PaperSection::index`)
+    pub section_index: i16,
+
+    /// The hit with surrounding context, whitespace collapsed
+    pub snippet: String,
+
+    /// Start of the hit in the section content, in characters
+    pub offset: usize,
+}
+
+/// One revision of an arXiv paper
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArxivVersion {
+    /// Version number (1 for the original submission)
+    pub version: u32,
+
+    /// When this version was submitted
+    pub submitted_at: DateTime<Local>,
+
+    /// Size as reported by arXiv (e.g. "1,102 KB")
+    pub size: String,
+}
+
 /// Extracted text from a paper PDF in multiple formats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PaperText {
@@ -327,6 +797,212 @@ impl PaperText {
             .find(|s| s.title.to_lowercase() == title.to_lowercase())
     }
 
+    /// Select sections that fit within a character budget, by importance
+    ///
+    /// Sections are considered greedily in importance order (critical first,
+    /// references last; document order within the same level). A section is
+    /// included if its content still fits in the remaining budget. The result
+    /// is returned in original document order.
+    pub fn select_sections_within_budget(&self, max_chars: usize) -> Vec<&PaperSection> {
+        self.select_sections_within_budget_by(max_chars, |s| s.content.len())
+    }
+
+    /// Like [`select_sections_within_budget`](Self::select_sections_within_budget),
+    /// charging each section `cost(section)` instead of its content length
+    ///
+    /// Use it when sections are rendered with extra text such as headings.
+    pub fn select_sections_within_budget_by(
+        &self,
+        max_chars: usize,
+        cost: impl Fn(&PaperSection) -> usize,
+    ) -> Vec<&PaperSection> {
+        let mut candidates: Vec<(usize, &PaperSection)> =
+            self.sections.iter().enumerate().collect();
+        candidates.sort_by_key(|(pos, s)| (s.importance.priority(), *pos));
+
+        let mut remaining = max_chars;
+        let mut selected: Vec<(usize, &PaperSection)> = Vec::new();
+        for (pos, section) in candidates {
+            let size = cost(section);
+            if size <= remaining {
+                remaining -= size;
+                selected.push((pos, section));
+            }
+        }
+
+        selected.sort_by_key(|(pos, _)| *pos);
+        selected.into_iter().map(|(_, s)| s).collect()
+    }
+
+    /// Split sections into chunks of at most `max_chars` characters
+    ///
+    /// Chunks never cross section boundaries: a section that fits in
+    /// `max_chars` becomes a single chunk, and a longer one is split into
+    /// windows that overlap by `overlap` characters (clamped below
+    /// `max_chars`). Empty sections are skipped.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_chunks(&self, max_chars: usize, overlap: usize) -> Vec<TextChunk> {
+        let max_chars = max_chars.max(1);
+        let step = max_chars - overlap.min(max_chars - 1);
+        let mut chunks = Vec::new();
+
+        for section in &self.sections {
+            if section.content.trim().is_empty() {
+                continue;
+            }
+            let chars: Vec<char> = section.content.chars().collect();
+            let mut start = 0;
+            loop {
+                let end = (start + max_chars).min(chars.len());
+                chunks.push(TextChunk {
+                    section_title: section.title.clone(),
+                    section_index: section.index,
+                    content: chars[start..end].iter().collect(),
+                    char_start: start,
+                    char_end: end,
+                });
+                if end == chars.len() {
+                    break;
+                }
+                start += step;
+            }
+        }
+
+        chunks
+    }
+
+    /// Render the sections as markdown with `**term**` around each keyword occurrence
+    ///
+    /// See [`highlight_with_counts`](Self::highlight_with_counts) for the
+    /// matching rules.
+    pub fn highlight(&self, terms: &[String]) -> String {
+        self.highlight_with_counts(terms).0
+    }
+
+    /// Highlight keywords in the sections and count their occurrences
+    ///
+    /// Matching is case-insensitive and only whole words match ("net" does
+    /// not match inside "network"). When terms overlap, the longest one wins.
+    /// Returns the markdown body (one `## title` block per section) and the
+    /// number of occurrences of each distinct term, in input order.
+    pub fn highlight_with_counts(&self, terms: &[String]) -> (String, Vec<(String, usize)>) {
+        let mut unique: Vec<&str> = Vec::new();
+        for term in terms.iter().map(|t| t.trim()) {
+            if !term.is_empty()
+                && !unique
+                    .iter()
+                    .any(|u| u.to_lowercase() == term.to_lowercase())
+            {
+                unique.push(term);
+            }
+        }
+        let mut counts: Vec<(String, usize)> =
+            unique.iter().map(|term| (term.to_string(), 0)).collect();
+
+        let mut by_length: Vec<usize> = (0..unique.len()).collect();
+        by_length.sort_by_key(|&i| std::cmp::Reverse(unique[i].chars().count()));
+        let alternatives: Vec<String> = by_length
+            .iter()
+            .map(|&i| format!("({})", word_bounded(unique[i])))
+            .collect();
+        let pattern = (!alternatives.is_empty()).then(|| {
+            RegexBuilder::new(&alternatives.join("|"))
+                .case_insensitive(true)
+                .build()
+                .expect("escaped literals form a valid regex")
+        });
+
+        let mut blocks = Vec::with_capacity(self.sections.len());
+        for section in &self.sections {
+            let content = match &pattern {
+                Some(pattern) => pattern
+                    .replace_all(&section.content, |caps: &regex::Captures| {
+                        if let Some(group) = (1..caps.len()).find(|&g| caps.get(g).is_some()) {
+                            counts[by_length[group - 1]].1 += 1;
+                        }
+                        format!("**{}**", &caps[0])
+                    })
+                    .into_owned(),
+                None => section.content.clone(),
+            };
+            blocks.push(format!("## {}\n\n{}", section.title, content.trim()));
+        }
+
+        (blocks.join("\n\n"), counts)
+    }
+
+    /// Find a literal term in the section contents, with context
+    ///
+    /// Hits are reported in section order; overlapping occurrences (e.g. "aa"
+    /// in "aaa") are all reported. An empty needle matches nothing.
+    pub fn search(&self, needle: &str, case_insensitive: bool) -> Vec<SectionMatch> {
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        let pattern = RegexBuilder::new(&regex::escape(needle))
+            .case_insensitive(case_insensitive)
+            .build()
+            .expect("escaped literal is a valid regex");
+        self.find_matches(&pattern, true)
+    }
+
+    /// Find matches of a regular expression in the section contents, with context
+    ///
+    /// Matches do not overlap: scanning resumes at the end of each match, so
+    /// `\d+` reports "2023" once rather than also "023", "23" and "3".
+    pub fn search_regex(&self, pattern: &Regex) -> Vec<SectionMatch> {
+        self.find_matches(pattern, false)
+    }
+
+    /// Collect matches of `pattern`, resuming one character after each match
+    /// start when `overlapping`, or at its end otherwise
+    fn find_matches(&self, pattern: &Regex, overlapping: bool) -> Vec<SectionMatch> {
+        let mut matches = Vec::new();
+
+        for section in &self.sections {
+            let content = section.content.as_str();
+            let mut pos = 0;
+            while pos <= content.len() {
+                let Some(hit) = pattern.find_at(content, pos) else {
+                    break;
+                };
+                let context_start = content[..hit.start()]
+                    .char_indices()
+                    .rev()
+                    .nth(SEARCH_CONTEXT_CHARS.saturating_sub(1))
+                    .map_or(0, |(i, _)| i);
+                let context_end = content[hit.end()..]
+                    .char_indices()
+                    .nth(SEARCH_CONTEXT_CHARS)
+                    .map_or(content.len(), |(i, _)| hit.end() + i);
+
+                matches.push(SectionMatch {
+                    section_title: section.title.clone(),
+                    section_index: section.index,
+                    snippet: content[context_start..context_end]
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    offset: content[..hit.start()].chars().count(),
+                });
+
+                // Stepping one character past the start also guarantees progress
+                // on empty matches
+                pos = if overlapping || hit.is_empty() {
+                    hit.start()
+                        + content[hit.start()..]
+                            .chars()
+                            .next()
+                            .map_or(1, char::len_utf8)
+                } else {
+                    hit.end()
+                };
+            }
+        }
+
+        matches
+    }
+
     /// Get abstract section if available
     pub fn get_abstract(&self) -> Option<&PaperSection> {
         self.get_section("Abstract")
@@ -385,6 +1061,64 @@ impl PaperText {
     }
 }
 
+/// Records serialized before `metrics_known` existed are assumed to have real counts
+pub(crate) fn default_metrics_known() -> bool {
+    true
+}
+
+/// Split an author name into (given names, surname)
+///
+/// Accepts "Given Surname" and "Surname, Given"; a single word is a surname.
+pub(crate) fn split_author_name(name: &str) -> (String, String) {
+    if let Some((surname, given)) = name.split_once(',') {
+        return (given.trim().to_string(), surname.trim().to_string());
+    }
+    let mut words: Vec<&str> = name.split_whitespace().collect();
+    let surname = words.pop().unwrap_or_default().to_string();
+    (words.join(" "), surname)
+}
+
+/// APA author form: "Surname, I. N." (hyphenated given names keep the hyphen)
+fn apa_author(name: &str) -> String {
+    let (given, surname) = split_author_name(name);
+    let initials = given
+        .split_whitespace()
+        .map(|word| {
+            word.split('-')
+                .filter_map(|part| part.chars().next())
+                .map(|initial| format!("{}.", initial.to_uppercase()))
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    if initials.is_empty() {
+        surname
+    } else {
+        format!("{}, {}", surname, initials)
+    }
+}
+
+/// MLA first-author form: "Surname, Given"
+fn mla_inverted_author(name: &str) -> String {
+    let (given, surname) = split_author_name(name);
+    if given.is_empty() {
+        surname
+    } else {
+        format!("{}, {}", surname, given)
+    }
+}
+
+/// Terminate `text` with a period unless it already ends in punctuation
+fn with_period(text: &str) -> String {
+    let text = text.trim();
+    if text.ends_with(['.', '?', '!']) {
+        text.to_string()
+    } else {
+        format!("{}.", text)
+    }
+}
+
 /// Escape special XML characters
 fn escape_xml(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -394,6 +1128,16 @@ fn escape_xml(s: &str) -> String {
         .replace('\'', "&apos;")
 }
 
+/// Source of paper data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaperSource {
+    ArXiv,
+    SemanticScholar,
+    CrossRef,
+    Dblp,
+    Both,
+}
+
 /// Unified academic paper representation
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AcademicPaper {
@@ -410,6 +1154,10 @@ pub struct AcademicPaper {
     /// arXiv paper ID (e.g., "2106.09685")
     pub arxiv_id: String,
 
+    /// arXiv version number (e.g., 5 for "1706.03762v5"); `None` means latest
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arxiv_version: Option<u32>,
+
     /// Digital Object Identifier
     pub doi: String,
 
@@ -426,6 +1174,10 @@ pub struct AcademicPaper {
     /// Japanese translation of abstract
     pub abstract_text_ja: String,
 
+    /// Original abstract when `abstract_text` was machine-translated to English
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub abstract_text_original: String,
+
     /// Paper URL
     pub url: String,
 
@@ -454,12 +1206,26 @@ pub struct AcademicPaper {
     /// Influential citation count (Semantic Scholar metric)
     pub influential_citation_count: i32,
 
+    /// Whether the citation/reference counts were reported by a source
+    ///
+    /// arXiv provides no metrics, so papers that could not be matched on
+    /// Semantic Scholar (or CrossRef) keep zero counts with this flag unset;
+    /// treat their counts as unknown rather than zero.
+    #[serde(default = "default_metrics_known")]
+    pub metrics_known: bool,
+
     /// Whether open access PDF is available
     pub is_open_access: bool,
 
     /// Open access PDF URL
     pub open_access_pdf_url: Option<String>,
 
+    /// Source this record came from (`Both` once records from different sources merge)
+    ///
+    /// Not serialized with the paper; exports include it on request.
+    #[serde(skip)]
+    pub source: Option<PaperSource>,
+
     // LLM Analysis
     /// LLM-generated analysis (populated by agents)
     pub analysis: Option<PaperAnalysis>,
@@ -498,12 +1264,13 @@ impl AcademicPaper {
             .map(|name| Author::from_arxiv_name(name))
             .collect();
 
-        // Extract clean arXiv ID from URL (e.g., "http://arxiv.org/abs/1706.03762v7" -> "1706.03762")
-        let arxiv_id = Self::extract_arxiv_id(&paper.id);
+        // Split the URL into ID and version (e.g., "http://arxiv.org/abs/1706.03762v7" -> ("1706.03762", 7))
+        let (arxiv_id, arxiv_version) = Self::parse_arxiv_id(&paper.id);
 
         Self {
             arxiv_paper: Some(paper.clone()),
             arxiv_id: arxiv_id.clone(),
+            arxiv_version,
             title: paper.title.clone(),
             abstract_text: paper.abstract_text.clone(),
             authors,
@@ -517,6 +1284,7 @@ impl AcademicPaper {
             },
             doi: paper.doi.clone(),
             published_date,
+            source: Some(PaperSource::ArXiv),
             created_at: now,
             updated_at: now,
             ..Default::default()
@@ -563,11 +1331,24 @@ impl AcademicPaper {
             .map(|ids| {
                 (
                     ids.arxiv.clone().unwrap_or_default(),
-                    ids.doi.clone().unwrap_or_default(),
+                    ids.doi
+                        .as_deref()
+                        .and_then(normalize_doi)
+                        .unwrap_or_default(),
                 )
             })
             .unwrap_or_default();
 
+        // Fall back to the arXiv abstract page when SS has no landing URL
+        let url = paper
+            .url
+            .clone()
+            .filter(|u| !u.is_empty())
+            .or_else(|| {
+                (!arxiv_id.is_empty()).then(|| format!("https://arxiv.org/abs/{}", arxiv_id))
+            })
+            .unwrap_or_default();
+
         Self {
             ss_paper: Some(paper.clone()),
             ss_id: paper.paper_id.clone().unwrap_or_default(),
@@ -576,15 +1357,17 @@ impl AcademicPaper {
             title: paper.title.clone().unwrap_or_default(),
             abstract_text: paper.abstract_text.clone().unwrap_or_default(),
             authors,
-            url: paper.url.clone().unwrap_or_default(),
+            url,
             journal,
             citations_count: paper.citation_count.unwrap_or(0) as i32,
             references_count: paper.reference_count.unwrap_or(0) as i32,
             influential_citation_count: paper.influential_citation_count.unwrap_or(0) as i32,
+            metrics_known: true,
             is_open_access,
             open_access_pdf_url,
             bibtex,
             published_date,
+            source: Some(PaperSource::SemanticScholar),
             created_at: now,
             updated_at: now,
             ..Default::default()
@@ -598,6 +1381,7 @@ impl AcademicPaper {
         self.citations_count = paper.citation_count.unwrap_or(0) as i32;
         self.references_count = paper.reference_count.unwrap_or(0) as i32;
         self.influential_citation_count = paper.influential_citation_count.unwrap_or(0) as i32;
+        self.metrics_known = true;
 
         // Update authors with h-index if available
         if let Some(ss_authors) = &paper.authors {
@@ -637,9 +1421,13 @@ impl AcademicPaper {
 
         // Fill in doi from external_ids if not already set
         if self.doi.is_empty()
-            && let Some(doi) = paper.external_ids.as_ref().and_then(|ids| ids.doi.as_ref())
+            && let Some(doi) = paper
+                .external_ids
+                .as_ref()
+                .and_then(|ids| ids.doi.as_deref())
+                .and_then(normalize_doi)
         {
-            self.doi = doi.clone();
+            self.doi = doi;
         }
 
         // Update open access info
@@ -656,7 +1444,7 @@ impl AcademicPaper {
     /// arXiv is the authoritative source for abstracts, URLs, and publication dates,
     /// so these fields are always overwritten. Other fields are only filled if empty.
     pub fn enrich_from_arxiv(&mut self, paper: ArxivPaper) {
-        let arxiv_id = Self::extract_arxiv_id(&paper.id);
+        let (arxiv_id, arxiv_version) = Self::parse_arxiv_id(&paper.id);
 
         // Always overwrite: arXiv is authoritative for these fields
         self.abstract_text = paper.abstract_text.clone();
@@ -667,6 +1455,9 @@ impl AcademicPaper {
         if self.arxiv_id.is_empty() {
             self.arxiv_id = arxiv_id;
         }
+        if self.arxiv_version.is_none() {
+            self.arxiv_version = arxiv_version;
+        }
         if self.title.is_empty() {
             self.title = paper.title.clone();
         }
@@ -702,18 +1493,139 @@ impl AcademicPaper {
 
     /// Merge another paper's data into this one
     ///
-    /// Applies SS enrichment first (metrics, author details, bibtex), then
-    /// arXiv enrichment (overwrites abstract, url, published_date).
-    /// This ordering ensures arXiv's authoritative fields win.
+    /// Source-agnostic: empty fields (IDs, DOI, title, abstract, categories,
+    /// open-access URL, bibtex, ...) are filled from `other` and non-empty ones
+    /// are kept, with two exceptions:
+    ///
+    /// - Citation metrics reported by a source (`metrics_known`) replace unknown
+    ///   ones, even non-zero counts. When both sides agree on `metrics_known`,
+    ///   only zero counts are filled.
+    /// - If `other` carries arXiv data this paper lacks, [`Self::enrich_from_arxiv`]
+    ///   runs last and overwrites the abstract, URL and published date, for
+    ///   which arXiv is authoritative.
+    ///
+    /// Authors are taken from `other` only when this paper has none; otherwise
+    /// empty metadata (SS ID, h-index, counts, affiliations) of same-named
+    /// authors is filled in.
     pub fn merge_with(&mut self, other: AcademicPaper) {
-        if let Some(ss_paper) = other.ss_paper {
-            self.enrich_from_semantic_scholar(ss_paper);
+        fn fill(target: &mut String, source: String) {
+            if target.is_empty() {
+                *target = source;
+            }
         }
-        if let Some(arxiv_paper) = other.arxiv_paper {
+
+        fill(&mut self.ss_id, other.ss_id);
+        fill(&mut self.arxiv_id, other.arxiv_id);
+        fill(&mut self.doi, other.doi);
+        fill(&mut self.title, other.title);
+        fill(&mut self.abstract_text, other.abstract_text);
+        fill(&mut self.abstract_text_ja, other.abstract_text_ja);
+        fill(
+            &mut self.abstract_text_original,
+            other.abstract_text_original,
+        );
+        fill(&mut self.url, other.url);
+        fill(&mut self.journal, other.journal);
+        fill(&mut self.primary_category, other.primary_category);
+        fill(&mut self.bibtex, other.bibtex);
+
+        if self.categories.is_empty() {
+            self.categories = other.categories;
+        }
+        if self.published_date == DateTime::<Local>::default() {
+            self.published_date = other.published_date;
+        }
+        if !self.metrics_known && other.metrics_known {
+            self.citations_count = other.citations_count;
+            self.references_count = other.references_count;
+            self.influential_citation_count = other.influential_citation_count;
+            self.metrics_known = true;
+        } else if self.metrics_known == other.metrics_known {
+            if self.citations_count == 0 {
+                self.citations_count = other.citations_count;
+            }
+            if self.references_count == 0 {
+                self.references_count = other.references_count;
+            }
+            if self.influential_citation_count == 0 {
+                self.influential_citation_count = other.influential_citation_count;
+            }
+        }
+        self.is_open_access |= other.is_open_access;
+        if self.open_access_pdf_url.is_none() {
+            self.open_access_pdf_url = other.open_access_pdf_url;
+        }
+        self.source = match (self.source, other.source) {
+            (Some(mine), Some(theirs)) if mine != theirs => Some(PaperSource::Both),
+            (mine, theirs) => mine.or(theirs),
+        };
+        if self.analysis.is_none() {
+            self.analysis = other.analysis;
+        }
+        if self.extracted_text.is_none() {
+            self.extracted_text = other.extracted_text;
+        }
+
+        if self.authors.is_empty() {
+            self.authors = other.authors;
+        } else {
+            for other_author in other.authors {
+                if let Some(existing) = self
+                    .authors
+                    .iter_mut()
+                    .find(|a| a.name == other_author.name)
+                {
+                    fill(&mut existing.ss_id, other_author.ss_id);
+                    if existing.h_index == 0 {
+                        existing.h_index = other_author.h_index;
+                    }
+                    if existing.paper_count == 0 {
+                        existing.paper_count = other_author.paper_count;
+                    }
+                    if existing.citation_count == 0 {
+                        existing.citation_count = other_author.citation_count;
+                    }
+                    if existing.affiliations.is_empty() {
+                        existing.affiliations = other_author.affiliations;
+                    }
+                }
+            }
+        }
+
+        if self.ss_paper.is_none() {
+            self.ss_paper = other.ss_paper;
+        }
+        if self.arxiv_paper.is_none()
+            && let Some(arxiv_paper) = other.arxiv_paper
+        {
             self.enrich_from_arxiv(arxiv_paper);
+        } else {
+            self.updated_at = Local::now();
         }
     }
 
+    /// Count how many identifying and metric fields are populated
+    ///
+    /// Used to decide which record to keep as the base when merging duplicates.
+    pub fn metadata_score(&self) -> usize {
+        [
+            !self.ss_id.is_empty(),
+            !self.arxiv_id.is_empty(),
+            !self.doi.is_empty(),
+            !self.abstract_text.is_empty(),
+            !self.authors.is_empty(),
+            !self.journal.is_empty(),
+            !self.categories.is_empty(),
+            !self.bibtex.is_empty(),
+            self.citations_count > 0,
+            self.references_count > 0,
+            self.open_access_pdf_url.is_some(),
+        ]
+        .into_iter()
+        .filter(|&populated| populated)
+        .count()
+    }
+
     /// Get arXiv ID (returns error if not available)
     pub fn arxiv_id(&self) -> AppResult<String> {
         if !self.arxiv_id.is_empty() {
@@ -768,7 +1680,59 @@ impl AcademicPaper {
         }
     }
 
-    /// Check if paper has been analyzed by LLM
+    /// Classify the paper as preprint or published
+    ///
+    /// A publisher DOI (not arXiv's own `10.48550/` DOIs) plus a venue other
+    /// than arXiv means published; an arXiv venue, or an arXiv ID with no
+    /// venue, means preprint. Anything else is unknown.
+    pub fn publication_status(&self) -> PublicationStatus {
+        let is_arxiv_venue = self.journal.eq_ignore_ascii_case("arxiv");
+        let has_publisher_doi =
+            !self.doi.is_empty() && !self.doi.to_ascii_lowercase().starts_with(ARXIV_DOI_PREFIX);
+
+        if has_publisher_doi && !self.journal.is_empty() && !is_arxiv_venue {
+            PublicationStatus::Published
+        } else if is_arxiv_venue || (self.journal.is_empty() && !self.arxiv_id.is_empty()) {
+            PublicationStatus::Preprint
+        } else {
+            PublicationStatus::Unknown
+        }
+    }
+
+    /// Report metadata problems that break exports and statistics
+    ///
+    /// Papers assembled from partial API responses can lack a title, carry
+    /// the epoch fallback of [`datetime_from_str`] as published date, have no
+    /// identifier at all, or an unusable arXiv ID. Returns an empty list for a
+    /// well-formed paper.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if self.title.trim().is_empty() {
+            issues.push(ValidationIssue::EmptyTitle);
+        }
+        if self.known_year().is_none() {
+            issues.push(ValidationIssue::EpochPublishedDate);
+        }
+        if self.arxiv_id.is_empty() && self.doi.is_empty() && self.ss_id.is_empty() {
+            issues.push(ValidationIssue::NoIdentifiers);
+        }
+        if !self.arxiv_id.is_empty() && !is_arxiv_id(&self.arxiv_id) {
+            issues.push(ValidationIssue::MalformedArxivId(self.arxiv_id.clone()));
+        }
+        issues
+    }
+
+    /// Publication year, `None` when the published date is the epoch fallback
+    ///
+    /// [`datetime_from_str`] falls back to local midnight on 1970-01-01, so
+    /// any date within a day of the Unix epoch counts as unknown.
+    pub fn known_year(&self) -> Option<i32> {
+        let timestamp = self.published_date.timestamp();
+        (!(-EPOCH_TOLERANCE_SECS..=EPOCH_TOLERANCE_SECS).contains(&timestamp))
+            .then(|| self.published_date.year())
+    }
+
+    /// Check if paper has been analyzed by LLM
     pub fn is_analyzed(&self) -> bool {
         self.analysis
             .as_ref()
@@ -798,6 +1762,108 @@ impl AcademicPaper {
         format!("{} ({}). {}", authors_str, year, self.title)
     }
 
+    /// Format an APA (7th edition) reference
+    ///
+    /// Authors are listed as "Surname, I." joined with "&"; with more than 20,
+    /// the first 19 are followed by an ellipsis and the last author. The venue
+    /// (journal, or arXiv for preprints) is plain text without italics, and
+    /// the DOI is preferred over the URL. An unknown date gives "(n.d.)".
+    pub fn to_apa(&self) -> String {
+        let names: Vec<String> = self.authors.iter().map(|a| apa_author(&a.name)).collect();
+        let authors = match names.as_slice() {
+            [] => String::new(),
+            [only] => only.clone(),
+            [first, second] => format!("{}, & {}", first, second),
+            [rest @ .., last] if names.len() <= APA_MAX_LISTED_AUTHORS => {
+                format!("{}, & {}", rest.join(", "), last)
+            }
+            [.., last] => format!(
+                "{}, . . . {}",
+                names[..APA_MAX_LISTED_AUTHORS - 1].join(", "),
+                last
+            ),
+        };
+        let year = self
+            .known_year()
+            .map_or_else(|| "n.d.".to_string(), |year| year.to_string());
+
+        let mut citation = if authors.is_empty() {
+            format!("{} ({}).", with_period(&self.title), year)
+        } else {
+            format!("{} ({}). {}", authors, year, with_period(&self.title))
+        };
+        if let Some(venue) = self.citation_venue() {
+            citation.push_str(&format!(" {}", with_period(venue)));
+        }
+        if let Some(link) = self.citation_link() {
+            citation.push_str(&format!(" {}", link));
+        }
+        citation
+    }
+
+    /// Format an MLA (9th edition) works-cited entry
+    ///
+    /// The first author is inverted ("Surname, Given"); two authors are joined
+    /// with "and", three or more become "Surname, Given, et al.". The title is
+    /// quoted, and the venue (plain text, no italics), year and DOI or URL
+    /// follow as comma-separated containers.
+    pub fn to_mla(&self) -> String {
+        let authors = match self.authors.as_slice() {
+            [] => String::new(),
+            [only] => mla_inverted_author(&only.name),
+            [first, second] => format!(
+                "{}, and {}",
+                mla_inverted_author(&first.name),
+                second.name.trim()
+            ),
+            [first, ..] => format!("{}, et al", mla_inverted_author(&first.name)),
+        };
+
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(venue) = self.citation_venue() {
+            parts.push(venue.to_string());
+        }
+        if let Some(year) = self.known_year() {
+            parts.push(year.to_string());
+        }
+        if let Some(link) = self.citation_link() {
+            parts.push(link);
+        }
+
+        let mut citation = String::new();
+        if !authors.is_empty() {
+            citation.push_str(&with_period(&authors));
+            citation.push(' ');
+        }
+        citation.push_str(&format!("\"{}\"", with_period(&self.title)));
+        if !parts.is_empty() {
+            citation.push_str(&format!(" {}.", parts.join(", ")));
+        }
+        citation
+    }
+
+    /// Venue for citations: the journal, or arXiv for preprints
+    fn citation_venue(&self) -> Option<&str> {
+        if !self.journal.trim().is_empty() {
+            Some(self.journal.trim())
+        } else if !self.arxiv_id.is_empty() {
+            Some("arXiv")
+        } else {
+            None
+        }
+    }
+
+    /// Link for citations: DOI URL, then arXiv abstract page, then the paper URL
+    fn citation_link(&self) -> Option<String> {
+        if let Some(doi) = normalize_doi(&self.doi) {
+            Some(format!("https://doi.org/{}", doi))
+        } else if !self.arxiv_id.is_empty() {
+            Some(format!("https://arxiv.org/abs/{}", self.arxiv_id))
+        } else {
+            Some(self.url.trim().to_string()).filter(|url| !url.is_empty())
+        }
+    }
+
     /// Check if paper has extracted text
     pub fn has_extracted_text(&self) -> bool {
         self.extracted_text
@@ -814,6 +1880,35 @@ impl AcademicPaper {
             .unwrap_or("")
     }
 
+    /// Number of words in the abstract
+    pub fn abstract_word_count(&self) -> usize {
+        count_words(&self.abstract_text)
+    }
+
+    /// Number of words in the extracted section text, if text was extracted
+    pub fn full_text_word_count(&self) -> Option<usize> {
+        self.extracted_text
+            .as_ref()
+            .filter(|text| text.is_valid())
+            .map(|text| text.sections.iter().map(|s| count_words(&s.content)).sum())
+    }
+
+    /// Minutes needed to read the extracted text at [`READING_WORDS_PER_MINUTE`], rounded up
+    pub fn estimated_reading_minutes(&self) -> Option<u32> {
+        self.full_text_word_count()
+            .map(|words| words.div_ceil(READING_WORDS_PER_MINUTE) as u32)
+    }
+
+    /// Detect the language of the abstract
+    ///
+    /// Returns an ISO 639-3 code (e.g. "eng", "fra"), or `None` when the
+    /// abstract is empty or too short to classify reliably.
+    pub fn detect_abstract_language(&self) -> Option<String> {
+        whatlang::detect(&self.abstract_text)
+            .filter(|info| info.is_reliable())
+            .map(|info| info.lang().code().to_string())
+    }
+
     /// Get PDF URL for extraction (prefers open_access, falls back to arXiv)
     pub fn pdf_url(&self) -> Option<String> {
         if let Some(ref url) = self.open_access_pdf_url
@@ -821,10 +1916,18 @@ impl AcademicPaper {
         {
             return Some(url.clone());
         }
-        if !self.arxiv_id.is_empty() {
-            return Some(format!("https://arxiv.org/pdf/{}", self.arxiv_id));
+        self.arxiv_pdf_url()
+    }
+
+    /// Get the arXiv PDF URL, pinned to `arxiv_version` when known
+    pub fn arxiv_pdf_url(&self) -> Option<String> {
+        if self.arxiv_id.is_empty() {
+            return None;
         }
-        None
+        Some(match self.arxiv_version {
+            Some(version) => format!("https://arxiv.org/pdf/{}v{}", self.arxiv_id, version),
+            None => format!("https://arxiv.org/pdf/{}", self.arxiv_id),
+        })
     }
 
     /// Set extracted text
@@ -840,6 +1943,16 @@ impl AcademicPaper {
     /// - With version: "1706.03762v7" -> "1706.03762"
     /// - Clean ID: "1706.03762" -> "1706.03762"
     fn extract_arxiv_id(raw_id: &str) -> String {
+        Self::parse_arxiv_id(raw_id).0
+    }
+
+    /// Split an arXiv ID into the clean ID and its version number
+    ///
+    /// Accepts the same forms as `extract_arxiv_id`:
+    /// - "http://arxiv.org/abs/1706.03762v7" -> ("1706.03762", Some(7))
+    /// - "cs.CL/0001001v1" -> ("cs.CL/0001001", Some(1))
+    /// - "1706.03762" -> ("1706.03762", None)
+    pub fn parse_arxiv_id(raw_id: &str) -> (String, Option<u32>) {
         let id = raw_id
             // Remove URL prefix
             .trim_start_matches("http://arxiv.org/abs/")
@@ -847,14 +1960,14 @@ impl AcademicPaper {
             // Remove trailing whitespace
             .trim();
 
-        // Remove version suffix (e.g., "v7")
+        // Split off version suffix (e.g., "v7")
         if let Some(pos) = id.rfind('v')
             && id[pos + 1..].chars().all(|c| c.is_ascii_digit())
         {
-            return id[..pos].to_string();
+            return (id[..pos].to_string(), id[pos + 1..].parse().ok());
         }
 
-        id.to_string()
+        (id.to_string(), None)
     }
 }
 
@@ -862,6 +1975,102 @@ impl AcademicPaper {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_placeholder() {
+        assert!(is_placeholder("記載なし"));
+        assert!(is_placeholder(" N/A "));
+        assert!(is_placeholder("Not mentioned."));
+        assert!(is_placeholder("アブストラクトに記載なし。"));
+        assert!(!is_placeholder(""));
+        assert!(!is_placeholder("Transformer architecture"));
+        assert!(!is_placeholder(
+            "The dataset size is not mentioned in the abstract, but the authors evaluate on WMT 2014 English-German"
+        ));
+    }
+
+    #[test]
+    fn test_validate_well_formed_paper() {
+        let mut paper = AcademicPaper::new();
+        paper.title = "Attention Is All You Need".to_string();
+        paper.arxiv_id = "1706.03762".to_string();
+        paper.published_date = datetime_from_str("2017-06-12");
+        assert!(paper.validate().is_empty());
+
+        // Old-style IDs and DOI-only papers are fine too
+        paper.arxiv_id = "hep-th/9901001".to_string();
+        assert!(paper.validate().is_empty());
+        paper.arxiv_id.clear();
+        paper.doi = "10.1145/3292500.3330701".to_string();
+        assert!(paper.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_degenerate_paper() {
+        let mut paper = AcademicPaper::new();
+        paper.published_date = datetime_from_str("");
+        assert_eq!(
+            paper.validate(),
+            vec![
+                ValidationIssue::EmptyTitle,
+                ValidationIssue::EpochPublishedDate,
+                ValidationIssue::NoIdentifiers,
+            ]
+        );
+
+        paper.title = "  ".to_string();
+        paper.arxiv_id = "arXiv:1706.03762".to_string();
+        let issues = paper.validate();
+        assert!(issues.contains(&ValidationIssue::EmptyTitle));
+        assert!(!issues.contains(&ValidationIssue::NoIdentifiers));
+        assert!(issues.contains(&ValidationIssue::MalformedArxivId(
+            "arXiv:1706.03762".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_known_year() {
+        let mut paper = AcademicPaper::new();
+        paper.published_date = datetime_from_str("2017-06-12");
+        assert_eq!(paper.known_year(), Some(2017));
+
+        paper.published_date = datetime_from_str("");
+        assert_eq!(paper.known_year(), None);
+    }
+
+    #[test]
+    fn test_publication_status() {
+        let mut arxiv_only = AcademicPaper::new();
+        arxiv_only.arxiv_id = "2106.09685".to_string();
+        arxiv_only.journal = "arXiv".to_string();
+        arxiv_only.doi = "10.48550/arXiv.2106.09685".to_string();
+        assert_eq!(arxiv_only.publication_status(), PublicationStatus::Preprint);
+
+        let mut published = AcademicPaper::new();
+        published.doi = "10.1145/3065386".to_string();
+        published.journal = "Communications of the ACM".to_string();
+        assert_eq!(published.publication_status(), PublicationStatus::Published);
+
+        // A preprint later published at a venue counts as published
+        published.arxiv_id = "1409.0575".to_string();
+        assert_eq!(published.publication_status(), PublicationStatus::Published);
+
+        let mut no_venue = AcademicPaper::new();
+        no_venue.arxiv_id = "2106.09685".to_string();
+        assert_eq!(no_venue.publication_status(), PublicationStatus::Preprint);
+
+        let mut venue_without_doi = AcademicPaper::new();
+        venue_without_doi.journal = "NeurIPS".to_string();
+        assert_eq!(
+            venue_without_doi.publication_status(),
+            PublicationStatus::Unknown
+        );
+
+        assert_eq!(
+            AcademicPaper::new().publication_status(),
+            PublicationStatus::Unknown
+        );
+    }
+
     #[test]
     fn test_paper_analysis_is_complete() {
         let mut analysis = PaperAnalysis::default();
@@ -874,6 +2083,52 @@ mod tests {
         assert!(analysis.is_complete());
     }
 
+    #[test]
+    fn test_paper_analysis_diff() {
+        let previous = PaperAnalysis {
+            summary: "A transformer model.".to_string(),
+            methodology: "Self-attention".to_string(),
+            results: "BLEU 28.4".to_string(),
+            key_contributions: vec![
+                "Introduces the Transformer".to_string(),
+                "Removes recurrence".to_string(),
+            ],
+            tasks: vec!["Machine Translation".to_string(), "Parsing".to_string()],
+            model: "gpt-4o-mini".to_string(),
+            ..Default::default()
+        };
+        let current = PaperAnalysis {
+            summary: "  A transformer model.\n".to_string(),
+            methodology: "Multi-head self-attention".to_string(),
+            results: "BLEU 28.4".to_string(),
+            key_contributions: vec![
+                "introduces the transformer".to_string(),
+                "Shows attention alone suffices".to_string(),
+            ],
+            tasks: vec![
+                "Machine Translation".to_string(),
+                "Constituency Parsing".to_string(),
+            ],
+            model: "gpt-5.2".to_string(),
+            ..Default::default()
+        };
+
+        let diff = previous.diff(&current);
+        assert_eq!(diff.previous_model, "gpt-4o-mini");
+        assert_eq!(diff.current_model, "gpt-5.2");
+        assert_eq!(diff.changed_sections, vec!["methodology"]);
+        assert_eq!(
+            diff.added_key_contributions,
+            vec!["Shows attention alone suffices"]
+        );
+        assert_eq!(diff.removed_key_contributions, vec!["Removes recurrence"]);
+        assert_eq!(diff.added_tasks, vec!["Constituency Parsing"]);
+        assert_eq!(diff.removed_tasks, vec!["Parsing"]);
+        assert!(!diff.is_empty());
+
+        assert!(current.diff(&current).is_empty());
+    }
+
     #[test]
     fn test_academic_paper_is_analyzed() {
         let mut paper = AcademicPaper::new();
@@ -904,6 +2159,108 @@ mod tests {
         assert!(citation.contains("Attention Is All You Need"));
     }
 
+    fn transformer_paper() -> AcademicPaper {
+        use chrono::TimeZone;
+        AcademicPaper {
+            title: "Attention Is All You Need".to_string(),
+            authors: [
+                "Ashish Vaswani",
+                "Noam Shazeer",
+                "Niki Parmar",
+                "Jakob Uszkoreit",
+                "Llion Jones",
+                "Aidan N. Gomez",
+                "Łukasz Kaiser",
+                "Illia Polosukhin",
+            ]
+            .into_iter()
+            .map(Author::from_arxiv_name)
+            .collect(),
+            journal: "Advances in Neural Information Processing Systems".to_string(),
+            arxiv_id: "1706.03762".to_string(),
+            published_date: Local.with_ymd_and_hms(2017, 6, 12, 0, 0, 0).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    fn lecun_bengio_paper() -> AcademicPaper {
+        use chrono::TimeZone;
+        AcademicPaper {
+            title: "Convolutional networks for images, speech, and time series".to_string(),
+            authors: vec![
+                Author::from_arxiv_name("LeCun, Yann"),
+                Author::from_arxiv_name("Yoshua Bengio"),
+            ],
+            journal: "The Handbook of Brain Theory and Neural Networks".to_string(),
+            doi: "https://doi.org/10.5555/303568.303704".to_string(),
+            published_date: Local.with_ymd_and_hms(1995, 1, 1, 0, 0, 0).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_to_apa() {
+        assert_eq!(
+            transformer_paper().to_apa(),
+            "Vaswani, A., Shazeer, N., Parmar, N., Uszkoreit, J., Jones, L., Gomez, A. N., \
+             Kaiser, Ł., & Polosukhin, I. (2017). Attention Is All You Need. Advances in Neural \
+             Information Processing Systems. https://arxiv.org/abs/1706.03762"
+        );
+        assert_eq!(
+            lecun_bengio_paper().to_apa(),
+            "LeCun, Y., & Bengio, Y. (1995). Convolutional networks for images, speech, and \
+             time series. The Handbook of Brain Theory and Neural Networks. \
+             https://doi.org/10.5555/303568.303704"
+        );
+    }
+
+    #[test]
+    fn test_to_apa_many_authors_and_unknown_date() {
+        use chrono::TimeZone;
+        let paper = AcademicPaper {
+            title: "A Large Collaboration?".to_string(),
+            authors: (1..=21)
+                .map(|i| Author::from_arxiv_name(&format!("Jean-Paul Author{}", i)))
+                .collect(),
+            published_date: Local.timestamp_opt(0, 0).unwrap(),
+            ..Default::default()
+        };
+
+        let citation = paper.to_apa();
+        assert!(citation.starts_with("Author1, J.-P., Author2, J.-P., "));
+        assert!(
+            citation
+                .ends_with("Author19, J.-P., . . . Author21, J.-P. (n.d.). A Large Collaboration?")
+        );
+        assert!(!citation.contains("Author20"));
+    }
+
+    #[test]
+    fn test_to_mla() {
+        assert_eq!(
+            transformer_paper().to_mla(),
+            "Vaswani, Ashish, et al. \"Attention Is All You Need.\" Advances in Neural \
+             Information Processing Systems, 2017, https://arxiv.org/abs/1706.03762."
+        );
+        assert_eq!(
+            lecun_bengio_paper().to_mla(),
+            "LeCun, Yann, and Yoshua Bengio. \"Convolutional networks for images, speech, and \
+             time series.\" The Handbook of Brain Theory and Neural Networks, 1995, \
+             https://doi.org/10.5555/303568.303704."
+        );
+
+        let single = AcademicPaper {
+            title: "Untitled Notes".to_string(),
+            authors: vec![Author::from_arxiv_name("Jean-Paul Sartre")],
+            ..Default::default()
+        };
+        assert!(
+            single
+                .to_mla()
+                .starts_with("Sartre, Jean-Paul. \"Untitled Notes.\"")
+        );
+    }
+
     #[test]
     fn test_extract_arxiv_id() {
         // Full URL with version
@@ -934,6 +2291,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_abstract_language() {
+        let mut paper = AcademicPaper::new();
+        assert_eq!(paper.detect_abstract_language(), None);
+
+        paper.abstract_text =
+            "Les modèles dominants de transduction de séquences reposent sur des \
+            réseaux de neurones récurrents ou convolutifs complexes. Nous proposons une nouvelle \
+            architecture de réseau simple, le Transformer, fondée uniquement sur des mécanismes \
+            d'attention."
+                .to_string();
+        let language = paper.detect_abstract_language();
+        assert_eq!(language.as_deref(), Some("fra"));
+        assert_ne!(language.as_deref(), Some(ENGLISH_LANGUAGE_CODE));
+
+        paper.abstract_text = "The dominant sequence transduction models are based on complex \
+            recurrent or convolutional neural networks. We propose a new simple network \
+            architecture, the Transformer, based solely on attention mechanisms."
+            .to_string();
+        assert_eq!(
+            paper.detect_abstract_language().as_deref(),
+            Some(ENGLISH_LANGUAGE_CODE)
+        );
+    }
+
+    #[test]
+    fn test_parse_arxiv_id_version() {
+        assert_eq!(
+            AcademicPaper::parse_arxiv_id("http://arxiv.org/abs/1706.03762v7"),
+            ("1706.03762".to_string(), Some(7))
+        );
+        assert_eq!(
+            AcademicPaper::parse_arxiv_id("2301.00001v12"),
+            ("2301.00001".to_string(), Some(12))
+        );
+        assert_eq!(
+            AcademicPaper::parse_arxiv_id("cs.CL/0001001v1"),
+            ("cs.CL/0001001".to_string(), Some(1))
+        );
+        assert_eq!(
+            AcademicPaper::parse_arxiv_id("https://arxiv.org/abs/1706.03762"),
+            ("1706.03762".to_string(), None)
+        );
+        assert_eq!(
+            AcademicPaper::parse_arxiv_id("hep-th/9901001"),
+            ("hep-th/9901001".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_pdf_url_includes_arxiv_version() {
+        let mut paper = AcademicPaper::new();
+        paper.arxiv_id = "1706.03762".to_string();
+        assert_eq!(
+            paper.pdf_url().as_deref(),
+            Some("https://arxiv.org/pdf/1706.03762")
+        );
+
+        paper.arxiv_version = Some(5);
+        assert_eq!(
+            paper.pdf_url().as_deref(),
+            Some("https://arxiv.org/pdf/1706.03762v5")
+        );
+    }
+
     #[test]
     fn test_dataset_info_serialization() {
         let dataset = DatasetInfo {
@@ -1150,6 +2572,29 @@ mod tests {
         assert_eq!(paper.arxiv_id, "2301.00001");
         assert_eq!(paper.doi, "10.1234/test.2023");
         assert_eq!(paper.ss_id, "abc123");
+        assert_eq!(paper.url, "https://arxiv.org/abs/2301.00001");
+    }
+
+    #[test]
+    fn test_from_semantic_scholar_keeps_ss_url() {
+        use ss_tools::structs::ExternalIds;
+
+        let ss_paper = SsPaper {
+            paper_id: Some("abc123".to_string()),
+            url: Some("https://www.semanticscholar.org/paper/abc123".to_string()),
+            external_ids: Some(ExternalIds {
+                arxiv: Some("2301.00001".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let paper = AcademicPaper::from_semantic_scholar(ss_paper);
+        assert_eq!(paper.url, "https://www.semanticscholar.org/paper/abc123");
+        assert_eq!(
+            paper.pdf_url().as_deref(),
+            Some("https://arxiv.org/pdf/2301.00001")
+        );
     }
 
     #[test]
@@ -1223,6 +2668,329 @@ mod tests {
         assert_eq!(pdf_url.unwrap(), "https://arxiv.org/pdf/2301.00001");
     }
 
+    #[test]
+    fn test_select_sections_within_budget_prioritizes_importance() {
+        let text = PaperText {
+            sections: vec![
+                PaperSection::for_test(0, "Abstract", "x".repeat(100)),
+                PaperSection::for_test(1, "Introduction", "x".repeat(300)),
+                PaperSection::for_test(2, "Method", "x".repeat(400)),
+                PaperSection::for_test(3, "Related Work", "x".repeat(200)),
+                PaperSection::for_test(4, "References", "x".repeat(150)),
+            ],
+            ..Default::default()
+        };
+
+        let titles: Vec<&str> = text
+            .select_sections_within_budget(600)
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Abstract", "Method"]);
+
+        // A generous budget keeps everything in document order
+        let titles: Vec<&str> = text
+            .select_sections_within_budget(10_000)
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Abstract",
+                "Introduction",
+                "Method",
+                "Related Work",
+                "References"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_select_sections_within_budget_drops_references_first() {
+        let text = PaperText {
+            sections: vec![
+                PaperSection::for_test(0, "Abstract", "x".repeat(100)),
+                PaperSection::for_test(1, "Method", "x".repeat(200)),
+                PaperSection::for_test(2, "Conclusion", "x".repeat(100)),
+                PaperSection::for_test(3, "References", "x".repeat(100)),
+            ],
+            ..Default::default()
+        };
+
+        let titles: Vec<&str> = text
+            .select_sections_within_budget(400)
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Abstract", "Method", "Conclusion"]);
+    }
+
+    #[test]
+    fn test_into_chunks_short_section_is_single_chunk() {
+        let text = PaperText {
+            sections: vec![
+                PaperSection::for_test(0, "Abstract", "x".repeat(80)),
+                PaperSection::for_test(1, "Empty", ""),
+            ],
+            ..Default::default()
+        };
+
+        let chunks = text.into_chunks(100, 20);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].section_title, "Abstract");
+        assert_eq!(chunks[0].section_index, 0);
+        assert_eq!((chunks[0].char_start, chunks[0].char_end), (0, 80));
+        assert_eq!(chunks[0].content.len(), 80);
+    }
+
+    #[test]
+    fn test_into_chunks_long_section_overlaps() {
+        let content: String = ('a'..='z').cycle().take(250).collect();
+        let text = PaperText {
+            sections: vec![
+                PaperSection {
+                    index: 2,
+                    title: "Method".to_string(),
+                    content: content.clone(),
+                    ..Default::default()
+                },
+                PaperSection::for_test(3, "Results", "x".repeat(50)),
+            ],
+            ..Default::default()
+        };
+
+        let chunks = text.into_chunks(100, 20);
+        let spans: Vec<(usize, usize)> =
+            chunks.iter().map(|c| (c.char_start, c.char_end)).collect();
+        assert_eq!(spans, vec![(0, 100), (80, 180), (160, 250), (0, 50)]);
+
+        // Consecutive chunks of a section share `overlap` characters
+        assert_eq!(chunks[0].content[80..], chunks[1].content[..20]);
+        assert_eq!(chunks[1].content, content[80..180]);
+        assert!(chunks[..3].iter().all(|c| c.section_title == "Method"));
+        assert_eq!(chunks[3].section_title, "Results");
+    }
+
+    #[test]
+    fn test_search_across_sections_with_overlapping_hits() {
+        let text = PaperText {
+            sections: vec![
+                PaperSection {
+                    index: 0,
+                    title: "Method".to_string(),
+                    content: "We run an Ablation.\nThe ablation removes attention.".to_string(),
+                    ..Default::default()
+                },
+                PaperSection {
+                    index: 1,
+                    title: "Results".to_string(),
+                    content: "aaa".to_string(),
+                    ..Default::default()
+                },
+                PaperSection {
+                    index: 2,
+                    title: "Appendix".to_string(),
+                    content: "Full ablation tables.".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let hits = text.search("ablation", true);
+        let found: Vec<(&str, usize)> = hits
+            .iter()
+            .map(|m| (m.section_title.as_str(), m.offset))
+            .collect();
+        assert_eq!(found, vec![("Method", 10), ("Method", 24), ("Appendix", 5)]);
+        assert_eq!(
+            hits[0].snippet,
+            "We run an Ablation. The ablation removes attention."
+        );
+
+        assert_eq!(text.search("ablation", false).len(), 2);
+
+        // Overlapping occurrences are all reported
+        let hits = text.search("aa", false);
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[0].offset, hits[1].offset), (0, 1));
+        assert!(hits.iter().all(|m| m.section_index == 1));
+
+        assert!(text.search("", true).is_empty());
+    }
+
+    #[test]
+    fn test_search_regex_context_window() {
+        let long_prefix = "x".repeat(200);
+        let text = PaperText {
+            sections: vec![PaperSection {
+                index: 4,
+                title: "Experiments".to_string(),
+                content: format!("{} table 3 and table 12 ", long_prefix),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let hits = text.search_regex(&Regex::new(r"table \d+").unwrap());
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].offset, 201);
+        // Context is capped at SEARCH_CONTEXT_CHARS on each side
+        assert!(
+            hits[0]
+                .snippet
+                .starts_with(&"x".repeat(SEARCH_CONTEXT_CHARS - 1))
+        );
+        assert!(hits[0].snippet.ends_with("table 3 and table 12"));
+    }
+
+    #[test]
+    fn test_search_regex_reports_non_overlapping_matches() {
+        let text = PaperText {
+            sections: vec![PaperSection {
+                title: "Introduction".to_string(),
+                content: "Published in 2023.".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let hits = text.search_regex(&Regex::new(r"\d+").unwrap());
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].offset, 13);
+
+        // Empty matches still terminate
+        let hits = text.search_regex(&Regex::new(r"x*").unwrap());
+        assert_eq!(hits.len(), "Published in 2023.".chars().count() + 1);
+    }
+
+    #[test]
+    fn test_highlight_respects_word_boundaries() {
+        let text = PaperText {
+            plain_text: "x".to_string(),
+            sections: vec![
+                PaperSection {
+                    index: 0,
+                    title: "Introduction".to_string(),
+                    content: "Attention networks use attention. The network is a Net.".to_string(),
+                    ..Default::default()
+                },
+                PaperSection {
+                    index: 1,
+                    title: "Method".to_string(),
+                    content: "Self-attention in C++ and c++ code; multi-head attention."
+                        .to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let terms = vec![
+            "attention".to_string(),
+            "net".to_string(),
+            "C++".to_string(),
+            "multi-head attention".to_string(),
+            "Attention".to_string(),
+            " ".to_string(),
+        ];
+
+        let (markdown, counts) = text.highlight_with_counts(&terms);
+
+        assert_eq!(
+            markdown,
+            "## Introduction\n\n**Attention** networks use **attention**. The network is a **Net**.\n\n\
+             ## Method\n\nSelf-**attention** in **C++** and **c++** code; **multi-head attention**."
+        );
+        assert_eq!(
+            counts,
+            vec![
+                ("attention".to_string(), 3),
+                ("net".to_string(), 1),
+                ("C++".to_string(), 2),
+                ("multi-head attention".to_string(), 1),
+            ]
+        );
+        assert_eq!(text.highlight(&terms), markdown);
+
+        // No terms: sections are rendered unchanged
+        assert!(text.highlight(&[]).contains("The network is a Net."));
+    }
+
+    #[test]
+    fn test_abstract_word_count() {
+        let mut paper = AcademicPaper::new();
+        assert_eq!(paper.abstract_word_count(), 0);
+
+        paper.abstract_text =
+            "The dominant sequence transduction models\nare based on\t complex networks."
+                .to_string();
+        assert_eq!(paper.abstract_word_count(), 10);
+
+        // Full-width (U+3000) and no-break spaces separate words too
+        paper.abstract_text = "Transformer\u{3000}モデル\u{00A0}attention".to_string();
+        assert_eq!(paper.abstract_word_count(), 3);
+        assert_eq!(paper.full_text_word_count(), None);
+        assert_eq!(paper.estimated_reading_minutes(), None);
+    }
+
+    #[test]
+    fn test_full_text_word_count_and_reading_time() {
+        let mut paper = AcademicPaper::new();
+        paper.extracted_text = Some(PaperText {
+            plain_text: "only section content is counted".to_string(),
+            sections: vec![
+                PaperSection {
+                    title: "Introduction".to_string(),
+                    content: "word ".repeat(300),
+                    ..Default::default()
+                },
+                PaperSection {
+                    title: "Method".to_string(),
+                    content: "word ".repeat(141),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        });
+        assert_eq!(paper.full_text_word_count(), Some(441));
+        // 441 words at 220 wpm rounds up to 3 minutes
+        assert_eq!(paper.estimated_reading_minutes(), Some(3));
+
+        paper.extracted_text.as_mut().unwrap().sections[1].content = "word ".repeat(140);
+        assert_eq!(paper.estimated_reading_minutes(), Some(2));
+    }
+
+    #[test]
+    fn test_source_provenance_and_merge() {
+        let arxiv = AcademicPaper::from_arxiv(make_arxiv_paper(
+            "1706.03762",
+            "Attention Is All You Need",
+            "abstract",
+            "2017-06-12T00:00:00Z",
+        ));
+        assert_eq!(arxiv.source, Some(PaperSource::ArXiv));
+
+        let ss = AcademicPaper::from_semantic_scholar(SsPaper {
+            paper_id: Some("ss789".to_string()),
+            title: Some("Attention Is All You Need".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(ss.source, Some(PaperSource::SemanticScholar));
+
+        let mut merged = arxiv.clone();
+        merged.merge_with(ss);
+        assert_eq!(merged.source, Some(PaperSource::Both));
+
+        // Same source, or an untagged record, keeps the original provenance
+        let mut same = arxiv.clone();
+        same.merge_with(arxiv.clone());
+        assert_eq!(same.source, Some(PaperSource::ArXiv));
+        let mut untagged = AcademicPaper::new();
+        untagged.merge_with(arxiv);
+        assert_eq!(untagged.source, Some(PaperSource::ArXiv));
+    }
+
     /// Helper to create a test ArxivPaper
     fn make_arxiv_paper(id: &str, title: &str, abstract_text: &str, published: &str) -> ArxivPaper {
         ArxivPaper {
@@ -1389,4 +3157,116 @@ mod tests {
         assert_eq!(base.references_count, 40);
         assert_eq!(base.ss_id, "ss456");
     }
+
+    #[test]
+    fn test_merge_with_combines_arxiv_and_ss_records() {
+        // SS record as the base, arXiv record merged in
+        let ss_paper = SsPaper {
+            paper_id: Some("ss789".to_string()),
+            title: Some("Attention Is All You Need".to_string()),
+            abstract_text: Some("SS abstract".to_string()),
+            citation_count: Some(50_000),
+            ..Default::default()
+        };
+        let mut base = AcademicPaper::from_semantic_scholar(ss_paper);
+        base.bibtex = "@article{vaswani2017}".to_string();
+
+        let arxiv_paper = make_arxiv_paper(
+            "1706.03762",
+            "Attention Is All You Need",
+            "arXiv abstract",
+            "2017-06-12T00:00:00Z",
+        );
+        let other = AcademicPaper::from_arxiv(arxiv_paper);
+
+        base.merge_with(other);
+
+        // Both identifiers are populated
+        assert_eq!(base.ss_id, "ss789");
+        assert_eq!(base.arxiv_id, "1706.03762");
+
+        // Empty fields are filled from the arXiv record
+        assert_eq!(base.categories, vec!["cs.CL", "cs.AI"]);
+        assert_eq!(base.primary_category, "cs.CL");
+
+        // Non-empty fields are kept
+        assert_eq!(base.citations_count, 50_000);
+        assert_eq!(base.bibtex, "@article{vaswani2017}");
+
+        // arXiv remains authoritative for the abstract
+        assert_eq!(base.abstract_text, "arXiv abstract");
+    }
+
+    #[test]
+    fn test_merge_with_takes_known_metrics_over_unknown() {
+        let arxiv_paper = make_arxiv_paper(
+            "1706.03762",
+            "Attention Is All You Need",
+            "arXiv abstract",
+            "2017-06-12T00:00:00Z",
+        );
+        let mut base = AcademicPaper::from_arxiv(arxiv_paper);
+        assert!(!base.metrics_known);
+
+        let mut other = AcademicPaper::new();
+        other.metrics_known = true;
+        other.citations_count = 0;
+        other.references_count = 40;
+
+        base.merge_with(other);
+
+        assert!(base.metrics_known);
+        assert_eq!(base.citations_count, 0);
+        assert_eq!(base.references_count, 40);
+    }
+
+    #[test]
+    fn test_merge_with_keeps_known_metrics_over_unknown() {
+        let mut base = AcademicPaper::new();
+        base.metrics_known = true;
+        base.citations_count = 0;
+
+        let mut other = AcademicPaper::new();
+        other.citations_count = 7;
+
+        base.merge_with(other);
+
+        assert!(base.metrics_known);
+        assert_eq!(base.citations_count, 0);
+    }
+
+    #[test]
+    fn test_metrics_known_defaults_to_true_when_missing() {
+        let mut value = serde_json::to_value(AcademicPaper::new()).unwrap();
+        value.as_object_mut().unwrap().remove("metrics_known");
+        let paper: AcademicPaper = serde_json::from_value(value).unwrap();
+        assert!(paper.metrics_known);
+    }
+
+    #[test]
+    fn test_merge_with_fills_without_source_payloads() {
+        let mut base = AcademicPaper::new();
+        base.title = "Paper".to_string();
+        base.doi = "10.1234/base".to_string();
+
+        let mut other = AcademicPaper::new();
+        other.title = "Other".to_string();
+        other.doi = "10.1234/other".to_string();
+        other.ss_id = "ss1".to_string();
+        other.citations_count = 12;
+        other.is_open_access = true;
+        other.open_access_pdf_url = Some("https://example.com/paper.pdf".to_string());
+
+        base.merge_with(other);
+
+        assert_eq!(base.title, "Paper");
+        assert_eq!(base.doi, "10.1234/base");
+        assert_eq!(base.ss_id, "ss1");
+        assert_eq!(base.citations_count, 12);
+        assert!(base.is_open_access);
+        assert_eq!(
+            base.open_access_pdf_url.as_deref(),
+            Some("https://example.com/paper.pdf")
+        );
+    }
 }
@@ -6,10 +6,84 @@ use crate::models::{
 use crate::shared::errors::{AppError, AppResult};
 use chrono::Local;
 use futures::FutureExt;
+use regex::Regex;
 use rsrpp::config::ParserConfig;
 use rsrpp::models::{Reference, Section};
 use rsrpp::parser::{pages2paper_output, pages2sections, parse};
+use std::collections::HashMap;
 use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Default number of download retries after the first attempt
+const DEFAULT_DOWNLOAD_RETRY_COUNT: u32 = 3;
+
+/// Default wait between download attempts
+const DEFAULT_DOWNLOAD_RETRY_WAIT: Duration = Duration::from_secs(2);
+
+/// Default markdown heading level for top-level sections (`##`)
+const DEFAULT_MARKDOWN_BASE_LEVEL: u8 = 2;
+
+/// Default minimum number of alphanumeric characters expected from a text PDF
+///
+/// A typical paper yields tens of thousands; scanned PDFs without a text layer
+/// yield almost none.
+pub const DEFAULT_MIN_TEXT_CHARS: usize = 500;
+
+/// Sequence number keeping concurrent temp file names unique within a process
+static TEMP_PDF_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Section numbering such as "3", "3.1", "A.2" at the start of a title
+static SECTION_NUMBER_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(?:\d+|[A-Z])((?:\.\d+)*)\.?\s").expect("valid section number regex")
+});
+
+/// Math spans marked up by rsrpp
+static MATH_TAG_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<math>(.*?)</math>").expect("valid math tag regex"));
+
+/// Inclusive, 1-based range of PDF pages to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRange {
+    /// First page to keep (1-based)
+    pub start: usize,
+    /// Last page to keep (inclusive); `None` keeps everything from `start`
+    pub end: Option<usize>,
+}
+
+impl PageRange {
+    /// Check whether a (1-based) page falls within the range
+    pub fn contains(&self, page: usize) -> bool {
+        page >= self.start && self.end.is_none_or(|end| page <= end)
+    }
+}
+
+impl FromStr for PageRange {
+    type Err = AppError;
+
+    /// Parse "1-20", "5-" (to the end) or "3" (a single page)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AppError::ConfigError(format!("Invalid page range: {}", s));
+        let parse_page = |p: &str| p.trim().parse::<usize>().map_err(|_| invalid());
+
+        let (start, end) = match s.split_once('-') {
+            Some((start, "")) => (parse_page(start)?, None),
+            Some((start, end)) => (parse_page(start)?, Some(parse_page(end)?)),
+            None => {
+                let page = parse_page(s)?;
+                (page, Some(page))
+            }
+        };
+
+        if start == 0 || end.is_some_and(|end| end < start) {
+            return Err(invalid());
+        }
+        Ok(Self { start, end })
+    }
+}
 
 /// Configuration for PDF extraction
 #[derive(Debug, Clone)]
@@ -22,6 +96,25 @@ pub struct ExtractionConfig {
     pub include_math: bool,
     /// Extract bibliographic references from PDF (requires OPENAI_API_KEY)
     pub extract_references: bool,
+    /// Only keep sections starting within this page range
+    pub page_range: Option<PageRange>,
+    /// Retries after a failed PDF download (network error, 429 or 5xx)
+    pub retry_count: u32,
+    /// Wait between PDF download attempts
+    pub retry_wait: Duration,
+    /// Markdown heading level of top-level sections (1 = `#`)
+    pub markdown_base_level: u8,
+    /// Minimum alphanumeric characters across all sections before the PDF is
+    /// treated as scanned/image-only (0 disables the check)
+    pub min_text_chars: usize,
+    /// Section importance by lowercased title, checked before the default
+    /// title heuristic
+    pub importance_overrides: HashMap<String, SectionImportance>,
+    /// OCR the rendered pages when the text layer is too thin
+    ///
+    /// Needs the `ocr` feature; without it, a thin text layer fails with an
+    /// error saying so.
+    pub ocr: bool,
 }
 
 impl Default for ExtractionConfig {
@@ -31,6 +124,13 @@ impl Default for ExtractionConfig {
             cleanup: true,
             include_math: true,
             extract_references: true,
+            page_range: None,
+            retry_count: DEFAULT_DOWNLOAD_RETRY_COUNT,
+            retry_wait: DEFAULT_DOWNLOAD_RETRY_WAIT,
+            markdown_base_level: DEFAULT_MARKDOWN_BASE_LEVEL,
+            min_text_chars: DEFAULT_MIN_TEXT_CHARS,
+            importance_overrides: HashMap::new(),
+            ocr: false,
         }
     }
 }
@@ -64,29 +164,245 @@ impl ExtractionConfig {
         self.extract_references = extract_references;
         self
     }
+
+    /// Only keep sections from pages `start..=end` (1-based; `None` = to the end)
+    ///
+    /// rsrpp does not expose page spans per section, so each section's start
+    /// page is estimated from its character offset within the document. Sections
+    /// near a range boundary may therefore be included or dropped by one page.
+    pub fn with_page_range(mut self, start: usize, end: Option<usize>) -> Self {
+        self.page_range = Some(PageRange { start, end });
+        self
+    }
+
+    /// Set how many times a failed PDF download is retried
+    pub fn with_retry_count(mut self, retry_count: u32) -> Self {
+        self.retry_count = retry_count;
+        self
+    }
+
+    /// Set the wait between PDF download attempts
+    pub fn with_retry_wait(mut self, retry_wait: Duration) -> Self {
+        self.retry_wait = retry_wait;
+        self
+    }
+
+    /// Set the markdown heading level of top-level sections (clamped to 1..=6)
+    ///
+    /// Numbered subsections ("3.1", "3.1.2") are placed one level deeper per
+    /// number component.
+    pub fn with_markdown_base_level(mut self, level: u8) -> Self {
+        self.markdown_base_level = level.clamp(1, 6);
+        self
+    }
+
+    /// Set the minimum extracted text size below which extraction fails as a likely scanned PDF
+    ///
+    /// Counts alphanumeric characters across all sections, so whitespace and
+    /// symbol noise from image-only pages does not pass; 0 disables the check.
+    pub fn with_min_text_chars(mut self, min_chars: usize) -> Self {
+        self.min_text_chars = min_chars;
+        self
+    }
+
+    /// Override the importance of sections by title
+    ///
+    /// Titles are matched case-insensitively and without their section
+    /// number, so an override for "Related Work" also applies to the
+    /// extracted heading "2 Related Work". Sections without an override keep
+    /// the [`SectionImportance::from_title`] heuristic.
+    pub fn with_importance_overrides(
+        mut self,
+        overrides: HashMap<String, SectionImportance>,
+    ) -> Self {
+        self.importance_overrides = overrides
+            .into_iter()
+            .map(|(title, importance)| (Self::override_key(&title), importance))
+            .collect();
+        self
+    }
+
+    /// Importance of a section titled `title`, honoring the overrides
+    pub fn section_importance(&self, title: &str) -> SectionImportance {
+        self.importance_overrides
+            .get(&Self::override_key(title))
+            .copied()
+            .unwrap_or_else(|| SectionImportance::from_title(title))
+    }
+
+    /// Lowercased section title without its leading number ("3.1 ", "A. ")
+    fn override_key(title: &str) -> String {
+        SECTION_NUMBER_PATTERN
+            .replace(title, "")
+            .trim()
+            .to_lowercase()
+    }
+
+    /// Fall back to OCR when the PDF has too little text (see `min_text_chars`)
+    ///
+    /// Requires the external `pdftoppm` (poppler-utils) and `tesseract`
+    /// programs on `PATH`; extraction fails with a `PdfExtractionError` naming
+    /// the missing tool otherwise. OCR output has no section structure, so
+    /// each page becomes one section titled "Page N". Builds without the `ocr`
+    /// feature report that the feature is missing instead.
+    pub fn with_ocr(mut self, ocr: bool) -> Self {
+        self.ocr = ocr;
+        self
+    }
 }
 
 /// PDF text extractor using rsrpp
 pub struct PdfExtractor {
     config: ExtractionConfig,
+    http_client: reqwest::Client,
 }
 
 impl PdfExtractor {
     /// Create a new extractor with default configuration
     pub fn new() -> Self {
-        Self {
-            config: ExtractionConfig::default(),
-        }
+        Self::with_config(ExtractionConfig::default())
     }
 
     /// Create a new extractor with custom configuration
     pub fn with_config(config: ExtractionConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Use a preconfigured HTTP client for PDF downloads
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
     }
 
     /// Extract text from a PDF URL
+    ///
+    /// Remote PDFs are downloaded to a temporary file first, retrying transient
+    /// failures as configured by `retry_count`/`retry_wait`; anything that is
+    /// not an `http(s)` URL is treated as a local path.
     pub async fn extract_from_url(&self, url: &str) -> AppResult<PaperText> {
-        tracing::info!("Extracting text from PDF: {}", url);
+        self.extract_from_url_with_callback(url, |_| {}).await
+    }
+
+    /// Extract text from a PDF URL, calling `on_section` for each section
+    ///
+    /// The callback fires once per kept section, in document order, as soon as
+    /// the section is built, so callers can stream sections to disk or report
+    /// progress. Sections dropped by `page_range` are not reported.
+    pub async fn extract_from_url_with_callback(
+        &self,
+        url: &str,
+        mut on_section: impl FnMut(&PaperSection),
+    ) -> AppResult<PaperText> {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return self.parse_pdf(url, url, &mut on_section).await;
+        }
+
+        let bytes = self.download_pdf(url).await?;
+        let temp_path = Self::temp_pdf_path();
+        tokio::fs::write(&temp_path, &bytes).await.map_err(|e| {
+            AppError::PdfExtractionError(format!(
+                "Failed to write temporary PDF {}: {}",
+                temp_path.display(),
+                e
+            ))
+        })?;
+
+        let result = self
+            .parse_pdf(&temp_path.to_string_lossy(), url, &mut on_section)
+            .await;
+        if let Err(e) = tokio::fs::remove_file(&temp_path).await {
+            tracing::warn!(
+                "Failed to remove temporary PDF {}: {}",
+                temp_path.display(),
+                e
+            );
+        }
+        result
+    }
+
+    /// Extract text from a local PDF file
+    pub async fn extract_from_path(&self, path: impl AsRef<Path>) -> AppResult<PaperText> {
+        self.extract_from_path_with_callback(path, |_| {}).await
+    }
+
+    /// Extract text from a local PDF file, calling `on_section` for each section
+    ///
+    /// See [`extract_from_url_with_callback`](Self::extract_from_url_with_callback)
+    /// for when the callback fires.
+    pub async fn extract_from_path_with_callback(
+        &self,
+        path: impl AsRef<Path>,
+        mut on_section: impl FnMut(&PaperSection),
+    ) -> AppResult<PaperText> {
+        let path = path.as_ref().to_string_lossy();
+        self.parse_pdf(&path, &path, &mut on_section).await
+    }
+
+    /// Download a PDF, retrying network errors, 429s and 5xx responses
+    async fn download_pdf(&self, url: &str) -> AppResult<Vec<u8>> {
+        let attempts = self.config.retry_count + 1;
+        let mut last_error = String::new();
+
+        for attempt in 1..=attempts {
+            match self.http_client.get(url).send().await {
+                Ok(response) if response.status().is_success() => match response.bytes().await {
+                    Ok(bytes) => return Ok(bytes.to_vec()),
+                    Err(e) => last_error = format!("failed to read body: {}", e),
+                },
+                Ok(response) => {
+                    let status = response.status();
+                    if !(status.is_server_error()
+                        || status == reqwest::StatusCode::TOO_MANY_REQUESTS)
+                    {
+                        return Err(AppError::PdfExtractionError(format!(
+                            "PDF download failed ({}): HTTP {}",
+                            url, status
+                        )));
+                    }
+                    last_error = format!("HTTP {}", status);
+                }
+                Err(e) => last_error = e.to_string(),
+            }
+
+            if attempt < attempts {
+                tracing::warn!(
+                    "PDF download attempt {}/{} failed for {}: {}; retrying",
+                    attempt,
+                    attempts,
+                    url,
+                    last_error
+                );
+                tokio::time::sleep(self.config.retry_wait).await;
+            }
+        }
+
+        Err(AppError::PdfExtractionError(format!(
+            "PDF download failed ({}) after {} attempts: {}",
+            url, attempts, last_error
+        )))
+    }
+
+    /// Unique path for a downloaded PDF in the system temp directory
+    fn temp_pdf_path() -> PathBuf {
+        let sequence = TEMP_PDF_COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!(
+            "academic-paper-interpreter-{}-{}.pdf",
+            std::process::id(),
+            sequence
+        ))
+    }
+
+    /// Parse a local PDF with rsrpp; `source_url` is recorded in the result
+    async fn parse_pdf(
+        &self,
+        path: &str,
+        source_url: &str,
+        on_section: &mut dyn FnMut(&PaperSection),
+    ) -> AppResult<PaperText> {
+        tracing::info!("Extracting text from PDF: {}", source_url);
 
         // Bridge OPENAI_MODEL → OPENAI_API_MODEL so rsrpp uses the same env var
         let model = std::env::var("OPENAI_MODEL").unwrap_or_else(|_| "gpt-5-mini".to_string());
@@ -101,7 +417,7 @@ impl PdfExtractor {
         }
 
         // Wrap parse call in catch_unwind to handle panics from rsrpp gracefully
-        let parse_result = AssertUnwindSafe(parse(url, &mut parser_config, self.config.verbose))
+        let parse_result = AssertUnwindSafe(parse(path, &mut parser_config, self.config.verbose))
             .catch_unwind()
             .await;
 
@@ -151,12 +467,33 @@ impl PdfExtractor {
         };
 
         // Build PaperText from sections
-        let paper_text = self.build_paper_text(&sections, url, references);
+        let paper_text =
+            self.build_paper_text(&sections, pages.len(), source_url, references, on_section);
 
         // Cleanup temp files
         if self.config.cleanup && parser_config.clean_files().is_err() {
             tracing::warn!("Failed to cleanup temp files");
         }
+        // Scanned PDFs have no text layer worth keeping; OCR the pages instead
+        #[cfg(feature = "ocr")]
+        let paper_text = match paper_text {
+            Err(AppError::PdfExtractionError(reason)) if self.config.ocr => {
+                tracing::info!("{}; falling back to OCR", reason);
+                self.ocr_paper_text(path, source_url, on_section).await
+            }
+            result => result,
+        };
+        #[cfg(not(feature = "ocr"))]
+        let paper_text = match paper_text {
+            Err(AppError::PdfExtractionError(reason)) if self.config.ocr => {
+                Err(AppError::PdfExtractionError(format!(
+                    "{}; OCR was requested but this build lacks the `ocr` feature",
+                    reason
+                )))
+            }
+            result => result,
+        };
+        let paper_text = paper_text?;
 
         tracing::info!(
             "Extracted {} sections, {} chars total{}",
@@ -188,8 +525,8 @@ impl PdfExtractor {
         }
 
         // Fall back to arXiv PDF if arxiv_id is available
-        if !paper.arxiv_id.is_empty() {
-            return Ok(format!("https://arxiv.org/pdf/{}", paper.arxiv_id));
+        if let Some(url) = paper.arxiv_pdf_url() {
+            return Ok(url);
         }
 
         Err(AppError::PdfExtractionError(
@@ -201,35 +538,146 @@ impl PdfExtractor {
     fn build_paper_text(
         &self,
         sections: &[Section],
+        page_count: usize,
         source_url: &str,
         references: Option<Vec<ExtractedReference>>,
-    ) -> PaperText {
-        let paper_sections: Vec<PaperSection> = sections
-            .iter()
-            .map(|s| self.build_paper_section(s))
-            .collect();
+        on_section: &mut dyn FnMut(&PaperSection),
+    ) -> AppResult<PaperText> {
+        let texts = sections.iter().map(|s| s.get_text()).collect();
+        let paper_sections = self.select_sections(
+            texts,
+            page_count,
+            |i, text| self.build_paper_section(&sections[i], text),
+            on_section,
+        )?;
 
         let plain_text = self.build_plain_text(&paper_sections);
         let markdown = self.build_markdown(&paper_sections);
 
-        PaperText {
+        Ok(PaperText {
             plain_text,
             sections: paper_sections,
             markdown,
             extracted_at: Local::now(),
             source_url: source_url.to_string(),
             extracted_references: references,
+        })
+    }
+
+    /// Build the kept sections in order, reporting each to `on_section` as it is built
+    ///
+    /// `texts` holds the raw text of every section. Text density and start
+    /// pages are judged on it for the whole document; `build` then turns the
+    /// index and text of each section within `page_range` into a [`PaperSection`].
+    fn select_sections(
+        &self,
+        texts: Vec<String>,
+        page_count: usize,
+        mut build: impl FnMut(usize, String) -> PaperSection,
+        on_section: &mut dyn FnMut(&PaperSection),
+    ) -> AppResult<Vec<PaperSection>> {
+        self.check_text_density(&texts)?;
+        let lengths: Vec<usize> = texts.iter().map(String::len).collect();
+        let start_pages = Self::estimate_start_pages(&lengths, page_count);
+
+        let mut sections = Vec::new();
+        for (i, (text, page)) in texts.into_iter().zip(start_pages).enumerate() {
+            if self
+                .config
+                .page_range
+                .is_some_and(|range| !range.contains(page))
+            {
+                continue;
+            }
+            let section = build(i, text);
+            on_section(&section);
+            sections.push(section);
         }
+        Ok(sections)
+    }
+
+    /// Build PaperText from OCR output, one section per page
+    #[cfg(feature = "ocr")]
+    async fn ocr_paper_text(
+        &self,
+        path: &str,
+        source_url: &str,
+        on_section: &mut dyn FnMut(&PaperSection),
+    ) -> AppResult<PaperText> {
+        let pages = super::ocr::ocr_pdf(Path::new(path)).await?;
+        // Judge the whole document, before page filtering
+        self.check_text_density(&pages).map_err(|_| {
+            AppError::PdfExtractionError(format!(
+                "OCR found too little text in {} page(s)",
+                pages.len()
+            ))
+        })?;
+        let sections: Vec<PaperSection> = pages
+            .iter()
+            .enumerate()
+            .filter(|(i, text)| {
+                !text.trim().is_empty()
+                    && self
+                        .config
+                        .page_range
+                        .is_none_or(|range| range.contains(i + 1))
+            })
+            .map(|(i, text)| {
+                let section = PaperSection {
+                    index: i as i16,
+                    title: format!("Page {}", i + 1),
+                    content: text.trim().to_string(),
+                    importance: SectionImportance::Medium,
+                    math_content: None,
+                    captions: None,
+                };
+                on_section(&section);
+                section
+            })
+            .collect();
+
+        Ok(PaperText {
+            plain_text: self.build_plain_text(&sections),
+            markdown: self.build_markdown(&sections),
+            sections,
+            extracted_at: Local::now(),
+            source_url: source_url.to_string(),
+            extracted_references: None,
+        })
+    }
+
+    /// Reject extractions with too little text to be a text-layer PDF
+    ///
+    /// Runs before any page-range filtering, so it judges the whole document.
+    fn check_text_density(&self, sections: &[impl AsRef<str>]) -> AppResult<()> {
+        let min_chars = self.config.min_text_chars;
+        if min_chars == 0 {
+            return Ok(());
+        }
+        let text_chars: usize = sections
+            .iter()
+            .map(|s| s.as_ref().chars().filter(|c| c.is_alphanumeric()).count())
+            .sum();
+        if text_chars < min_chars {
+            return Err(AppError::PdfExtractionError(format!(
+                "likely scanned/image PDF; OCR required (only {} text characters in {} sections, expected at least {})",
+                text_chars,
+                sections.len(),
+                min_chars
+            )));
+        }
+        Ok(())
     }
 
     /// Build a PaperSection from rsrpp Section with math and captions
-    fn build_paper_section(&self, s: &Section) -> PaperSection {
+    ///
+    /// `content` is the section's plain text (`Section::get_text`).
+    fn build_paper_section(&self, s: &Section, content: String) -> PaperSection {
         // Get math-marked content if include_math is enabled and math content differs from regular
         let math_content = if self.config.include_math {
             let math_text = s.get_math_text();
-            let regular_text = s.get_text();
             // Only include math_content if it's different from regular content
-            if math_text != regular_text {
+            if math_text != content {
                 Some(math_text)
             } else {
                 None
@@ -248,8 +696,8 @@ impl PdfExtractor {
         PaperSection {
             index: s.index,
             title: s.title.clone(),
-            content: s.get_text(),
-            importance: SectionImportance::from_title(&s.title),
+            content,
+            importance: self.config.section_importance(&s.title),
             math_content,
             captions,
         }
@@ -270,6 +718,29 @@ impl PdfExtractor {
         }
     }
 
+    /// Estimate the 1-based start page of each section
+    ///
+    /// Pages are assumed to hold roughly equal amounts of text, so a section
+    /// starting at a given fraction of the document's characters is placed at
+    /// the same fraction of `page_count`. `lengths` are the sections' text
+    /// lengths in bytes.
+    fn estimate_start_pages(lengths: &[usize], page_count: usize) -> Vec<usize> {
+        let total_chars: usize = lengths.iter().sum();
+        if total_chars == 0 || page_count == 0 {
+            return vec![1; lengths.len()];
+        }
+
+        let mut offset = 0;
+        lengths
+            .iter()
+            .map(|len| {
+                let page = offset * page_count / total_chars + 1;
+                offset += len;
+                page.min(page_count)
+            })
+            .collect()
+    }
+
     /// Build plain text from sections
     fn build_plain_text(&self, sections: &[PaperSection]) -> String {
         sections
@@ -280,13 +751,58 @@ impl PdfExtractor {
     }
 
     /// Build markdown from sections
+    ///
+    /// Heading levels follow the section numbering, and math-marked content
+    /// is used with `$...$`/`$$...$$` delimiters when `include_math` is set.
     fn build_markdown(&self, sections: &[PaperSection]) -> String {
         sections
             .iter()
-            .map(|s| format!("## {}\n\n{}", s.title, s.content))
+            .map(|s| {
+                let level = Self::heading_level(&s.title, self.config.markdown_base_level);
+                let body = match &s.math_content {
+                    Some(math) if self.config.include_math => Self::math_to_markdown(math),
+                    _ => s.content.clone(),
+                };
+                format!("{} {}\n\n{}", "#".repeat(level), s.title, body)
+            })
             .collect::<Vec<_>>()
             .join("\n\n")
     }
+
+    /// Heading level for a section title, one deeper per extra number component
+    ///
+    /// rsrpp does not expose section depth, so "3 Method" stays at `base`,
+    /// "3.1 Setup" becomes `base + 1`, and so on, capped at 6.
+    fn heading_level(title: &str, base: u8) -> usize {
+        let depth = SECTION_NUMBER_PATTERN
+            .captures(title)
+            .map(|caps| caps[1].matches('.').count())
+            .unwrap_or(0);
+        (base as usize + depth).clamp(1, 6)
+    }
+
+    /// Replace `<math>` tags with markdown math delimiters
+    ///
+    /// Math standing alone on its line becomes a `$$` display block; math
+    /// inside running text stays inline.
+    fn math_to_markdown(text: &str) -> String {
+        text.lines()
+            .map(|line| {
+                let trimmed = line.trim();
+                if let Some(caps) = MATH_TAG_PATTERN.captures(trimmed)
+                    && caps[0].len() == trimmed.len()
+                {
+                    return format!("$$\n{}\n$$", caps[1].trim());
+                }
+                MATH_TAG_PATTERN
+                    .replace_all(line, |caps: &regex::Captures| {
+                        format!("${}$", caps[1].trim())
+                    })
+                    .into_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 }
 
 impl Default for PdfExtractor {
@@ -298,12 +814,61 @@ impl Default for PdfExtractor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::test_server::serve_responses;
 
     #[test]
     fn test_extraction_config_default() {
         let config = ExtractionConfig::default();
         assert!(!config.verbose);
         assert!(config.cleanup);
+        assert_eq!(config.retry_count, DEFAULT_DOWNLOAD_RETRY_COUNT);
+    }
+
+    #[tokio::test]
+    async fn test_download_pdf_retries_server_errors() {
+        let (url, server) =
+            serve_responses(vec![(503, "busy"), (503, "busy"), (200, "%PDF-1.4")]).await;
+        let extractor = PdfExtractor::with_config(
+            ExtractionConfig::new()
+                .with_retry_count(2)
+                .with_retry_wait(Duration::from_millis(1)),
+        );
+
+        let bytes = extractor.download_pdf(&url).await.unwrap();
+        assert_eq!(bytes, b"%PDF-1.4");
+        assert_eq!(server.await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_download_pdf_gives_up_after_retries() {
+        let (url, _server) = serve_responses(vec![(503, "busy"), (503, "busy")]).await;
+        let extractor = PdfExtractor::with_config(
+            ExtractionConfig::new()
+                .with_retry_count(1)
+                .with_retry_wait(Duration::from_millis(1)),
+        );
+
+        match extractor.download_pdf(&url).await {
+            Err(AppError::PdfExtractionError(message)) => {
+                assert!(message.contains("after 2 attempts"));
+                assert!(message.contains("503"));
+            }
+            other => panic!(
+                "expected PdfExtractionError, got {:?}",
+                other.map(|b| b.len())
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_pdf_does_not_retry_not_found() {
+        let (url, server) = serve_responses(vec![(404, "")]).await;
+        let extractor = PdfExtractor::with_config(
+            ExtractionConfig::new().with_retry_wait(Duration::from_millis(1)),
+        );
+
+        assert!(extractor.download_pdf(&url).await.is_err());
+        assert_eq!(server.await.unwrap().len(), 1);
     }
 
     #[test]
@@ -319,6 +884,42 @@ mod tests {
         assert!(!config.extract_references);
     }
 
+    #[test]
+    fn test_importance_overrides() {
+        let default = ExtractionConfig::new();
+        assert_eq!(
+            default.section_importance("Related Work"),
+            SectionImportance::Medium
+        );
+
+        let config = ExtractionConfig::new().with_importance_overrides(HashMap::from([(
+            "related work".to_string(),
+            SectionImportance::Critical,
+        )]));
+        assert_eq!(
+            config.section_importance("Related Work"),
+            SectionImportance::Critical
+        );
+        assert_eq!(
+            config.section_importance(" RELATED WORK "),
+            SectionImportance::Critical
+        );
+        // Extracted headings keep their section numbers
+        assert_eq!(
+            config.section_importance("2 Related Work"),
+            SectionImportance::Critical
+        );
+        assert_eq!(
+            config.section_importance("2.1. Related Work"),
+            SectionImportance::Critical
+        );
+        // Titles without an override keep the heuristic
+        assert_eq!(
+            config.section_importance("Introduction"),
+            SectionImportance::from_title("Introduction")
+        );
+    }
+
     #[test]
     fn test_build_plain_text() {
         let extractor = PdfExtractor::new();
@@ -346,6 +947,42 @@ mod tests {
         assert!(plain.contains("\n\n"));
     }
 
+    #[test]
+    fn test_check_text_density() {
+        let section = |content: &str| content.to_string();
+        let extractor = PdfExtractor::with_config(ExtractionConfig::new().with_min_text_chars(100));
+
+        // Image-only pages: a few stray glyphs and whitespace
+        let scanned = vec![section("  . , ~ \n\n"), section("l1 |"), section("")];
+        match extractor.check_text_density(&scanned) {
+            Err(AppError::PdfExtractionError(message)) => {
+                assert!(message.contains("likely scanned/image PDF; OCR required"));
+                assert!(message.contains("only 2 text characters in 3 sections"));
+            }
+            other => panic!("expected PdfExtractionError, got {:?}", other),
+        }
+
+        let text = vec![
+            section(&"word ".repeat(15)),
+            section(&"more text ".repeat(5)),
+        ];
+        assert!(extractor.check_text_density(&text).is_ok());
+
+        // Exactly at the threshold passes; 0 disables the check
+        assert!(
+            extractor
+                .check_text_density(&[section(&"a".repeat(100))])
+                .is_ok()
+        );
+        let disabled = PdfExtractor::with_config(ExtractionConfig::new().with_min_text_chars(0));
+        assert!(disabled.check_text_density(&scanned).is_ok());
+        assert!(disabled.check_text_density::<String>(&[]).is_ok());
+        assert_eq!(
+            ExtractionConfig::default().min_text_chars,
+            DEFAULT_MIN_TEXT_CHARS
+        );
+    }
+
     #[test]
     fn test_build_markdown() {
         let extractor = PdfExtractor::new();
@@ -362,6 +999,203 @@ mod tests {
         assert!(md.contains("This is the abstract."));
     }
 
+    #[test]
+    fn test_heading_level_from_numbered_titles() {
+        assert_eq!(PdfExtractor::heading_level("Abstract", 2), 2);
+        assert_eq!(PdfExtractor::heading_level("3 Method", 2), 2);
+        assert_eq!(PdfExtractor::heading_level("3. Method", 2), 2);
+        assert_eq!(PdfExtractor::heading_level("3.1 Training Setup", 2), 3);
+        assert_eq!(PdfExtractor::heading_level("3.1.2 Optimizer", 2), 4);
+        assert_eq!(PdfExtractor::heading_level("A.1 Proofs", 1), 2);
+        assert_eq!(PdfExtractor::heading_level("2.1.1.1.1.1 Deep", 3), 6);
+    }
+
+    #[test]
+    fn test_build_markdown_heading_levels_and_math() {
+        let extractor =
+            PdfExtractor::with_config(ExtractionConfig::new().with_markdown_base_level(1));
+        let mut method = PaperSection::for_test(0, "3 Method", "");
+        method.content = "We minimize the loss.".to_string();
+        let mut setup = PaperSection::for_test(1, "3.1 Setup", "");
+        setup.content = "The loss L is defined below.".to_string();
+        setup.math_content = Some(
+            "The loss <math>L</math> is defined below.\n<math>L = \\sum_i l_i</math>".to_string(),
+        );
+
+        let md = extractor.build_markdown(&[method, setup]);
+        assert!(md.starts_with("# 3 Method\n\nWe minimize the loss."));
+        assert!(md.contains("## 3.1 Setup"));
+        assert!(md.contains("The loss $L$ is defined below."));
+        assert!(md.contains("$$\nL = \\sum_i l_i\n$$"));
+
+        let plain = PdfExtractor::with_config(ExtractionConfig::new().with_include_math(false));
+        let mut section = PaperSection::for_test(0, "Setup", "");
+        section.content = "plain".to_string();
+        section.math_content = Some("<math>x</math>".to_string());
+        assert_eq!(plain.build_markdown(&[section]), "## Setup\n\nplain");
+    }
+
+    #[test]
+    fn test_markdown_base_level_is_clamped() {
+        assert_eq!(
+            ExtractionConfig::new()
+                .with_markdown_base_level(0)
+                .markdown_base_level,
+            1
+        );
+        assert_eq!(
+            ExtractionConfig::new()
+                .with_markdown_base_level(9)
+                .markdown_base_level,
+            6
+        );
+    }
+
+    // Run select_sections over (title, chars) sections built with PaperSection::for_test
+    fn select(
+        extractor: &PdfExtractor,
+        sections: &[(&str, usize)],
+        page_count: usize,
+        on_section: &mut dyn FnMut(&PaperSection),
+    ) -> Vec<PaperSection> {
+        let texts = sections
+            .iter()
+            .map(|(_, chars)| "x".repeat(*chars))
+            .collect();
+        extractor
+            .select_sections(
+                texts,
+                page_count,
+                |i, _| PaperSection::for_test(i as i16, sections[i].0, "x".repeat(sections[i].1)),
+                on_section,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_select_sections_calls_back_per_section() {
+        let extractor = PdfExtractor::with_config(ExtractionConfig::new().with_min_text_chars(0));
+        let sections = [("Introduction", 100), ("Method", 100), ("Conclusion", 100)];
+
+        let mut seen = Vec::new();
+        let kept = select(&extractor, &sections, 3, &mut |s| {
+            seen.push((s.index, s.title.clone()))
+        });
+        assert_eq!(kept.len(), 3);
+        assert_eq!(
+            seen,
+            vec![
+                (0, "Introduction".to_string()),
+                (1, "Method".to_string()),
+                (2, "Conclusion".to_string()),
+            ]
+        );
+
+        // Each section is reported before the next one is built
+        let events = std::cell::RefCell::new(Vec::new());
+        extractor
+            .select_sections(
+                vec!["a".repeat(10), "b".repeat(10)],
+                1,
+                |i, text| {
+                    events.borrow_mut().push(format!("build {}", i));
+                    PaperSection::for_test(i as i16, "Body", "x".repeat(text.len()))
+                },
+                &mut |s| events.borrow_mut().push(format!("report {}", s.index)),
+            )
+            .unwrap();
+        assert_eq!(
+            events.into_inner(),
+            vec!["build 0", "report 0", "build 1", "report 1"]
+        );
+
+        // Sections outside the page range are neither built nor reported
+        let extractor = PdfExtractor::with_config(
+            ExtractionConfig::new()
+                .with_min_text_chars(0)
+                .with_page_range(3, None),
+        );
+        let mut seen = Vec::new();
+        select(&extractor, &sections, 3, &mut |s| seen.push(s.index));
+        assert_eq!(seen, vec![2]);
+    }
+
+    #[test]
+    fn test_select_sections_checks_density_before_building() {
+        let extractor = PdfExtractor::with_config(ExtractionConfig::new().with_min_text_chars(100));
+        let mut built = 0;
+        let result = extractor.select_sections(
+            vec!["  . , ~ ".to_string(), "l1 |".to_string()],
+            2,
+            |i, text| {
+                built += 1;
+                PaperSection::for_test(i as i16, "Body", "x".repeat(text.len()))
+            },
+            &mut |_| {},
+        );
+        assert!(matches!(result, Err(AppError::PdfExtractionError(_))));
+        assert_eq!(built, 0);
+    }
+
+    #[test]
+    fn test_page_range_from_str() {
+        assert_eq!(
+            "1-20".parse::<PageRange>().unwrap(),
+            PageRange {
+                start: 1,
+                end: Some(20)
+            }
+        );
+        assert_eq!(
+            "5-".parse::<PageRange>().unwrap(),
+            PageRange {
+                start: 5,
+                end: None
+            }
+        );
+        assert_eq!(
+            "3".parse::<PageRange>().unwrap(),
+            PageRange {
+                start: 3,
+                end: Some(3)
+            }
+        );
+        assert!("0-4".parse::<PageRange>().is_err());
+        assert!("10-2".parse::<PageRange>().is_err());
+        assert!("a-b".parse::<PageRange>().is_err());
+    }
+
+    #[test]
+    fn test_filter_by_page_range() {
+        // 10 pages, 1000 chars each: sections start on pages 1, 2, 5, 9 and 10
+        let sections = [
+            ("Abstract", 1000),
+            ("Introduction", 3000),
+            ("Method", 4000),
+            ("Conclusion", 1000),
+            ("Appendix", 1000),
+        ];
+        let lengths: Vec<usize> = sections.iter().map(|(_, chars)| *chars).collect();
+        assert_eq!(
+            PdfExtractor::estimate_start_pages(&lengths, 10),
+            vec![1, 2, 5, 9, 10]
+        );
+
+        let config = ExtractionConfig::new().with_min_text_chars(0);
+        let extractor = PdfExtractor::with_config(config.clone().with_page_range(1, Some(9)));
+        let kept = select(&extractor, &sections, 10, &mut |_| {});
+        let titles: Vec<&str> = kept.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["Abstract", "Introduction", "Method", "Conclusion"]
+        );
+
+        let extractor = PdfExtractor::with_config(config.with_page_range(5, None));
+        let kept = select(&extractor, &sections, 10, &mut |_| {});
+        let titles: Vec<&str> = kept.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Method", "Conclusion", "Appendix"]);
+    }
+
     #[test]
     fn test_get_pdf_url_open_access() {
         let extractor = PdfExtractor::new();
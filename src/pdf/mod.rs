@@ -4,7 +4,9 @@
 //! using the rsrpp crate.
 
 mod extractor;
+#[cfg(feature = "ocr")]
+mod ocr;
 mod resolver;
 
-pub use extractor::{ExtractionConfig, PdfExtractor};
+pub use extractor::{DEFAULT_MIN_TEXT_CHARS, ExtractionConfig, PageRange, PdfExtractor};
 pub use resolver::PdfUrlResolver;
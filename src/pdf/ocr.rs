@@ -0,0 +1,132 @@
+//! OCR fallback for scanned/image-only PDFs (`ocr` feature)
+//!
+//! Shells out to two external programs, which must be installed and on `PATH`:
+//!
+//! - `pdftoppm` (poppler-utils) renders each PDF page to a PNG image
+//! - `tesseract` recognizes the text in each page image
+//!
+//! On Debian/Ubuntu: `apt install poppler-utils tesseract-ocr`;
+//! on macOS: `brew install poppler tesseract`.
+
+use crate::shared::errors::{AppError, AppResult};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::process::Command;
+
+/// Resolution used to render pages for OCR (tesseract works best around 300 DPI)
+const OCR_RENDER_DPI: u32 = 300;
+
+/// Sequence number keeping concurrent OCR work directories unique within a process
+static OCR_DIR_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Recognize the text of every page of a local PDF, in page order
+pub(crate) async fn ocr_pdf(path: &Path) -> AppResult<Vec<String>> {
+    let work_dir = std::env::temp_dir().join(format!(
+        "academic-paper-interpreter-ocr-{}-{}",
+        std::process::id(),
+        OCR_DIR_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    tokio::fs::create_dir_all(&work_dir).await.map_err(|e| {
+        AppError::PdfExtractionError(format!(
+            "Failed to create OCR work directory {}: {}",
+            work_dir.display(),
+            e
+        ))
+    })?;
+
+    let result = ocr_pages(path, &work_dir).await;
+    if let Err(e) = tokio::fs::remove_dir_all(&work_dir).await {
+        tracing::warn!(
+            "Failed to remove OCR work directory {}: {}",
+            work_dir.display(),
+            e
+        );
+    }
+    result
+}
+
+/// Render the pages into `work_dir` and OCR each image
+async fn ocr_pages(path: &Path, work_dir: &Path) -> AppResult<Vec<String>> {
+    let prefix = work_dir.join("page");
+    run_tool(
+        Command::new("pdftoppm")
+            .arg("-r")
+            .arg(OCR_RENDER_DPI.to_string())
+            .arg("-png")
+            .arg(path)
+            .arg(&prefix),
+        "pdftoppm",
+        "poppler-utils",
+    )
+    .await?;
+
+    let mut images: Vec<PathBuf> = std::fs::read_dir(work_dir)
+        .map_err(|e| AppError::PdfExtractionError(format!("Failed to list page images: {}", e)))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "png"))
+        .collect();
+    // pdftoppm zero-pads page numbers to a common width, so names sort in page order
+    images.sort();
+
+    let mut pages = Vec::with_capacity(images.len());
+    for image in &images {
+        let stdout = run_tool(
+            Command::new("tesseract").arg(image).arg("stdout"),
+            "tesseract",
+            "tesseract-ocr",
+        )
+        .await?;
+        pages.push(String::from_utf8_lossy(&stdout).into_owned());
+    }
+    Ok(pages)
+}
+
+/// Run an external tool and return its stdout, mapping failures to extraction errors
+async fn run_tool(command: &mut Command, tool: &str, package: &str) -> AppResult<Vec<u8>> {
+    let output = command.output().await.map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            AppError::PdfExtractionError(format!(
+                "OCR requires `{}` on PATH (install {})",
+                tool, package
+            ))
+        } else {
+            AppError::PdfExtractionError(format!("Failed to run {}: {}", tool, e))
+        }
+    })?;
+
+    if !output.status.success() {
+        return Err(AppError::PdfExtractionError(format!(
+            "{} failed ({}): {}",
+            tool,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(output.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Image-only PDF whose single page shows "HELLO OCR" in large block letters
+    const SCANNED_FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/scanned_hello_ocr.pdf"
+    );
+
+    #[tokio::test]
+    #[ignore = "requires pdftoppm and tesseract on PATH"]
+    async fn test_ocr_pdf_reads_image_fixture() {
+        let pages = ocr_pdf(Path::new(SCANNED_FIXTURE)).await.unwrap();
+        assert_eq!(pages.len(), 1);
+        let text = pages[0].to_uppercase();
+        assert!(text.contains("HELLO"), "OCR output: {:?}", pages[0]);
+    }
+
+    #[tokio::test]
+    async fn test_ocr_pdf_missing_file_is_extraction_error() {
+        let result = ocr_pdf(Path::new("/nonexistent/scan.pdf")).await;
+        assert!(matches!(result, Err(AppError::PdfExtractionError(_))));
+    }
+}
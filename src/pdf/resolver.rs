@@ -41,9 +41,9 @@ impl<'a> PdfUrlResolver<'a> {
         }
 
         // 2. Try arXiv PDF URL
-        if !paper.arxiv_id.is_empty() {
+        if let Some(url) = paper.arxiv_pdf_url() {
             tracing::debug!("PDF URL resolved via arXiv ID: {}", paper.arxiv_id);
-            return Ok(format!("https://arxiv.org/pdf/{}", paper.arxiv_id));
+            return Ok(url);
         }
 
         // 3. Try Semantic Scholar re-fetch
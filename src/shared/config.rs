@@ -1,7 +1,44 @@
 //! Configuration management for the library
 
 use crate::shared::errors::{AppError, AppResult};
+use crate::shared::utils::parse_duration;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How long Ollama keeps a model loaded after a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OllamaKeepAlive {
+    /// Unload the model after this long
+    For(Duration),
+    /// Never unload the model (Ollama's `-1`)
+    Forever,
+}
+
+impl OllamaKeepAlive {
+    /// Parse a keep-alive value: `-1` (any negative value) keeps the model
+    /// loaded forever, anything else is a [`parse_duration`] duration
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().strip_prefix('-') {
+            Some(negative) => parse_duration(negative).map(|_| Self::Forever),
+            None => parse_duration(value).map(Self::For),
+        }
+    }
+
+    /// Value of the `keep_alive` request field
+    pub fn to_request_value(self) -> serde_json::Value {
+        match self {
+            Self::For(duration) => format!("{}s", duration.as_secs()).into(),
+            Self::Forever => (-1).into(),
+        }
+    }
+}
+
+impl From<Duration> for OllamaKeepAlive {
+    fn from(duration: Duration) -> Self {
+        Self::For(duration)
+    }
+}
 
 /// Type of LLM provider to use
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -10,18 +47,35 @@ pub enum LlmProviderType {
     /// OpenAI API (GPT-4, etc.)
     #[default]
     OpenAi,
+    /// Azure OpenAI deployments
+    #[serde(rename = "azure-openai")]
+    AzureOpenAi,
+    /// Any OpenAI-compatible Chat Completions endpoint (DeepSeek, Groq, vLLM, ...)
+    #[serde(rename = "openai-compatible")]
+    OpenAiCompatible,
     /// Anthropic API (Claude)
     Anthropic,
+    /// AWS Bedrock (Claude on Bedrock)
+    Bedrock,
+    /// Cohere API (Command R)
+    Cohere,
     /// Ollama (local LLMs)
     Ollama,
+    /// Offline mock with canned responses (demos and CI only)
+    Mock,
 }
 
 impl std::fmt::Display for LlmProviderType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             LlmProviderType::OpenAi => write!(f, "openai"),
+            LlmProviderType::AzureOpenAi => write!(f, "azure-openai"),
+            LlmProviderType::OpenAiCompatible => write!(f, "openai-compatible"),
             LlmProviderType::Anthropic => write!(f, "anthropic"),
+            LlmProviderType::Bedrock => write!(f, "bedrock"),
+            LlmProviderType::Cohere => write!(f, "cohere"),
             LlmProviderType::Ollama => write!(f, "ollama"),
+            LlmProviderType::Mock => write!(f, "mock"),
         }
     }
 }
@@ -35,18 +89,60 @@ pub struct Config {
     /// OpenAI model (default: gpt-5.2)
     pub openai_model: Option<String>,
 
+    /// Azure OpenAI resource endpoint
+    pub azure_openai_endpoint: Option<String>,
+
+    /// Azure OpenAI API key
+    pub azure_openai_api_key: Option<String>,
+
+    /// Azure OpenAI deployment name
+    pub azure_openai_deployment: Option<String>,
+
+    /// Azure OpenAI `api-version` query parameter (default: 2024-10-21)
+    pub azure_openai_api_version: Option<String>,
+
+    /// OpenAI-compatible endpoint base URL (e.g. https://api.deepseek.com/v1)
+    pub openai_compat_base_url: Option<String>,
+
+    /// OpenAI-compatible endpoint API key
+    pub openai_compat_api_key: Option<String>,
+
+    /// OpenAI-compatible endpoint model
+    pub openai_compat_model: Option<String>,
+
     /// Anthropic API key
     pub anthropic_api_key: Option<String>,
 
     /// Anthropic model (default: claude-sonnet-4-20250514)
     pub anthropic_model: Option<String>,
 
+    /// AWS region for Bedrock
+    pub aws_region: Option<String>,
+
+    /// Bedrock model ID (default: anthropic.claude-3-5-sonnet-20240620-v1:0)
+    pub bedrock_model_id: Option<String>,
+
+    /// Cohere API key
+    pub cohere_api_key: Option<String>,
+
+    /// Cohere model (default: command-r-plus)
+    pub cohere_model: Option<String>,
+
     /// Ollama base URL (default: http://localhost:11434)
     pub ollama_base_url: Option<String>,
 
     /// Default Ollama model
     pub ollama_model: Option<String>,
 
+    /// How long Ollama keeps the model loaded after a request
+    pub ollama_keep_alive: Option<OllamaKeepAlive>,
+
+    /// Ollama context window size in tokens (`num_ctx`)
+    pub ollama_num_ctx: Option<u32>,
+
+    /// Semantic Scholar API key (higher rate limits)
+    pub semantic_scholar_api_key: Option<String>,
+
     /// Default LLM provider to use
     pub default_llm_provider: LlmProviderType,
 
@@ -58,6 +154,9 @@ pub struct Config {
 
     /// Wait time between retries (seconds)
     pub retry_wait_time: u64,
+
+    /// Write export timestamps in UTC (`Z`) instead of the local offset
+    pub use_utc: bool,
 }
 
 impl Default for Config {
@@ -65,14 +164,29 @@ impl Default for Config {
         Self {
             openai_api_key: None,
             openai_model: None,
+            azure_openai_endpoint: None,
+            azure_openai_api_key: None,
+            azure_openai_deployment: None,
+            azure_openai_api_version: None,
+            openai_compat_base_url: None,
+            openai_compat_api_key: None,
+            openai_compat_model: None,
             anthropic_api_key: None,
             anthropic_model: None,
+            aws_region: None,
+            bedrock_model_id: None,
+            cohere_api_key: None,
+            cohere_model: None,
             ollama_base_url: None,
             ollama_model: None,
+            ollama_keep_alive: None,
+            ollama_num_ctx: None,
+            semantic_scholar_api_key: None,
             default_llm_provider: LlmProviderType::default(),
             default_model: None,
             retry_count: 3,
             retry_wait_time: 5,
+            use_utc: true,
         }
     }
 }
@@ -88,10 +202,24 @@ impl Config {
         Ok(Self {
             openai_api_key: std::env::var("OPENAI_API_KEY").ok(),
             openai_model: std::env::var("OPENAI_MODEL").ok(),
+            azure_openai_endpoint: std::env::var("AZURE_OPENAI_ENDPOINT").ok(),
+            azure_openai_api_key: std::env::var("AZURE_OPENAI_API_KEY").ok(),
+            azure_openai_deployment: std::env::var("AZURE_OPENAI_DEPLOYMENT").ok(),
+            azure_openai_api_version: std::env::var("AZURE_OPENAI_API_VERSION").ok(),
+            openai_compat_base_url: std::env::var("OPENAI_COMPAT_BASE_URL").ok(),
+            openai_compat_api_key: std::env::var("OPENAI_COMPAT_API_KEY").ok(),
+            openai_compat_model: std::env::var("OPENAI_COMPAT_MODEL").ok(),
             anthropic_api_key: std::env::var("ANTHROPIC_API_KEY").ok(),
             anthropic_model: std::env::var("ANTHROPIC_MODEL").ok(),
+            aws_region: std::env::var("AWS_REGION").ok(),
+            bedrock_model_id: std::env::var("BEDROCK_MODEL_ID").ok(),
+            cohere_api_key: std::env::var("COHERE_API_KEY").ok(),
+            cohere_model: std::env::var("COHERE_MODEL").ok(),
             ollama_base_url: std::env::var("OLLAMA_BASE_URL").ok(),
             ollama_model: std::env::var("OLLAMA_MODEL").ok(),
+            ollama_keep_alive: Self::ollama_keep_alive_from_env(),
+            ollama_num_ctx: Self::ollama_num_ctx_from_env(),
+            semantic_scholar_api_key: std::env::var("SEMANTIC_SCHOLAR_API_KEY").ok(),
             default_llm_provider: Self::parse_provider_from_env()?,
             default_model: std::env::var("LLM_MODEL").ok(),
             retry_count: std::env::var("API_RETRY_COUNT")
@@ -102,17 +230,55 @@ impl Config {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(5),
+            use_utc: Self::use_utc_from_env(),
         })
     }
 
-    /// Parse LLM provider type from environment
-    fn parse_provider_from_env() -> AppResult<LlmProviderType> {
+    /// Read `OLLAMA_KEEP_ALIVE`, warning about and ignoring invalid values
+    pub fn ollama_keep_alive_from_env() -> Option<OllamaKeepAlive> {
+        let value = std::env::var("OLLAMA_KEEP_ALIVE").ok()?;
+        let keep_alive = OllamaKeepAlive::parse(&value);
+        if keep_alive.is_none() {
+            tracing::warn!("Ignoring invalid OLLAMA_KEEP_ALIVE: {:?}", value);
+        }
+        keep_alive
+    }
+
+    /// Read `OLLAMA_NUM_CTX`, warning about and ignoring invalid values
+    pub fn ollama_num_ctx_from_env() -> Option<u32> {
+        let value = std::env::var("OLLAMA_NUM_CTX").ok()?;
+        let num_ctx = value.trim().parse().ok();
+        if num_ctx.is_none() {
+            tracing::warn!("Ignoring invalid OLLAMA_NUM_CTX: {:?}", value);
+        }
+        num_ctx
+    }
+
+    /// Read the `EXPORT_UTC` setting alone (UTC unless "0", "false" or "no")
+    ///
+    /// Lets exports honor the timestamp setting without loading the rest of
+    /// the configuration, which fails on an invalid `LLM_PROVIDER`.
+    pub fn use_utc_from_env() -> bool {
+        std::env::var("EXPORT_UTC")
+            .map(|s| !matches!(s.to_lowercase().as_str(), "0" | "false" | "no"))
+            .unwrap_or(true)
+    }
+
+    /// Parse LLM provider type from `LLM_PROVIDER` (default when unset)
+    ///
+    /// Fails with [`AppError::ConfigError`] on an unknown provider name.
+    pub fn parse_provider_from_env() -> AppResult<LlmProviderType> {
         match std::env::var("LLM_PROVIDER").as_deref() {
             Ok("openai") => Ok(LlmProviderType::OpenAi),
+            Ok("azure-openai") => Ok(LlmProviderType::AzureOpenAi),
+            Ok("openai-compatible") => Ok(LlmProviderType::OpenAiCompatible),
             Ok("anthropic") => Ok(LlmProviderType::Anthropic),
+            Ok("bedrock") => Ok(LlmProviderType::Bedrock),
+            Ok("cohere") => Ok(LlmProviderType::Cohere),
             Ok("ollama") => Ok(LlmProviderType::Ollama),
+            Ok("mock") => Ok(LlmProviderType::Mock),
             Ok(other) => Err(AppError::ConfigError(format!(
-                "Unknown LLM provider: {}. Valid options: openai, anthropic, ollama",
+                "Unknown LLM provider: {}. Valid options: openai, azure-openai, openai-compatible, anthropic, bedrock, cohere, ollama, mock",
                 other
             ))),
             Err(_) => Ok(LlmProviderType::default()),
@@ -124,11 +290,33 @@ impl Config {
         self.openai_api_key.is_some()
     }
 
+    /// Check if Azure OpenAI is configured
+    pub fn has_azure_openai(&self) -> bool {
+        self.azure_openai_endpoint.is_some()
+            && self.azure_openai_api_key.is_some()
+            && self.azure_openai_deployment.is_some()
+    }
+
+    /// Check if an OpenAI-compatible endpoint is configured
+    pub fn has_openai_compatible(&self) -> bool {
+        self.openai_compat_base_url.is_some() && self.openai_compat_model.is_some()
+    }
+
     /// Check if Anthropic is configured
     pub fn has_anthropic(&self) -> bool {
         self.anthropic_api_key.is_some()
     }
 
+    /// Check if Bedrock is configured (credentials are resolved by the AWS SDK)
+    pub fn has_bedrock(&self) -> bool {
+        self.aws_region.is_some()
+    }
+
+    /// Check if Cohere is configured
+    pub fn has_cohere(&self) -> bool {
+        self.cohere_api_key.is_some()
+    }
+
     /// Check if Ollama is available (assumes local availability)
     pub fn has_ollama(&self) -> bool {
         // Ollama is assumed to be available if configured or at default location
@@ -174,15 +362,112 @@ impl Config {
     }
 }
 
+/// HTTP settings shared by the network clients
+///
+/// Applied to every `reqwest::Client` this crate builds itself: CrossRef,
+/// DBLP, Unpaywall, arXiv feeds, Semantic Scholar search and author endpoints
+/// and PDF downloads (via `PaperClient::with_http_config`) and the
+/// Ollama/Cohere providers.
+///
+/// Known gaps: arXiv keyword searches and ID lookups (`arxiv-tools`),
+/// Semantic Scholar paper details (`ss-tools`) and the OpenAI/Anthropic/Bedrock
+/// SDKs create their own HTTP clients and do not accept one. Those still
+/// honour the standard `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` environment
+/// variables, so set them as well behind a proxy.
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfig {
+    /// Proxy URL for all requests (e.g. `http://proxy.example.com:8080`)
+    pub proxy: Option<String>,
+
+    /// Total request timeout
+    pub timeout: Option<Duration>,
+
+    /// `User-Agent` header value
+    pub user_agent: Option<String>,
+}
+
+impl ClientConfig {
+    /// Create an empty configuration (reqwest defaults)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Route all requests through a proxy
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Set the total request timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the `User-Agent` header
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Build a `reqwest::Client` with these settings
+    ///
+    /// Fails with [`AppError::ConfigError`] on an invalid proxy URL.
+    pub fn build_client(&self) -> AppResult<reqwest::Client> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(ref proxy) = self.proxy {
+            let proxy = reqwest::Proxy::all(proxy).map_err(|e| {
+                AppError::ConfigError(format!("Invalid proxy URL '{}': {}", proxy, e))
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(ref user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        builder
+            .build()
+            .map_err(|e| AppError::ConfigError(format!("Failed to build HTTP client: {}", e)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ollama_keep_alive_parse() {
+        assert_eq!(
+            OllamaKeepAlive::parse("10m"),
+            Some(OllamaKeepAlive::For(Duration::from_secs(600)))
+        );
+        assert_eq!(OllamaKeepAlive::parse("-1"), Some(OllamaKeepAlive::Forever));
+        assert_eq!(
+            OllamaKeepAlive::parse(" -5m "),
+            Some(OllamaKeepAlive::Forever)
+        );
+        for invalid in ["", "-", "forever", "1.5h"] {
+            assert_eq!(OllamaKeepAlive::parse(invalid), None, "{:?}", invalid);
+        }
+
+        assert_eq!(
+            OllamaKeepAlive::Forever.to_request_value(),
+            serde_json::json!(-1)
+        );
+        assert_eq!(
+            OllamaKeepAlive::For(Duration::from_secs(90)).to_request_value(),
+            serde_json::json!("90s")
+        );
+    }
+
     #[test]
     fn test_default_config() {
         let config = Config::default();
         assert_eq!(config.default_llm_provider, LlmProviderType::OpenAi);
         assert_eq!(config.retry_count, 3);
+        assert!(config.use_utc);
     }
 
     #[test]
@@ -197,6 +482,26 @@ mod tests {
         assert_eq!(config.default_model, Some("claude-3-opus".to_string()));
     }
 
+    #[test]
+    fn test_client_config_builds_with_proxy() {
+        let config = ClientConfig::new()
+            .with_proxy("http://proxy.example.com:8080")
+            .with_timeout(Duration::from_secs(30))
+            .with_user_agent("academic-paper-interpreter/test");
+
+        assert!(config.build_client().is_ok());
+        assert!(ClientConfig::new().build_client().is_ok());
+    }
+
+    #[test]
+    fn test_client_config_rejects_invalid_proxy() {
+        let err = ClientConfig::new()
+            .with_proxy("not a url")
+            .build_client()
+            .unwrap_err();
+        assert!(matches!(err, AppError::ConfigError(_)));
+    }
+
     #[test]
     fn test_ollama_url() {
         let config = Config::default();
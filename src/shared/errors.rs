@@ -1,7 +1,20 @@
 //! Error types for the library
 
+use regex::Regex;
+use std::sync::LazyLock;
+use std::time::Duration;
 use thiserror::Error;
 
+/// HTTP status codes embedded in provider error messages (e.g. "error: 429 Too Many
+/// Requests", "status 401", "HTTP/1.1 503"); a code only counts when it directly follows
+/// the start of the message or a status, HTTP, error or code marker
+static HTTP_STATUS_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?:^|\berror\s*:|\bstatus(?:\s+code)?\s*[:=]?|\bhttp(?:/[\d.]+)?|\bcode\s*[:=]?)\s*([45]\d{2})\b",
+    )
+    .expect("valid status regex")
+});
+
 /// Main error type for the library
 #[derive(Error, Debug)]
 pub enum AppError {
@@ -17,14 +30,37 @@ pub enum AppError {
     #[error("Semantic Scholar API error: {0}")]
     SemanticScholarError(String),
 
-    /// LLM provider error
+    /// LLM provider error (catch-all)
     #[error("LLM error: {0}")]
     LlmError(String),
 
+    /// LLM provider rate limit (HTTP 429)
+    #[error("LLM rate limited{}", retry_after_suffix(.retry_after))]
+    LlmRateLimited {
+        /// Delay requested by the provider, if any
+        retry_after: Option<Duration>,
+    },
+
+    /// LLM authentication/authorization failure (HTTP 401/403)
+    #[error("LLM authentication failed: {0}")]
+    LlmAuth(String),
+
+    /// LLM provider server error (HTTP 5xx)
+    #[error("LLM server error: HTTP {0}")]
+    LlmServer(u16),
+
+    /// LLM response did not contain the expected JSON structure
+    #[error("LLM response could not be parsed: {0}")]
+    LlmResponseParse(String),
+
     /// Paper not found
     #[error("Paper not found: {0}")]
     PaperNotFound(String),
 
+    /// Every queried source failed (as opposed to returning no matches)
+    #[error("All paper sources failed: {}", .0.join("; "))]
+    AllSourcesFailed(Vec<String>),
+
     /// Analysis failed
     #[error("Analysis failed: {0}")]
     AnalysisError(String),
@@ -33,6 +69,14 @@ pub enum AppError {
     #[error("PDF extraction failed: {0}")]
     PdfExtractionError(String),
 
+    /// CrossRef API error
+    #[error("CrossRef API error: {0}")]
+    CrossRefError(String),
+
+    /// DBLP API error
+    #[error("DBLP API error: {0}")]
+    DblpError(String),
+
     /// Unpaywall API error
     #[error("Unpaywall API error: {0}")]
     UnpaywallError(String),
@@ -70,6 +114,61 @@ impl From<String> for AppError {
     }
 }
 
+impl AppError {
+    /// Classify an LLM provider HTTP failure by status code
+    pub fn from_llm_status(
+        status: u16,
+        retry_after: Option<Duration>,
+        message: impl Into<String>,
+    ) -> Self {
+        match status {
+            429 => AppError::LlmRateLimited { retry_after },
+            401 | 403 => AppError::LlmAuth(message.into()),
+            500..=599 => AppError::LlmServer(status),
+            _ => AppError::LlmError(message.into()),
+        }
+    }
+
+    /// Classify an LLM provider failure from its error message
+    ///
+    /// Used for client crates that only expose errors as text; the first
+    /// 4xx/5xx status code in a status context decides the variant. Other
+    /// numbers (token counts, positions) are ignored.
+    pub fn from_llm_message(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let status = HTTP_STATUS_PATTERN
+            .captures(&message)
+            .and_then(|c| c[1].parse::<u16>().ok());
+
+        match status {
+            Some(status) => Self::from_llm_status(status, None, message),
+            None if message.to_lowercase().contains("rate limit") => {
+                AppError::LlmRateLimited { retry_after: None }
+            }
+            None => AppError::LlmError(message),
+        }
+    }
+
+    /// Whether the failure is transient and the request may be retried
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            AppError::LlmRateLimited { .. } | AppError::LlmServer(_)
+        )
+    }
+}
+
+/// Parse a `Retry-After` header value given in seconds
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+fn retry_after_suffix(retry_after: &Option<Duration>) -> String {
+    retry_after
+        .map(|d| format!(" (retry after {}s)", d.as_secs()))
+        .unwrap_or_default()
+}
+
 /// Result type alias using AppError
 pub type AppResult<T> = Result<T, AppError>;
 
@@ -98,4 +197,72 @@ mod tests {
         let llm_error = AppError::LlmError("rate limit exceeded".to_string());
         assert!(llm_error.to_string().contains("LLM"));
     }
+
+    #[test]
+    fn test_llm_status_classification() {
+        let error = AppError::from_llm_status(429, Some(Duration::from_secs(30)), "slow down");
+        assert!(matches!(
+            error,
+            AppError::LlmRateLimited {
+                retry_after: Some(d)
+            } if d == Duration::from_secs(30)
+        ));
+        assert!(error.is_retryable());
+        assert_eq!(error.to_string(), "LLM rate limited (retry after 30s)");
+
+        let error = AppError::from_llm_status(401, None, "invalid api key");
+        assert!(matches!(error, AppError::LlmAuth(_)));
+        assert!(!error.is_retryable());
+
+        let error = AppError::from_llm_status(503, None, "unavailable");
+        assert!(matches!(error, AppError::LlmServer(503)));
+        assert!(error.is_retryable());
+
+        let error = AppError::from_llm_status(400, None, "bad request");
+        assert!(matches!(error, AppError::LlmError(_)));
+    }
+
+    #[test]
+    fn test_llm_message_classification() {
+        let error = AppError::from_llm_message("OpenAI API error: 429 Too Many Requests");
+        assert!(matches!(
+            error,
+            AppError::LlmRateLimited { retry_after: None }
+        ));
+
+        let error =
+            AppError::from_llm_message("Anthropic API error: status 401, invalid x-api-key");
+        assert!(matches!(error, AppError::LlmAuth(_)));
+
+        let error = AppError::from_llm_message("OpenAI API error: connection reset");
+        assert!(matches!(error, AppError::LlmError(_)));
+    }
+
+    #[test]
+    fn test_llm_message_ignores_numbers_outside_status_context() {
+        let error = AppError::from_llm_message("HTTP 429 Too Many Requests");
+        assert!(matches!(error, AppError::LlmRateLimited { .. }));
+
+        let error = AppError::from_llm_message("request failed with status: 503");
+        assert!(matches!(error, AppError::LlmServer(503)));
+
+        let error = AppError::from_llm_message("OpenAI API error: error code: 401");
+        assert!(matches!(error, AppError::LlmAuth(_)));
+
+        let error = AppError::from_llm_message(
+            "OpenAI API error: input has 413 tokens, invalid character at position 500",
+        );
+        assert!(matches!(error, AppError::LlmError(_)));
+
+        let error = AppError::from_llm_message(
+            "Anthropic API error: model claude-3-5-sonnet-20241022 returned 404 items",
+        );
+        assert!(matches!(error, AppError::LlmError(_)));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("12"), Some(Duration::from_secs(12)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
 }
@@ -18,7 +18,9 @@ pub fn init_logger<T: AsRef<str>>(log_level: T) -> AppResult<()> {
     let subscriber = tracing_subscriber::fmt::layer()
         .with_file(true)
         .with_line_number(true)
-        .with_target(false);
+        .with_target(false)
+        // Keep stdout for command output (JSON, MCP messages)
+        .with_writer(std::io::stderr);
     tracing_subscriber::registry()
         .with(subscriber)
         .with(env_filter)
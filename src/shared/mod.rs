@@ -3,4 +3,6 @@
 pub mod config;
 pub mod errors;
 pub mod logger;
+#[cfg(test)]
+pub(crate) mod test_server;
 pub mod utils;
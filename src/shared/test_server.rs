@@ -0,0 +1,38 @@
+//! One-shot local HTTP server for tests
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::task::JoinHandle;
+
+/// Serve `(status, body)` responses in order, one per connection
+///
+/// Returns the server's base URL (`http://127.0.0.1:<port>`) and a handle
+/// resolving to each request received (request line first, then headers).
+pub(crate) async fn serve_responses(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, JoinHandle<Vec<String>>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for (status, body) in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            requests.push(String::from_utf8_lossy(&buf[..n]).to_string());
+            let response = format!(
+                "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+        requests
+    });
+    (url, handle)
+}
+
+/// Request line of a request returned by [`serve_responses`]
+pub(crate) fn request_line(request: &str) -> &str {
+    request.lines().next().unwrap_or_default()
+}
@@ -1,5 +1,22 @@
 use chrono::{DateTime, Local, TimeZone};
 use indicatif::{ProgressBar, ProgressStyle};
+use regex::Regex;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// Prefixes DOIs commonly arrive with, matched case-insensitively
+const DOI_PREFIXES: [&str; 6] = [
+    "https://doi.org/",
+    "http://doi.org/",
+    "https://dx.doi.org/",
+    "http://dx.doi.org/",
+    "doi.org/",
+    "doi:",
+];
+
+/// A bare DOI: "10.", a numeric registrant code, "/", and a non-empty suffix
+static DOI_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^10\.\d+(?:\.\d+)*/\S+$").expect("valid DOI regex"));
 
 /// Creates and configures a new `ProgressBar` with a custom style and optional message.
 ///
@@ -120,3 +137,121 @@ pub fn datetime_from_str(date_str: &str) -> DateTime<Local> {
         }
     }
 }
+
+/// Parses a duration such as `300`, `45s`, `10m` or `1h`.
+///
+/// A bare number is read as seconds.
+///
+/// # Arguments
+///
+/// * `value` - The duration text, e.g. from an environment variable.
+///
+/// # Returns
+///
+/// The parsed `Duration`, or `None` if the text is not a non-negative whole
+/// number with an optional `s`, `m` or `h` suffix.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(parse_duration("10m"), Some(Duration::from_secs(600)));
+/// ```
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (number, unit_secs) = match value.char_indices().last()? {
+        (i, 's') => (&value[..i], 1),
+        (i, 'm') => (&value[..i], 60),
+        (i, 'h') => (&value[..i], 3600),
+        _ => (value, 1),
+    };
+    let count: u64 = number.trim().parse().ok()?;
+    Some(Duration::from_secs(count.checked_mul(unit_secs)?))
+}
+
+/// Normalizes a DOI to its bare, lowercase form.
+///
+/// Strips `https://doi.org/`, `dx.doi.org` and `doi:` prefixes and surrounding
+/// whitespace, then lowercases the result (DOIs are case-insensitive), so the
+/// same DOI compares equal however it was written.
+///
+/// # Arguments
+///
+/// * `raw` - A DOI as found in metadata or user input.
+///
+/// # Returns
+///
+/// The normalized DOI, or `None` if the input does not have the `10.NNNN/suffix` shape.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(normalize_doi("https://doi.org/10.1145/3065386"), Some("10.1145/3065386".to_string()));
+/// assert_eq!(normalize_doi("not a doi"), None);
+/// ```
+pub fn normalize_doi(raw: &str) -> Option<String> {
+    let mut doi = raw.trim();
+    for prefix in DOI_PREFIXES {
+        if let Some(head) = doi.get(..prefix.len())
+            && head.eq_ignore_ascii_case(prefix)
+        {
+            doi = doi[prefix.len()..].trim_start();
+            break;
+        }
+    }
+    let doi = doi.to_lowercase();
+    DOI_PATTERN.is_match(&doi).then_some(doi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("300"), Some(Duration::from_secs(300)));
+        assert_eq!(parse_duration("45s"), Some(Duration::from_secs(45)));
+        assert_eq!(parse_duration(" 10m "), Some(Duration::from_secs(600)));
+        assert_eq!(parse_duration("1h"), Some(Duration::from_secs(3600)));
+        for invalid in ["", "m", "-1", "1.5h", "10d", "ten"] {
+            assert_eq!(parse_duration(invalid), None, "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_normalize_doi_forms() {
+        for raw in [
+            "10.1145/3065386",
+            "  10.1145/3065386\n",
+            "https://doi.org/10.1145/3065386",
+            "http://dx.doi.org/10.1145/3065386",
+            "doi.org/10.1145/3065386",
+            "doi:10.1145/3065386",
+            "DOI: 10.1145/3065386",
+        ] {
+            assert_eq!(
+                normalize_doi(raw).as_deref(),
+                Some("10.1145/3065386"),
+                "{:?}",
+                raw
+            );
+        }
+        assert_eq!(
+            normalize_doi("https://doi.org/10.48550/arXiv.1706.03762").as_deref(),
+            Some("10.48550/arxiv.1706.03762")
+        );
+    }
+
+    #[test]
+    fn test_normalize_doi_invalid() {
+        for raw in [
+            "",
+            "doi:",
+            "1706.03762",
+            "10.1145",
+            "10.abc/xyz",
+            "https://arxiv.org/abs/1706.03762",
+        ] {
+            assert_eq!(normalize_doi(raw), None, "{:?}", raw);
+        }
+    }
+}
@@ -124,6 +124,41 @@ mod openai {
         println!("{}", truncate_str(&summary, 500));
     }
 
+    #[tokio::test]
+    #[ignore = "APIキーが必要"]
+    async fn test_openai_extract_keywords() {
+        let Some(provider) = skip_if_no_api_key() else {
+            eprintln!("OPENAI_API_KEY が設定されていないためスキップ");
+            return;
+        };
+
+        let analyzer = PaperAnalyzer::new(provider).with_model("gpt-4o-mini");
+
+        let paper = create_sample_paper();
+        let result = analyzer.extract_keywords(&paper).await;
+
+        assert!(result.is_ok(), "キーワード抽出エラー: {:?}", result.err());
+
+        let keywords = result.unwrap();
+        assert!(!keywords.keywords.is_empty(), "キーワードが空");
+
+        let result = analyzer
+            .extract_research_context(&paper, &keywords.keywords)
+            .await;
+
+        assert!(
+            result.is_ok(),
+            "研究コンテキスト抽出エラー: {:?}",
+            result.err()
+        );
+
+        let context = result.unwrap();
+        assert!(!context.primary_field.is_empty(), "研究分野が空");
+        println!("=== OpenAI キーワード ===");
+        println!("キーワード: {:?}", keywords.keywords);
+        println!("研究分野: {}", context.primary_field);
+    }
+
     #[tokio::test]
     #[ignore = "APIキーが必要"]
     async fn test_openai_translate_to_japanese() {